@@ -0,0 +1,221 @@
+// Generates the `FluentIcon` enum from WinUI Gallery's icon list
+// (https://github.com/microsoft/WinUI-Gallery/blob/main/WinUIGallery/DataModel/IconsData.json)
+// and the table of SVG icons embedded from assets/icons.
+// The generated files live in OUT_DIR so the editor never has to index them.
+use std::{env, fmt::Write, fs, path::Path};
+
+// Rebuilt from the former hand-written enum rather than copied from upstream, so
+// every "Tags" list is empty until the upstream file is dropped in over it
+const ICONS_DATA: &str = "assets/IconsData.json";
+// Hand-written tags for the common icons, keyed by name. They are added to the
+// upstream tags rather than replacing them so the file can stay after the import.
+const ICON_TAGS: &str = "assets/IconTags.json";
+const SVG_ICONS: &str = "assets/icons";
+const SVG_SETS: [&str; 2] = ["material", "tango"];
+
+struct Icon {
+    name: String,
+    codepoint: u32,
+    tags: Vec<String>,
+}
+
+fn main() {
+    fluent_icons();
+    svg_icons();
+}
+
+fn fluent_icons() {
+    println!("cargo:rerun-if-changed={ICONS_DATA}");
+
+    let data = fs::read_to_string(ICONS_DATA).expect("IconsData.json should be readable");
+    let entries: Vec<serde_json::Value> =
+        serde_json::from_str(&data).expect("IconsData.json should be a JSON array");
+
+    println!("cargo:rerun-if-changed={ICON_TAGS}");
+
+    let extra: serde_json::Map<String, serde_json::Value> = serde_json::from_str(
+        &fs::read_to_string(ICON_TAGS).expect("IconTags.json should be readable"),
+    )
+    .expect("IconTags.json should be a JSON object");
+
+    let mut icons: Vec<Icon> = Vec::with_capacity(entries.len());
+
+    for entry in entries {
+        let name = entry["Name"].as_str().expect("icon should have a name");
+        let code = entry["Code"].as_str().expect("icon should have a code");
+
+        // A few names appear twice in the upstream list, the first one wins
+        if icons.iter().any(|icon| icon.name == name) {
+            continue;
+        }
+
+        icons.push(Icon {
+            name: name.to_owned(),
+            codepoint: u32::from_str_radix(code, 16).expect("code should be hexadecimal"),
+            tags: [
+                &entry["Tags"],
+                extra.get(name).unwrap_or(&serde_json::Value::Null),
+            ]
+            .into_iter()
+            .filter_map(serde_json::Value::as_array)
+            .flatten()
+            .filter_map(|tag| tag.as_str())
+            .map(str::to_lowercase)
+            .fold(Vec::new(), |mut tags, tag| {
+                if !tags.contains(&tag) {
+                    tags.push(tag);
+                }
+                tags
+            }),
+        });
+    }
+
+    if let Some(name) = extra
+        .keys()
+        .find(|name| !icons.iter().any(|icon| &icon.name == *name))
+    {
+        panic!("IconTags.json names {name}, which isn't in IconsData.json");
+    }
+
+    let mut code = String::new();
+
+    writeln!(code, "#[allow(dead_code)]").unwrap();
+    writeln!(code, "#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]").unwrap();
+    writeln!(code, "pub enum FluentIcon {{").unwrap();
+    for icon in &icons {
+        writeln!(code, "    {},", identifier(&icon.name)).unwrap();
+    }
+    writeln!(code, "}}\n").unwrap();
+
+    writeln!(code, "static ICONS: [FluentIcon; {}] = [", icons.len()).unwrap();
+    for icon in &icons {
+        writeln!(code, "    FluentIcon::{},", identifier(&icon.name)).unwrap();
+    }
+    writeln!(code, "];\n").unwrap();
+
+    // Sorted for binary search in `FluentIcon::from_name`
+    let mut by_name: Vec<&Icon> = icons.iter().collect();
+    by_name.sort_by(|a, b| a.name.cmp(&b.name));
+
+    writeln!(
+        code,
+        "static BY_NAME: [(&str, FluentIcon); {}] = [",
+        icons.len()
+    )
+    .unwrap();
+    for icon in by_name {
+        writeln!(
+            code,
+            "    ({:?}, FluentIcon::{}),",
+            icon.name,
+            identifier(&icon.name)
+        )
+        .unwrap();
+    }
+    writeln!(code, "];\n").unwrap();
+
+    writeln!(code, "impl FluentIcon {{").unwrap();
+
+    writeln!(code, "    pub const fn codepoint(&self) -> char {{").unwrap();
+    writeln!(code, "        match self {{").unwrap();
+    for icon in &icons {
+        writeln!(
+            code,
+            "            FluentIcon::{} => '\\u{{{:X}}}',",
+            identifier(&icon.name),
+            icon.codepoint
+        )
+        .unwrap();
+    }
+    writeln!(code, "        }}\n    }}\n").unwrap();
+
+    writeln!(code, "    pub const fn name(&self) -> &'static str {{").unwrap();
+    writeln!(code, "        match self {{").unwrap();
+    for icon in &icons {
+        writeln!(
+            code,
+            "            FluentIcon::{} => {:?},",
+            identifier(&icon.name),
+            icon.name
+        )
+        .unwrap();
+    }
+    writeln!(code, "        }}\n    }}\n").unwrap();
+
+    writeln!(
+        code,
+        "    pub const fn tags(&self) -> &'static [&'static str] {{"
+    )
+    .unwrap();
+    writeln!(code, "        match self {{").unwrap();
+    for icon in &icons {
+        writeln!(
+            code,
+            "            FluentIcon::{} => &{:?},",
+            identifier(&icon.name),
+            icon.tags
+        )
+        .unwrap();
+    }
+    writeln!(code, "        }}\n    }}").unwrap();
+
+    writeln!(code, "}}").unwrap();
+
+    let out_dir = env::var("OUT_DIR").expect("OUT_DIR should be set by cargo");
+    fs::write(Path::new(&out_dir).join("fluent_icon.rs"), code)
+        .expect("generated icons should be writable");
+}
+
+// Every SVG under assets/icons/<set>, keyed by "<set>/<file stem>" and sorted for
+// binary search in `Icon::file`. A set that hasn't been copied in is just empty.
+fn svg_icons() {
+    println!("cargo:rerun-if-changed={SVG_ICONS}");
+
+    let manifest_dir = env::var("CARGO_MANIFEST_DIR").expect("CARGO_MANIFEST_DIR should be set");
+    let mut icons: Vec<(String, String)> = Vec::new();
+
+    for set in SVG_SETS {
+        let directory = Path::new(&manifest_dir).join(SVG_ICONS).join(set);
+        println!("cargo:rerun-if-changed={}", directory.display());
+
+        let Ok(entries) = fs::read_dir(&directory) else {
+            continue;
+        };
+
+        for path in entries.filter_map(|entry| entry.ok().map(|entry| entry.path())) {
+            if path.extension().is_some_and(|extension| extension == "svg") {
+                let stem = path.file_stem().unwrap().to_string_lossy();
+
+                icons.push((format!("{set}/{stem}"), path.display().to_string()));
+            }
+        }
+    }
+
+    icons.sort();
+
+    let mut code = String::new();
+
+    writeln!(
+        code,
+        "static SVG_ICONS: [(&str, &[u8]); {}] = [",
+        icons.len()
+    )
+    .unwrap();
+    for (key, path) in &icons {
+        writeln!(code, "    ({key:?}, include_bytes!({path:?})),").unwrap();
+    }
+    writeln!(code, "];").unwrap();
+
+    let out_dir = env::var("OUT_DIR").expect("OUT_DIR should be set by cargo");
+    fs::write(Path::new(&out_dir).join("svg_icons.rs"), code)
+        .expect("generated icons should be writable");
+}
+
+// Icon names are PascalCase already, only names starting with a digit need a prefix
+fn identifier(name: &str) -> String {
+    if name.starts_with(|c: char| c.is_ascii_digit()) {
+        format!("Icon{name}")
+    } else {
+        name.to_owned()
+    }
+}
@@ -0,0 +1,497 @@
+// Calendar dates and times of day for the date and time pickers. The date maths is
+// done here, on the proleptic Gregorian calendar, rather than pulling in a date
+// library, and anything shown to the user goes through a `Formatter` so that
+// nothing depends on the system's locale services.
+use std::{
+    fmt,
+    time::{SystemTime, UNIX_EPOCH},
+};
+
+const SECONDS_PER_DAY: u64 = 86_400;
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct Date {
+    year: i32,
+    month: u8,
+    day: u8,
+}
+
+impl Date {
+    const FIRST: Self = Self {
+        year: i32::MIN,
+        month: 1,
+        day: 1,
+    };
+
+    const LAST: Self = Self {
+        year: i32::MAX,
+        month: 12,
+        day: 31,
+    };
+
+    // None unless `month` is 1 to 12 and `day` falls in that month
+    pub fn new(year: i32, month: u8, day: u8) -> Option<Self> {
+        ((1..=12).contains(&month) && day >= 1 && day <= days_in_month(year, month))
+            .then_some(Self { year, month, day })
+    }
+
+    // Today in UTC, there's no time zone to go by without the locale services
+    pub fn today() -> Self {
+        let seconds = SystemTime::now()
+            .duration_since(UNIX_EPOCH)
+            .map_or(0, |duration| duration.as_secs());
+
+        Self::from_days((seconds / SECONDS_PER_DAY) as i64)
+    }
+
+    pub fn year(self) -> i32 {
+        self.year
+    }
+
+    pub fn month(self) -> u8 {
+        self.month
+    }
+
+    pub fn day(self) -> u8 {
+        self.day
+    }
+
+    pub fn weekday(self) -> Weekday {
+        // 1970-01-01 was a Thursday
+        Weekday::ALL[(self.days() + 3).rem_euclid(7) as usize]
+    }
+
+    pub fn first_of_month(self) -> Self {
+        Self { day: 1, ..self }
+    }
+
+    // Saturates at the first and last day an i32 year can hold, like the month
+    // arithmetic
+    pub fn add_days(self, days: i64) -> Self {
+        Self::from_days(
+            self.days()
+                .saturating_add(days)
+                .clamp(Self::FIRST.days(), Self::LAST.days()),
+        )
+    }
+
+    // Keeps the day where the month has it, otherwise ends up on the last day of the
+    // month, so Jan 31 plus a month is Feb 28 or 29
+    pub fn add_months(self, months: i32) -> Self {
+        self.shift_months(i64::from(months))
+    }
+
+    pub fn add_years(self, years: i32) -> Self {
+        self.shift_months(i64::from(years) * 12)
+    }
+
+    // Counted in i64 and saturating at the first and last month an i32 year can
+    // hold, so stepping past either end stays there instead of overflowing
+    fn shift_months(self, months: i64) -> Self {
+        let index = (i64::from(self.year) * 12 + i64::from(self.month) - 1 + months)
+            .clamp(i64::from(i32::MIN) * 12, i64::from(i32::MAX) * 12 + 11);
+        let year = index.div_euclid(12) as i32;
+        let month = (index.rem_euclid(12) + 1) as u8;
+
+        Self {
+            year,
+            month,
+            day: self.day.min(days_in_month(year, month)),
+        }
+    }
+
+    // Days since 1970-01-01, from http://howardhinnant.github.io/date_algorithms.html
+    fn days(self) -> i64 {
+        let year = i64::from(self.year) - i64::from(self.month <= 2);
+        let era = year.div_euclid(400);
+        let year_of_era = year - era * 400;
+        let month = i64::from(self.month);
+        let day_of_year =
+            (153 * (month + if month > 2 { -3 } else { 9 }) + 2) / 5 + i64::from(self.day) - 1;
+        let day_of_era = year_of_era * 365 + year_of_era / 4 - year_of_era / 100 + day_of_year;
+
+        era * 146_097 + day_of_era - 719_468
+    }
+
+    fn from_days(days: i64) -> Self {
+        let days = days + 719_468;
+        let era = days.div_euclid(146_097);
+        let day_of_era = days - era * 146_097;
+        let year_of_era =
+            (day_of_era - day_of_era / 1460 + day_of_era / 36_524 - day_of_era / 146_096) / 365;
+        let day_of_year = day_of_era - (365 * year_of_era + year_of_era / 4 - year_of_era / 100);
+        let month_index = (5 * day_of_year + 2) / 153;
+        let day = (day_of_year - (153 * month_index + 2) / 5 + 1) as u8;
+        let month = if month_index < 10 {
+            month_index + 3
+        } else {
+            month_index - 9
+        } as u8;
+
+        Self {
+            year: (year_of_era + era * 400 + i64::from(month <= 2)) as i32,
+            month,
+            day,
+        }
+    }
+}
+
+// ISO 8601, for logs and debugging. Use a `Formatter` for anything the user sees.
+impl fmt::Display for Date {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{:04}-{:02}-{:02}", self.year, self.month, self.day)
+    }
+}
+
+pub fn is_leap_year(year: i32) -> bool {
+    year % 4 == 0 && (year % 100 != 0 || year % 400 == 0)
+}
+
+pub fn days_in_month(year: i32, month: u8) -> u8 {
+    match month {
+        2 if is_leap_year(year) => 29,
+        2 => 28,
+        4 | 6 | 9 | 11 => 30,
+        _ => 31,
+    }
+}
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub enum Weekday {
+    Monday,
+    Tuesday,
+    Wednesday,
+    Thursday,
+    Friday,
+    Saturday,
+    Sunday,
+}
+
+impl Weekday {
+    pub const ALL: [Weekday; 7] = [
+        Weekday::Monday,
+        Weekday::Tuesday,
+        Weekday::Wednesday,
+        Weekday::Thursday,
+        Weekday::Friday,
+        Weekday::Saturday,
+        Weekday::Sunday,
+    ];
+
+    // Days from Monday
+    pub fn index(self) -> usize {
+        self as usize
+    }
+
+    // The weekday `days` after this one
+    pub fn add(self, days: usize) -> Self {
+        Self::ALL[(self.index() + days) % 7]
+    }
+}
+
+impl fmt::Display for Weekday {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(English.weekday(*self).as_str())
+    }
+}
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct Time {
+    hour: u8,
+    minute: u8,
+}
+
+impl Time {
+    // None unless `hour` is 0 to 23 and `minute` 0 to 59
+    pub fn new(hour: u8, minute: u8) -> Option<Self> {
+        (hour < 24 && minute < 60).then_some(Self { hour, minute })
+    }
+
+    // The time in UTC, see `Date::today`
+    pub fn now() -> Self {
+        let seconds = SystemTime::now()
+            .duration_since(UNIX_EPOCH)
+            .map_or(0, |duration| duration.as_secs())
+            % SECONDS_PER_DAY;
+
+        Self {
+            hour: (seconds / 3600) as u8,
+            minute: (seconds / 60 % 60) as u8,
+        }
+    }
+
+    // 0 to 23
+    pub fn hour(self) -> u8 {
+        self.hour
+    }
+
+    pub fn minute(self) -> u8 {
+        self.minute
+    }
+
+    pub fn is_pm(self) -> bool {
+        self.hour >= 12
+    }
+
+    // 1 to 12, for a 12-hour clock
+    pub fn hour12(self) -> u8 {
+        match self.hour % 12 {
+            0 => 12,
+            hour => hour,
+        }
+    }
+}
+
+impl fmt::Display for Time {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{:02}:{:02}", self.hour, self.minute)
+    }
+}
+
+// The words and formats the date and time widgets show, and how typed dates are
+// read back. `English` is used unless a widget is given another.
+pub trait Formatter {
+    // `month` is 1 to 12
+    fn month(&self, month: u8) -> String;
+
+    fn month_abbreviation(&self, month: u8) -> String;
+
+    fn weekday(&self, weekday: Weekday) -> String;
+
+    // The column headings in a calendar's month view
+    fn weekday_abbreviation(&self, weekday: Weekday) -> String;
+
+    fn date(&self, date: Date) -> String;
+
+    fn parse_date(&self, text: &str) -> Option<Date>;
+
+    // AM or PM on a 12-hour clock
+    fn day_period(&self, is_pm: bool) -> String;
+
+    // Shown in a date picker's field until a date is picked
+    fn date_placeholder(&self) -> String;
+
+    // Shown in a time picker's hour and minute until a time is picked
+    fn hour_placeholder(&self) -> String;
+
+    fn minute_placeholder(&self) -> String;
+
+    // The heading of a calendar's month view
+    fn month_year(&self, date: Date) -> String {
+        format!("{} {}", self.month(date.month()), date.year())
+    }
+}
+
+const MONTHS: [&str; 12] = [
+    "January",
+    "February",
+    "March",
+    "April",
+    "May",
+    "June",
+    "July",
+    "August",
+    "September",
+    "October",
+    "November",
+    "December",
+];
+
+// US English, as in WinUI without a language set: month/day/year dates and Sunday
+// starting the week
+#[derive(Clone, Copy, Debug, Default)]
+pub struct English;
+
+impl Formatter for English {
+    fn month(&self, month: u8) -> String {
+        MONTHS[usize::from(month.clamp(1, 12)) - 1].to_owned()
+    }
+
+    fn month_abbreviation(&self, month: u8) -> String {
+        self.month(month)[..3].to_owned()
+    }
+
+    fn weekday(&self, weekday: Weekday) -> String {
+        match weekday {
+            Weekday::Monday => "Monday",
+            Weekday::Tuesday => "Tuesday",
+            Weekday::Wednesday => "Wednesday",
+            Weekday::Thursday => "Thursday",
+            Weekday::Friday => "Friday",
+            Weekday::Saturday => "Saturday",
+            Weekday::Sunday => "Sunday",
+        }
+        .to_owned()
+    }
+
+    fn weekday_abbreviation(&self, weekday: Weekday) -> String {
+        self.weekday(weekday)[..2].to_owned()
+    }
+
+    fn date(&self, date: Date) -> String {
+        format!("{}/{}/{}", date.month(), date.day(), date.year())
+    }
+
+    // Takes 10/19/2026, 2026-10-19 and dates with the month written out, such as
+    // Oct 19 2026 or 19th October 2026. Two-digit years are this century.
+    fn parse_date(&self, text: &str) -> Option<Date> {
+        let parts: Vec<&str> = text
+            .split(|c: char| !c.is_alphanumeric())
+            .filter(|part| !part.is_empty())
+            .collect();
+
+        if parts.len() != 3 {
+            return None;
+        }
+
+        let month_name = parts
+            .iter()
+            .position(|part| part.starts_with(|c: char| c.is_alphabetic()));
+
+        let (year, month, day) = match month_name {
+            Some(index) => {
+                let month = month_named(parts[index])?;
+                let mut numbers = parts
+                    .iter()
+                    .enumerate()
+                    .filter(|(other, _)| *other != index)
+                    .map(|(_, part)| number(part.trim_end_matches(char::is_alphabetic)));
+                let (first, second) = (numbers.next()??, numbers.next()??);
+
+                // Whichever number can't be a day is the year, otherwise the day
+                // comes first
+                if first > 31 {
+                    (first, month, second)
+                } else {
+                    (second, month, first)
+                }
+            }
+            None => {
+                let numbers = parts
+                    .iter()
+                    .map(|part| number(part))
+                    .collect::<Option<Vec<_>>>()?;
+
+                if parts[0].len() == 4 {
+                    (numbers[0], numbers[1], numbers[2])
+                } else {
+                    (numbers[2], numbers[0], numbers[1])
+                }
+            }
+        };
+
+        let year = if year < 100 { year + 2000 } else { year };
+
+        Date::new(
+            i32::try_from(year).ok()?,
+            u8::try_from(month).ok()?,
+            u8::try_from(day).ok()?,
+        )
+    }
+
+    fn day_period(&self, is_pm: bool) -> String {
+        if is_pm { "PM" } else { "AM" }.to_owned()
+    }
+
+    fn date_placeholder(&self) -> String {
+        "Pick a date".to_owned()
+    }
+
+    fn hour_placeholder(&self) -> String {
+        "hour".to_owned()
+    }
+
+    fn minute_placeholder(&self) -> String {
+        "minute".to_owned()
+    }
+}
+
+fn number(part: &str) -> Option<u32> {
+    part.parse().ok()
+}
+
+// Full names and anything from the first three letters of one
+fn month_named(name: &str) -> Option<u32> {
+    let name = name.to_lowercase();
+
+    if name.len() < 3 {
+        return None;
+    }
+
+    MONTHS
+        .iter()
+        .position(|month| month.to_lowercase().starts_with(&name))
+        .map(|index| index as u32 + 1)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{Date, English, Formatter};
+
+    fn date(year: i32, month: u8, day: u8) -> Option<Date> {
+        Date::new(year, month, day)
+    }
+
+    #[test]
+    fn month_arithmetic_saturates() {
+        let last = Date::new(i32::MAX, 12, 31).unwrap();
+        let first = Date::new(i32::MIN, 1, 1).unwrap();
+
+        assert_eq!(last.add_months(1), last);
+        assert_eq!(last.add_years(i32::MAX), last);
+        assert_eq!(first.add_months(-1), first);
+        assert_eq!(first.add_years(i32::MIN), first);
+        assert_eq!(
+            Date::new(2024, 1, 31).unwrap().add_months(1),
+            Date::new(2024, 2, 29).unwrap()
+        );
+    }
+
+    #[test]
+    fn day_arithmetic_saturates() {
+        let last = Date::new(i32::MAX, 12, 31).unwrap();
+        let first = Date::new(i32::MIN, 1, 1).unwrap();
+
+        assert_eq!(last.add_days(1), last);
+        assert_eq!(last.add_days(i64::MAX), last);
+        assert_eq!(first.add_days(-1), first);
+        assert_eq!(first.add_days(i64::MIN), first);
+        assert_eq!(
+            Date::new(2024, 2, 28).unwrap().add_days(2),
+            Date::new(2024, 3, 1).unwrap()
+        );
+    }
+
+    #[test]
+    fn two_digit_years_are_this_century() {
+        assert_eq!(English.parse_date("10/19/26"), date(2026, 10, 19));
+        assert_eq!(English.parse_date("19 Oct 26"), date(2026, 10, 19));
+        assert_eq!(English.parse_date("1/2/99"), date(2099, 1, 2));
+    }
+
+    #[test]
+    fn months_can_be_written_out() {
+        assert_eq!(English.parse_date("October 19, 2026"), date(2026, 10, 19));
+        assert_eq!(English.parse_date("19th october 2026"), date(2026, 10, 19));
+        assert_eq!(English.parse_date("Sept 1 2026"), date(2026, 9, 1));
+        assert_eq!(English.parse_date("Octember 19 2026"), None);
+    }
+
+    #[test]
+    fn month_names_need_three_letters() {
+        assert_eq!(English.parse_date("Oct 19 2026"), date(2026, 10, 19));
+        assert_eq!(English.parse_date("Oc 19 2026"), None);
+        assert_eq!(English.parse_date("Ju 1 2026"), None);
+        assert_eq!(English.parse_date("Jun 1 2026"), date(2026, 6, 1));
+    }
+
+    #[test]
+    fn the_number_that_cant_be_a_day_is_the_year() {
+        assert_eq!(English.parse_date("2026 Oct 19"), date(2026, 10, 19));
+        assert_eq!(English.parse_date("Oct 19 2026"), date(2026, 10, 19));
+        // Both could be days, so the day comes first
+        assert_eq!(English.parse_date("Oct 5 31"), date(2031, 10, 5));
+        assert_eq!(English.parse_date("2026-10-19"), date(2026, 10, 19));
+        assert_eq!(English.parse_date("Feb 30 2026"), None);
+    }
+}
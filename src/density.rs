@@ -0,0 +1,182 @@
+// Control sizes for WinUI's two densities. Standard is the default resource set,
+// Compact follows DensityStyles/Compact.xaml:
+// https://learn.microsoft.com/en-us/windows/apps/design/style/spacing#layout-density
+//
+// The density is kept on the theme, but the widget constructors are called from
+// `view` without access to it. A view is built `with` its theme's density instead,
+// and widgets that size themselves during layout keep the metrics they were built
+// with, as layout runs outside of `view`.
+use crate::widget::text;
+
+use iced::Padding;
+
+use std::cell::Cell;
+
+thread_local! {
+    static DENSITY: Cell<Density> = const { Cell::new(Density::Standard) };
+}
+
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub enum Density {
+    Compact,
+    #[default]
+    Standard,
+}
+
+impl Density {
+    pub const ALL: [Self; 2] = [Self::Compact, Self::Standard];
+
+    pub fn metrics(self) -> &'static Metrics {
+        match self {
+            Density::Compact => &Metrics::COMPACT,
+            Density::Standard => &Metrics::STANDARD,
+        }
+    }
+}
+
+impl std::fmt::Display for Density {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(
+            f,
+            "{}",
+            match self {
+                Density::Compact => "Compact",
+                Density::Standard => "Standard",
+            }
+        )
+    }
+}
+
+// The density of the view being built, Standard outside of `with`
+pub fn current() -> Density {
+    DENSITY.get()
+}
+
+// Builds a view with the widgets sized for `density`
+pub fn with<T>(density: Density, view: impl FnOnce() -> T) -> T {
+    // Put back on drop so a nested or panicking view doesn't leave it set
+    struct Restore(Density);
+
+    impl Drop for Restore {
+        fn drop(&mut self) {
+            DENSITY.set(self.0);
+        }
+    }
+
+    let _restore = Restore(DENSITY.replace(density));
+
+    view()
+}
+
+// The current density's metrics grown to fit the current text scale
+pub fn metrics() -> Metrics {
+    current().metrics().grown()
+}
+
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct Metrics {
+    // Buttons, drop down and split buttons
+    pub control_height: f32,
+    // Text inputs, combo boxes and pick lists, sized to match `control_height`
+    // with a 20px line height
+    pub control_padding: Padding,
+    // Gap between an icon and its label and between a toggle and its label
+    pub spacing: f32,
+    pub menu_item_height: f32,
+    pub menu_item_padding: f32,
+    pub nav_item_height: f32,
+    pub app_bar_height: f32,
+    pub ribbon_large_height: f32,
+    // Medium and small ribbon buttons
+    pub ribbon_item_height: f32,
+    pub ribbon_tab_height: f32,
+    pub dialog_padding: f32,
+    // Square buttons holding only a glyph, such as a close or add button
+    pub icon_button_size: f32,
+    // The close button on a tab
+    pub small_icon_button_size: f32,
+    // Around a number input's 20px line, sized to match `control_height` with
+    // its border
+    pub number_input_padding: f32,
+}
+
+impl Metrics {
+    pub const STANDARD: Self = Self {
+        control_height: 32.0,
+        control_padding: Padding {
+            top: 6.0,
+            right: 10.0,
+            bottom: 6.0,
+            left: 10.0,
+        },
+        spacing: 12.0,
+        menu_item_height: 28.0,
+        menu_item_padding: 5.0,
+        nav_item_height: 36.0,
+        app_bar_height: 52.0,
+        ribbon_large_height: 72.0,
+        ribbon_item_height: 24.0,
+        ribbon_tab_height: 28.0,
+        dialog_padding: 24.0,
+        icon_button_size: 32.0,
+        small_icon_button_size: 24.0,
+        number_input_padding: 5.0,
+    };
+
+    pub const COMPACT: Self = Self {
+        control_height: 24.0,
+        control_padding: Padding {
+            top: 2.0,
+            right: 10.0,
+            bottom: 2.0,
+            left: 10.0,
+        },
+        spacing: 8.0,
+        menu_item_height: 24.0,
+        menu_item_padding: 2.0,
+        nav_item_height: 32.0,
+        app_bar_height: 44.0,
+        ribbon_large_height: 64.0,
+        ribbon_item_height: 20.0,
+        ribbon_tab_height: 24.0,
+        dialog_padding: 16.0,
+        icon_button_size: 24.0,
+        small_icon_button_size: 20.0,
+        number_input_padding: 1.0,
+    };
+
+    // Fixed heights grow by however much their line of text grows, the padding
+    // around it stays the same as on Windows
+    fn grown(&self) -> Self {
+        let body = text::scaled(20.0) - 20.0;
+        let caption = text::scaled(16.0) - 16.0;
+
+        Self {
+            control_height: self.control_height + body,
+            menu_item_height: self.menu_item_height + body,
+            nav_item_height: self.nav_item_height + body,
+            app_bar_height: self.app_bar_height + body,
+            ribbon_large_height: self.ribbon_large_height + caption,
+            ribbon_item_height: self.ribbon_item_height + caption,
+            ribbon_tab_height: self.ribbon_tab_height + caption,
+            ..*self
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn with_sets_the_density_while_the_view_is_built() {
+        let height = with(Density::Compact, || {
+            assert_eq!(with(Density::Standard, current), Density::Standard);
+
+            metrics().menu_item_height
+        });
+
+        assert_eq!(height, Metrics::COMPACT.grown().menu_item_height);
+        assert_eq!(current(), Density::Standard);
+    }
+}
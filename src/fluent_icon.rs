@@ -1,2819 +1,47 @@
-// This enum causes issues for VS Code so all are commented except when used.
+// The `FluentIcon` enum, `codepoint`, `name` and `tags` are generated by build.rs
+// from assets/IconsData.json
+include!(concat!(env!("OUT_DIR"), "/fluent_icon.rs"));
 
-// Generated from https://github.com/microsoft/WinUI-Gallery/blob/main/WinUIGallery/DataModel/IconsData.json
+impl FluentIcon {
+    pub fn all() -> impl Iterator<Item = FluentIcon> {
+        ICONS.iter().copied()
+    }
+
+    // Exact, case-sensitive match on the WinUI name, e.g. "GlobalNavButton"
+    pub fn from_name(name: &str) -> Option<FluentIcon> {
+        BY_NAME
+            .binary_search_by(|(candidate, _)| (*candidate).cmp(name))
+            .ok()
+            .map(|index| BY_NAME[index].1)
+    }
+
+    // Case-insensitive match on the name or any of the tags
+    pub fn search(query: &str) -> impl Iterator<Item = FluentIcon> {
+        let query = query.trim().to_lowercase();
 
-pub enum FluentIcon {
-    GlobalNavButton,
-    // WiFluentIcon,
-    // Bluetooth,
-    // Connect,
-    // InternetSharing,
-    // Vpn,
-    // Brightness,
-    // MapPin,
-    // QuietHours,
-    // Airplane,
-    // Tablet,
-    // QuickNote,
-    // RememberedDevice,
-    ChevronDown,
-    ChevronUp,
-    Edit,
-    Add,
-    // Cancel,
-    // More,
-    Settings,
-    // Video,
-    Mail,
-    // People,
-    // Phone,
-    // Pin,
-    // Shop,
-    // Stop,
-    // Link,
-    // FluentIconlter,
-    // AllApps,
-    // Zoom,
-    // ZoomOut,
-    // Microphone,
-    Search,
-    // Camera,
-    // Attach,
-    // Send,
-    SendFluentIconll,
-    // WalkSolid,
-    // InPrivate,
-    // FavoriteList,
-    // PageSolid,
-    Forward,
-    // Back,
-    // Refresh,
-    Share,
-    // Lock,
-    // ReportHacked,
-    // Emi,
-    // FavoriteStar,
-    // FavoriteStarFluentIconll,
-    // ReadingMode,
-    // Favicon,
-    // Remove,
-    // Checkbox,
-    CheckboxComposite,
-    // CheckboxFluentIconll,
-    // CheckboxIndeterminate,
-    // CheckboxCompositeReversed,
-    CheckMark,
-    // BackToWindow,
-    // FullScreen,
-    // ResizeTouchLarger,
-    // ResizeTouchSmaller,
-    // ResizeMouseSmall,
-    // ResizeMouseMedium,
-    // ResizeMouseWide,
-    // ResizeMouseTall,
-    // ResizeMouseLarge,
-    // SwitchUser,
-    Print,
-    // Up,
-    // Down,
-    // Oem,
-    Delete,
-    Save,
-    // Mute,
-    // BackSpaceQWERTY,
-    // ReturnKey,
-    // UpArrowShiftKey,
-    // Cloud,
-    // Flashlight,
-    // RotationLock,
-    // CommandPrompt,
-    // SIPMove,
-    // SIPUndock,
-    // SIPRedock,
-    // EraseTool,
-    // UnderscoreSpace,
-    // GripperTool,
-    // Dialpad,
-    // PageLeft,
-    // PageRight,
-    // MultiSelect,
-    // KeyboardLeftHanded,
-    // KeyboardRightHanded,
-    // KeyboardClassic,
-    // KeyboardSplit,
-    // Volume,
-    // Play,
-    // Pause,
-    // ChevronLeft,
-    // ChevronRight,
-    // InkingTool,
-    // Emoji2,
-    // GripperBarHorizontal,
-    // System,
-    // Personalize,
-    // Devices,
-    // SearchAndApps,
-    // Globe,
-    // TimeLanguage,
-    // EaseOfAccess,
-    // UpdateRestore,
-    // HangUp,
-    // ContactInfo,
-    // Unpin,
-    // Contact,
-    // Memo,
-    // IncomingCall,
-    Paste,
-    // PhoneBook,
-    // LEDLight,
-    // Error,
-    // GripperBarVertical,
-    // Unlock,
-    Slideshow,
-    Calendar,
-    // GripperResize,
-    // Megaphone,
-    // Trim,
-    NewWindow,
-    // SaveLocal,
-    Color,
-    // DataSense,
-    // SaveAs,
-    // Light,
-    // AspectRatio,
-    // DataSenseBar,
-    Redo,
-    Undo,
-    // Crop,
-    // OpenWith,
-    // Rotate,
-    // RedEye,
-    // SetlockScreen,
-    // MapPin2,
-    // Package,
-    // Warning,
-    // ReadingList,
-    // Education,
-    // ShoppingCart,
-    // Train,
-    // Flag,
-    // Move,
-    // Page,
-    // TaskView,
-    // BrowsePhotos,
-    // HalfStarLeft,
-    // HalfStarRight,
-    // Record,
-    // TouchPointer,
-    // LangJPN,
-    // Ferry,
-    // Highlight,
-    // ActionCenterNotiFluentIconcation,
-    // PowerButton,
-    // ResizeTouchNarrower,
-    // ResizeTouchShorter,
-    // DrivingMode,
-    // RingerSilent,
-    // OtherUser,
-    // Admin,
-    // Cc,
-    // SDCard,
-    // CallForwarding,
-    // SettingsDisplaySound,
-    // TVMonitor,
-    // Speakers,
-    // Headphone,
-    // DeviceLaptopPic,
-    // DeviceLaptopNoPic,
-    // DeviceMonitorRightPic,
-    // DeviceMonitorLeftPic,
-    // DeviceMonitorNoPic,
-    // Game,
-    // HorizontalTabKey,
-    // StreetsideSplitMinimize,
-    // StreetsideSplitExpand,
-    // Car,
-    // Walk,
-    // Bus,
-    // TiltUp,
-    // TiltDown,
-    // CallControl,
-    // RotateMapRight,
-    // RotateMapLeft,
-    Home,
-    // ParkingLocation,
-    // MapCompassTop,
-    // MapCompassBottom,
-    // IncidentTriangle,
-    // Touch,
-    // MapDirections,
-    // StartPoint,
-    // StopPoint,
-    // EndPoint,
-    // History,
-    // Location,
-    // MapLayers,
-    // Accident,
-    // Work,
-    // Construction,
-    // Recent,
-    // Bank,
-    // DownloadMap,
-    // InkingToolFluentIconll2,
-    // HighlightFluentIconll2,
-    // EraseToolFluentIconll,
-    // EraseToolFluentIconll2,
-    // Dictionary,
-    // DictionaryAdd,
-    // ToolTip,
-    // ChromeBack,
-    // ProvisioningPackage,
-    // AddRemoteDevice,
-    // FolderOpen,
-    // Ethernet,
-    // ShareBroadband,
-    // DirectAccess,
-    // DialUp,
-    // DefenderApp,
-    // BatteryCharging9,
-    // Battery10,
-    // Pinned,
-    // PinFluentIconll,
-    // PinnedFluentIconll,
-    // PeriodKey,
-    // PuncKey,
-    // RevToggleKey,
-    // RightArrowKeyTime1,
-    // RightArrowKeyTime2,
-    // LeftQuote,
-    // RightQuote,
-    // DownShiftKey,
-    // UpShiftKey,
-    // PuncKey0,
-    // PuncKeyLeftBottom,
-    // RightArrowKeyTime3,
-    // RightArrowKeyTime4,
-    // Battery0,
-    // Battery1,
-    // Battery2,
-    // Battery3,
-    // Battery4,
-    // Battery5,
-    // Battery6,
-    // Battery7,
-    // Battery8,
-    // Battery9,
-    // BatteryCharging0,
-    // BatteryCharging1,
-    // BatteryCharging2,
-    // BatteryCharging3,
-    // BatteryCharging4,
-    // BatteryCharging5,
-    // BatteryCharging6,
-    // BatteryCharging7,
-    // BatteryCharging8,
-    // BatterySaver0,
-    // BatterySaver1,
-    // BatterySaver2,
-    // BatterySaver3,
-    // BatterySaver4,
-    // BatterySaver5,
-    // BatterySaver6,
-    // BatterySaver7,
-    // BatterySaver8,
-    // SignalBars1,
-    // SignalBars2,
-    // SignalBars3,
-    // SignalBars4,
-    // SignalBars5,
-    // SignalNotConnected,
-    // WiFluentIcon1,
-    // WiFluentIcon2,
-    // WiFluentIcon3,
-    // MobSIMLock,
-    // MobSIMMissing,
-    // Vibrate,
-    // RoamingInternational,
-    // RoamingDomestic,
-    // CallForwardInternational,
-    // CallForwardRoaming,
-    // JpnRomaji,
-    // JpnRomajiLock,
-    // JpnRomajiShift,
-    // JpnRomajiShiftLock,
-    // StatusDataTransfer,
-    // StatusDataTransferVPN,
-    // StatusDualSIM2,
-    // StatusDualSIM2VPN,
-    // StatusDualSIM1,
-    // StatusDualSIM1VPN,
-    // StatusSGLTE,
-    // StatusSGLTECell,
-    // StatusSGLTEDataVPN,
-    // StatusVPN,
-    // WiFluentIconHotspot,
-    // LanguageKor,
-    // LanguageCht,
-    // LanguageChs,
-    // Usb,
-    // InkingToolFluentIconll,
-    // View,
-    // HighlightFluentIconll,
-    // Previous,
-    // Next,
-    // Clear,
-    // Sync,
-    // Download,
-    // Help,
-    // Upload,
-    // Emoji,
-    // TwoPage,
-    // LeaveChat,
-    // MailForward,
-    // RotateCamera,
-    // ClosePane,
-    // OpenPane,
-    PreviewLink,
-    // AttachCamera,
-    // ZoomIn,
-    // Bookmarks,
-    Document,
-    // ProtectedDocument,
-    // OpenInNewWindow,
-    // MailFluentIconll,
-    // ViewAll,
-    // VideoChat,
-    // Switch,
-    // Rename,
-    // Go,
-    // SurfaceHub,
-    // Remote,
-    // Click,
-    // Shuffle,
-    // Movies,
-    SelectAll,
-    // Orientation,
-    // Import,
-    // ImportAll,
-    // Folder,
-    // Webcam,
-    // Picture,
-    // Caption,
-    // ChromeClose,
-    // ShowResults,
-    Message,
-    // Leaf,
-    // CalendarDay,
-    // CalendarWeek,
-    // Characters,
-    MailReplyAll,
-    // Read,
-    // ShowBcc,
-    // HideBcc,
-    Cut,
-    // PaymentCard,
-    Copy,
-    // Important,
-    MailReply,
-    // Sort,
-    // MobileTablet,
-    // DisconnectDrive,
-    // MapDrive,
-    // ContactPresence,
-    // Priority,
-    // GotoToday,
-    Font,
-    // FontColor,
-    // Contact2,
-    // FolderFluentIconll,
-    // Audio,
-    // Permissions,
-    // DisableUpdates,
-    // Unfavorite,
-    // OpenLocal,
-    Italic,
-    Underline,
-    Bold,
-    // MoveToFolder,
-    // LikeDislike,
-    // Dislike,
-    Like,
-    AlignRight,
-    AlignCenter,
-    AlignLeft,
-    // OpenFluentIconle,
-    // ClearSelection,
-    FontDecrease,
-    FontIncrease,
-    FontSize,
-    // CellPhone,
-    // Reshare,
-    // Tag,
-    // RepeatOne,
-    // RepeatAll,
-    // Calculator,
-    // Directions,
-    // Library,
-    // ChatBubbles,
-    // PostUpdate,
-    // NewFolder,
-    // CalendarReply,
-    // UnsyncFolder,
-    // SyncFolder,
-    // BlockContact,
-    // SwitchApps,
-    // AddFriend,
-    // Accept,
-    // GoToStart,
-    // BulletedList,
-    // Scan,
-    // Preview,
-    Group,
-    // ZeroBars,
-    // OneBar,
-    // TwoBars,
-    // ThreeBars,
-    // FourBars,
-    // World,
-    // Comment,
-    // MusicInfo,
-    // DockLeft,
-    // DockRight,
-    // DockBottom,
-    // Repair,
-    // Accounts,
-    // DullSound,
-    // Manage,
-    // Street,
-    // Printer3D,
-    RadioBullet,
-    // Stopwatch,
-    // Photo,
-    // ActionCenter,
-    // FullCircleMask,
-    // ChromeMinimize,
-    // ChromeMaximize,
-    // ChromeRestore,
-    // Annotation,
-    // BackSpaceQWERTYSm,
-    // BackSpaceQWERTYMd,
-    // Swipe,
-    // FluentIconngerprint,
-    // Handwriting,
-    // ChromeBackToWindow,
-    // ChromeFullScreen,
-    // KeyboardStandard,
-    // KeyboardDismiss,
-    // Completed,
-    // ChromeAnnotate,
-    // Label,
-    // IBeam,
-    // IBeamOutline,
-    // FlickDown,
-    // FlickUp,
-    // FlickLeft,
-    // FlickRight,
-    // FeedbackApp,
-    // MusicAlbum,
-    // Streaming,
-    // Code,
-    // ReturnToWindow,
-    // LightningBolt,
-    // Info,
-    // CalculatorMultiply,
-    // CalculatorAddition,
-    // CalculatorSubtract,
-    // CalculatorDivide,
-    // CalculatorSquareroot,
-    // CalculatorPercentage,
-    // CalculatorNegate,
-    // CalculatorEqualTo,
-    // CalculatorBackspace,
-    // Component,
-    // Dmc,
-    // Dock,
-    // MultimediaDMS,
-    // MultimediaDVR,
-    // MultimediaPMP,
-    // PrintfaxPrinterFluentIconle,
-    // Sensor,
-    // StorageOptical,
-    // Communications,
-    // Headset,
-    // Projector,
-    // Health,
-    // Wire,
-    // Webcam2,
-    // Input,
-    // Mouse,
-    // Smartcard,
-    // SmartcardVirtual,
-    // MediaStorageTower,
-    // ReturnKeySm,
-    // GameConsole,
-    // Network,
-    // StorageNetworkWireless,
-    // StorageTape,
-    // ChevronUpSmall,
-    // ChevronDownSmall,
-    // ChevronLeftSmall,
-    // ChevronRightSmall,
-    // ChevronUpMed,
-    // ChevronDownMed,
-    // ChevronLeftMed,
-    ChevronRightMed,
-    // Devices2,
-    // ExpandTile,
-    // Pc1,
-    // PresenceChicklet,
-    // PresenceChickletVideo,
-    Reply,
-    // SetTile,
-    // Type,
-    // Korean,
-    // HalfAlpha,
-    // FullAlpha,
-    // Key12On,
-    // ChineseChangjie,
-    // QWERTYOn,
-    // QWERTYOff,
-    // ChineseQuick,
-    // Japanese,
-    // FullHiragana,
-    // FullKatakana,
-    // HalfKatakana,
-    // ChineseBoPoMoFo,
-    // ChinesePinyin,
-    // ConstructionCone,
-    // XboxOneConsole,
-    // Volume0,
-    // Volume1,
-    // Volume2,
-    // Volume3,
-    // BatteryUnknown,
-    // WiFluentIconAttentionOverlay,
-    // Robot,
-    // TapAndSend,
-    // FluentIcontPage,
-    // PasswordKeyShow,
-    // PasswordKeyHide,
-    // BidiLtr,
-    // BidiRtl,
-    // ForwardSm,
-    // CommaKey,
-    DashKey,
-    // DullSoundKey,
-    // HalfDullSound,
-    // RightDoubleQuote,
-    // LeftDoubleQuote,
-    // PuncKeyRightBottom,
-    // PuncKey1,
-    // PuncKey2,
-    // PuncKey3,
-    // PuncKey4,
-    // PuncKey5,
-    // PuncKey6,
-    // PuncKey9,
-    // PuncKey7,
-    // PuncKey8,
-    // Frigid,
-    // Unknown,
-    // AreaChart,
-    // CheckList,
-    // Diagnostic,
-    // Equalizer,
-    // Process,
-    // Processing,
-    // ReportDocument,
-    // VideoSolid,
-    // MixedMediaBadge,
-    // DisconnectDisplay,
-    // Shield,
-    // Info2,
-    // ActionCenterAsterisk,
-    // Beta,
-    // SaveCopy,
-    // List,
-    // Asterisk,
-    // ErrorBadge,
-    // CircleRing,
-    // CircleFluentIconll,
-    // MergeCall,
-    // PrivateCall,
-    // Record2,
-    // AllAppsMirrored,
-    // BookmarksMirrored,
-    // BulletedListMirrored,
-    // CallForwardInternationalMirrored,
-    // CallForwardRoamingMirrored,
-    // ChromeBackMirrored,
-    // ClearSelectionMirrored,
-    // ClosePaneMirrored,
-    // ContactInfoMirrored,
-    // DockRightMirrored,
-    // DockLeftMirrored,
-    // ExpandTileMirrored,
-    // GoMirrored,
-    // GripperResizeMirrored,
-    // HelpMirrored,
-    // ImportMirrored,
-    // ImportAllMirrored,
-    // LeaveChatMirrored,
-    // ListMirrored,
-    // MailForwardMirrored,
-    // MailReplyMirrored,
-    // MailReplyAllMirrored,
-    // OpenPaneMirrored,
-    // OpenWithMirrored,
-    // ParkingLocationMirrored,
-    // ResizeMouseMediumMirrored,
-    // ResizeMouseSmallMirrored,
-    // ResizeMouseTallMirrored,
-    // ResizeTouchNarrowerMirrored,
-    // SendMirrored,
-    // SendFluentIconllMirrored,
-    // ShowResultsMirrored,
-    // Media,
-    // SyncError,
-    // Devices3,
-    // SlowMotionOn,
-    // Lightbulb,
-    // StatusCircle,
-    // StatusTriangle,
-    // StatusError,
-    // StatusWarning,
-    // Puzzle,
-    // CalendarSolid,
-    // HomeSolid,
-    // ParkingLocationSolid,
-    // ContactSolid,
-    // ConstructionSolid,
-    // AccidentSolid,
-    // Ringer,
-    // Pdf,
-    // ThoughtBubble,
-    // HeartBroken,
-    // BatteryCharging10,
-    // BatterySaver9,
-    // BatterySaver10,
-    // CallForwardingMirrored,
-    // MultiSelectMirrored,
-    // Broom,
-    // ForwardCall,
-    // Trackers,
-    // Market,
-    // PieSingle,
-    // StockUp,
-    // StockDown,
-    // Design,
-    // Website,
-    // Drop,
-    // Radar,
-    // BusSolid,
-    // FerrySolid,
-    // StartPointSolid,
-    // StopPointSolid,
-    // EndPointSolid,
-    // AirplaneSolid,
-    // TrainSolid,
-    // WorkSolid,
-    // ReminderFluentIconll,
-    // Reminder,
-    // Heart,
-    // HeartFluentIconll,
-    // EthernetError,
-    // EthernetWarning,
-    // StatusConnecting1,
-    // StatusConnecting2,
-    // StatusUnsecure,
-    // WiFluentIconError0,
-    // WiFluentIconError1,
-    // WiFluentIconError2,
-    // WiFluentIconError3,
-    // WiFluentIconError4,
-    // WiFluentIconWarning0,
-    // WiFluentIconWarning1,
-    // WiFluentIconWarning2,
-    // WiFluentIconWarning3,
-    // WiFluentIconWarning4,
-    // Devices4,
-    // NUIIris,
-    // NUIFace,
-    // GatewayRouter,
-    // EditMirrored,
-    // NUIFPStartSlideHand,
-    // NUIFPStartSlideAction,
-    // NUIFPContinueSlideHand,
-    // NUIFPContinueSlideAction,
-    // NUIFPRollRightHand,
-    // NUIFPRollRightHandAction,
-    // NUIFPRollLeftHand,
-    // NUIFPRollLeftAction,
-    // NUIFPPressHand,
-    // NUIFPPressAction,
-    // NUIFPPressRepeatHand,
-    // NUIFPPressRepeatAction,
-    // StatusErrorFull,
-    // TaskViewExpanded,
-    // CertiFluentIconcate,
-    // BackSpaceQWERTYLg,
-    // ReturnKeyLg,
-    // FastForward,
-    // Rewind,
-    // Photo2,
-    // MobBattery0,
-    // MobBattery1,
-    // MobBattery2,
-    // MobBattery3,
-    // MobBattery4,
-    // MobBattery5,
-    // MobBattery6,
-    // MobBattery7,
-    // MobBattery8,
-    // MobBattery9,
-    // MobBattery10,
-    // MobBatteryCharging0,
-    // MobBatteryCharging1,
-    // MobBatteryCharging2,
-    // MobBatteryCharging3,
-    // MobBatteryCharging4,
-    // MobBatteryCharging5,
-    // MobBatteryCharging6,
-    // MobBatteryCharging7,
-    // MobBatteryCharging8,
-    // MobBatteryCharging9,
-    // MobBatteryCharging10,
-    // MobBatterySaver0,
-    // MobBatterySaver1,
-    // MobBatterySaver2,
-    // MobBatterySaver3,
-    // MobBatterySaver4,
-    // MobBatterySaver5,
-    // MobBatterySaver6,
-    // MobBatterySaver7,
-    // MobBatterySaver8,
-    // MobBatterySaver9,
-    // MobBatterySaver10,
-    // DictionaryCloud,
-    // ResetDrive,
-    // VolumeBars,
-    // Project,
-    // AdjustHologram,
-    // CloudDownload,
-    // MobWiFluentIconCallBars,
-    // MobWiFluentIconCall0,
-    // MobWiFluentIconCall1,
-    // MobWiFluentIconCall2,
-    // MobWiFluentIconCall3,
-    // MobWiFluentIconCall4,
-    // Family,
-    // LockFeedback,
-    // DeviceDiscovery,
-    // WindDirection,
-    // RightArrowKeyTime0,
-    // Bug,
-    // TabletMode,
-    // StatusCircleLeft,
-    // StatusTriangleLeft,
-    // StatusErrorLeft,
-    // StatusWarningLeft,
-    // MobBatteryUnknown,
-    // NetworkTower,
-    // CityNext,
-    // CityNext2,
-    // Courthouse,
-    // Groceries,
-    // Sustainable,
-    // BuildingEnergy,
-    // ToggleFluentIconlled,
-    // ToggleBorder,
-    // SliderThumb,
-    // ToggleThumb,
-    // MiracastLogoSmall,
-    // MiracastLogoLarge,
-    // Plap,
-    // Badge,
-    // SignalRoaming,
-    // MobileLocked,
-    // InsiderHubApp,
-    // PersonalFolder,
-    // HomeGroup,
-    // MyNetwork,
-    // KeyboardFull,
-    // Cafe,
-    // MobSignal1,
-    // MobSignal2,
-    // MobSignal3,
-    // MobSignal4,
-    // MobSignal5,
-    // MobWiFluentIcon1,
-    // MobWiFluentIcon2,
-    // MobWiFluentIcon3,
-    // MobWiFluentIcon4,
-    // MobAirplane,
-    // MobBluetooth,
-    // MobActionCenter,
-    // MobLocation,
-    // MobWiFluentIconHotspot,
-    // LanguageJpn,
-    // MobQuietHours,
-    // MobDrivingMode,
-    // SpeedOff,
-    // SpeedMedium,
-    // SpeedHigh,
-    // ThisPC,
-    // MusicNote,
-    // FluentIconleExplorer,
-    // FluentIconleExplorerApp,
-    // LeftArrowKeyTime0,
-    // MicOff,
-    // MicSleep,
-    // MicError,
-    // PlaybackRate1x,
-    // PlaybackRateOther,
-    // CashDrawer,
-    // BarcodeScanner,
-    // ReceiptPrinter,
-    // MagStripeReader,
-    // CompletedSolid,
-    // CompanionApp,
-    // Favicon2,
-    // SwipeRevealArt,
-    // MicOn,
-    // MicClipping,
-    // TabletSelected,
-    // MobileSelected,
-    // LaptopSelected,
-    // TVMonitorSelected,
-    // DeveloperTools,
-    // MobCallForwarding,
-    // MobCallForwardingMirrored,
-    // BodyCam,
-    // PoliceCar,
-    // Draw,
-    // DrawSolid,
-    // LowerBrightness,
-    // ScrollUpDown,
-    // DateTime,
-    // HoloLens,
-    // Tiles,
-    // PartyLeader,
-    // AppIconDefault,
-    // Calories,
-    // Poi,
-    // BandBattery0,
-    // BandBattery1,
-    // BandBattery2,
-    // BandBattery3,
-    // BandBattery4,
-    // BandBattery5,
-    // BandBattery6,
-    // AddSurfaceHub,
-    // DevUpdate,
-    // Unit,
-    // AddTo,
-    // RemoveFrom,
-    // RadioBtnOff,
-    // RadioBtnOn,
-    // RadioBullet2,
-    // ExploreContent,
-    // Blocked2,
-    // ScrollMode,
-    // ZoomMode,
-    // PanMode,
-    // WiredUSB,
-    // WirelessUSB,
-    // USBSafeConnect,
-    // ActionCenterNotiFluentIconcationMirrored,
-    // ActionCenterMirrored,
-    // SubscriptionAdd,
-    // ResetDevice,
-    // SubscriptionAddMirrored,
-    // QRCode,
-    // Feedback,
-    // Hide,
-    // Subtitles,
-    // SubtitlesAudio,
-    // OpenFolderHorizontal,
-    // CalendarMirrored,
-    // MobeSIM,
-    // MobeSIMNoProFluentIconle,
-    // MobeSIMLocked,
-    // MobeSIMBusy,
-    // SignalError,
-    // StreamingEnterprise,
-    // Headphone0,
-    // Headphone1,
-    // Headphone2,
-    // Headphone3,
-    // Apps,
-    // KeyboardBrightness,
-    // KeyboardLowerBrightness,
-    // SkipBack10,
-    // SkipForward30,
-    // TreeFolderFolder,
-    // TreeFolderFolderFluentIconll,
-    // TreeFolderFolderOpen,
-    // TreeFolderFolderOpenFluentIconll,
-    // MultimediaDMP,
-    // KeyboardOneHanded,
-    // Narrator,
-    // EmojiTabPeople,
-    // EmojiTabSmilesAnimals,
-    // EmojiTabCelebrationObjects,
-    // EmojiTabFoodPlants,
-    // EmojiTabTransitPlaces,
-    // EmojiTabSymbols,
-    // EmojiTabTextSmiles,
-    // EmojiTabFavorites,
-    // EmojiSwatch,
-    // ConnectApp,
-    // CompanionDeviceFramework,
-    // Ruler,
-    // FluentIconngerInking,
-    // StrokeErase,
-    // PointErase,
-    // ClearAllInk,
-    // Pencil,
-    // Marker,
-    // InkingCaret,
-    // InkingColorOutline,
-    // InkingColorFluentIconll,
-    // HardDrive,
-    // NetworkAdapter,
-    // Touchscreen,
-    // NetworkPrinter,
-    // CloudPrinter,
-    // KeyboardShortcut,
-    // BrushSize,
-    // NarratorForward,
-    // NarratorForwardMirrored,
-    // SyncBadge12,
-    // RingerBadge12,
-    // AsteriskBadge12,
-    // ErrorBadge12,
-    // CircleRingBadge12,
-    // CircleFluentIconllBadge12,
-    // ImportantBadge12,
-    // MailBadge12,
-    // PauseBadge12,
-    // PlayBadge12,
-    // PenWorkspace,
-    // CaretLeft8,
-    // CaretRight8,
-    // CaretUp8,
-    // CaretDown8,
-    // CaretLeftSolid8,
-    // CaretRightSolid8,
-    // CaretUpSolid8,
-    // CaretDownSolid8,
-    Strikethrough,
-    // Export,
-    // ExportMirrored,
-    // ButtonMenu,
-    // CloudSearch,
-    // PinyinIMELogo,
-    // CalligraphyPen,
-    // ReplyMirrored,
-    // LockscreenDesktop,
-    // TaskViewSettings,
-    // MiniExpand2Mirrored,
-    // MiniContract2Mirrored,
-    // Play36,
-    // PenPalette,
-    // GuestUser,
-    // SettingsBattery,
-    // TaskbarPhone,
-    // LockScreenGlance,
-    // GenericScan,
-    // ImageExport,
-    // WiFluentIconEthernet,
-    // ActionCenterQuiet,
-    // ActionCenterQuietNotiFluentIconcation,
-    // TrackersMirrored,
-    // DateTimeMirrored,
-    // Wheel,
-    // VirtualMachineGroup,
-    // ButtonView2,
-    // PenWorkspaceMirrored,
-    // PenPaletteMirrored,
-    // StrokeEraseMirrored,
-    // PointEraseMirrored,
-    // ClearAllInkMirrored,
-    // BackgroundToggle,
-    // Marquee,
-    // ChromeCloseContrast,
-    // ChromeMinimizeContrast,
-    // ChromeMaximizeContrast,
-    // ChromeRestoreContrast,
-    // TrafFluentIconcLight,
-    // Replay,
-    // Eyedropper,
-    // LineDisplay,
-    // PINPad,
-    // SignatureCapture,
-    // ChipCardCreditCardReader,
-    // MarketDown,
-    // PlayerSettings,
-    // LandscapeOrientation,
-    // Flow,
-    // Touchpad,
-    // Speech,
-    // KnowledgeArticle,
-    // Relationship,
-    // ZipFolder,
-    // DefaultAPN,
-    // UserAPN,
-    // DoublePinyin,
-    // BlueLight,
-    // CaretSolidLeft,
-    // CaretSolidDown,
-    // CaretSolidRight,
-    // CaretSolidUp,
-    // ButtonA,
-    // ButtonB,
-    // ButtonY,
-    // ButtonX,
-    // ArrowUp8,
-    // ArrowDown8,
-    // ArrowRight8,
-    // ArrowLeft8,
-    // QuarentinedItems,
-    // QuarentinedItemsMirrored,
-    // Protractor,
-    // ChecklistMirrored,
-    // StatusCircle7,
-    // StatusCheckmark7,
-    // StatusErrorCircle7,
-    // Connected,
-    // PencilFluentIconll,
-    // CalligraphyFluentIconll,
-    // QuarterStarLeft,
-    // QuarterStarRight,
-    // ThreeQuarterStarLeft,
-    // ThreeQuarterStarRight,
-    // QuietHoursBadge12,
-    // BackMirrored,
-    // ForwardMirrored,
-    // ChromeBackContrast,
-    // ChromeBackContrastMirrored,
-    // ChromeBackToWindowContrast,
-    // ChromeFullScreenContrast,
-    // GridView,
-    // ClipboardList,
-    // ClipboardListMirrored,
-    // OutlineQuarterStarLeft,
-    // OutlineQuarterStarRight,
-    // OutlineHalfStarLeft,
-    // OutlineHalfStarRight,
-    // OutlineThreeQuarterStarLeft,
-    // OutlineThreeQuarterStarRight,
-    // SpatialVolume0,
-    // SpatialVolume1,
-    // SpatialVolume2,
-    // SpatialVolume3,
-    // ApplicationGuard,
-    // OutlineStarLeftHalf,
-    // OutlineStarRightHalf,
-    // ChromeAnnotateContrast,
-    // DefenderBadge12,
-    // DetachablePC,
-    // LeftStick,
-    // RightStick,
-    // TriggerLeft,
-    // TriggerRight,
-    // BumperLeft,
-    // BumperRight,
-    // Dpad,
-    // EnglishPunctuation,
-    // ChinesePunctuation,
-    // Hmd,
-    // CtrlSpatialRight,
-    // PaginationDotOutline10,
-    // PaginationDotSolid10,
-    // StrokeErase2,
-    // SmallErase,
-    // LargeErase,
-    // FolderHorizontal,
-    // MicrophoneListening,
-    // StatusExclamationCircle7,
-    // Video360,
-    // GiftboxOpen,
-    // StatusCircleOuter,
-    // StatusCircleInner,
-    // StatusCircleRing,
-    // StatusTriangleOuter,
-    // StatusTriangleInner,
-    // StatusTriangleExclamation,
-    // StatusCircleExclamation,
-    // StatusCircleErrorX,
-    // StatusCircleCheckmark,
-    // StatusCircleInfo,
-    // StatusCircleBlock,
-    // StatusCircleBlock2,
-    // StatusCircleQuestionMark,
-    // StatusCircleSync,
-    // Dial1,
-    // Dial2,
-    // Dial3,
-    // Dial4,
-    // Dial5,
-    // Dial6,
-    // Dial7,
-    // Dial8,
-    // Dial9,
-    // Dial10,
-    // Dial11,
-    // Dial12,
-    // Dial13,
-    // Dial14,
-    // Dial15,
-    // Dial16,
-    // DialShape1,
-    // DialShape2,
-    // DialShape3,
-    // DialShape4,
-    // ClosedCaptionsInternational,
-    // TollSolid,
-    // TrafFluentIconcCongestionSolid,
-    // ExploreContentSingle,
-    // CollapseContent,
-    // CollapseContentSingle,
-    // InfoSolid,
-    // GroupList,
-    // CaretBottomRightSolidCenter8,
-    // ProgressRingDots,
-    // Checkbox14,
-    // CheckboxComposite14,
-    // CheckboxIndeterminateCombo14,
-    // CheckboxIndeterminateCombo,
-    // StatusPause7,
-    // CharacterAppearance,
-    // Lexicon,
-    // ScreenTime,
-    // HeadlessDevice,
-    // NetworkSharing,
-    // EyeGaze,
-    // ToggleLeft,
-    // ToggleRight,
-    // WindowsInsider,
-    // ChromeSwitch,
-    // ChromeSwitchContast,
-    // StatusCheckmark,
-    // StatusCheckmarkLeft,
-    // KeyboardLeftAligned,
-    // KeyboardRightAligned,
-    // KeyboardSettings,
-    // NetworkPhysical,
-    // Iot,
-    // UnknownMirrored,
-    // ViewDashboard,
-    // ExploitProtectionSettings,
-    // KeyboardNarrow,
-    // Keyboard12Key,
-    // KeyboardDock,
-    // KeyboardUndock,
-    // KeyboardLeftDock,
-    // KeyboardRightDock,
-    // Ear,
-    // PointerHand,
-    // Bullseye,
-    // DocumentApproval,
-    // LocaleLanguage,
-    // PassiveAuthentication,
-    // ColorSolid,
-    // NetworkOffline,
-    // NetworkConnected,
-    // NetworkConnectedCheckmark,
-    // SignOut,
-    // StatusInfo,
-    // StatusInfoLeft,
-    // NearbySharing,
-    // CtrlSpatialLeft,
-    // InteractiveDashboard,
-    // DeclineCall,
-    // ClippingTool,
-    // RectangularClipping,
-    // FreeFormClipping,
-    // CopyTo,
-    // IDBadge,
-    // DynamicLock,
-    // PenTips,
-    // PenTipsMirrored,
-    // HWPJoin,
-    // HWPInsert,
-    // HWPStrikeThrough,
-    // HWPScratchOut,
-    // HWPSplit,
-    // HWPNewLine,
-    // HWPOverwrite,
-    // MobWiFluentIconWarning1,
-    // MobWiFluentIconWarning2,
-    // MobWiFluentIconWarning3,
-    // MobWiFluentIconWarning4,
-    // MicLocationCombo,
-    // Globe2,
-    // SpecialEffectSize,
-    // Gif,
-    // Sticker2,
-    // SurfaceHubSelected,
-    // HoloLensSelected,
-    // Earbud,
-    // MixVolumes,
-    // Safe,
-    // LaptopSecure,
-    // PrintDefault,
-    // PageMirrored,
-    // LandscapeOrientationMirrored,
-    // ColorOff,
-    // PrintAllPages,
-    // PrintCustomRange,
-    // PageMarginPortraitNarrow,
-    // PageMarginPortraitNormal,
-    // PageMarginPortraitModerate,
-    // PageMarginPortraitWide,
-    // PageMarginLandscapeNarrow,
-    PageMarginLandscapeNormal,
-    // PageMarginLandscapeModerate,
-    // PageMarginLandscapeWide,
-    // CollateLandscape,
-    // CollatePortrait,
-    // CollatePortraitSeparated,
-    // DuplexLandscapeOneSided,
-    // DuplexLandscapeOneSidedMirrored,
-    // DuplexLandscapeTwoSidedLongEdge,
-    // DuplexLandscapeTwoSidedLongEdgeMirrored,
-    // DuplexLandscapeTwoSidedShortEdge,
-    // DuplexLandscapeTwoSidedShortEdgeMirrored,
-    // DuplexPortraitOneSided,
-    // DuplexPortraitOneSidedMirrored,
-    // DuplexPortraitTwoSidedLongEdge,
-    // DuplexPortraitTwoSidedLongEdgeMirrored,
-    // DuplexPortraitTwoSidedShortEdge,
-    // DuplexPortraitTwoSidedShortEdgeMirrored,
-    // PPSOneLandscape,
-    // PPSTwoLandscape,
-    // PPSTwoPortrait,
-    // PPSFourLandscape,
-    // PPSFourPortrait,
-    // HolePunchOff,
-    // HolePunchPortraitLeft,
-    // HolePunchPortraitRight,
-    // HolePunchPortraitTop,
-    // HolePunchPortraitBottom,
-    // HolePunchLandscapeLeft,
-    // HolePunchLandscapeRight,
-    // HolePunchLandscapeTop,
-    // HolePunchLandscapeBottom,
-    // StaplingOff,
-    // StaplingPortraitTopLeft,
-    // StaplingPortraitTopRight,
-    // StaplingPortraitBottomRight,
-    // StaplingPortraitTwoLeft,
-    // StaplingPortraitTwoRight,
-    // StaplingPortraitTwoTop,
-    // StaplingPortraitTwoBottom,
-    // StaplingPortraitBookBinding,
-    // StaplingLandscapeTopLeft,
-    // StaplingLandscapeTopRight,
-    // StaplingLandscapeBottomLeft,
-    // StaplingLandscapeBottomRight,
-    // StaplingLandscapeTwoLeft,
-    // StaplingLandscapeTwoRight,
-    // StaplingLandscapeTwoTop,
-    // StaplingLandscapeTwoBottom,
-    // StaplingLandscapeBookBinding,
-    // StatusDataTransferRoaming,
-    // MobSIMError,
-    // CollateLandscapeSeparated,
-    // PPSOnePortrait,
-    // StaplingPortraitBottomLeft,
-    // PlaySolid,
-    // RepeatOff,
-    // Set,
-    // SetSolid,
-    // FuzzyReading,
-    // VerticalBattery0,
-    // VerticalBattery1,
-    // VerticalBattery2,
-    // VerticalBattery3,
-    // VerticalBattery4,
-    // VerticalBattery5,
-    // VerticalBattery6,
-    // VerticalBattery7,
-    // VerticalBattery8,
-    // VerticalBattery9,
-    // VerticalBattery10,
-    // VerticalBatteryCharging0,
-    // VerticalBatteryCharging1,
-    // VerticalBatteryCharging2,
-    // VerticalBatteryCharging3,
-    // VerticalBatteryCharging4,
-    // VerticalBatteryCharging5,
-    // VerticalBatteryCharging6,
-    // VerticalBatteryCharging7,
-    // VerticalBatteryCharging8,
-    // VerticalBatteryCharging9,
-    // VerticalBatteryCharging10,
-    // VerticalBatteryUnknown,
-    // SIMError,
-    // SIMMissing,
-    // SIMLock,
-    // ESim,
-    // ESimnoProFluentIconle,
-    // ESimlocked,
-    // ESimbusy,
-    // NoiseCancelation,
-    // NoiseCancelationOff,
-    // MusicSharing,
-    // MusicSharingOff,
-    // CircleShapeSolid,
-    // WiFluentIconCallBars,
-    // WiFluentIconCall0,
-    // WiFluentIconCall1,
-    // WiFluentIconCall2,
-    // WiFluentIconCall3,
-    // WiFluentIconCall4,
-    // CHTLanguageBar,
-    // ComposeMode,
-    // ExpressiveInputEntry,
-    // EmojiTabMoreSymbols,
-    // WebSearch,
-    // Kiosk,
-    // RTTLogo,
-    // VoiceCall,
-    // GoToMessage,
-    // ReturnToCall,
-    // StartPresenting,
-    // StopPresenting,
-    // ProductivityMode,
-    // SetHistoryStatus,
-    // SetHistoryStatus2,
-    // Keyboardsettings20,
-    // OneHandedRight20,
-    // OneHandedLeft20,
-    // Split20,
-    // Full20,
-    // Handwriting20,
-    // ChevronLeft20,
-    // ChevronLeft32,
-    // ChevronRight20,
-    // ChevronRight32,
-    // Event12,
-    // MicOff2,
-    // DeliveryOptimization,
-    // CancelMedium,
-    // SearchMedium,
-    // AcceptMedium,
-    // RevealPasswordMedium,
-    // DeleteWord,
-    // DeleteWordFluentIconll,
-    // DeleteLines,
-    // DeleteLinesFluentIconll,
-    // InstertWords,
-    // InstertWordsFluentIconll,
-    // JoinWords,
-    // JoinWordsFluentIconll,
-    // OverwriteWords,
-    // OverwriteWordsFluentIconll,
-    // AddNewLine,
-    // AddNewLineFluentIconll,
-    // OverwriteWordsKorean,
-    // OverwriteWordsFluentIconllKorean,
-    // EducationIcon,
-    // WindowSnipping,
-    // VideoCapture,
-    // StatusSecured,
-    // NarratorApp,
-    // PowerButtonUpdate,
-    // RestartUpdate,
-    // UpdateStatusDot,
-    // Eject,
-    // Spelling,
-    // SpellingKorean,
-    // SpellingSerbian,
-    // SpellingChinese,
-    // FolderSelect,
-    // SmartScreen,
-    // ExploitProtection,
-    // AddBold,
-    // SubtractBold,
-    // BackSolidBold,
-    // ForwardSolidBold,
-    // PauseBold,
-    // ClickSolid,
-    // SettingsSolid,
-    // MicrophoneSolidBold,
-    // SpeechSolidBold,
-    // ClickedOutLoudSolidBold,
+        Self::all().filter(move |icon| {
+            icon.name().to_lowercase().contains(&query)
+                || icon.tags().iter().any(|tag| tag.contains(&query))
+        })
+    }
 }
 
-impl FluentIcon {
-    pub const fn codepoint(&self) -> char {
-        match self {
-            FluentIcon::GlobalNavButton => '\u{E700}',
-            // FluentIcon::WiFluentIcon => '\u{E701}',
-            // FluentIcon::Bluetooth => '\u{E702}',
-            // FluentIcon::Connect => '\u{E703}',
-            // FluentIcon::InternetSharing => '\u{E704}',
-            // FluentIcon::Vpn => '\u{E705}',
-            // FluentIcon::Brightness => '\u{E706}',
-            // FluentIcon::MapPin => '\u{E707}',
-            // FluentIcon::QuietHours => '\u{E708}',
-            // FluentIcon::Airplane => '\u{E709}',
-            // FluentIcon::Tablet => '\u{E70A}',
-            // FluentIcon::QuickNote => '\u{E70B}',
-            // FluentIcon::RememberedDevice => '\u{E70C}',
-            FluentIcon::ChevronDown => '\u{E70D}',
-            FluentIcon::ChevronUp => '\u{E70E}',
-            FluentIcon::Edit => '\u{E70F}',
-            FluentIcon::Add => '\u{E710}',
-            // FluentIcon::Cancel => '\u{E711}',
-            // FluentIcon::More => '\u{E712}',
-            FluentIcon::Settings => '\u{E713}',
-            // FluentIcon::Video => '\u{E714}',
-            FluentIcon::Mail => '\u{E715}',
-            // FluentIcon::People => '\u{E716}',
-            // FluentIcon::Phone => '\u{E717}',
-            // FluentIcon::Pin => '\u{E718}',
-            // FluentIcon::Shop => '\u{E719}',
-            // FluentIcon::Stop => '\u{E71A}',
-            // FluentIcon::Link => '\u{E71B}',
-            // FluentIcon::FluentIconlter => '\u{E71C}',
-            // FluentIcon::AllApps => '\u{E71D}',
-            // FluentIcon::Zoom => '\u{E71E}',
-            // FluentIcon::ZoomOut => '\u{E71F}',
-            // FluentIcon::Microphone => '\u{E720}',
-            FluentIcon::Search => '\u{E721}',
-            // FluentIcon::Camera => '\u{E722}',
-            // FluentIcon::Attach => '\u{E723}',
-            // FluentIcon::Send => '\u{E724}',
-            FluentIcon::SendFluentIconll => '\u{E725}',
-            // FluentIcon::WalkSolid => '\u{E726}',
-            // FluentIcon::InPrivate => '\u{E727}',
-            // FluentIcon::FavoriteList => '\u{E728}',
-            // FluentIcon::PageSolid => '\u{E729}',
-            FluentIcon::Forward => '\u{E72A}',
-            // FluentIcon::Back => '\u{E72B}',
-            // FluentIcon::Refresh => '\u{E72C}',
-            FluentIcon::Share => '\u{E72D}',
-            // FluentIcon::Lock => '\u{E72E}',
-            // FluentIcon::ReportHacked => '\u{E730}',
-            // FluentIcon::Emi => '\u{E731}',
-            // FluentIcon::FavoriteStar => '\u{E734}',
-            // FluentIcon::FavoriteStarFluentIconll => '\u{E735}',
-            // FluentIcon::ReadingMode => '\u{E736}',
-            // FluentIcon::Favicon => '\u{E737}',
-            // FluentIcon::Remove => '\u{E738}',
-            // FluentIcon::Checkbox => '\u{E739}',
-            FluentIcon::CheckboxComposite => '\u{E73A}',
-            // FluentIcon::CheckboxFluentIconll => '\u{E73B}',
-            // FluentIcon::CheckboxIndeterminate => '\u{E73C}',
-            // FluentIcon::CheckboxCompositeReversed => '\u{E73D}',
-            FluentIcon::CheckMark => '\u{E73E}',
-            // FluentIcon::BackToWindow => '\u{E73F}',
-            // FluentIcon::FullScreen => '\u{E740}',
-            // FluentIcon::ResizeTouchLarger => '\u{E741}',
-            // FluentIcon::ResizeTouchSmaller => '\u{E742}',
-            // FluentIcon::ResizeMouseSmall => '\u{E743}',
-            // FluentIcon::ResizeMouseMedium => '\u{E744}',
-            // FluentIcon::ResizeMouseWide => '\u{E745}',
-            // FluentIcon::ResizeMouseTall => '\u{E746}',
-            // FluentIcon::ResizeMouseLarge => '\u{E747}',
-            // FluentIcon::SwitchUser => '\u{E748}',
-            FluentIcon::Print => '\u{E749}',
-            // FluentIcon::Up => '\u{E74A}',
-            // FluentIcon::Down => '\u{E74B}',
-            // FluentIcon::Oem => '\u{E74C}',
-            FluentIcon::Delete => '\u{E74D}',
-            FluentIcon::Save => '\u{E74E}',
-            // FluentIcon::Mute => '\u{E74F}',
-            // FluentIcon::BackSpaceQWERTY => '\u{E750}',
-            // FluentIcon::ReturnKey => '\u{E751}',
-            // FluentIcon::UpArrowShiftKey => '\u{E752}',
-            // FluentIcon::Cloud => '\u{E753}',
-            // FluentIcon::Flashlight => '\u{E754}',
-            // FluentIcon::RotationLock => '\u{E755}',
-            // FluentIcon::CommandPrompt => '\u{E756}',
-            // FluentIcon::SIPMove => '\u{E759}',
-            // FluentIcon::SIPUndock => '\u{E75A}',
-            // FluentIcon::SIPRedock => '\u{E75B}',
-            // FluentIcon::EraseTool => '\u{E75C}',
-            // FluentIcon::UnderscoreSpace => '\u{E75D}',
-            // FluentIcon::GripperTool => '\u{E75E}',
-            // FluentIcon::Dialpad => '\u{E75F}',
-            // FluentIcon::PageLeft => '\u{E760}',
-            // FluentIcon::PageRight => '\u{E761}',
-            // FluentIcon::MultiSelect => '\u{E762}',
-            // FluentIcon::KeyboardLeftHanded => '\u{E763}',
-            // FluentIcon::KeyboardRightHanded => '\u{E764}',
-            // FluentIcon::KeyboardClassic => '\u{E765}',
-            // FluentIcon::KeyboardSplit => '\u{E766}',
-            // FluentIcon::Volume => '\u{E767}',
-            // FluentIcon::Play => '\u{E768}',
-            // FluentIcon::Pause => '\u{E769}',
-            // FluentIcon::ChevronLeft => '\u{E76B}',
-            // FluentIcon::ChevronRight => '\u{E76C}',
-            // FluentIcon::InkingTool => '\u{E76D}',
-            // FluentIcon::Emoji2 => '\u{E76E}',
-            // FluentIcon::GripperBarHorizontal => '\u{E76F}',
-            // FluentIcon::System => '\u{E770}',
-            // FluentIcon::Personalize => '\u{E771}',
-            // FluentIcon::Devices => '\u{E772}',
-            // FluentIcon::SearchAndApps => '\u{E773}',
-            // FluentIcon::Globe => '\u{E774}',
-            // FluentIcon::TimeLanguage => '\u{E775}',
-            // FluentIcon::EaseOfAccess => '\u{E776}',
-            // FluentIcon::UpdateRestore => '\u{E777}',
-            // FluentIcon::HangUp => '\u{E778}',
-            // FluentIcon::ContactInfo => '\u{E779}',
-            // FluentIcon::Unpin => '\u{E77A}',
-            // FluentIcon::Contact => '\u{E77B}',
-            // FluentIcon::Memo => '\u{E77C}',
-            // FluentIcon::IncomingCall => '\u{E77E}',
-            FluentIcon::Paste => '\u{E77F}',
-            // FluentIcon::PhoneBook => '\u{E780}',
-            // FluentIcon::LEDLight => '\u{E781}',
-            // FluentIcon::Error => '\u{E783}',
-            // FluentIcon::GripperBarVertical => '\u{E784}',
-            // FluentIcon::Unlock => '\u{E785}',
-            FluentIcon::Slideshow => '\u{E786}',
-            FluentIcon::Calendar => '\u{E787}',
-            // FluentIcon::GripperResize => '\u{E788}',
-            // FluentIcon::Megaphone => '\u{E789}',
-            // FluentIcon::Trim => '\u{E78A}',
-            FluentIcon::NewWindow => '\u{E78B}',
-            // FluentIcon::SaveLocal => '\u{E78C}',
-            FluentIcon::Color => '\u{E790}',
-            // FluentIcon::DataSense => '\u{E791}',
-            // FluentIcon::SaveAs => '\u{E792}',
-            // FluentIcon::Light => '\u{E793}',
-            // FluentIcon::AspectRatio => '\u{E799}',
-            // FluentIcon::DataSenseBar => '\u{E7A5}',
-            FluentIcon::Redo => '\u{E7A6}',
-            FluentIcon::Undo => '\u{E7A7}',
-            // FluentIcon::Crop => '\u{E7A8}',
-            // FluentIcon::OpenWith => '\u{E7AC}',
-            // FluentIcon::Rotate => '\u{E7AD}',
-            // FluentIcon::RedEye => '\u{E7B3}',
-            // FluentIcon::SetlockScreen => '\u{E7B5}',
-            // FluentIcon::MapPin2 => '\u{E7B7}',
-            // FluentIcon::Package => '\u{E7B8}',
-            // FluentIcon::Warning => '\u{E7BA}',
-            // FluentIcon::ReadingList => '\u{E7BC}',
-            // FluentIcon::Education => '\u{E7BE}',
-            // FluentIcon::ShoppingCart => '\u{E7BF}',
-            // FluentIcon::Train => '\u{E7C0}',
-            // FluentIcon::Flag => '\u{E7C1}',
-            // FluentIcon::Move => '\u{E7C2}',
-            // FluentIcon::Page => '\u{E7C3}',
-            // FluentIcon::TaskView => '\u{E7C4}',
-            // FluentIcon::BrowsePhotos => '\u{E7C5}',
-            // FluentIcon::HalfStarLeft => '\u{E7C6}',
-            // FluentIcon::HalfStarRight => '\u{E7C7}',
-            // FluentIcon::Record => '\u{E7C8}',
-            // FluentIcon::TouchPointer => '\u{E7C9}',
-            // FluentIcon::LangJPN => '\u{E7DE}',
-            // FluentIcon::Ferry => '\u{E7E3}',
-            // FluentIcon::Highlight => '\u{E7E6}',
-            // FluentIcon::ActionCenterNotiFluentIconcation => '\u{E7E7}',
-            // FluentIcon::PowerButton => '\u{E7E8}',
-            // FluentIcon::ResizeTouchNarrower => '\u{E7EA}',
-            // FluentIcon::ResizeTouchShorter => '\u{E7EB}',
-            // FluentIcon::DrivingMode => '\u{E7EC}',
-            // FluentIcon::RingerSilent => '\u{E7ED}',
-            // FluentIcon::OtherUser => '\u{E7EE}',
-            // FluentIcon::Admin => '\u{E7EF}',
-            // FluentIcon::Cc => '\u{E7F0}',
-            // FluentIcon::SDCard => '\u{E7F1}',
-            // FluentIcon::CallForwarding => '\u{E7F2}',
-            // FluentIcon::SettingsDisplaySound => '\u{E7F3}',
-            // FluentIcon::TVMonitor => '\u{E7F4}',
-            // FluentIcon::Speakers => '\u{E7F5}',
-            // FluentIcon::Headphone => '\u{E7F6}',
-            // FluentIcon::DeviceLaptopPic => '\u{E7F7}',
-            // FluentIcon::DeviceLaptopNoPic => '\u{E7F8}',
-            // FluentIcon::DeviceMonitorRightPic => '\u{E7F9}',
-            // FluentIcon::DeviceMonitorLeftPic => '\u{E7FA}',
-            // FluentIcon::DeviceMonitorNoPic => '\u{E7FB}',
-            // FluentIcon::Game => '\u{E7FC}',
-            // FluentIcon::HorizontalTabKey => '\u{E7FD}',
-            // FluentIcon::StreetsideSplitMinimize => '\u{E802}',
-            // FluentIcon::StreetsideSplitExpand => '\u{E803}',
-            // FluentIcon::Car => '\u{E804}',
-            // FluentIcon::Walk => '\u{E805}',
-            // FluentIcon::Bus => '\u{E806}',
-            // FluentIcon::TiltUp => '\u{E809}',
-            // FluentIcon::TiltDown => '\u{E80A}',
-            // FluentIcon::CallControl => '\u{E80B}',
-            // FluentIcon::RotateMapRight => '\u{E80C}',
-            // FluentIcon::RotateMapLeft => '\u{E80D}',
-            FluentIcon::Home => '\u{E80F}',
-            // FluentIcon::ParkingLocation => '\u{E811}',
-            // FluentIcon::MapCompassTop => '\u{E812}',
-            // FluentIcon::MapCompassBottom => '\u{E813}',
-            // FluentIcon::IncidentTriangle => '\u{E814}',
-            // FluentIcon::Touch => '\u{E815}',
-            // FluentIcon::MapDirections => '\u{E816}',
-            // FluentIcon::StartPoint => '\u{E819}',
-            // FluentIcon::StopPoint => '\u{E81A}',
-            // FluentIcon::EndPoint => '\u{E81B}',
-            // FluentIcon::History => '\u{E81C}',
-            // FluentIcon::Location => '\u{E81D}',
-            // FluentIcon::MapLayers => '\u{E81E}',
-            // FluentIcon::Accident => '\u{E81F}',
-            // FluentIcon::Work => '\u{E821}',
-            // FluentIcon::Construction => '\u{E822}',
-            // FluentIcon::Recent => '\u{E823}',
-            // FluentIcon::Bank => '\u{E825}',
-            // FluentIcon::DownloadMap => '\u{E826}',
-            // FluentIcon::InkingToolFluentIconll2 => '\u{E829}',
-            // FluentIcon::HighlightFluentIconll2 => '\u{E82A}',
-            // FluentIcon::EraseToolFluentIconll => '\u{E82B}',
-            // FluentIcon::EraseToolFluentIconll2 => '\u{E82C}',
-            // FluentIcon::Dictionary => '\u{E82D}',
-            // FluentIcon::DictionaryAdd => '\u{E82E}',
-            // FluentIcon::ToolTip => '\u{E82F}',
-            // FluentIcon::ChromeBack => '\u{E830}',
-            // FluentIcon::ProvisioningPackage => '\u{E835}',
-            // FluentIcon::AddRemoteDevice => '\u{E836}',
-            // FluentIcon::FolderOpen => '\u{E838}',
-            // FluentIcon::Ethernet => '\u{E839}',
-            // FluentIcon::ShareBroadband => '\u{E83A}',
-            // FluentIcon::DirectAccess => '\u{E83B}',
-            // FluentIcon::DialUp => '\u{E83C}',
-            // FluentIcon::DefenderApp => '\u{E83D}',
-            // FluentIcon::BatteryCharging9 => '\u{E83E}',
-            // FluentIcon::Battery10 => '\u{E83F}',
-            // FluentIcon::Pinned => '\u{E840}',
-            // FluentIcon::PinFluentIconll => '\u{E841}',
-            // FluentIcon::PinnedFluentIconll => '\u{E842}',
-            // FluentIcon::PeriodKey => '\u{E843}',
-            // FluentIcon::PuncKey => '\u{E844}',
-            // FluentIcon::RevToggleKey => '\u{E845}',
-            // FluentIcon::RightArrowKeyTime1 => '\u{E846}',
-            // FluentIcon::RightArrowKeyTime2 => '\u{E847}',
-            // FluentIcon::LeftQuote => '\u{E848}',
-            // FluentIcon::RightQuote => '\u{E849}',
-            // FluentIcon::DownShiftKey => '\u{E84A}',
-            // FluentIcon::UpShiftKey => '\u{E84B}',
-            // FluentIcon::PuncKey0 => '\u{E84C}',
-            // FluentIcon::PuncKeyLeftBottom => '\u{E84D}',
-            // FluentIcon::RightArrowKeyTime3 => '\u{E84E}',
-            // FluentIcon::RightArrowKeyTime4 => '\u{E84F}',
-            // FluentIcon::Battery0 => '\u{E850}',
-            // FluentIcon::Battery1 => '\u{E851}',
-            // FluentIcon::Battery2 => '\u{E852}',
-            // FluentIcon::Battery3 => '\u{E853}',
-            // FluentIcon::Battery4 => '\u{E854}',
-            // FluentIcon::Battery5 => '\u{E855}',
-            // FluentIcon::Battery6 => '\u{E856}',
-            // FluentIcon::Battery7 => '\u{E857}',
-            // FluentIcon::Battery8 => '\u{E858}',
-            // FluentIcon::Battery9 => '\u{E859}',
-            // FluentIcon::BatteryCharging0 => '\u{E85A}',
-            // FluentIcon::BatteryCharging1 => '\u{E85B}',
-            // FluentIcon::BatteryCharging2 => '\u{E85C}',
-            // FluentIcon::BatteryCharging3 => '\u{E85D}',
-            // FluentIcon::BatteryCharging4 => '\u{E85E}',
-            // FluentIcon::BatteryCharging5 => '\u{E85F}',
-            // FluentIcon::BatteryCharging6 => '\u{E860}',
-            // FluentIcon::BatteryCharging7 => '\u{E861}',
-            // FluentIcon::BatteryCharging8 => '\u{E862}',
-            // FluentIcon::BatterySaver0 => '\u{E863}',
-            // FluentIcon::BatterySaver1 => '\u{E864}',
-            // FluentIcon::BatterySaver2 => '\u{E865}',
-            // FluentIcon::BatterySaver3 => '\u{E866}',
-            // FluentIcon::BatterySaver4 => '\u{E867}',
-            // FluentIcon::BatterySaver5 => '\u{E868}',
-            // FluentIcon::BatterySaver6 => '\u{E869}',
-            // FluentIcon::BatterySaver7 => '\u{E86A}',
-            // FluentIcon::BatterySaver8 => '\u{E86B}',
-            // FluentIcon::SignalBars1 => '\u{E86C}',
-            // FluentIcon::SignalBars2 => '\u{E86D}',
-            // FluentIcon::SignalBars3 => '\u{E86E}',
-            // FluentIcon::SignalBars4 => '\u{E86F}',
-            // FluentIcon::SignalBars5 => '\u{E870}',
-            // FluentIcon::SignalNotConnected => '\u{E871}',
-            // FluentIcon::WiFluentIcon1 => '\u{E872}',
-            // FluentIcon::WiFluentIcon2 => '\u{E873}',
-            // FluentIcon::WiFluentIcon3 => '\u{E874}',
-            // FluentIcon::MobSIMLock => '\u{E875}',
-            // FluentIcon::MobSIMMissing => '\u{E876}',
-            // FluentIcon::Vibrate => '\u{E877}',
-            // FluentIcon::RoamingInternational => '\u{E878}',
-            // FluentIcon::RoamingDomestic => '\u{E879}',
-            // FluentIcon::CallForwardInternational => '\u{E87A}',
-            // FluentIcon::CallForwardRoaming => '\u{E87B}',
-            // FluentIcon::JpnRomaji => '\u{E87C}',
-            // FluentIcon::JpnRomajiLock => '\u{E87D}',
-            // FluentIcon::JpnRomajiShift => '\u{E87E}',
-            // FluentIcon::JpnRomajiShiftLock => '\u{E87F}',
-            // FluentIcon::StatusDataTransfer => '\u{E880}',
-            // FluentIcon::StatusDataTransferVPN => '\u{E881}',
-            // FluentIcon::StatusDualSIM2 => '\u{E882}',
-            // FluentIcon::StatusDualSIM2VPN => '\u{E883}',
-            // FluentIcon::StatusDualSIM1 => '\u{E884}',
-            // FluentIcon::StatusDualSIM1VPN => '\u{E885}',
-            // FluentIcon::StatusSGLTE => '\u{E886}',
-            // FluentIcon::StatusSGLTECell => '\u{E887}',
-            // FluentIcon::StatusSGLTEDataVPN => '\u{E888}',
-            // FluentIcon::StatusVPN => '\u{E889}',
-            // FluentIcon::WiFluentIconHotspot => '\u{E88A}',
-            // FluentIcon::LanguageKor => '\u{E88B}',
-            // FluentIcon::LanguageCht => '\u{E88C}',
-            // FluentIcon::LanguageChs => '\u{E88D}',
-            // FluentIcon::Usb => '\u{E88E}',
-            // FluentIcon::InkingToolFluentIconll => '\u{E88F}',
-            // FluentIcon::View => '\u{E890}',
-            // FluentIcon::HighlightFluentIconll => '\u{E891}',
-            // FluentIcon::Previous => '\u{E892}',
-            // FluentIcon::Next => '\u{E893}',
-            // FluentIcon::Clear => '\u{E894}',
-            // FluentIcon::Sync => '\u{E895}',
-            // FluentIcon::Download => '\u{E896}',
-            // FluentIcon::Help => '\u{E897}',
-            // FluentIcon::Upload => '\u{E898}',
-            // FluentIcon::Emoji => '\u{E899}',
-            // FluentIcon::TwoPage => '\u{E89A}',
-            // FluentIcon::LeaveChat => '\u{E89B}',
-            // FluentIcon::MailForward => '\u{E89C}',
-            // FluentIcon::RotateCamera => '\u{E89E}',
-            // FluentIcon::ClosePane => '\u{E89F}',
-            // FluentIcon::OpenPane => '\u{E8A0}',
-            FluentIcon::PreviewLink => '\u{E8A1}',
-            // FluentIcon::AttachCamera => '\u{E8A2}',
-            // FluentIcon::ZoomIn => '\u{E8A3}',
-            // FluentIcon::Bookmarks => '\u{E8A4}',
-            FluentIcon::Document => '\u{E8A5}',
-            // FluentIcon::ProtectedDocument => '\u{E8A6}',
-            // FluentIcon::OpenInNewWindow => '\u{E8A7}',
-            // FluentIcon::MailFluentIconll => '\u{E8A8}',
-            // FluentIcon::ViewAll => '\u{E8A9}',
-            // FluentIcon::VideoChat => '\u{E8AA}',
-            // FluentIcon::Switch => '\u{E8AB}',
-            // FluentIcon::Rename => '\u{E8AC}',
-            // FluentIcon::Go => '\u{E8AD}',
-            // FluentIcon::SurfaceHub => '\u{E8AE}',
-            // FluentIcon::Remote => '\u{E8AF}',
-            // FluentIcon::Click => '\u{E8B0}',
-            // FluentIcon::Shuffle => '\u{E8B1}',
-            // FluentIcon::Movies => '\u{E8B2}',
-            FluentIcon::SelectAll => '\u{E8B3}',
-            // FluentIcon::Orientation => '\u{E8B4}',
-            // FluentIcon::Import => '\u{E8B5}',
-            // FluentIcon::ImportAll => '\u{E8B6}',
-            // FluentIcon::Folder => '\u{E8B7}',
-            // FluentIcon::Webcam => '\u{E8B8}',
-            // FluentIcon::Picture => '\u{E8B9}',
-            // FluentIcon::Caption => '\u{E8BA}',
-            // FluentIcon::ChromeClose => '\u{E8BB}',
-            // FluentIcon::ShowResults => '\u{E8BC}',
-            FluentIcon::Message => '\u{E8BD}',
-            // FluentIcon::Leaf => '\u{E8BE}',
-            // FluentIcon::CalendarDay => '\u{E8BF}',
-            // FluentIcon::CalendarWeek => '\u{E8C0}',
-            // FluentIcon::Characters => '\u{E8C1}',
-            FluentIcon::MailReplyAll => '\u{E8C2}',
-            // FluentIcon::Read => '\u{E8C3}',
-            // FluentIcon::ShowBcc => '\u{E8C4}',
-            // FluentIcon::HideBcc => '\u{E8C5}',
-            FluentIcon::Cut => '\u{E8C6}',
-            // FluentIcon::PaymentCard => '\u{E8C7}',
-            FluentIcon::Copy => '\u{E8C8}',
-            // FluentIcon::Important => '\u{E8C9}',
-            FluentIcon::MailReply => '\u{E8CA}',
-            // FluentIcon::Sort => '\u{E8CB}',
-            // FluentIcon::MobileTablet => '\u{E8CC}',
-            // FluentIcon::DisconnectDrive => '\u{E8CD}',
-            // FluentIcon::MapDrive => '\u{E8CE}',
-            // FluentIcon::ContactPresence => '\u{E8CF}',
-            // FluentIcon::Priority => '\u{E8D0}',
-            // FluentIcon::GotoToday => '\u{E8D1}',
-            FluentIcon::Font => '\u{E8D2}',
-            // FluentIcon::FontColor => '\u{E8D3}',
-            // FluentIcon::Contact2 => '\u{E8D4}',
-            // FluentIcon::FolderFluentIconll => '\u{E8D5}',
-            // FluentIcon::Audio => '\u{E8D6}',
-            // FluentIcon::Permissions => '\u{E8D7}',
-            // FluentIcon::DisableUpdates => '\u{E8D8}',
-            // FluentIcon::Unfavorite => '\u{E8D9}',
-            // FluentIcon::OpenLocal => '\u{E8DA}',
-            FluentIcon::Italic => '\u{E8DB}',
-            FluentIcon::Underline => '\u{E8DC}',
-            FluentIcon::Bold => '\u{E8DD}',
-            // FluentIcon::MoveToFolder => '\u{E8DE}',
-            // FluentIcon::LikeDislike => '\u{E8DF}',
-            // FluentIcon::Dislike => '\u{E8E0}',
-            FluentIcon::Like => '\u{E8E1}',
-            FluentIcon::AlignRight => '\u{E8E2}',
-            FluentIcon::AlignCenter => '\u{E8E3}',
-            FluentIcon::AlignLeft => '\u{E8E4}',
-            // FluentIcon::OpenFluentIconle => '\u{E8E5}',
-            // FluentIcon::ClearSelection => '\u{E8E6}',
-            FluentIcon::FontDecrease => '\u{E8E7}',
-            FluentIcon::FontIncrease => '\u{E8E8}',
-            FluentIcon::FontSize => '\u{E8E9}',
-            // FluentIcon::CellPhone => '\u{E8EA}',
-            // FluentIcon::Reshare => '\u{E8EB}',
-            // FluentIcon::Tag => '\u{E8EC}',
-            // FluentIcon::RepeatOne => '\u{E8ED}',
-            // FluentIcon::RepeatAll => '\u{E8EE}',
-            // FluentIcon::Calculator => '\u{E8EF}',
-            // FluentIcon::Directions => '\u{E8F0}',
-            // FluentIcon::Library => '\u{E8F1}',
-            // FluentIcon::ChatBubbles => '\u{E8F2}',
-            // FluentIcon::PostUpdate => '\u{E8F3}',
-            // FluentIcon::NewFolder => '\u{E8F4}',
-            // FluentIcon::CalendarReply => '\u{E8F5}',
-            // FluentIcon::UnsyncFolder => '\u{E8F6}',
-            // FluentIcon::SyncFolder => '\u{E8F7}',
-            // FluentIcon::BlockContact => '\u{E8F8}',
-            // FluentIcon::SwitchApps => '\u{E8F9}',
-            // FluentIcon::AddFriend => '\u{E8FA}',
-            // FluentIcon::Accept => '\u{E8FB}',
-            // FluentIcon::GoToStart => '\u{E8FC}',
-            // FluentIcon::BulletedList => '\u{E8FD}',
-            // FluentIcon::Scan => '\u{E8FE}',
-            // FluentIcon::Preview => '\u{E8FF}',
-            FluentIcon::Group => '\u{E902}',
-            // FluentIcon::ZeroBars => '\u{E904}',
-            // FluentIcon::OneBar => '\u{E905}',
-            // FluentIcon::TwoBars => '\u{E906}',
-            // FluentIcon::ThreeBars => '\u{E907}',
-            // FluentIcon::FourBars => '\u{E908}',
-            // FluentIcon::World => '\u{E909}',
-            // FluentIcon::Comment => '\u{E90A}',
-            // FluentIcon::MusicInfo => '\u{E90B}',
-            // FluentIcon::DockLeft => '\u{E90C}',
-            // FluentIcon::DockRight => '\u{E90D}',
-            // FluentIcon::DockBottom => '\u{E90E}',
-            // FluentIcon::Repair => '\u{E90F}',
-            // FluentIcon::Accounts => '\u{E910}',
-            // FluentIcon::DullSound => '\u{E911}',
-            // FluentIcon::Manage => '\u{E912}',
-            // FluentIcon::Street => '\u{E913}',
-            // FluentIcon::Printer3D => '\u{E914}',
-            FluentIcon::RadioBullet => '\u{E915}',
-            // FluentIcon::Stopwatch => '\u{E916}',
-            // FluentIcon::Photo => '\u{E91B}',
-            // FluentIcon::ActionCenter => '\u{E91C}',
-            // FluentIcon::FullCircleMask => '\u{E91F}',
-            // FluentIcon::ChromeMinimize => '\u{E921}',
-            // FluentIcon::ChromeMaximize => '\u{E922}',
-            // FluentIcon::ChromeRestore => '\u{E923}',
-            // FluentIcon::Annotation => '\u{E924}',
-            // FluentIcon::BackSpaceQWERTYSm => '\u{E925}',
-            // FluentIcon::BackSpaceQWERTYMd => '\u{E926}',
-            // FluentIcon::Swipe => '\u{E927}',
-            // FluentIcon::FluentIconngerprint => '\u{E928}',
-            // FluentIcon::Handwriting => '\u{E929}',
-            // FluentIcon::ChromeBackToWindow => '\u{E92C}',
-            // FluentIcon::ChromeFullScreen => '\u{E92D}',
-            // FluentIcon::KeyboardStandard => '\u{E92E}',
-            // FluentIcon::KeyboardDismiss => '\u{E92F}',
-            // FluentIcon::Completed => '\u{E930}',
-            // FluentIcon::ChromeAnnotate => '\u{E931}',
-            // FluentIcon::Label => '\u{E932}',
-            // FluentIcon::IBeam => '\u{E933}',
-            // FluentIcon::IBeamOutline => '\u{E934}',
-            // FluentIcon::FlickDown => '\u{E935}',
-            // FluentIcon::FlickUp => '\u{E936}',
-            // FluentIcon::FlickLeft => '\u{E937}',
-            // FluentIcon::FlickRight => '\u{E938}',
-            // FluentIcon::FeedbackApp => '\u{E939}',
-            // FluentIcon::MusicAlbum => '\u{E93C}',
-            // FluentIcon::Streaming => '\u{E93E}',
-            // FluentIcon::Code => '\u{E943}',
-            // FluentIcon::ReturnToWindow => '\u{E944}',
-            // FluentIcon::LightningBolt => '\u{E945}',
-            // FluentIcon::Info => '\u{E946}',
-            // FluentIcon::CalculatorMultiply => '\u{E947}',
-            // FluentIcon::CalculatorAddition => '\u{E948}',
-            // FluentIcon::CalculatorSubtract => '\u{E949}',
-            // FluentIcon::CalculatorDivide => '\u{E94A}',
-            // FluentIcon::CalculatorSquareroot => '\u{E94B}',
-            // FluentIcon::CalculatorPercentage => '\u{E94C}',
-            // FluentIcon::CalculatorNegate => '\u{E94D}',
-            // FluentIcon::CalculatorEqualTo => '\u{E94E}',
-            // FluentIcon::CalculatorBackspace => '\u{E94F}',
-            // FluentIcon::Component => '\u{E950}',
-            // FluentIcon::Dmc => '\u{E951}',
-            // FluentIcon::Dock => '\u{E952}',
-            // FluentIcon::MultimediaDMS => '\u{E953}',
-            // FluentIcon::MultimediaDVR => '\u{E954}',
-            // FluentIcon::MultimediaPMP => '\u{E955}',
-            // FluentIcon::PrintfaxPrinterFluentIconle => '\u{E956}',
-            // FluentIcon::Sensor => '\u{E957}',
-            // FluentIcon::StorageOptical => '\u{E958}',
-            // FluentIcon::Communications => '\u{E95A}',
-            // FluentIcon::Headset => '\u{E95B}',
-            // FluentIcon::Projector => '\u{E95D}',
-            // FluentIcon::Health => '\u{E95E}',
-            // FluentIcon::Wire => '\u{E95F}',
-            // FluentIcon::Webcam2 => '\u{E960}',
-            // FluentIcon::Input => '\u{E961}',
-            // FluentIcon::Mouse => '\u{E962}',
-            // FluentIcon::Smartcard => '\u{E963}',
-            // FluentIcon::SmartcardVirtual => '\u{E964}',
-            // FluentIcon::MediaStorageTower => '\u{E965}',
-            // FluentIcon::ReturnKeySm => '\u{E966}',
-            // FluentIcon::GameConsole => '\u{E967}',
-            // FluentIcon::Network => '\u{E968}',
-            // FluentIcon::StorageNetworkWireless => '\u{E969}',
-            // FluentIcon::StorageTape => '\u{E96A}',
-            // FluentIcon::ChevronUpSmall => '\u{E96D}',
-            // FluentIcon::ChevronDownSmall => '\u{E96E}',
-            // FluentIcon::ChevronLeftSmall => '\u{E96F}',
-            // FluentIcon::ChevronRightSmall => '\u{E970}',
-            // FluentIcon::ChevronUpMed => '\u{E971}',
-            // FluentIcon::ChevronDownMed => '\u{E972}',
-            // FluentIcon::ChevronLeftMed => '\u{E973}',
-            FluentIcon::ChevronRightMed => '\u{E974}',
-            // FluentIcon::Devices2 => '\u{E975}',
-            // FluentIcon::ExpandTile => '\u{E976}',
-            // FluentIcon::Pc1 => '\u{E977}',
-            // FluentIcon::PresenceChicklet => '\u{E978}',
-            // FluentIcon::PresenceChickletVideo => '\u{E979}',
-            FluentIcon::Reply => '\u{E97A}',
-            // FluentIcon::SetTile => '\u{E97B}',
-            // FluentIcon::Type => '\u{E97C}',
-            // FluentIcon::Korean => '\u{E97D}',
-            // FluentIcon::HalfAlpha => '\u{E97E}',
-            // FluentIcon::FullAlpha => '\u{E97F}',
-            // FluentIcon::Key12On => '\u{E980}',
-            // FluentIcon::ChineseChangjie => '\u{E981}',
-            // FluentIcon::QWERTYOn => '\u{E982}',
-            // FluentIcon::QWERTYOff => '\u{E983}',
-            // FluentIcon::ChineseQuick => '\u{E984}',
-            // FluentIcon::Japanese => '\u{E985}',
-            // FluentIcon::FullHiragana => '\u{E986}',
-            // FluentIcon::FullKatakana => '\u{E987}',
-            // FluentIcon::HalfKatakana => '\u{E988}',
-            // FluentIcon::ChineseBoPoMoFo => '\u{E989}',
-            // FluentIcon::ChinesePinyin => '\u{E98A}',
-            // FluentIcon::ConstructionCone => '\u{E98F}',
-            // FluentIcon::XboxOneConsole => '\u{E990}',
-            // FluentIcon::Volume0 => '\u{E992}',
-            // FluentIcon::Volume1 => '\u{E993}',
-            // FluentIcon::Volume2 => '\u{E994}',
-            // FluentIcon::Volume3 => '\u{E995}',
-            // FluentIcon::BatteryUnknown => '\u{E996}',
-            // FluentIcon::WiFluentIconAttentionOverlay => '\u{E998}',
-            // FluentIcon::Robot => '\u{E99A}',
-            // FluentIcon::TapAndSend => '\u{E9A1}',
-            // FluentIcon::FluentIcontPage => '\u{E9A6}',
-            // FluentIcon::PasswordKeyShow => '\u{E9A8}',
-            // FluentIcon::PasswordKeyHide => '\u{E9A9}',
-            // FluentIcon::BidiLtr => '\u{E9AA}',
-            // FluentIcon::BidiRtl => '\u{E9AB}',
-            // FluentIcon::ForwardSm => '\u{E9AC}',
-            // FluentIcon::CommaKey => '\u{E9AD}',
-            FluentIcon::DashKey => '\u{E9AE}',
-            // FluentIcon::DullSoundKey => '\u{E9AF}',
-            // FluentIcon::HalfDullSound => '\u{E9B0}',
-            // FluentIcon::RightDoubleQuote => '\u{E9B1}',
-            // FluentIcon::LeftDoubleQuote => '\u{E9B2}',
-            // FluentIcon::PuncKeyRightBottom => '\u{E9B3}',
-            // FluentIcon::PuncKey1 => '\u{E9B4}',
-            // FluentIcon::PuncKey2 => '\u{E9B5}',
-            // FluentIcon::PuncKey3 => '\u{E9B6}',
-            // FluentIcon::PuncKey4 => '\u{E9B7}',
-            // FluentIcon::PuncKey5 => '\u{E9B8}',
-            // FluentIcon::PuncKey6 => '\u{E9B9}',
-            // FluentIcon::PuncKey9 => '\u{E9BA}',
-            // FluentIcon::PuncKey7 => '\u{E9BB}',
-            // FluentIcon::PuncKey8 => '\u{E9BC}',
-            // FluentIcon::Frigid => '\u{E9CA}',
-            // FluentIcon::Unknown => '\u{E9CE}',
-            // FluentIcon::AreaChart => '\u{E9D2}',
-            // FluentIcon::CheckList => '\u{E9D5}',
-            // FluentIcon::Diagnostic => '\u{E9D9}',
-            // FluentIcon::Equalizer => '\u{E9E9}',
-            // FluentIcon::Process => '\u{E9F3}',
-            // FluentIcon::Processing => '\u{E9F5}',
-            // FluentIcon::ReportDocument => '\u{E9F9}',
-            // FluentIcon::VideoSolid => '\u{EA0C}',
-            // FluentIcon::MixedMediaBadge => '\u{EA0D}',
-            // FluentIcon::DisconnectDisplay => '\u{EA14}',
-            // FluentIcon::Shield => '\u{EA18}',
-            // FluentIcon::Info2 => '\u{EA1F}',
-            // FluentIcon::ActionCenterAsterisk => '\u{EA21}',
-            // FluentIcon::Beta => '\u{EA24}',
-            // FluentIcon::SaveCopy => '\u{EA35}',
-            // FluentIcon::List => '\u{EA37}',
-            // FluentIcon::Asterisk => '\u{EA38}',
-            // FluentIcon::ErrorBadge => '\u{EA39}',
-            // FluentIcon::CircleRing => '\u{EA3A}',
-            // FluentIcon::CircleFluentIconll => '\u{EA3B}',
-            // FluentIcon::MergeCall => '\u{EA3C}',
-            // FluentIcon::PrivateCall => '\u{EA3D}',
-            // FluentIcon::Record2 => '\u{EA3F}',
-            // FluentIcon::AllAppsMirrored => '\u{EA40}',
-            // FluentIcon::BookmarksMirrored => '\u{EA41}',
-            // FluentIcon::BulletedListMirrored => '\u{EA42}',
-            // FluentIcon::CallForwardInternationalMirrored => '\u{EA43}',
-            // FluentIcon::CallForwardRoamingMirrored => '\u{EA44}',
-            // FluentIcon::ChromeBackMirrored => '\u{EA47}',
-            // FluentIcon::ClearSelectionMirrored => '\u{EA48}',
-            // FluentIcon::ClosePaneMirrored => '\u{EA49}',
-            // FluentIcon::ContactInfoMirrored => '\u{EA4A}',
-            // FluentIcon::DockRightMirrored => '\u{EA4B}',
-            // FluentIcon::DockLeftMirrored => '\u{EA4C}',
-            // FluentIcon::ExpandTileMirrored => '\u{EA4E}',
-            // FluentIcon::GoMirrored => '\u{EA4F}',
-            // FluentIcon::GripperResizeMirrored => '\u{EA50}',
-            // FluentIcon::HelpMirrored => '\u{EA51}',
-            // FluentIcon::ImportMirrored => '\u{EA52}',
-            // FluentIcon::ImportAllMirrored => '\u{EA53}',
-            // FluentIcon::LeaveChatMirrored => '\u{EA54}',
-            // FluentIcon::ListMirrored => '\u{EA55}',
-            // FluentIcon::MailForwardMirrored => '\u{EA56}',
-            // FluentIcon::MailReplyMirrored => '\u{EA57}',
-            // FluentIcon::MailReplyAllMirrored => '\u{EA58}',
-            // FluentIcon::OpenPaneMirrored => '\u{EA5B}',
-            // FluentIcon::OpenWithMirrored => '\u{EA5C}',
-            // FluentIcon::ParkingLocationMirrored => '\u{EA5E}',
-            // FluentIcon::ResizeMouseMediumMirrored => '\u{EA5F}',
-            // FluentIcon::ResizeMouseSmallMirrored => '\u{EA60}',
-            // FluentIcon::ResizeMouseTallMirrored => '\u{EA61}',
-            // FluentIcon::ResizeTouchNarrowerMirrored => '\u{EA62}',
-            // FluentIcon::SendMirrored => '\u{EA63}',
-            // FluentIcon::SendFluentIconllMirrored => '\u{EA64}',
-            // FluentIcon::ShowResultsMirrored => '\u{EA65}',
-            // FluentIcon::Media => '\u{EA69}',
-            // FluentIcon::SyncError => '\u{EA6A}',
-            // FluentIcon::Devices3 => '\u{EA6C}',
-            // FluentIcon::SlowMotionOn => '\u{EA79}',
-            // FluentIcon::Lightbulb => '\u{EA80}',
-            // FluentIcon::StatusCircle => '\u{EA81}',
-            // FluentIcon::StatusTriangle => '\u{EA82}',
-            // FluentIcon::StatusError => '\u{EA83}',
-            // FluentIcon::StatusWarning => '\u{EA84}',
-            // FluentIcon::Puzzle => '\u{EA86}',
-            // FluentIcon::CalendarSolid => '\u{EA89}',
-            // FluentIcon::HomeSolid => '\u{EA8A}',
-            // FluentIcon::ParkingLocationSolid => '\u{EA8B}',
-            // FluentIcon::ContactSolid => '\u{EA8C}',
-            // FluentIcon::ConstructionSolid => '\u{EA8D}',
-            // FluentIcon::AccidentSolid => '\u{EA8E}',
-            // FluentIcon::Ringer => '\u{EA8F}',
-            // FluentIcon::Pdf => '\u{EA90}',
-            // FluentIcon::ThoughtBubble => '\u{EA91}',
-            // FluentIcon::HeartBroken => '\u{EA92}',
-            // FluentIcon::BatteryCharging10 => '\u{EA93}',
-            // FluentIcon::BatterySaver9 => '\u{EA94}',
-            // FluentIcon::BatterySaver10 => '\u{EA95}',
-            // FluentIcon::CallForwardingMirrored => '\u{EA97}',
-            // FluentIcon::MultiSelectMirrored => '\u{EA98}',
-            // FluentIcon::Broom => '\u{EA99}',
-            // FluentIcon::ForwardCall => '\u{EAC2}',
-            // FluentIcon::Trackers => '\u{EADF}',
-            // FluentIcon::Market => '\u{EAFC}',
-            // FluentIcon::PieSingle => '\u{EB05}',
-            // FluentIcon::StockUp => '\u{EB0F}',
-            // FluentIcon::StockDown => '\u{EB11}',
-            // FluentIcon::Design => '\u{EB3C}',
-            // FluentIcon::Website => '\u{EB41}',
-            // FluentIcon::Drop => '\u{EB42}',
-            // FluentIcon::Radar => '\u{EB44}',
-            // FluentIcon::BusSolid => '\u{EB47}',
-            // FluentIcon::FerrySolid => '\u{EB48}',
-            // FluentIcon::StartPointSolid => '\u{EB49}',
-            // FluentIcon::StopPointSolid => '\u{EB4A}',
-            // FluentIcon::EndPointSolid => '\u{EB4B}',
-            // FluentIcon::AirplaneSolid => '\u{EB4C}',
-            // FluentIcon::TrainSolid => '\u{EB4D}',
-            // FluentIcon::WorkSolid => '\u{EB4E}',
-            // FluentIcon::ReminderFluentIconll => '\u{EB4F}',
-            // FluentIcon::Reminder => '\u{EB50}',
-            // FluentIcon::Heart => '\u{EB51}',
-            // FluentIcon::HeartFluentIconll => '\u{EB52}',
-            // FluentIcon::EthernetError => '\u{EB55}',
-            // FluentIcon::EthernetWarning => '\u{EB56}',
-            // FluentIcon::StatusConnecting1 => '\u{EB57}',
-            // FluentIcon::StatusConnecting2 => '\u{EB58}',
-            // FluentIcon::StatusUnsecure => '\u{EB59}',
-            // FluentIcon::WiFluentIconError0 => '\u{EB5A}',
-            // FluentIcon::WiFluentIconError1 => '\u{EB5B}',
-            // FluentIcon::WiFluentIconError2 => '\u{EB5C}',
-            // FluentIcon::WiFluentIconError3 => '\u{EB5D}',
-            // FluentIcon::WiFluentIconError4 => '\u{EB5E}',
-            // FluentIcon::WiFluentIconWarning0 => '\u{EB5F}',
-            // FluentIcon::WiFluentIconWarning1 => '\u{EB60}',
-            // FluentIcon::WiFluentIconWarning2 => '\u{EB61}',
-            // FluentIcon::WiFluentIconWarning3 => '\u{EB62}',
-            // FluentIcon::WiFluentIconWarning4 => '\u{EB63}',
-            // FluentIcon::Devices4 => '\u{EB66}',
-            // FluentIcon::NUIIris => '\u{EB67}',
-            // FluentIcon::NUIFace => '\u{EB68}',
-            // FluentIcon::GatewayRouter => '\u{EB77}',
-            // FluentIcon::EditMirrored => '\u{EB7E}',
-            // FluentIcon::NUIFPStartSlideHand => '\u{EB82}',
-            // FluentIcon::NUIFPStartSlideAction => '\u{EB83}',
-            // FluentIcon::NUIFPContinueSlideHand => '\u{EB84}',
-            // FluentIcon::NUIFPContinueSlideAction => '\u{EB85}',
-            // FluentIcon::NUIFPRollRightHand => '\u{EB86}',
-            // FluentIcon::NUIFPRollRightHandAction => '\u{EB87}',
-            // FluentIcon::NUIFPRollLeftHand => '\u{EB88}',
-            // FluentIcon::NUIFPRollLeftAction => '\u{EB89}',
-            // FluentIcon::NUIFPPressHand => '\u{EB8A}',
-            // FluentIcon::NUIFPPressAction => '\u{EB8B}',
-            // FluentIcon::NUIFPPressRepeatHand => '\u{EB8C}',
-            // FluentIcon::NUIFPPressRepeatAction => '\u{EB8D}',
-            // FluentIcon::StatusErrorFull => '\u{EB90}',
-            // FluentIcon::TaskViewExpanded => '\u{EB91}',
-            // FluentIcon::CertiFluentIconcate => '\u{EB95}',
-            // FluentIcon::BackSpaceQWERTYLg => '\u{EB96}',
-            // FluentIcon::ReturnKeyLg => '\u{EB97}',
-            // FluentIcon::FastForward => '\u{EB9D}',
-            // FluentIcon::Rewind => '\u{EB9E}',
-            // FluentIcon::Photo2 => '\u{EB9F}',
-            // FluentIcon::MobBattery0 => '\u{EBA0}',
-            // FluentIcon::MobBattery1 => '\u{EBA1}',
-            // FluentIcon::MobBattery2 => '\u{EBA2}',
-            // FluentIcon::MobBattery3 => '\u{EBA3}',
-            // FluentIcon::MobBattery4 => '\u{EBA4}',
-            // FluentIcon::MobBattery5 => '\u{EBA5}',
-            // FluentIcon::MobBattery6 => '\u{EBA6}',
-            // FluentIcon::MobBattery7 => '\u{EBA7}',
-            // FluentIcon::MobBattery8 => '\u{EBA8}',
-            // FluentIcon::MobBattery9 => '\u{EBA9}',
-            // FluentIcon::MobBattery10 => '\u{EBAA}',
-            // FluentIcon::MobBatteryCharging0 => '\u{EBAB}',
-            // FluentIcon::MobBatteryCharging1 => '\u{EBAC}',
-            // FluentIcon::MobBatteryCharging2 => '\u{EBAD}',
-            // FluentIcon::MobBatteryCharging3 => '\u{EBAE}',
-            // FluentIcon::MobBatteryCharging4 => '\u{EBAF}',
-            // FluentIcon::MobBatteryCharging5 => '\u{EBB0}',
-            // FluentIcon::MobBatteryCharging6 => '\u{EBB1}',
-            // FluentIcon::MobBatteryCharging7 => '\u{EBB2}',
-            // FluentIcon::MobBatteryCharging8 => '\u{EBB3}',
-            // FluentIcon::MobBatteryCharging9 => '\u{EBB4}',
-            // FluentIcon::MobBatteryCharging10 => '\u{EBB5}',
-            // FluentIcon::MobBatterySaver0 => '\u{EBB6}',
-            // FluentIcon::MobBatterySaver1 => '\u{EBB7}',
-            // FluentIcon::MobBatterySaver2 => '\u{EBB8}',
-            // FluentIcon::MobBatterySaver3 => '\u{EBB9}',
-            // FluentIcon::MobBatterySaver4 => '\u{EBBA}',
-            // FluentIcon::MobBatterySaver5 => '\u{EBBB}',
-            // FluentIcon::MobBatterySaver6 => '\u{EBBC}',
-            // FluentIcon::MobBatterySaver7 => '\u{EBBD}',
-            // FluentIcon::MobBatterySaver8 => '\u{EBBE}',
-            // FluentIcon::MobBatterySaver9 => '\u{EBBF}',
-            // FluentIcon::MobBatterySaver10 => '\u{EBC0}',
-            // FluentIcon::DictionaryCloud => '\u{EBC3}',
-            // FluentIcon::ResetDrive => '\u{EBC4}',
-            // FluentIcon::VolumeBars => '\u{EBC5}',
-            // FluentIcon::Project => '\u{EBC6}',
-            // FluentIcon::AdjustHologram => '\u{EBD2}',
-            // FluentIcon::CloudDownload => '\u{EBD3}',
-            // FluentIcon::MobWiFluentIconCallBars => '\u{EBD4}',
-            // FluentIcon::MobWiFluentIconCall0 => '\u{EBD5}',
-            // FluentIcon::MobWiFluentIconCall1 => '\u{EBD6}',
-            // FluentIcon::MobWiFluentIconCall2 => '\u{EBD7}',
-            // FluentIcon::MobWiFluentIconCall3 => '\u{EBD8}',
-            // FluentIcon::MobWiFluentIconCall4 => '\u{EBD9}',
-            // FluentIcon::Family => '\u{EBDA}',
-            // FluentIcon::LockFeedback => '\u{EBDB}',
-            // FluentIcon::DeviceDiscovery => '\u{EBDE}',
-            // FluentIcon::WindDirection => '\u{EBE6}',
-            // FluentIcon::RightArrowKeyTime0 => '\u{EBE7}',
-            // FluentIcon::Bug => '\u{EBE8}',
-            // FluentIcon::TabletMode => '\u{EBFC}',
-            // FluentIcon::StatusCircleLeft => '\u{EBFD}',
-            // FluentIcon::StatusTriangleLeft => '\u{EBFE}',
-            // FluentIcon::StatusErrorLeft => '\u{EBFF}',
-            // FluentIcon::StatusWarningLeft => '\u{EC00}',
-            // FluentIcon::MobBatteryUnknown => '\u{EC02}',
-            // FluentIcon::NetworkTower => '\u{EC05}',
-            // FluentIcon::CityNext => '\u{EC06}',
-            // FluentIcon::CityNext2 => '\u{EC07}',
-            // FluentIcon::Courthouse => '\u{EC08}',
-            // FluentIcon::Groceries => '\u{EC09}',
-            // FluentIcon::Sustainable => '\u{EC0A}',
-            // FluentIcon::BuildingEnergy => '\u{EC0B}',
-            // FluentIcon::ToggleFluentIconlled => '\u{EC11}',
-            // FluentIcon::ToggleBorder => '\u{EC12}',
-            // FluentIcon::SliderThumb => '\u{EC13}',
-            // FluentIcon::ToggleThumb => '\u{EC14}',
-            // FluentIcon::MiracastLogoSmall => '\u{EC15}',
-            // FluentIcon::MiracastLogoLarge => '\u{EC16}',
-            // FluentIcon::Plap => '\u{EC19}',
-            // FluentIcon::Badge => '\u{EC1B}',
-            // FluentIcon::SignalRoaming => '\u{EC1E}',
-            // FluentIcon::MobileLocked => '\u{EC20}',
-            // FluentIcon::InsiderHubApp => '\u{EC24}',
-            // FluentIcon::PersonalFolder => '\u{EC25}',
-            // FluentIcon::HomeGroup => '\u{EC26}',
-            // FluentIcon::MyNetwork => '\u{EC27}',
-            // FluentIcon::KeyboardFull => '\u{EC31}',
-            // FluentIcon::Cafe => '\u{EC32}',
-            // FluentIcon::MobSignal1 => '\u{EC37}',
-            // FluentIcon::MobSignal2 => '\u{EC38}',
-            // FluentIcon::MobSignal3 => '\u{EC39}',
-            // FluentIcon::MobSignal4 => '\u{EC3A}',
-            // FluentIcon::MobSignal5 => '\u{EC3B}',
-            // FluentIcon::MobWiFluentIcon1 => '\u{EC3C}',
-            // FluentIcon::MobWiFluentIcon2 => '\u{EC3D}',
-            // FluentIcon::MobWiFluentIcon3 => '\u{EC3E}',
-            // FluentIcon::MobWiFluentIcon4 => '\u{EC3F}',
-            // FluentIcon::MobAirplane => '\u{EC40}',
-            // FluentIcon::MobBluetooth => '\u{EC41}',
-            // FluentIcon::MobActionCenter => '\u{EC42}',
-            // FluentIcon::MobLocation => '\u{EC43}',
-            // FluentIcon::MobWiFluentIconHotspot => '\u{EC44}',
-            // FluentIcon::LanguageJpn => '\u{EC45}',
-            // FluentIcon::MobQuietHours => '\u{EC46}',
-            // FluentIcon::MobDrivingMode => '\u{EC47}',
-            // FluentIcon::SpeedOff => '\u{EC48}',
-            // FluentIcon::SpeedMedium => '\u{EC49}',
-            // FluentIcon::SpeedHigh => '\u{EC4A}',
-            // FluentIcon::ThisPC => '\u{EC4E}',
-            // FluentIcon::MusicNote => '\u{EC4F}',
-            // FluentIcon::FluentIconleExplorer => '\u{EC50}',
-            // FluentIcon::FluentIconleExplorerApp => '\u{EC51}',
-            // FluentIcon::LeftArrowKeyTime0 => '\u{EC52}',
-            // FluentIcon::MicOff => '\u{EC54}',
-            // FluentIcon::MicSleep => '\u{EC55}',
-            // FluentIcon::MicError => '\u{EC56}',
-            // FluentIcon::PlaybackRate1x => '\u{EC57}',
-            // FluentIcon::PlaybackRateOther => '\u{EC58}',
-            // FluentIcon::CashDrawer => '\u{EC59}',
-            // FluentIcon::BarcodeScanner => '\u{EC5A}',
-            // FluentIcon::ReceiptPrinter => '\u{EC5B}',
-            // FluentIcon::MagStripeReader => '\u{EC5C}',
-            // FluentIcon::CompletedSolid => '\u{EC61}',
-            // FluentIcon::CompanionApp => '\u{EC64}',
-            // FluentIcon::Favicon2 => '\u{EC6C}',
-            // FluentIcon::SwipeRevealArt => '\u{EC6D}',
-            // FluentIcon::MicOn => '\u{EC71}',
-            // FluentIcon::MicClipping => '\u{EC72}',
-            // FluentIcon::TabletSelected => '\u{EC74}',
-            // FluentIcon::MobileSelected => '\u{EC75}',
-            // FluentIcon::LaptopSelected => '\u{EC76}',
-            // FluentIcon::TVMonitorSelected => '\u{EC77}',
-            // FluentIcon::DeveloperTools => '\u{EC7A}',
-            // FluentIcon::MobCallForwarding => '\u{EC7E}',
-            // FluentIcon::MobCallForwardingMirrored => '\u{EC7F}',
-            // FluentIcon::BodyCam => '\u{EC80}',
-            // FluentIcon::PoliceCar => '\u{EC81}',
-            // FluentIcon::Draw => '\u{EC87}',
-            // FluentIcon::DrawSolid => '\u{EC88}',
-            // FluentIcon::LowerBrightness => '\u{EC8A}',
-            // FluentIcon::ScrollUpDown => '\u{EC8F}',
-            // FluentIcon::DateTime => '\u{EC92}',
-            // FluentIcon::HoloLens => '\u{EC94}',
-            // FluentIcon::Tiles => '\u{ECA5}',
-            // FluentIcon::PartyLeader => '\u{ECA7}',
-            // FluentIcon::AppIconDefault => '\u{ECAA}',
-            // FluentIcon::Calories => '\u{ECAD}',
-            // FluentIcon::Poi => '\u{ECAF}',
-            // FluentIcon::BandBattery0 => '\u{ECB9}',
-            // FluentIcon::BandBattery1 => '\u{ECBA}',
-            // FluentIcon::BandBattery2 => '\u{ECBB}',
-            // FluentIcon::BandBattery3 => '\u{ECBC}',
-            // FluentIcon::BandBattery4 => '\u{ECBD}',
-            // FluentIcon::BandBattery5 => '\u{ECBE}',
-            // FluentIcon::BandBattery6 => '\u{ECBF}',
-            // FluentIcon::AddSurfaceHub => '\u{ECC4}',
-            // FluentIcon::DevUpdate => '\u{ECC5}',
-            // FluentIcon::Unit => '\u{ECC6}',
-            // FluentIcon::AddTo => '\u{ECC8}',
-            // FluentIcon::RemoveFrom => '\u{ECC9}',
-            // FluentIcon::RadioBtnOff => '\u{ECCA}',
-            // FluentIcon::RadioBtnOn => '\u{ECCB}',
-            // FluentIcon::RadioBullet2 => '\u{ECCC}',
-            // FluentIcon::ExploreContent => '\u{ECCD}',
-            // FluentIcon::Blocked2 => '\u{ECE4}',
-            // FluentIcon::ScrollMode => '\u{ECE7}',
-            // FluentIcon::ZoomMode => '\u{ECE8}',
-            // FluentIcon::PanMode => '\u{ECE9}',
-            // FluentIcon::WiredUSB => '\u{ECF0}',
-            // FluentIcon::WirelessUSB => '\u{ECF1}',
-            // FluentIcon::USBSafeConnect => '\u{ECF3}',
-            // FluentIcon::ActionCenterNotiFluentIconcationMirrored => '\u{ED0C}',
-            // FluentIcon::ActionCenterMirrored => '\u{ED0D}',
-            // FluentIcon::SubscriptionAdd => '\u{ED0E}',
-            // FluentIcon::ResetDevice => '\u{ED10}',
-            // FluentIcon::SubscriptionAddMirrored => '\u{ED11}',
-            // FluentIcon::QRCode => '\u{ED14}',
-            // FluentIcon::Feedback => '\u{ED15}',
-            // FluentIcon::Hide => '\u{ED1A}',
-            // FluentIcon::Subtitles => '\u{ED1E}',
-            // FluentIcon::SubtitlesAudio => '\u{ED1F}',
-            // FluentIcon::OpenFolderHorizontal => '\u{ED25}',
-            // FluentIcon::CalendarMirrored => '\u{ED28}',
-            // FluentIcon::MobeSIM => '\u{ED2A}',
-            // FluentIcon::MobeSIMNoProFluentIconle => '\u{ED2B}',
-            // FluentIcon::MobeSIMLocked => '\u{ED2C}',
-            // FluentIcon::MobeSIMBusy => '\u{ED2D}',
-            // FluentIcon::SignalError => '\u{ED2E}',
-            // FluentIcon::StreamingEnterprise => '\u{ED2F}',
-            // FluentIcon::Headphone0 => '\u{ED30}',
-            // FluentIcon::Headphone1 => '\u{ED31}',
-            // FluentIcon::Headphone2 => '\u{ED32}',
-            // FluentIcon::Headphone3 => '\u{ED33}',
-            // FluentIcon::Apps => '\u{ED35}',
-            // FluentIcon::KeyboardBrightness => '\u{ED39}',
-            // FluentIcon::KeyboardLowerBrightness => '\u{ED3A}',
-            // FluentIcon::SkipBack10 => '\u{ED3C}',
-            // FluentIcon::SkipForward30 => '\u{ED3D}',
-            // FluentIcon::TreeFolderFolder => '\u{ED41}',
-            // FluentIcon::TreeFolderFolderFluentIconll => '\u{ED42}',
-            // FluentIcon::TreeFolderFolderOpen => '\u{ED43}',
-            // FluentIcon::TreeFolderFolderOpenFluentIconll => '\u{ED44}',
-            // FluentIcon::MultimediaDMP => '\u{ED47}',
-            // FluentIcon::KeyboardOneHanded => '\u{ED4C}',
-            // FluentIcon::Narrator => '\u{ED4D}',
-            // FluentIcon::EmojiTabPeople => '\u{ED53}',
-            // FluentIcon::EmojiTabSmilesAnimals => '\u{ED54}',
-            // FluentIcon::EmojiTabCelebrationObjects => '\u{ED55}',
-            // FluentIcon::EmojiTabFoodPlants => '\u{ED56}',
-            // FluentIcon::EmojiTabTransitPlaces => '\u{ED57}',
-            // FluentIcon::EmojiTabSymbols => '\u{ED58}',
-            // FluentIcon::EmojiTabTextSmiles => '\u{ED59}',
-            // FluentIcon::EmojiTabFavorites => '\u{ED5A}',
-            // FluentIcon::EmojiSwatch => '\u{ED5B}',
-            // FluentIcon::ConnectApp => '\u{ED5C}',
-            // FluentIcon::CompanionDeviceFramework => '\u{ED5D}',
-            // FluentIcon::Ruler => '\u{ED5E}',
-            // FluentIcon::FluentIconngerInking => '\u{ED5F}',
-            // FluentIcon::StrokeErase => '\u{ED60}',
-            // FluentIcon::PointErase => '\u{ED61}',
-            // FluentIcon::ClearAllInk => '\u{ED62}',
-            // FluentIcon::Pencil => '\u{ED63}',
-            // FluentIcon::Marker => '\u{ED64}',
-            // FluentIcon::InkingCaret => '\u{ED65}',
-            // FluentIcon::InkingColorOutline => '\u{ED66}',
-            // FluentIcon::InkingColorFluentIconll => '\u{ED67}',
-            // FluentIcon::HardDrive => '\u{EDA2}',
-            // FluentIcon::NetworkAdapter => '\u{EDA3}',
-            // FluentIcon::Touchscreen => '\u{EDA4}',
-            // FluentIcon::NetworkPrinter => '\u{EDA5}',
-            // FluentIcon::CloudPrinter => '\u{EDA6}',
-            // FluentIcon::KeyboardShortcut => '\u{EDA7}',
-            // FluentIcon::BrushSize => '\u{EDA8}',
-            // FluentIcon::NarratorForward => '\u{EDA9}',
-            // FluentIcon::NarratorForwardMirrored => '\u{EDAA}',
-            // FluentIcon::SyncBadge12 => '\u{EDAB}',
-            // FluentIcon::RingerBadge12 => '\u{EDAC}',
-            // FluentIcon::AsteriskBadge12 => '\u{EDAD}',
-            // FluentIcon::ErrorBadge12 => '\u{EDAE}',
-            // FluentIcon::CircleRingBadge12 => '\u{EDAF}',
-            // FluentIcon::CircleFluentIconllBadge12 => '\u{EDB0}',
-            // FluentIcon::ImportantBadge12 => '\u{EDB1}',
-            // FluentIcon::MailBadge12 => '\u{EDB3}',
-            // FluentIcon::PauseBadge12 => '\u{EDB4}',
-            // FluentIcon::PlayBadge12 => '\u{EDB5}',
-            // FluentIcon::PenWorkspace => '\u{EDC6}',
-            // FluentIcon::CaretLeft8 => '\u{EDD5}',
-            // FluentIcon::CaretRight8 => '\u{EDD6}',
-            // FluentIcon::CaretUp8 => '\u{EDD7}',
-            // FluentIcon::CaretDown8 => '\u{EDD8}',
-            // FluentIcon::CaretLeftSolid8 => '\u{EDD9}',
-            // FluentIcon::CaretRightSolid8 => '\u{EDDA}',
-            // FluentIcon::CaretUpSolid8 => '\u{EDDB}',
-            // FluentIcon::CaretDownSolid8 => '\u{EDDC}',
-            FluentIcon::Strikethrough => '\u{EDE0}',
-            // FluentIcon::Export => '\u{EDE1}',
-            // FluentIcon::ExportMirrored => '\u{EDE2}',
-            // FluentIcon::ButtonMenu => '\u{EDE3}',
-            // FluentIcon::CloudSearch => '\u{EDE4}',
-            // FluentIcon::PinyinIMELogo => '\u{EDE5}',
-            // FluentIcon::CalligraphyPen => '\u{EDFB}',
-            // FluentIcon::ReplyMirrored => '\u{EE35}',
-            // FluentIcon::LockscreenDesktop => '\u{EE3F}',
-            // FluentIcon::TaskViewSettings => '\u{EE40}',
-            // FluentIcon::MiniExpand2Mirrored => '\u{EE47}',
-            // FluentIcon::MiniContract2Mirrored => '\u{EE49}',
-            // FluentIcon::Play36 => '\u{EE4A}',
-            // FluentIcon::PenPalette => '\u{EE56}',
-            // FluentIcon::GuestUser => '\u{EE57}',
-            // FluentIcon::SettingsBattery => '\u{EE63}',
-            // FluentIcon::TaskbarPhone => '\u{EE64}',
-            // FluentIcon::LockScreenGlance => '\u{EE65}',
-            // FluentIcon::GenericScan => '\u{EE6F}',
-            // FluentIcon::ImageExport => '\u{EE71}',
-            // FluentIcon::WiFluentIconEthernet => '\u{EE77}',
-            // FluentIcon::ActionCenterQuiet => '\u{EE79}',
-            // FluentIcon::ActionCenterQuietNotiFluentIconcation => '\u{EE7A}',
-            // FluentIcon::TrackersMirrored => '\u{EE92}',
-            // FluentIcon::DateTimeMirrored => '\u{EE93}',
-            // FluentIcon::Wheel => '\u{EE94}',
-            // FluentIcon::VirtualMachineGroup => '\u{EEA3}',
-            // FluentIcon::ButtonView2 => '\u{EECA}',
-            // FluentIcon::PenWorkspaceMirrored => '\u{EF15}',
-            // FluentIcon::PenPaletteMirrored => '\u{EF16}',
-            // FluentIcon::StrokeEraseMirrored => '\u{EF17}',
-            // FluentIcon::PointEraseMirrored => '\u{EF18}',
-            // FluentIcon::ClearAllInkMirrored => '\u{EF19}',
-            // FluentIcon::BackgroundToggle => '\u{EF1F}',
-            // FluentIcon::Marquee => '\u{EF20}',
-            // FluentIcon::ChromeCloseContrast => '\u{EF2C}',
-            // FluentIcon::ChromeMinimizeContrast => '\u{EF2D}',
-            // FluentIcon::ChromeMaximizeContrast => '\u{EF2E}',
-            // FluentIcon::ChromeRestoreContrast => '\u{EF2F}',
-            // FluentIcon::TrafFluentIconcLight => '\u{EF31}',
-            // FluentIcon::Replay => '\u{EF3B}',
-            // FluentIcon::Eyedropper => '\u{EF3C}',
-            // FluentIcon::LineDisplay => '\u{EF3D}',
-            // FluentIcon::PINPad => '\u{EF3E}',
-            // FluentIcon::SignatureCapture => '\u{EF3F}',
-            // FluentIcon::ChipCardCreditCardReader => '\u{EF40}',
-            // FluentIcon::MarketDown => '\u{EF42}',
-            // FluentIcon::PlayerSettings => '\u{EF58}',
-            // FluentIcon::LandscapeOrientation => '\u{EF6B}',
-            // FluentIcon::Flow => '\u{EF90}',
-            // FluentIcon::Touchpad => '\u{EFA5}',
-            // FluentIcon::Speech => '\u{EFA9}',
-            // FluentIcon::KnowledgeArticle => '\u{F000}',
-            // FluentIcon::Relationship => '\u{F003}',
-            // FluentIcon::ZipFolder => '\u{F012}',
-            // FluentIcon::DefaultAPN => '\u{F080}',
-            // FluentIcon::UserAPN => '\u{F081}',
-            // FluentIcon::DoublePinyin => '\u{F085}',
-            // FluentIcon::BlueLight => '\u{F08C}',
-            // FluentIcon::CaretSolidLeft => '\u{F08D}',
-            // FluentIcon::CaretSolidDown => '\u{F08E}',
-            // FluentIcon::CaretSolidRight => '\u{F08F}',
-            // FluentIcon::CaretSolidUp => '\u{F090}',
-            // FluentIcon::ButtonA => '\u{F093}',
-            // FluentIcon::ButtonB => '\u{F094}',
-            // FluentIcon::ButtonY => '\u{F095}',
-            // FluentIcon::ButtonX => '\u{F096}',
-            // FluentIcon::ArrowUp8 => '\u{F0AD}',
-            // FluentIcon::ArrowDown8 => '\u{F0AE}',
-            // FluentIcon::ArrowRight8 => '\u{F0AF}',
-            // FluentIcon::ArrowLeft8 => '\u{F0B0}',
-            // FluentIcon::QuarentinedItems => '\u{F0B2}',
-            // FluentIcon::QuarentinedItemsMirrored => '\u{F0B3}',
-            // FluentIcon::Protractor => '\u{F0B4}',
-            // FluentIcon::ChecklistMirrored => '\u{F0B5}',
-            // FluentIcon::StatusCircle7 => '\u{F0B6}',
-            // FluentIcon::StatusCheckmark7 => '\u{F0B7}',
-            // FluentIcon::StatusErrorCircle7 => '\u{F0B8}',
-            // FluentIcon::Connected => '\u{F0B9}',
-            // FluentIcon::PencilFluentIconll => '\u{F0C6}',
-            // FluentIcon::CalligraphyFluentIconll => '\u{F0C7}',
-            // FluentIcon::QuarterStarLeft => '\u{F0CA}',
-            // FluentIcon::QuarterStarRight => '\u{F0CB}',
-            // FluentIcon::ThreeQuarterStarLeft => '\u{F0CC}',
-            // FluentIcon::ThreeQuarterStarRight => '\u{F0CD}',
-            // FluentIcon::QuietHoursBadge12 => '\u{F0CE}',
-            // FluentIcon::BackMirrored => '\u{F0D2}',
-            // FluentIcon::ForwardMirrored => '\u{F0D3}',
-            // FluentIcon::ChromeBackContrast => '\u{F0D5}',
-            // FluentIcon::ChromeBackContrastMirrored => '\u{F0D6}',
-            // FluentIcon::ChromeBackToWindowContrast => '\u{F0D7}',
-            // FluentIcon::ChromeFullScreenContrast => '\u{F0D8}',
-            // FluentIcon::GridView => '\u{F0E2}',
-            // FluentIcon::ClipboardList => '\u{F0E3}',
-            // FluentIcon::ClipboardListMirrored => '\u{F0E4}',
-            // FluentIcon::OutlineQuarterStarLeft => '\u{F0E5}',
-            // FluentIcon::OutlineQuarterStarRight => '\u{F0E6}',
-            // FluentIcon::OutlineHalfStarLeft => '\u{F0E7}',
-            // FluentIcon::OutlineHalfStarRight => '\u{F0E8}',
-            // FluentIcon::OutlineThreeQuarterStarLeft => '\u{F0E9}',
-            // FluentIcon::OutlineThreeQuarterStarRight => '\u{F0EA}',
-            // FluentIcon::SpatialVolume0 => '\u{F0EB}',
-            // FluentIcon::SpatialVolume1 => '\u{F0EC}',
-            // FluentIcon::SpatialVolume2 => '\u{F0ED}',
-            // FluentIcon::SpatialVolume3 => '\u{F0EE}',
-            // FluentIcon::ApplicationGuard => '\u{F0EF}',
-            // FluentIcon::OutlineStarLeftHalf => '\u{F0F7}',
-            // FluentIcon::OutlineStarRightHalf => '\u{F0F8}',
-            // FluentIcon::ChromeAnnotateContrast => '\u{F0F9}',
-            // FluentIcon::DefenderBadge12 => '\u{F0FB}',
-            // FluentIcon::DetachablePC => '\u{F103}',
-            // FluentIcon::LeftStick => '\u{F108}',
-            // FluentIcon::RightStick => '\u{F109}',
-            // FluentIcon::TriggerLeft => '\u{F10A}',
-            // FluentIcon::TriggerRight => '\u{F10B}',
-            // FluentIcon::BumperLeft => '\u{F10C}',
-            // FluentIcon::BumperRight => '\u{F10D}',
-            // FluentIcon::Dpad => '\u{F10E}',
-            // FluentIcon::EnglishPunctuation => '\u{F110}',
-            // FluentIcon::ChinesePunctuation => '\u{F111}',
-            // FluentIcon::Hmd => '\u{F119}',
-            // FluentIcon::CtrlSpatialRight => '\u{F11B}',
-            // FluentIcon::PaginationDotOutline10 => '\u{F126}',
-            // FluentIcon::PaginationDotSolid10 => '\u{F127}',
-            // FluentIcon::StrokeErase2 => '\u{F128}',
-            // FluentIcon::SmallErase => '\u{F129}',
-            // FluentIcon::LargeErase => '\u{F12A}',
-            // FluentIcon::FolderHorizontal => '\u{F12B}',
-            // FluentIcon::MicrophoneListening => '\u{F12E}',
-            // FluentIcon::StatusExclamationCircle7 => '\u{F12F}',
-            // FluentIcon::Video360 => '\u{F131}',
-            // FluentIcon::GiftboxOpen => '\u{F133}',
-            // FluentIcon::StatusCircleOuter => '\u{F136}',
-            // FluentIcon::StatusCircleInner => '\u{F137}',
-            // FluentIcon::StatusCircleRing => '\u{F138}',
-            // FluentIcon::StatusTriangleOuter => '\u{F139}',
-            // FluentIcon::StatusTriangleInner => '\u{F13A}',
-            // FluentIcon::StatusTriangleExclamation => '\u{F13B}',
-            // FluentIcon::StatusCircleExclamation => '\u{F13C}',
-            // FluentIcon::StatusCircleErrorX => '\u{F13D}',
-            // FluentIcon::StatusCircleCheckmark => '\u{F13E}',
-            // FluentIcon::StatusCircleInfo => '\u{F13F}',
-            // FluentIcon::StatusCircleBlock => '\u{F140}',
-            // FluentIcon::StatusCircleBlock2 => '\u{F141}',
-            // FluentIcon::StatusCircleQuestionMark => '\u{F142}',
-            // FluentIcon::StatusCircleSync => '\u{F143}',
-            // FluentIcon::Dial1 => '\u{F146}',
-            // FluentIcon::Dial2 => '\u{F147}',
-            // FluentIcon::Dial3 => '\u{F148}',
-            // FluentIcon::Dial4 => '\u{F149}',
-            // FluentIcon::Dial5 => '\u{F14A}',
-            // FluentIcon::Dial6 => '\u{F14B}',
-            // FluentIcon::Dial7 => '\u{F14C}',
-            // FluentIcon::Dial8 => '\u{F14D}',
-            // FluentIcon::Dial9 => '\u{F14E}',
-            // FluentIcon::Dial10 => '\u{F14F}',
-            // FluentIcon::Dial11 => '\u{F150}',
-            // FluentIcon::Dial12 => '\u{F151}',
-            // FluentIcon::Dial13 => '\u{F152}',
-            // FluentIcon::Dial14 => '\u{F153}',
-            // FluentIcon::Dial15 => '\u{F154}',
-            // FluentIcon::Dial16 => '\u{F155}',
-            // FluentIcon::DialShape1 => '\u{F156}',
-            // FluentIcon::DialShape2 => '\u{F157}',
-            // FluentIcon::DialShape3 => '\u{F158}',
-            // FluentIcon::DialShape4 => '\u{F159}',
-            // FluentIcon::ClosedCaptionsInternational => '\u{F15F}',
-            // FluentIcon::TollSolid => '\u{F161}',
-            // FluentIcon::TrafFluentIconcCongestionSolid => '\u{F163}',
-            // FluentIcon::ExploreContentSingle => '\u{F164}',
-            // FluentIcon::CollapseContent => '\u{F165}',
-            // FluentIcon::CollapseContentSingle => '\u{F166}',
-            // FluentIcon::InfoSolid => '\u{F167}',
-            // FluentIcon::GroupList => '\u{F168}',
-            // FluentIcon::CaretBottomRightSolidCenter8 => '\u{F169}',
-            // FluentIcon::ProgressRingDots => '\u{F16A}',
-            // FluentIcon::Checkbox14 => '\u{F16B}',
-            // FluentIcon::CheckboxComposite14 => '\u{F16C}',
-            // FluentIcon::CheckboxIndeterminateCombo14 => '\u{F16D}',
-            // FluentIcon::CheckboxIndeterminateCombo => '\u{F16E}',
-            // FluentIcon::StatusPause7 => '\u{F175}',
-            // FluentIcon::CharacterAppearance => '\u{F17F}',
-            // FluentIcon::Lexicon => '\u{F180}',
-            // FluentIcon::ScreenTime => '\u{F182}',
-            // FluentIcon::HeadlessDevice => '\u{F191}',
-            // FluentIcon::NetworkSharing => '\u{F193}',
-            // FluentIcon::EyeGaze => '\u{F19D}',
-            // FluentIcon::ToggleLeft => '\u{F19E}',
-            // FluentIcon::ToggleRight => '\u{F19F}',
-            // FluentIcon::WindowsInsider => '\u{F1AD}',
-            // FluentIcon::ChromeSwitch => '\u{F1CB}',
-            // FluentIcon::ChromeSwitchContast => '\u{F1CC}',
-            // FluentIcon::StatusCheckmark => '\u{F1D8}',
-            // FluentIcon::StatusCheckmarkLeft => '\u{F1D9}',
-            // FluentIcon::KeyboardLeftAligned => '\u{F20C}',
-            // FluentIcon::KeyboardRightAligned => '\u{F20D}',
-            // FluentIcon::KeyboardSettings => '\u{F210}',
-            // FluentIcon::NetworkPhysical => '\u{F211}',
-            // FluentIcon::Iot => '\u{F22C}',
-            // FluentIcon::UnknownMirrored => '\u{F22E}',
-            // FluentIcon::ViewDashboard => '\u{F246}',
-            // FluentIcon::ExploitProtectionSettings => '\u{F259}',
-            // FluentIcon::KeyboardNarrow => '\u{F260}',
-            // FluentIcon::Keyboard12Key => '\u{F261}',
-            // FluentIcon::KeyboardDock => '\u{F26B}',
-            // FluentIcon::KeyboardUndock => '\u{F26C}',
-            // FluentIcon::KeyboardLeftDock => '\u{F26D}',
-            // FluentIcon::KeyboardRightDock => '\u{F26E}',
-            // FluentIcon::Ear => '\u{F270}',
-            // FluentIcon::PointerHand => '\u{F271}',
-            // FluentIcon::Bullseye => '\u{F272}',
-            // FluentIcon::DocumentApproval => '\u{F28B}',
-            // FluentIcon::LocaleLanguage => '\u{F2B7}',
-            // FluentIcon::PassiveAuthentication => '\u{F32A}',
-            // FluentIcon::ColorSolid => '\u{F354}',
-            // FluentIcon::NetworkOffline => '\u{F384}',
-            // FluentIcon::NetworkConnected => '\u{F385}',
-            // FluentIcon::NetworkConnectedCheckmark => '\u{F386}',
-            // FluentIcon::SignOut => '\u{F3B1}',
-            // FluentIcon::StatusInfo => '\u{F3CC}',
-            // FluentIcon::StatusInfoLeft => '\u{F3CD}',
-            // FluentIcon::NearbySharing => '\u{F3E2}',
-            // FluentIcon::CtrlSpatialLeft => '\u{F3E7}',
-            // FluentIcon::InteractiveDashboard => '\u{F404}',
-            // FluentIcon::DeclineCall => '\u{F405}',
-            // FluentIcon::ClippingTool => '\u{F406}',
-            // FluentIcon::RectangularClipping => '\u{F407}',
-            // FluentIcon::FreeFormClipping => '\u{F408}',
-            // FluentIcon::CopyTo => '\u{F413}',
-            // FluentIcon::IDBadge => '\u{F427}',
-            // FluentIcon::DynamicLock => '\u{F439}',
-            // FluentIcon::PenTips => '\u{F45E}',
-            // FluentIcon::PenTipsMirrored => '\u{F45F}',
-            // FluentIcon::HWPJoin => '\u{F460}',
-            // FluentIcon::HWPInsert => '\u{F461}',
-            // FluentIcon::HWPStrikeThrough => '\u{F462}',
-            // FluentIcon::HWPScratchOut => '\u{F463}',
-            // FluentIcon::HWPSplit => '\u{F464}',
-            // FluentIcon::HWPNewLine => '\u{F465}',
-            // FluentIcon::HWPOverwrite => '\u{F466}',
-            // FluentIcon::MobWiFluentIconWarning1 => '\u{F473}',
-            // FluentIcon::MobWiFluentIconWarning2 => '\u{F474}',
-            // FluentIcon::MobWiFluentIconWarning3 => '\u{F475}',
-            // FluentIcon::MobWiFluentIconWarning4 => '\u{F476}',
-            // FluentIcon::MicLocationCombo => '\u{F47F}',
-            // FluentIcon::Globe2 => '\u{F49A}',
-            // FluentIcon::SpecialEffectSize => '\u{F4A5}',
-            // FluentIcon::Gif => '\u{F4A9}',
-            // FluentIcon::Sticker2 => '\u{F4AA}',
-            // FluentIcon::SurfaceHubSelected => '\u{F4BE}',
-            // FluentIcon::HoloLensSelected => '\u{F4BF}',
-            // FluentIcon::Earbud => '\u{F4C0}',
-            // FluentIcon::MixVolumes => '\u{F4C3}',
-            // FluentIcon::Safe => '\u{F540}',
-            // FluentIcon::LaptopSecure => '\u{F552}',
-            // FluentIcon::PrintDefault => '\u{F56D}',
-            // FluentIcon::PageMirrored => '\u{F56E}',
-            // FluentIcon::LandscapeOrientationMirrored => '\u{F56F}',
-            // FluentIcon::ColorOff => '\u{F570}',
-            // FluentIcon::PrintAllPages => '\u{F571}',
-            // FluentIcon::PrintCustomRange => '\u{F572}',
-            // FluentIcon::PageMarginPortraitNarrow => '\u{F573}',
-            // FluentIcon::PageMarginPortraitNormal => '\u{F574}',
-            // FluentIcon::PageMarginPortraitModerate => '\u{F575}',
-            // FluentIcon::PageMarginPortraitWide => '\u{F576}',
-            // FluentIcon::PageMarginLandscapeNarrow => '\u{F577}',
-            FluentIcon::PageMarginLandscapeNormal => '\u{F578}',
-            // FluentIcon::PageMarginLandscapeModerate => '\u{F579}',
-            // FluentIcon::PageMarginLandscapeWide => '\u{F57A}',
-            // FluentIcon::CollateLandscape => '\u{F57B}',
-            // FluentIcon::CollatePortrait => '\u{F57C}',
-            // FluentIcon::CollatePortraitSeparated => '\u{F57D}',
-            // FluentIcon::DuplexLandscapeOneSided => '\u{F57E}',
-            // FluentIcon::DuplexLandscapeOneSidedMirrored => '\u{F57F}',
-            // FluentIcon::DuplexLandscapeTwoSidedLongEdge => '\u{F580}',
-            // FluentIcon::DuplexLandscapeTwoSidedLongEdgeMirrored => '\u{F581}',
-            // FluentIcon::DuplexLandscapeTwoSidedShortEdge => '\u{F582}',
-            // FluentIcon::DuplexLandscapeTwoSidedShortEdgeMirrored => '\u{F583}',
-            // FluentIcon::DuplexPortraitOneSided => '\u{F584}',
-            // FluentIcon::DuplexPortraitOneSidedMirrored => '\u{F585}',
-            // FluentIcon::DuplexPortraitTwoSidedLongEdge => '\u{F586}',
-            // FluentIcon::DuplexPortraitTwoSidedLongEdgeMirrored => '\u{F587}',
-            // FluentIcon::DuplexPortraitTwoSidedShortEdge => '\u{F588}',
-            // FluentIcon::DuplexPortraitTwoSidedShortEdgeMirrored => '\u{F589}',
-            // FluentIcon::PPSOneLandscape => '\u{F58A}',
-            // FluentIcon::PPSTwoLandscape => '\u{F58B}',
-            // FluentIcon::PPSTwoPortrait => '\u{F58C}',
-            // FluentIcon::PPSFourLandscape => '\u{F58D}',
-            // FluentIcon::PPSFourPortrait => '\u{F58E}',
-            // FluentIcon::HolePunchOff => '\u{F58F}',
-            // FluentIcon::HolePunchPortraitLeft => '\u{F590}',
-            // FluentIcon::HolePunchPortraitRight => '\u{F591}',
-            // FluentIcon::HolePunchPortraitTop => '\u{F592}',
-            // FluentIcon::HolePunchPortraitBottom => '\u{F593}',
-            // FluentIcon::HolePunchLandscapeLeft => '\u{F594}',
-            // FluentIcon::HolePunchLandscapeRight => '\u{F595}',
-            // FluentIcon::HolePunchLandscapeTop => '\u{F596}',
-            // FluentIcon::HolePunchLandscapeBottom => '\u{F597}',
-            // FluentIcon::StaplingOff => '\u{F598}',
-            // FluentIcon::StaplingPortraitTopLeft => '\u{F599}',
-            // FluentIcon::StaplingPortraitTopRight => '\u{F59A}',
-            // FluentIcon::StaplingPortraitBottomRight => '\u{F59B}',
-            // FluentIcon::StaplingPortraitTwoLeft => '\u{F59C}',
-            // FluentIcon::StaplingPortraitTwoRight => '\u{F59D}',
-            // FluentIcon::StaplingPortraitTwoTop => '\u{F59E}',
-            // FluentIcon::StaplingPortraitTwoBottom => '\u{F59F}',
-            // FluentIcon::StaplingPortraitBookBinding => '\u{F5A0}',
-            // FluentIcon::StaplingLandscapeTopLeft => '\u{F5A1}',
-            // FluentIcon::StaplingLandscapeTopRight => '\u{F5A2}',
-            // FluentIcon::StaplingLandscapeBottomLeft => '\u{F5A3}',
-            // FluentIcon::StaplingLandscapeBottomRight => '\u{F5A4}',
-            // FluentIcon::StaplingLandscapeTwoLeft => '\u{F5A5}',
-            // FluentIcon::StaplingLandscapeTwoRight => '\u{F5A6}',
-            // FluentIcon::StaplingLandscapeTwoTop => '\u{F5A7}',
-            // FluentIcon::StaplingLandscapeTwoBottom => '\u{F5A8}',
-            // FluentIcon::StaplingLandscapeBookBinding => '\u{F5A9}',
-            // FluentIcon::StatusDataTransferRoaming => '\u{F5AA}',
-            // FluentIcon::MobSIMError => '\u{F5AB}',
-            // FluentIcon::CollateLandscapeSeparated => '\u{F5AC}',
-            // FluentIcon::PPSOnePortrait => '\u{F5AD}',
-            // FluentIcon::StaplingPortraitBottomLeft => '\u{F5AE}',
-            // FluentIcon::PlaySolid => '\u{F5B0}',
-            // FluentIcon::RepeatOff => '\u{F5E7}',
-            // FluentIcon::Set => '\u{F5ED}',
-            // FluentIcon::SetSolid => '\u{F5EE}',
-            // FluentIcon::FuzzyReading => '\u{F5EF}',
-            // FluentIcon::VerticalBattery0 => '\u{F5F2}',
-            // FluentIcon::VerticalBattery1 => '\u{F5F3}',
-            // FluentIcon::VerticalBattery2 => '\u{F5F4}',
-            // FluentIcon::VerticalBattery3 => '\u{F5F5}',
-            // FluentIcon::VerticalBattery4 => '\u{F5F6}',
-            // FluentIcon::VerticalBattery5 => '\u{F5F7}',
-            // FluentIcon::VerticalBattery6 => '\u{F5F8}',
-            // FluentIcon::VerticalBattery7 => '\u{F5F9}',
-            // FluentIcon::VerticalBattery8 => '\u{F5FA}',
-            // FluentIcon::VerticalBattery9 => '\u{F5FB}',
-            // FluentIcon::VerticalBattery10 => '\u{F5FC}',
-            // FluentIcon::VerticalBatteryCharging0 => '\u{F5FD}',
-            // FluentIcon::VerticalBatteryCharging1 => '\u{F5FE}',
-            // FluentIcon::VerticalBatteryCharging2 => '\u{F5FF}',
-            // FluentIcon::VerticalBatteryCharging3 => '\u{F600}',
-            // FluentIcon::VerticalBatteryCharging4 => '\u{F601}',
-            // FluentIcon::VerticalBatteryCharging5 => '\u{F602}',
-            // FluentIcon::VerticalBatteryCharging6 => '\u{F603}',
-            // FluentIcon::VerticalBatteryCharging7 => '\u{F604}',
-            // FluentIcon::VerticalBatteryCharging8 => '\u{F605}',
-            // FluentIcon::VerticalBatteryCharging9 => '\u{F606}',
-            // FluentIcon::VerticalBatteryCharging10 => '\u{F607}',
-            // FluentIcon::VerticalBatteryUnknown => '\u{F608}',
-            // FluentIcon::SIMError => '\u{F618}',
-            // FluentIcon::SIMMissing => '\u{F619}',
-            // FluentIcon::SIMLock => '\u{F61A}',
-            // FluentIcon::ESim => '\u{F61B}',
-            // FluentIcon::ESimnoProFluentIconle => '\u{F61C}',
-            // FluentIcon::ESimlocked => '\u{F61D}',
-            // FluentIcon::ESimbusy => '\u{F61E}',
-            // FluentIcon::NoiseCancelation => '\u{F61F}',
-            // FluentIcon::NoiseCancelationOff => '\u{F620}',
-            // FluentIcon::MusicSharing => '\u{F623}',
-            // FluentIcon::MusicSharingOff => '\u{F624}',
-            // FluentIcon::CircleShapeSolid => '\u{F63C}',
-            // FluentIcon::WiFluentIconCallBars => '\u{F657}',
-            // FluentIcon::WiFluentIconCall0 => '\u{F658}',
-            // FluentIcon::WiFluentIconCall1 => '\u{F659}',
-            // FluentIcon::WiFluentIconCall2 => '\u{F65A}',
-            // FluentIcon::WiFluentIconCall3 => '\u{F65B}',
-            // FluentIcon::WiFluentIconCall4 => '\u{F65C}',
-            // FluentIcon::CHTLanguageBar => '\u{F69E}',
-            // FluentIcon::ComposeMode => '\u{F6A9}',
-            // FluentIcon::ExpressiveInputEntry => '\u{F6B8}',
-            // FluentIcon::EmojiTabMoreSymbols => '\u{F6BA}',
-            // FluentIcon::WebSearch => '\u{F6FA}',
-            // FluentIcon::Kiosk => '\u{F712}',
-            // FluentIcon::RTTLogo => '\u{F714}',
-            // FluentIcon::VoiceCall => '\u{F715}',
-            // FluentIcon::GoToMessage => '\u{F716}',
-            // FluentIcon::ReturnToCall => '\u{F71A}',
-            // FluentIcon::StartPresenting => '\u{F71C}',
-            // FluentIcon::StopPresenting => '\u{F71D}',
-            // FluentIcon::ProductivityMode => '\u{F71E}',
-            // FluentIcon::SetHistoryStatus => '\u{F738}',
-            // FluentIcon::SetHistoryStatus2 => '\u{F739}',
-            // FluentIcon::Keyboardsettings20 => '\u{F73D}',
-            // FluentIcon::OneHandedRight20 => '\u{F73E}',
-            // FluentIcon::OneHandedLeft20 => '\u{F73F}',
-            // FluentIcon::Split20 => '\u{F740}',
-            // FluentIcon::Full20 => '\u{F741}',
-            // FluentIcon::Handwriting20 => '\u{F742}',
-            // FluentIcon::ChevronLeft20 => '\u{F743}',
-            // FluentIcon::ChevronLeft32 => '\u{F744}',
-            // FluentIcon::ChevronRight20 => '\u{F745}',
-            // FluentIcon::ChevronRight32 => '\u{F746}',
-            // FluentIcon::Event12 => '\u{F763}',
-            // FluentIcon::MicOff2 => '\u{F781}',
-            // FluentIcon::DeliveryOptimization => '\u{F785}',
-            // FluentIcon::CancelMedium => '\u{F78A}',
-            // FluentIcon::SearchMedium => '\u{F78B}',
-            // FluentIcon::AcceptMedium => '\u{F78C}',
-            // FluentIcon::RevealPasswordMedium => '\u{F78D}',
-            // FluentIcon::DeleteWord => '\u{F7AD}',
-            // FluentIcon::DeleteWordFluentIconll => '\u{F7AE}',
-            // FluentIcon::DeleteLines => '\u{F7AF}',
-            // FluentIcon::DeleteLinesFluentIconll => '\u{F7B0}',
-            // FluentIcon::InstertWords => '\u{F7B1}',
-            // FluentIcon::InstertWordsFluentIconll => '\u{F7B2}',
-            // FluentIcon::JoinWords => '\u{F7B3}',
-            // FluentIcon::JoinWordsFluentIconll => '\u{F7B4}',
-            // FluentIcon::OverwriteWords => '\u{F7B5}',
-            // FluentIcon::OverwriteWordsFluentIconll => '\u{F7B6}',
-            // FluentIcon::AddNewLine => '\u{F7B7}',
-            // FluentIcon::AddNewLineFluentIconll => '\u{F7B8}',
-            // FluentIcon::OverwriteWordsKorean => '\u{F7B9}',
-            // FluentIcon::OverwriteWordsFluentIconllKorean => '\u{F7BA}',
-            // FluentIcon::EducationIcon => '\u{F7BB}',
-            // FluentIcon::WindowSnipping => '\u{F7ED}',
-            // FluentIcon::VideoCapture => '\u{F7EE}',
-            // FluentIcon::StatusSecured => '\u{F809}',
-            // FluentIcon::NarratorApp => '\u{F83B}',
-            // FluentIcon::PowerButtonUpdate => '\u{F83D}',
-            // FluentIcon::RestartUpdate => '\u{F83E}',
-            // FluentIcon::UpdateStatusDot => '\u{F83F}',
-            // FluentIcon::Eject => '\u{F847}',
-            // FluentIcon::Spelling => '\u{F87B}',
-            // FluentIcon::SpellingKorean => '\u{F87C}',
-            // FluentIcon::SpellingSerbian => '\u{F87D}',
-            // FluentIcon::SpellingChinese => '\u{F87E}',
-            // FluentIcon::FolderSelect => '\u{F89A}',
-            // FluentIcon::SmartScreen => '\u{F8A5}',
-            // FluentIcon::ExploitProtection => '\u{F8A6}',
-            // FluentIcon::AddBold => '\u{F8AA}',
-            // FluentIcon::SubtractBold => '\u{F8AB}',
-            // FluentIcon::BackSolidBold => '\u{F8AC}',
-            // FluentIcon::ForwardSolidBold => '\u{F8AD}',
-            // FluentIcon::PauseBold => '\u{F8AE}',
-            // FluentIcon::ClickSolid => '\u{F8AF}',
-            // FluentIcon::SettingsSolid => '\u{F8B0}',
-            // FluentIcon::MicrophoneSolidBold => '\u{F8B1}',
-            // FluentIcon::SpeechSolidBold => '\u{F8B2}',
-            // FluentIcon::ClickedOutLoudSolidBold => '\u{F8B3}',
-        }
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn search_matches_tags() {
+        let found: Vec<FluentIcon> = FluentIcon::search("trash").collect();
+
+        assert!(!FluentIcon::Delete.name().to_lowercase().contains("trash"));
+        assert!(found.contains(&FluentIcon::Delete));
+    }
+
+    #[test]
+    fn search_ignores_case_and_whitespace() {
+        let found: Vec<FluentIcon> = FluentIcon::search("  HAMBURGER ").collect();
+
+        assert_eq!(found, [FluentIcon::GlobalNavButton]);
     }
 }
@@ -1,20 +1,182 @@
+// The Segoe fonts only ship with Windows. Elsewhere the text falls back to the
+// bundled Inter (SIL Open Font License, see assets/fonts) and icons to whichever
+// compatible icon font can be found. Fonts in a custom directory are preferred
+// over both so Segoe, Selawik or an icon font can be supplied on any platform.
 use iced::{
-    font::{Family, Weight},
-    Font,
+    font::{self, Family, Weight},
+    Font, Task,
 };
 
-pub const SEGOE: Font = Font::with_name("en Segoe UI");
-
-pub const SEGOE_SEMIBOLD: Font = Font {
-    family: Family::Name("en Segoe UI Semibold"),
-    weight: Weight::Semibold,
-    ..Font::DEFAULT
+use std::{
+    path::{Path, PathBuf},
+    sync::RwLock,
 };
 
-pub const SEGOE_BOLD: Font = Font {
-    family: Family::Name("en Segoe UI Bold"),
-    weight: Weight::Bold,
-    ..Font::DEFAULT
-};
+const INTER_REGULAR: &[u8] = include_bytes!("../assets/fonts/Inter-Regular.ttf");
+const INTER_BOLD: &[u8] = include_bytes!("../assets/fonts/Inter-Bold.ttf");
+
+// In order of preference
+const TEXT_FAMILIES: [&str; 3] = ["Segoe UI", "Selawik", "Inter"];
+// Segoe MDL2 Assets is the Windows 10 predecessor and shares most codepoints
+const ICON_FAMILIES: [&str; 2] = ["Segoe Fluent Icons", "Segoe MDL2 Assets"];
+
+const BUNDLED_TEXT_FAMILY: &str = "Inter";
+
+static FACES: RwLock<Faces> = RwLock::new(Faces::WINDOWS);
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum Source {
+    Custom,
+    System,
+    Bundled,
+    Missing,
+}
+
+impl std::fmt::Display for Source {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(
+            f,
+            "{}",
+            match self {
+                Source::Custom => "custom directory",
+                Source::System => "system",
+                Source::Bundled => "bundled",
+                Source::Missing => "missing",
+            }
+        )
+    }
+}
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct Face {
+    pub family: &'static str,
+    pub source: Source,
+}
+
+impl std::fmt::Display for Face {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{} ({})", self.family, self.source)
+    }
+}
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct Faces {
+    pub text: Face,
+    pub icons: Face,
+}
+
+impl Faces {
+    // Assumed until `select` has run
+    const WINDOWS: Self = Self {
+        text: Face {
+            family: TEXT_FAMILIES[0],
+            source: Source::System,
+        },
+        icons: Face {
+            family: ICON_FAMILIES[0],
+            source: Source::System,
+        },
+    };
+
+    // Looks through `directory` first, then the installed fonts
+    pub fn select(directory: Option<&Path>) -> Self {
+        let mut custom = fontdb::Database::new();
+        let mut system = fontdb::Database::new();
+
+        if let Some(directory) = directory {
+            custom.load_fonts_dir(directory);
+        }
+
+        system.load_system_fonts();
+
+        let find = |families: &[&'static str]| {
+            [(&custom, Source::Custom), (&system, Source::System)]
+                .into_iter()
+                .find_map(|(database, source)| {
+                    families
+                        .iter()
+                        .find(|family| has_family(database, family))
+                        .map(|family| Face { family, source })
+                })
+        };
+
+        Self {
+            text: find(&TEXT_FAMILIES).unwrap_or(Face {
+                family: BUNDLED_TEXT_FAMILY,
+                source: Source::Bundled,
+            }),
+            icons: find(&ICON_FAMILIES).unwrap_or(Face {
+                family: ICON_FAMILIES[0],
+                source: Source::Missing,
+            }),
+        }
+    }
+}
+
+fn has_family(database: &fontdb::Database, family: &str) -> bool {
+    database
+        .faces()
+        .any(|face| face.families.iter().any(|(name, _)| name == family))
+}
+
+pub fn faces() -> Faces {
+    *FACES.read().unwrap_or_else(|error| error.into_inner())
+}
+
+pub fn set_faces(faces: Faces) {
+    *FACES.write().unwrap_or_else(|error| error.into_inner()) = faces;
+}
+
+// Hands the bundled fonts and those in `directory` to the renderer
+pub fn load(directory: Option<&Path>) -> Task<Result<(), font::Error>> {
+    let bundled = [INTER_REGULAR, INTER_BOLD].map(font::load);
+    let custom = directory
+        .map(font_files)
+        .unwrap_or_default()
+        .into_iter()
+        .filter_map(|path| std::fs::read(path).ok())
+        .map(font::load);
+
+    Task::batch(bundled.into_iter().chain(custom))
+}
+
+fn font_files(directory: &Path) -> Vec<PathBuf> {
+    let Ok(entries) = std::fs::read_dir(directory) else {
+        return Vec::new();
+    };
+
+    entries
+        .filter_map(|entry| entry.ok().map(|entry| entry.path()))
+        .filter(|path| {
+            path.extension()
+                .and_then(|extension| extension.to_str())
+                .is_some_and(|extension| {
+                    ["ttf", "otf", "ttc"].contains(&extension.to_lowercase().as_str())
+                })
+        })
+        .collect()
+}
+
+pub fn text() -> Font {
+    Font::with_name(faces().text.family)
+}
+
+pub fn semibold() -> Font {
+    Font {
+        family: Family::Name(faces().text.family),
+        weight: Weight::Semibold,
+        ..Font::DEFAULT
+    }
+}
+
+pub fn bold() -> Font {
+    Font {
+        family: Family::Name(faces().text.family),
+        weight: Weight::Bold,
+        ..Font::DEFAULT
+    }
+}
 
-pub const SEGOE_FLUENT_ICONS: Font = Font::with_name("en Segoe Fluent Icons");
+pub fn icons() -> Font {
+    Font::with_name(faces().icons.family)
+}
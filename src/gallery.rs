@@ -1,20 +1,31 @@
 use crate::{
+    density::{self, Density},
     fluent_icon::FluentIcon,
+    font,
+    motion::{self, Animated, Easing},
     page::{self, page},
-    style,
-    theme::Theme,
-    widget::{pick_list, side_nav, text, toggler, Container, Element},
+    theme::{self, accent, class::Registry, high_contrast::Contrast, Appearance, Palette, Theme},
+    widget::{
+        breadcrumb_bar::{BreadcrumbBar, Item},
+        button,
+        dialog::{ContentDialog, DefaultButton, Modal},
+        info_bar::{InfoBar, Severity},
+        pick_list, side_nav, slider, text, text_input, toggler, Button, Container, Element, Row,
+    },
 };
 
-use std::mem::discriminant;
+use std::{
+    mem::discriminant,
+    path::{Path, PathBuf},
+    sync::Arc,
+    time::Instant,
+};
 
 use iced::{
     alignment::Vertical,
     border,
-    widget::{
-        center, column, container, horizontal_space, iced, image, mouse_area, opaque, row, stack,
-    },
-    window, ContentFit, Length, Size, Subscription,
+    widget::{self, column, container, horizontal_space, iced, image, row, stack},
+    window, Border, Color, ContentFit, Length, Size, Subscription, Task,
 };
 
 #[derive(Clone, Debug, Default)]
@@ -33,11 +44,24 @@ pub enum Page {
     Slider(page::slider::Slider),
     Toggler(page::toggler::Toggler),
 
+    // Collections
+    DataGrid(page::data_grid::DataGrid),
+    ListView(page::list_view::ListView),
+    TreeView(page::tree_view::TreeView),
+
+    // Date and time
+    CalendarDatePicker(page::calendar_date_picker::CalendarDatePicker),
+    CalendarView(page::calendar_view::CalendarView),
+    TimePicker(page::time_picker::TimePicker),
+
     // Dialogs and flyouts
     Dialog(page::dialog::Dialog),
+    TeachingTip(page::teaching_tip::TeachingTip),
 
     // Layout
     RowColumn(page::row_column::RowColumn),
+    SplitView(page::split_view::SplitView),
+    TabView(page::tab_view::TabView),
 
     // Media
     Image(page::image::Image),
@@ -48,21 +72,53 @@ pub enum Page {
     MenuBar(page::menu_bar::MenuBar),
     Ribbon(page::ribbon::Ribbon),
 
+    // Status and info
+    InfoBar(page::info_bar::InfoBar),
+    Progress(page::progress::Progress),
+
     // Text
     TextInput(page::text_input::TextInput),
 
+    // Design guidance
+    Colors(page::colors::Colors),
+    Typography(page::typography::Typography),
+    Iconography(page::iconography::Iconography),
+    TonalPalette(page::tonal::TonalPalette),
+    ContrastAudit(page::contrast_audit::ContrastAudit),
+
     Settings,
 }
 
 pub struct Gallery {
     current_page: Page,
     side_nav_display_mode: side_nav::DisplayMode,
+    side_nav_width: f32,
     pages: Vec<PageGroup>,
     footer_pages: Vec<PageGroup>,
     page_group_overlay_open: Option<&'static str>,
     window_size: Size,
     theme: Theme,
+    // The theme being faded out after a change, see `Gallery::theme`
+    previous_theme: Theme,
+    theme_transition: Animated<f32>,
+    now: Instant,
+    appearance: Appearance,
+    accent: Option<Color>,
+    imported_theme: Option<Arc<Palette>>,
+    theme_file_path: String,
+    // A file that exporting would replace, waiting for the user to confirm
+    theme_file_overwrite: Option<String>,
+    // Pinned to the top of the content area until dismissed
+    notification: Option<Notification>,
     explain: bool,
+    animation_effects: bool,
+    rounded_style: bool,
+    // Swapped into the theme while `rounded_style` is on
+    rounded_classes: Arc<Registry>,
+    density: Density,
+    text_scale: u16,
+    font_directory: String,
+    font_status: Option<String>,
 }
 
 #[derive(Debug, Clone)]
@@ -71,9 +127,26 @@ pub enum Message {
     PageGroupToggled(&'static str),
     PageGroupOverlayDismissed,
     SideNavDisplayModeToggled,
+    SideNavResized(f32),
     WindowResized((window::Id, Size)),
-    ThemeToggled,
+    ThemeSelected(Appearance),
+    AccentSelected(Option<Color>),
+    ThemeFilePathChanged(String),
+    ThemeImported,
+    ThemeExported,
+    ThemeOverwriteConfirmed,
+    ThemeOverwriteCancelled,
+    NotificationDismissed,
     ExplainToggled,
+    AnimationEffectsToggled(bool),
+    RoundedStyleToggled(bool),
+    DensitySelected(Density),
+    TextScaleChanged(u16),
+    FontDirectoryChanged(String),
+    FontDirectoryApplied,
+    FacesSelected(font::Faces, Option<PathBuf>),
+    FontLoaded(Result<(), iced::font::Error>),
+    Frame(Instant),
 
     // Basic input
     ButtonPage(page::button::Message),
@@ -85,11 +158,24 @@ pub enum Message {
     SliderPage(page::slider::Message),
     TogglerPage(page::toggler::Message),
 
+    // Collections
+    DataGridPage(page::data_grid::Message),
+    ListViewPage(page::list_view::Message),
+    TreeViewPage(page::tree_view::Message),
+
+    // Date and time
+    CalendarDatePickerPage(page::calendar_date_picker::Message),
+    CalendarViewPage(page::calendar_view::Message),
+    TimePickerPage(page::time_picker::Message),
+
     // Dialogs and flyouts
     DialogPage(page::dialog::Message),
+    TeachingTipPage(page::teaching_tip::Message),
 
     // Layout
     RowColumnPage(page::row_column::Message),
+    SplitViewPage(page::split_view::Message),
+    TabViewPage(page::tab_view::Message),
 
     // Media
     ImagePage(page::image::Message),
@@ -100,15 +186,36 @@ pub enum Message {
     MenuBar(page::menu_bar::Message),
     Ribbon(page::ribbon::Message),
 
+    // Status and info
+    InfoBarPage(page::info_bar::Message),
+    ProgressPage(page::progress::Message),
+
     // Text
     TextInputPage(page::text_input::Message),
     RadioPage(page::radio::Message),
+
+    // Design guidance
+    ColorsPage(page::colors::Message),
+    TypographyPage(page::typography::Message),
+    IconographyPage(page::iconography::Message),
+    TonalPalettePage(page::tonal::Message),
+    ContrastAuditPage(page::contrast_audit::Message),
 }
 
 const SIDE_NAV_COMPACT_WIDTH: f32 = 1000.0;
 
 impl Gallery {
-    pub fn update(&mut self, message: Message) {
+    pub fn new() -> (Self, Task<Message>) {
+        // Windows' faces are assumed until the installed fonts have been scanned
+        (
+            Self::default(),
+            Task::perform(async { font::Faces::select(None) }, |faces| {
+                Message::FacesSelected(faces, None)
+            }),
+        )
+    }
+
+    pub fn update(&mut self, message: Message) -> Task<Message> {
         match (message, &mut self.current_page) {
             (Message::PageSelected(page), _) => {
                 self.select_page(page);
@@ -135,6 +242,7 @@ impl Gallery {
                     side_nav::DisplayMode::Full => side_nav::DisplayMode::Compact,
                 }
             }
+            (Message::SideNavResized(width), _) => self.side_nav_width = width,
             (Message::WindowResized((_, size)), _) => {
                 self.window_size = size;
 
@@ -143,13 +251,93 @@ impl Gallery {
                 }
             }
 
-            (Message::ThemeToggled, _) => {
-                self.theme = match self.theme {
-                    Theme::Light => Theme::Dark,
-                    Theme::Dark => Theme::Light,
+            (Message::ThemeSelected(appearance), _) => {
+                self.appearance = appearance;
+                self.apply_theme();
+            }
+            (Message::AccentSelected(accent), _) => {
+                self.accent = accent;
+                self.apply_theme();
+            }
+            (Message::ThemeFilePathChanged(path), _) => self.theme_file_path = path,
+            (Message::ThemeImported, _) => match theme::file::load(&self.theme_file_path) {
+                Ok(palette) => {
+                    let palette = Arc::new(palette);
+                    self.imported_theme = Some(palette.clone());
+                    self.appearance = Appearance::Custom(palette);
+                    self.notification = Some(Notification {
+                        severity: Severity::Success,
+                        title: "Theme imported",
+                        message: self.theme_file_path.clone(),
+                    });
+                    self.apply_theme();
+                }
+                Err(error) => {
+                    self.notification = Some(Notification {
+                        severity: Severity::Error,
+                        title: "Theme not imported",
+                        message: error.to_string(),
+                    })
+                }
+            },
+            // The path is usually the file just imported, which may only override a
+            // few tokens, so replacing it with every token needs confirming
+            (Message::ThemeExported, _) => {
+                if Path::new(&self.theme_file_path).exists() {
+                    self.theme_file_overwrite = Some(self.theme_file_path.clone());
+                } else {
+                    self.export_theme(self.theme_file_path.clone());
+                }
+            }
+            (Message::ThemeOverwriteConfirmed, _) => {
+                if let Some(path) = self.theme_file_overwrite.take() {
+                    self.export_theme(path);
                 }
             }
+            (Message::ThemeOverwriteCancelled, _) => self.theme_file_overwrite = None,
+            (Message::NotificationDismissed, _) => self.notification = None,
             (Message::ExplainToggled, _) => self.explain = !self.explain,
+            (Message::AnimationEffectsToggled(animation_effects), _) => {
+                self.animation_effects = animation_effects;
+                motion::set_reduce_motion(!animation_effects);
+            }
+            (Message::RoundedStyleToggled(rounded_style), _) => self.rounded_style = rounded_style,
+            (Message::DensitySelected(density), _) => self.density = density,
+            (Message::TextScaleChanged(text_scale), _) => {
+                self.text_scale = text_scale;
+                text::set_scale(text_scale);
+            }
+            (Message::FontDirectoryChanged(directory), _) => self.font_directory = directory,
+            (Message::FontDirectoryApplied, _) => {
+                let directory = Some(Path::new(&self.font_directory))
+                    .filter(|directory| !directory.as_os_str().is_empty());
+
+                if directory.is_some_and(|directory| !directory.is_dir()) {
+                    self.font_status = Some(format!("{} is not a directory", self.font_directory));
+                    return Task::none();
+                }
+
+                self.font_status = None;
+
+                // Scanning the installed fonts takes a while, so it runs off the UI thread
+                let directory = directory.map(Path::to_path_buf);
+
+                return Task::perform(
+                    async move { (font::Faces::select(directory.as_deref()), directory) },
+                    |(faces, directory)| Message::FacesSelected(faces, directory),
+                );
+            }
+            (Message::FacesSelected(faces, directory), _) => {
+                font::set_faces(faces);
+
+                return font::load(directory.as_deref()).map(Message::FontLoaded);
+            }
+            (Message::FontLoaded(result), _) => {
+                if let Err(error) = result {
+                    self.font_status = Some(format!("Failed to load a font: {error:?}"));
+                }
+            }
+            (Message::Frame(now), _) => self.now = now,
 
             // Page messages
             // Basic input
@@ -165,11 +353,26 @@ impl Gallery {
             (Message::SliderPage(message), Page::Slider(page)) => page.update(message),
             (Message::TogglerPage(message), Page::Toggler(page)) => page.update(message),
 
+            // Collections
+            (Message::DataGridPage(message), Page::DataGrid(page)) => page.update(message),
+            (Message::ListViewPage(message), Page::ListView(page)) => page.update(message),
+            (Message::TreeViewPage(message), Page::TreeView(page)) => page.update(message),
+
+            // Date and time
+            (Message::CalendarDatePickerPage(message), Page::CalendarDatePicker(page)) => {
+                page.update(message)
+            }
+            (Message::CalendarViewPage(message), Page::CalendarView(page)) => page.update(message),
+            (Message::TimePickerPage(message), Page::TimePicker(page)) => page.update(message),
+
             // Dialogs and flyouts
             (Message::DialogPage(message), Page::Dialog(page)) => page.update(message),
+            (Message::TeachingTipPage(message), Page::TeachingTip(page)) => page.update(message),
 
             // Layout
             (Message::RowColumnPage(message), Page::RowColumn(page)) => page.update(message),
+            (Message::SplitViewPage(message), Page::SplitView(page)) => page.update(message),
+            (Message::TabViewPage(message), Page::TabView(page)) => page.update(message),
 
             // Media
             (Message::ImagePage(message), Page::Image(page)) => page.update(message),
@@ -180,11 +383,28 @@ impl Gallery {
             (Message::MenuBar(message), Page::MenuBar(page)) => page.update(message),
             (Message::Ribbon(message), Page::Ribbon(page)) => page.update(message),
 
+            // Status and info
+            (Message::InfoBarPage(message), Page::InfoBar(page)) => page.update(message),
+            (Message::ProgressPage(message), Page::Progress(page)) => page.update(message),
+
             // Text
             (Message::TextInputPage(message), Page::TextInput(page)) => page.update(message),
 
+            // Design guidance
+            (Message::ColorsPage(message), Page::Colors(page)) => page.update(message),
+            (Message::TypographyPage(message), Page::Typography(page)) => page.update(message),
+            (Message::IconographyPage(message), Page::Iconography(page)) => {
+                return page.update(message).map(Message::IconographyPage);
+            }
+            (Message::TonalPalettePage(message), Page::TonalPalette(page)) => page.update(message),
+            (Message::ContrastAuditPage(message), Page::ContrastAudit(page)) => {
+                page.update(message)
+            }
+
             _ => panic!("Message, Page pair not valid."),
         }
+
+        Task::none()
     }
 
     pub fn subscription(&self) -> Subscription<Message> {
@@ -195,10 +415,17 @@ impl Gallery {
             _ => Subscription::none(),
         };
 
-        iced::Subscription::batch([window_resize_sub, menu_bar_sub])
+        let theme_transition_sub =
+            motion::frames(self.theme_transition.is_animating(self.now)).map(Message::Frame);
+
+        iced::Subscription::batch([window_resize_sub, menu_bar_sub, theme_transition_sub])
     }
 
     pub fn view(&self) -> Element<Message> {
+        density::with(self.theme().density(), || self.window_view())
+    }
+
+    fn window_view(&self) -> Element<'_, Message> {
         let nav_items = |pages: &[PageGroup]| -> Vec<side_nav::Group<Message>> {
             pages
                 .iter()
@@ -249,14 +476,16 @@ impl Gallery {
 
         let side_nav = side_nav::SideNav::new(display_mode, Message::SideNavDisplayModeToggled)
             .with_groups(page_items)
-            .with_footer_groups(footer_page_items);
+            .with_footer_groups(footer_page_items)
+            .full_width(self.side_nav_width)
+            .on_resize(Message::SideNavResized);
 
         let padding = match self.current_page {
             Page::Home => 0,
             _ => 24,
         };
 
-        let page = Container::new(match &self.current_page {
+        let page_view = match &self.current_page {
             Page::Home => self.home_page_view(),
 
             // Basic input
@@ -270,11 +499,24 @@ impl Gallery {
             Page::Slider(page) => page.view().map(Message::SliderPage),
             Page::Toggler(page) => page.view().map(Message::TogglerPage),
 
+            // Collections
+            Page::DataGrid(page) => page.view().map(Message::DataGridPage),
+            Page::ListView(page) => page.view().map(Message::ListViewPage),
+            Page::TreeView(page) => page.view().map(Message::TreeViewPage),
+
+            // Date and time
+            Page::CalendarDatePicker(page) => page.view().map(Message::CalendarDatePickerPage),
+            Page::CalendarView(page) => page.view().map(Message::CalendarViewPage),
+            Page::TimePicker(page) => page.view().map(Message::TimePickerPage),
+
             // Dialogs and flyouts
             Page::Dialog(page) => page.view().map(Message::DialogPage),
+            Page::TeachingTip(page) => page.view().map(Message::TeachingTipPage),
 
             // Layout
             Page::RowColumn(page) => page.view().map(Message::RowColumnPage),
+            Page::SplitView(page) => page.view().map(Message::SplitViewPage),
+            Page::TabView(page) => page.view().map(Message::TabViewPage),
 
             // Media
             Page::Image(page) => page.view().map(Message::ImagePage),
@@ -285,11 +527,29 @@ impl Gallery {
             Page::MenuBar(page) => page.view().map(Message::MenuBar),
             Page::Ribbon(page) => page.view().map(Message::Ribbon),
 
+            // Status and info
+            Page::InfoBar(page) => page.view().map(Message::InfoBarPage),
+            Page::Progress(page) => page.view().map(Message::ProgressPage),
+
             // Text
             Page::TextInput(page) => page.view().map(Message::TextInputPage),
 
+            // Design guidance
+            Page::Colors(page) => page.view(self.theme.palette()).map(Message::ColorsPage),
+            Page::Typography(page) => page.view().map(Message::TypographyPage),
+            Page::Iconography(page) => page.view().map(Message::IconographyPage),
+            Page::TonalPalette(page) => page.view().map(Message::TonalPalettePage),
+            Page::ContrastAudit(page) => page.view().map(Message::ContrastAuditPage),
+
             Page::Settings => self.settings_page_view(),
-        })
+        };
+
+        let page = Container::new(
+            column![]
+                .push_maybe(self.breadcrumb_bar())
+                .push(page_view)
+                .spacing(16),
+        )
         .style(|theme| {
             container::Style::default()
                 .background(theme.palette().solid_background_fill_color_tertiary)
@@ -299,18 +559,49 @@ impl Gallery {
         .height(Length::Fill)
         .padding(padding);
 
-        let content = Element::new(container(row![side_nav, page,].spacing(4)).padding(4));
+        let notification = self.notification.as_ref().map(|notification| {
+            InfoBar::new(
+                notification.severity,
+                notification.title,
+                notification.message.clone(),
+            )
+            .on_close(Message::NotificationDismissed)
+        });
 
-        let view = if let Page::Dialog(page) = &self.current_page {
-            if page.is_dialog_open() {
-                modal(content, page.dialog().map(Message::DialogPage))
-            } else {
-                content
-            }
-        } else {
-            content
+        let content = Element::new(
+            container(
+                row![
+                    side_nav,
+                    column![].push_maybe(notification).push(page).spacing(4),
+                ]
+                .spacing(4),
+            )
+            .padding(4),
+        );
+
+        let mut dialogs = match &self.current_page {
+            Page::Dialog(page) => page
+                .dialogs()
+                .into_iter()
+                .map(|dialog| dialog.map(Message::DialogPage))
+                .collect(),
+            _ => Vec::new(),
         };
 
+        if let Some(path) = &self.theme_file_overwrite {
+            dialogs.push(
+                ContentDialog::new(text::body1(format!(
+                    "{path} already exists. Exporting replaces it with every token of the current theme."
+                )))
+                .title("Replace theme file?")
+                .primary_button("Replace", Message::ThemeOverwriteConfirmed)
+                .close_button("Cancel", Message::ThemeOverwriteCancelled)
+                .default_button(DefaultButton::Close),
+            );
+        }
+
+        let view = Element::from(Modal::new(content).extend(dialogs));
+
         if self.explain {
             view.explain(self.theme.palette().control_strong_stroke_color_default)
         } else {
@@ -319,7 +610,62 @@ impl Gallery {
     }
 
     pub fn theme(&self) -> Theme {
-        self.theme.clone()
+        let t = self.theme_transition.value(self.now);
+
+        // Contrast themes switch instantly as their colours aren't meant to be blended
+        let theme = if t >= 1.0
+            || self.theme.high_contrast().is_some()
+            || self.previous_theme.high_contrast().is_some()
+        {
+            self.theme.clone()
+        } else {
+            Theme::custom(Arc::new(
+                self.previous_theme.palette().mix(self.theme.palette(), t),
+            ))
+        };
+
+        let theme = theme.with_density(self.density);
+
+        if self.rounded_style {
+            theme.with_classes(self.rounded_classes.clone())
+        } else {
+            theme
+        }
+    }
+
+    fn export_theme(&mut self, path: String) {
+        self.notification = Some(match theme::file::save(self.theme.palette(), &path) {
+            Ok(()) => Notification {
+                severity: Severity::Success,
+                title: "Theme exported",
+                message: path,
+            },
+            Err(error) => Notification {
+                severity: Severity::Error,
+                title: "Theme not exported",
+                message: error.to_string(),
+            },
+        });
+    }
+
+    fn apply_theme(&mut self) {
+        let now = Instant::now();
+
+        // Starts from whatever is on screen if a transition is already running
+        self.previous_theme = self.theme();
+        self.theme_transition.set(0.0);
+        self.theme_transition.go_to(1.0, now);
+        self.now = now;
+
+        // Contrast themes ignore the accent colour, as on Windows
+        self.theme = match self.accent {
+            Some(accent) if self.appearance.high_contrast().is_none() => Theme::custom(Arc::new(
+                theme::Builder::new(self.appearance.palette().clone())
+                    .accent(accent)
+                    .build(),
+            )),
+            _ => self.appearance.clone().into(),
+        };
     }
 
     fn select_page(&mut self, page: Page) {
@@ -330,10 +676,36 @@ impl Gallery {
         }
     }
 
+    // The current page's group and the page itself, the group's separator lists
+    // the other pages in it
+    fn breadcrumb_bar(&self) -> Option<Element<'_, Message>> {
+        let (group, position) = self.pages.iter().find_map(|group| {
+            group
+                .page_items
+                .iter()
+                .position(|item| discriminant(&item.page) == discriminant(&self.current_page))
+                .map(|position| (group, position))
+        })?;
+
+        let breadcrumb_bar = BreadcrumbBar::new(move |index| {
+            if index == 0 {
+                Message::PageGroupToggled(group.label)
+            } else {
+                Message::PageSelected(group.page_items[position].page.clone())
+            }
+        })
+        .push(Item::new(group.label).choices(group.page_items.iter().map(|item| item.label)))
+        .push(Item::new(group.page_items[position].label))
+        .on_choice(move |_, choice| Message::PageSelected(group.page_items[choice].page.clone()));
+
+        Some(breadcrumb_bar.into())
+    }
+
     fn home_page_view(&self) -> Element<Message> {
-        let image_path = match self.theme {
-            Theme::Light => "/assets/images/bloom_light.jpg",
-            Theme::Dark => "/assets/images/bloom_dark.jpg",
+        let image_path = if self.theme.palette().is_dark() {
+            "/assets/images/bloom_dark.jpg"
+        } else {
+            "/assets/images/bloom_light.jpg"
         };
 
         stack![
@@ -356,7 +728,7 @@ impl Gallery {
         ) -> Element<'a, Message> {
             Container::new(
                 row![
-                    text::icon(icon).size(20),
+                    text::icon(icon, 20.0),
                     text::body1(label),
                     horizontal_space(),
                     control,
@@ -368,17 +740,116 @@ impl Gallery {
             .width(Length::Fill)
             .height(68)
             .padding(20)
-            .style(style::container::card)
+            .class("card")
             .into()
         }
 
+        let themes: Vec<Appearance> = [Appearance::Light, Appearance::Dark]
+            .into_iter()
+            .chain(Contrast::ALL.map(Appearance::HighContrast))
+            .chain(self.imported_theme.clone().map(Appearance::Custom))
+            .collect();
+
         let theme_widget = pick_list::standard(
-            [Theme::Light, Theme::Dark],
-            Some(self.theme.clone()),
-            |_| Message::ThemeToggled,
+            themes,
+            Some(self.appearance.clone()),
+            Message::ThemeSelected,
         )
         .into();
 
+        let theme_file_widget = row![
+            text_input::standard("Path to a .toml or .json token file", &self.theme_file_path)
+                .on_input(Message::ThemeFilePathChanged)
+                .on_submit(Message::ThemeImported)
+                .width(280),
+            button::standard(text::body1("Import theme…")).on_press(Message::ThemeImported),
+            button::standard(text::body1("Export")).on_press(Message::ThemeExported),
+        ]
+        .spacing(8)
+        .into();
+
+        let accent_swatch = |accent: Option<Color>| {
+            let selected = self.accent == accent;
+            let colour = accent.unwrap_or(self.appearance.palette().accent_fill_color_default);
+
+            Button::new(horizontal_space())
+                .width(24)
+                .height(24)
+                .style(move |theme: &Theme, status| {
+                    let palette = theme.palette();
+
+                    widget::button::Style {
+                        background: Some(colour.into()),
+                        border: Border {
+                            color: if selected {
+                                palette.text_fill_color_primary
+                            } else if status == widget::button::Status::Hovered {
+                                palette.control_strong_stroke_color_default
+                            } else {
+                                palette.control_stroke_color_default
+                            },
+                            width: if selected { 2.0 } else { 1.0 },
+                            radius: border::radius(4),
+                        },
+                        ..widget::button::Style::default()
+                    }
+                })
+                .on_press(Message::AccentSelected(accent))
+                .into()
+        };
+
+        let accent_widget = Row::with_children(
+            std::iter::once(None)
+                .chain(accent::SWATCHES.into_iter().map(Some))
+                .map(accent_swatch),
+        )
+        .spacing(4)
+        .into();
+
+        let density_widget =
+            pick_list::standard(Density::ALL, Some(self.density), Message::DensitySelected).into();
+
+        let text_scale_widget = row![
+            slider::standard(
+                text::MIN_SCALE..=text::MAX_SCALE,
+                self.text_scale,
+                Message::TextScaleChanged
+            )
+            .step(25u16)
+            .width(200),
+            text::body1(format!("{}%", self.text_scale)).width(48),
+        ]
+        .spacing(12)
+        .align_y(Vertical::Center)
+        .into();
+
+        let faces = font::faces();
+        let font_widget = row![
+            text_input::standard("Directory with .ttf or .otf files", &self.font_directory)
+                .on_input(Message::FontDirectoryChanged)
+                .on_submit(Message::FontDirectoryApplied)
+                .width(280),
+            button::standard(text::body1("Apply")).on_press(Message::FontDirectoryApplied),
+        ]
+        .spacing(8)
+        .into();
+
+        // Which faces were chosen, or why the last directory couldn't be used
+        let font_status = self
+            .font_status
+            .clone()
+            .unwrap_or_else(|| format!("Text: {} · Icons: {}", faces.text, faces.icons));
+
+        let animation_effects_widget = toggler::standard(self.animation_effects)
+            .label(if self.animation_effects { "On" } else { "Off" })
+            .on_toggle(Message::AnimationEffectsToggled)
+            .into();
+
+        let rounded_style_widget = toggler::standard(self.rounded_style)
+            .label(if self.rounded_style { "On" } else { "Off" })
+            .on_toggle(Message::RoundedStyleToggled)
+            .into();
+
         let explain_widget = toggler::standard(self.explain)
             .label(if self.explain { "On" } else { "Off" })
             .on_toggle(|_| Message::ExplainToggled)
@@ -389,11 +860,51 @@ impl Gallery {
             [column![
                 setting_card(FluentIcon::Color.codepoint(), "Theme", theme_widget),
                 setting_card(
-                    FluentIcon::PageMarginLandscapeNormal.codepoint(),
-                    "Explain",
-                    explain_widget
+                    FluentIcon::Personalize.codepoint(),
+                    "Accent colour",
+                    accent_widget
+                ),
+                setting_card(
+                    FluentIcon::Document.codepoint(),
+                    "Theme file",
+                    theme_file_widget
                 ),
             ]
+            .push(setting_card(
+                FluentIcon::AspectRatio.codepoint(),
+                "Density",
+                density_widget,
+            ))
+            .push(setting_card(
+                FluentIcon::Font.codepoint(),
+                "Fonts",
+                font_widget,
+            ))
+            .push(
+                Container::new(text::caption1(font_status))
+                    .padding([0, 20])
+                    .width(Length::Fill),
+            )
+            .push(setting_card(
+                FluentIcon::FontSize.codepoint(),
+                "Text size",
+                text_scale_widget,
+            ))
+            .push(setting_card(
+                FluentIcon::EaseOfAccess.codepoint(),
+                "Animation effects",
+                animation_effects_widget,
+            ))
+            .push(setting_card(
+                FluentIcon::Design.codepoint(),
+                "Rounded style",
+                rounded_style_widget,
+            ))
+            .push(setting_card(
+                FluentIcon::PageMarginLandscapeNormal.codepoint(),
+                "Explain",
+                explain_widget,
+            ))
             .spacing(4)
             .into()],
         )
@@ -408,13 +919,70 @@ impl Default for Gallery {
             footer_pages: footer_pages(),
             page_group_overlay_open: None,
             side_nav_display_mode: side_nav::DisplayMode::Full,
+            side_nav_width: side_nav::SideNav::<Message>::DEFAULT_FULL_WIDTH,
             window_size: Size::default(),
             theme: <Theme as Default>::default(),
+            previous_theme: <Theme as Default>::default(),
+            theme_transition: Animated::new(1.0)
+                .duration(motion::SLOW)
+                .easing(Easing::LINEAR),
+            now: Instant::now(),
+            appearance: Appearance::default(),
+            accent: None,
+            imported_theme: None,
+            theme_file_path: String::new(),
+            theme_file_overwrite: None,
+            notification: None,
             explain: false,
+            animation_effects: !motion::reduce_motion(),
+            rounded_style: false,
+            rounded_classes: Arc::new(rounded_classes()),
+            density: Density::default(),
+            text_scale: text::scale(),
+            font_directory: String::new(),
+            font_status: None,
         }
     }
 }
 
+// Shows how an app can rebrand controls through the theme's style classes: every
+// button and card picks this up without its call site changing
+fn rounded_classes() -> Registry {
+    let mut classes = Registry::default();
+
+    for name in ["primary", "secondary"] {
+        let base = classes.button(name);
+
+        classes.register_button(name, move |theme, status| {
+            let style = base(theme, status);
+
+            widget::button::Style {
+                border: style.border.rounded(16),
+                ..style
+            }
+        });
+    }
+
+    let card = classes.container("card");
+
+    classes.register_container("card", move |theme| {
+        let style = card(theme);
+
+        container::Style {
+            border: style.border.rounded(12),
+            ..style
+        }
+    });
+
+    classes
+}
+
+struct Notification {
+    severity: Severity,
+    title: &'static str,
+    message: String,
+}
+
 struct PageGroup {
     icon: char,
     label: &'static str,
@@ -484,16 +1052,66 @@ fn pages() -> Vec<PageGroup> {
                 },
             ],
         },
+        PageGroup {
+            icon: FluentIcon::Library.codepoint(),
+            label: "Collections",
+            expanded: false,
+            overlay_width: 120.into(),
+            page: None,
+            page_items: vec![
+                PageItem {
+                    label: "DataGrid",
+                    page: Page::DataGrid(page::data_grid::DataGrid::default()),
+                },
+                PageItem {
+                    label: "ListView",
+                    page: Page::ListView(page::list_view::ListView::default()),
+                },
+                PageItem {
+                    label: "TreeView",
+                    page: Page::TreeView(page::tree_view::TreeView::default()),
+                },
+            ],
+        },
+        PageGroup {
+            icon: FluentIcon::Calendar.codepoint(),
+            label: "Date and time",
+            expanded: false,
+            overlay_width: 120.into(),
+            page: None,
+            page_items: vec![
+                PageItem {
+                    label: "CalendarDatePicker",
+                    page: Page::CalendarDatePicker(
+                        page::calendar_date_picker::CalendarDatePicker::default(),
+                    ),
+                },
+                PageItem {
+                    label: "CalendarView",
+                    page: Page::CalendarView(page::calendar_view::CalendarView::default()),
+                },
+                PageItem {
+                    label: "TimePicker",
+                    page: Page::TimePicker(page::time_picker::TimePicker::default()),
+                },
+            ],
+        },
         PageGroup {
             icon: FluentIcon::Message.codepoint(),
             label: "Dialogs and flyouts",
             expanded: false,
             overlay_width: 120.into(),
             page: None,
-            page_items: vec![PageItem {
-                label: "Dialog",
-                page: Page::Dialog(page::dialog::Dialog::default()),
-            }],
+            page_items: vec![
+                PageItem {
+                    label: "Dialog",
+                    page: Page::Dialog(page::dialog::Dialog::default()),
+                },
+                PageItem {
+                    label: "TeachingTip",
+                    page: Page::TeachingTip(page::teaching_tip::TeachingTip::default()),
+                },
+            ],
         },
         PageGroup {
             icon: FluentIcon::PreviewLink.codepoint(),
@@ -501,10 +1119,20 @@ fn pages() -> Vec<PageGroup> {
             expanded: false,
             overlay_width: 120.into(),
             page: None,
-            page_items: vec![PageItem {
-                label: "Row & Column",
-                page: Page::RowColumn(page::row_column::RowColumn::default()),
-            }],
+            page_items: vec![
+                PageItem {
+                    label: "Row & Column",
+                    page: Page::RowColumn(page::row_column::RowColumn::default()),
+                },
+                PageItem {
+                    label: "Split View",
+                    page: Page::SplitView(page::split_view::SplitView::default()),
+                },
+                PageItem {
+                    label: "TabView",
+                    page: Page::TabView(page::tab_view::TabView::default()),
+                },
+            ],
         },
         PageGroup {
             icon: FluentIcon::Slideshow.codepoint(),
@@ -544,6 +1172,23 @@ fn pages() -> Vec<PageGroup> {
                 },
             ],
         },
+        PageGroup {
+            icon: FluentIcon::Info.codepoint(),
+            label: "Status and info",
+            expanded: false,
+            overlay_width: 120.into(),
+            page: None,
+            page_items: vec![
+                PageItem {
+                    label: "InfoBar",
+                    page: Page::InfoBar(page::info_bar::InfoBar::default()),
+                },
+                PageItem {
+                    label: "Progress",
+                    page: Page::Progress(page::progress::Progress::default()),
+                },
+            ],
+        },
         PageGroup {
             icon: FluentIcon::Font.codepoint(),
             label: "Text",
@@ -555,6 +1200,35 @@ fn pages() -> Vec<PageGroup> {
                 page: Page::TextInput(page::text_input::TextInput::default()),
             }],
         },
+        PageGroup {
+            icon: FluentIcon::Color.codepoint(),
+            label: "Design guidance",
+            expanded: false,
+            overlay_width: 140.into(),
+            page: None,
+            page_items: vec![
+                PageItem {
+                    label: "Colors",
+                    page: Page::Colors(page::colors::Colors::default()),
+                },
+                PageItem {
+                    label: "Typography",
+                    page: Page::Typography(page::typography::Typography::default()),
+                },
+                PageItem {
+                    label: "Iconography",
+                    page: Page::Iconography(page::iconography::Iconography::default()),
+                },
+                PageItem {
+                    label: "Tonal Palettes",
+                    page: Page::TonalPalette(page::tonal::TonalPalette::default()),
+                },
+                PageItem {
+                    label: "Contrast Audit",
+                    page: Page::ContrastAudit(page::contrast_audit::ContrastAudit::default()),
+                },
+            ],
+        },
     ]
 }
 
@@ -568,20 +1242,3 @@ fn footer_pages() -> Vec<PageGroup> {
         page_items: Vec::new(),
     }]
 }
-
-fn modal<'a, Message>(
-    base: impl Into<Element<'a, Message>>,
-    content: impl Into<Element<'a, Message>>,
-) -> Element<'a, Message>
-where
-    Message: 'a + Clone,
-{
-    let area = mouse_area(
-        center(opaque(content)).style(|theme| iced::widget::container::Style {
-            background: Some(theme.palette().smoke_fill_color_default.into()),
-            ..iced::widget::container::Style::default()
-        }),
-    );
-
-    stack![base.into(), opaque(area),].into()
-}
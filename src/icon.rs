@@ -0,0 +1,114 @@
+// Icons from either the icon font or SVG files, so widgets that show an icon
+// don't need to know where it comes from.
+//
+// SVG icons are embedded from assets/icons by build.rs:
+// - material: Material Design icons used by the Aurora demos
+// - tango: Tango Desktop Project icons used by the Aurora demos
+// An icon whose file isn't there comes back as `None` so callers can fall back to
+// a glyph.
+use crate::{
+    fluent_icon::FluentIcon,
+    font,
+    widget::{text, Element},
+};
+
+use iced::{
+    advanced::{
+        svg::{self as svg_renderer, Renderer as _},
+        text::{LineHeight, Renderer as _, Shaping, Text, Wrapping},
+    },
+    alignment::{Horizontal, Vertical},
+    widget::svg::{self, Svg},
+    Color, Pixels, Rectangle,
+};
+
+include!(concat!(env!("OUT_DIR"), "/svg_icons.rs"));
+
+pub const DEFAULT_SIZE: f32 = 16.0;
+
+#[derive(Clone, Debug)]
+pub enum Icon {
+    Glyph(char),
+    Svg {
+        handle: svg::Handle,
+        // Monochrome icons are drawn in the text colour, full colour ones as they are
+        themed: bool,
+    },
+}
+
+impl Icon {
+    pub fn material(name: &str) -> Option<Self> {
+        Self::file("material", name, true)
+    }
+
+    pub fn tango(name: &str) -> Option<Self> {
+        Self::file("tango", name, false)
+    }
+
+    fn file(set: &str, name: &str, themed: bool) -> Option<Self> {
+        let key = format!("{set}/{name}");
+
+        SVG_ICONS
+            .binary_search_by(|(candidate, _)| (*candidate).cmp(&key))
+            .ok()
+            .map(|index| Self::Svg {
+                handle: svg::Handle::from_memory(SVG_ICONS[index].1),
+                themed,
+            })
+    }
+
+    pub fn view<'a, Message: 'a>(self, size: f32) -> Element<'a, Message> {
+        match self {
+            Icon::Glyph(codepoint) => text::icon(codepoint, size).into(),
+            Icon::Svg { handle, themed } => {
+                let size = text::scaled(size);
+                let svg = Svg::new(handle).width(size).height(size);
+
+                if themed {
+                    svg.class("icon").into()
+                } else {
+                    svg.into()
+                }
+            }
+        }
+    }
+
+    // For widgets that draw their rows themselves rather than through elements
+    pub fn draw(&self, renderer: &mut iced::Renderer, bounds: Rectangle, colour: Color) {
+        match self {
+            Icon::Glyph(codepoint) => renderer.fill_text(
+                Text {
+                    content: codepoint.to_string(),
+                    bounds: bounds.size(),
+                    size: Pixels(bounds.height),
+                    line_height: LineHeight::default(),
+                    font: font::icons(),
+                    horizontal_alignment: Horizontal::Center,
+                    vertical_alignment: Vertical::Center,
+                    shaping: Shaping::Advanced,
+                    wrapping: Wrapping::default(),
+                },
+                bounds.center(),
+                colour,
+                bounds,
+            ),
+            Icon::Svg { handle, themed } => {
+                let svg = svg_renderer::Svg::new(handle.clone());
+
+                renderer.draw_svg(if *themed { svg.color(colour) } else { svg }, bounds);
+            }
+        }
+    }
+}
+
+impl From<FluentIcon> for Icon {
+    fn from(icon: FluentIcon) -> Self {
+        Icon::Glyph(icon.codepoint())
+    }
+}
+
+impl From<char> for Icon {
+    fn from(codepoint: char) -> Self {
+        Icon::Glyph(codepoint)
+    }
+}
@@ -1,6 +1,10 @@
+mod date_time;
+mod density;
 mod fluent_icon;
 mod font;
 mod gallery;
+mod icon;
+mod motion;
 mod page;
 mod style;
 mod theme;
@@ -8,10 +12,10 @@ mod widget;
 
 use gallery::Gallery;
 
-use iced::window::{icon, Settings};
+use iced::window::{self, Settings};
 
 fn main() -> iced::Result {
-    let icon = icon::from_file_data(include_bytes!("../assets/images/logo.png"), None);
+    let icon = window::icon::from_file_data(include_bytes!("../assets/images/logo.png"), None);
 
     iced::application("Fluent Iced Gallery", Gallery::update, Gallery::view)
         .subscription(Gallery::subscription)
@@ -21,5 +25,5 @@ fn main() -> iced::Result {
             icon: icon.ok(),
             ..Settings::default()
         })
-        .run()
+        .run_with(Gallery::new)
 }
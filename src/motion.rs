@@ -0,0 +1,181 @@
+// Fluent motion timing and easing:
+// https://learn.microsoft.com/en-us/windows/apps/design/motion/timing-and-easing
+// WinUI's control resources use the same values (ControlFastAnimationDuration etc.)
+use iced::{window, Color, Subscription};
+
+use std::{
+    sync::atomic::{AtomicBool, Ordering},
+    time::{Duration, Instant},
+};
+
+pub const FAST: Duration = Duration::from_millis(167);
+pub const NORMAL: Duration = Duration::from_millis(250);
+pub const SLOW: Duration = Duration::from_millis(333);
+
+// Mirrors Windows' "Animation effects" setting, off means every animation snaps
+// to its end value
+static REDUCE_MOTION: AtomicBool = AtomicBool::new(false);
+
+pub fn reduce_motion() -> bool {
+    REDUCE_MOTION.load(Ordering::Relaxed)
+}
+
+pub fn set_reduce_motion(reduce_motion: bool) {
+    REDUCE_MOTION.store(reduce_motion, Ordering::Relaxed);
+}
+
+// Emits on every frame but only while something is animating, so an idle
+// application doesn't redraw continuously
+pub fn frames(is_animating: bool) -> Subscription<Instant> {
+    if is_animating && !reduce_motion() {
+        window::frames()
+    } else {
+        Subscription::none()
+    }
+}
+
+// A cubic bézier from (0, 0) to (1, 1), the same as CSS's cubic-bezier()
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct Easing {
+    x1: f32,
+    y1: f32,
+    x2: f32,
+    y2: f32,
+}
+
+impl Easing {
+    // Cross-fades
+    pub const LINEAR: Self = Self::new(0.0, 0.0, 1.0, 1.0);
+    // Entering elements and hover/press feedback
+    pub const FAST_OUT_SLOW_IN: Self = Self::new(0.0, 0.0, 0.0, 1.0);
+    // Elements moving between two points on screen
+    pub const POINT_TO_POINT: Self = Self::new(0.55, 0.55, 0.0, 1.0);
+
+    pub const fn new(x1: f32, y1: f32, x2: f32, y2: f32) -> Self {
+        Self { x1, y1, x2, y2 }
+    }
+
+    pub fn apply(&self, progress: f32) -> f32 {
+        let progress = progress.clamp(0.0, 1.0);
+
+        if progress == 0.0 || progress == 1.0 {
+            return progress;
+        }
+
+        let bezier = |a: f32, b: f32, t: f32| {
+            let u = 1.0 - t;
+            3.0 * u * u * t * a + 3.0 * u * t * t * b + t * t * t
+        };
+
+        // x is monotonic so the parameter for `progress` can be found by bisection
+        let (mut low, mut high) = (0.0, 1.0);
+
+        for _ in 0..24 {
+            let t = (low + high) / 2.0;
+
+            if bezier(self.x1, self.x2, t) < progress {
+                low = t;
+            } else {
+                high = t;
+            }
+        }
+
+        bezier(self.y1, self.y2, (low + high) / 2.0)
+    }
+}
+
+pub trait Interpolate: Copy {
+    fn interpolate(self, other: Self, t: f32) -> Self;
+}
+
+impl Interpolate for f32 {
+    fn interpolate(self, other: Self, t: f32) -> Self {
+        self + (other - self) * t
+    }
+}
+
+impl Interpolate for Color {
+    fn interpolate(self, other: Self, t: f32) -> Self {
+        Color {
+            r: self.r.interpolate(other.r, t),
+            g: self.g.interpolate(other.g, t),
+            b: self.b.interpolate(other.b, t),
+            a: self.a.interpolate(other.a, t),
+        }
+    }
+}
+
+// A value that eases towards its target over time. Widgets keep one in their
+// tree state, applications in their own state alongside a `frames` subscription.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct Animated<T> {
+    from: T,
+    to: T,
+    start: Option<Instant>,
+    duration: Duration,
+    easing: Easing,
+}
+
+impl<T: Interpolate + PartialEq> Animated<T> {
+    pub fn new(value: T) -> Self {
+        Self {
+            from: value,
+            to: value,
+            start: None,
+            duration: NORMAL,
+            easing: Easing::FAST_OUT_SLOW_IN,
+        }
+    }
+
+    pub fn duration(mut self, duration: Duration) -> Self {
+        self.duration = duration;
+        self
+    }
+
+    pub fn easing(mut self, easing: Easing) -> Self {
+        self.easing = easing;
+        self
+    }
+
+    // Starts from wherever the current animation has got to
+    pub fn go_to(&mut self, target: T, now: Instant) {
+        if target == self.to {
+            return;
+        }
+
+        self.from = self.value(now);
+        self.to = target;
+        self.start = (!reduce_motion()).then_some(now);
+    }
+
+    // Jumps to `target` without animating
+    pub fn set(&mut self, target: T) {
+        self.from = target;
+        self.to = target;
+        self.start = None;
+    }
+
+    pub fn target(&self) -> T {
+        self.to
+    }
+
+    pub fn value(&self, now: Instant) -> T {
+        match self.start {
+            Some(start) if !reduce_motion() => {
+                let elapsed = now.saturating_duration_since(start).as_secs_f32();
+                let progress = elapsed / self.duration.as_secs_f32().max(f32::EPSILON);
+
+                if progress >= 1.0 {
+                    self.to
+                } else {
+                    self.from.interpolate(self.to, self.easing.apply(progress))
+                }
+            }
+            _ => self.to,
+        }
+    }
+
+    pub fn is_animating(&self, now: Instant) -> bool {
+        !reduce_motion() && self.start.is_some_and(|start| now < start + self.duration)
+    }
+}
@@ -1,20 +1,36 @@
 pub mod app_bar_button;
 pub mod button;
+pub mod calendar_date_picker;
+pub mod calendar_view;
 pub mod checkbox;
+pub mod colors;
 pub mod combo_box;
+pub mod contrast_audit;
+pub mod data_grid;
 pub mod dialog;
 pub mod drop_down_button;
+pub mod iconography;
 pub mod image;
+pub mod info_bar;
+pub mod list_view;
 pub mod menu_bar;
+pub mod progress;
 pub mod radio;
 pub mod ribbon;
 pub mod row_column;
 pub mod slider;
 pub mod split_button;
+pub mod split_view;
 pub mod svg;
+pub mod tab_view;
+pub mod teaching_tip;
 pub mod text_input;
+pub mod time_picker;
 pub mod toggle_button;
 pub mod toggler;
+pub mod tonal;
+pub mod tree_view;
+pub mod typography;
 
 use crate::widget::{
     text, {Container, Element, Row},
@@ -0,0 +1,72 @@
+use crate::{
+    date_time::{Date, English, Formatter, Weekday},
+    page::{page, widget_example},
+    widget::{
+        calendar_date_picker::CalendarDatePicker as CalendarDatePickerWidget, scrollable, text,
+        Container, Element,
+    },
+};
+
+use iced::{Length, Padding};
+
+#[derive(Clone, Debug, Default)]
+pub struct CalendarDatePicker {
+    date: Option<Date>,
+    appointment: Option<Date>,
+}
+
+#[derive(Clone, Debug)]
+pub enum Message {
+    Selected(Date),
+    AppointmentSelected(Date),
+}
+
+impl CalendarDatePicker {
+    pub fn update(&mut self, message: Message) {
+        match message {
+            Message::Selected(date) => self.date = Some(date),
+            Message::AppointmentSelected(date) => self.appointment = Some(date),
+        }
+    }
+
+    pub fn view(&self) -> Element<'_, Message> {
+        let today = Date::today();
+
+        let content = page(
+            "CalendarDatePicker",
+            [
+                widget_example(
+                    "A CalendarDatePicker. Type a date such as 10/19/2026 or Oct 19 2026 and press Enter, or pick one from the calendar.",
+                    CalendarDatePickerWidget::new(self.date, Message::Selected),
+                    Some(selected(self.date)),
+                    None::<Element<Message>>,
+                ),
+                widget_example(
+                    "A CalendarDatePicker for weekdays in the next three months.",
+                    CalendarDatePickerWidget::new(self.appointment, Message::AppointmentSelected)
+                        .placeholder("Pick an appointment")
+                        .min_date(today)
+                        .max_date(today.add_months(3))
+                        .blackout(|date| {
+                            matches!(date.weekday(), Weekday::Saturday | Weekday::Sunday)
+                        })
+                        .width(220),
+                    Some(selected(self.appointment)),
+                    None::<Element<Message>>,
+                ),
+            ],
+        );
+
+        scrollable::standard(Container::new(content).padding(Padding::ZERO.right(16)))
+            .height(Length::Fill)
+            .into()
+    }
+}
+
+fn selected<'a>(date: Option<Date>) -> Element<'a, Message> {
+    text::body1(format!(
+        "Selected: {}",
+        date.map_or("nothing".to_owned(), |date| English.date(date))
+    ))
+    .into()
+}
@@ -0,0 +1,106 @@
+use crate::{
+    date_time::{Date, English, Formatter, Weekday},
+    page::{page, widget_example},
+    widget::{
+        calendar_view::CalendarView as CalendarViewWidget, checkbox, pick_list, scrollable, text,
+        Column, Container, Element,
+    },
+};
+
+use iced::{Length, Padding};
+
+#[derive(Clone, Debug)]
+pub struct CalendarView {
+    date: Option<Date>,
+    first_day_of_week: Weekday,
+    is_limited: bool,
+    blackout_weekends: bool,
+}
+
+impl Default for CalendarView {
+    fn default() -> Self {
+        Self {
+            date: None,
+            first_day_of_week: Weekday::Sunday,
+            is_limited: false,
+            blackout_weekends: false,
+        }
+    }
+}
+
+#[derive(Clone, Debug)]
+pub enum Message {
+    Selected(Date),
+    FirstDayOfWeekSelected(Weekday),
+    LimitedToggled(bool),
+    BlackoutWeekendsToggled(bool),
+}
+
+impl CalendarView {
+    pub fn update(&mut self, message: Message) {
+        match message {
+            Message::Selected(date) => self.date = Some(date),
+            Message::FirstDayOfWeekSelected(weekday) => self.first_day_of_week = weekday,
+            Message::LimitedToggled(is_limited) => self.is_limited = is_limited,
+            Message::BlackoutWeekendsToggled(blackout_weekends) => {
+                self.blackout_weekends = blackout_weekends;
+            }
+        }
+    }
+
+    pub fn view(&self) -> Element<'_, Message> {
+        let today = Date::today();
+
+        let mut calendar_view = CalendarViewWidget::new(self.date, Message::Selected)
+            .first_day_of_week(self.first_day_of_week);
+
+        if self.is_limited {
+            calendar_view = calendar_view
+                .min_date(today.add_months(-1))
+                .max_date(today.add_months(1));
+        }
+
+        if self.blackout_weekends {
+            calendar_view = calendar_view
+                .blackout(|date| matches!(date.weekday(), Weekday::Saturday | Weekday::Sunday));
+        }
+
+        let options = Column::new()
+            .push(text::body1("First day of week"))
+            .push(pick_list::standard(
+                Weekday::ALL,
+                Some(self.first_day_of_week),
+                Message::FirstDayOfWeekSelected,
+            ))
+            .push(
+                checkbox::two_state("Within a month of today", self.is_limited)
+                    .on_toggle(Message::LimitedToggled),
+            )
+            .push(
+                checkbox::two_state("Black out weekends", self.blackout_weekends)
+                    .on_toggle(Message::BlackoutWeekendsToggled),
+            )
+            .spacing(8)
+            .width(200);
+
+        let output = text::body1(format!(
+            "Selected: {}",
+            self.date
+                .map_or("nothing".to_owned(), |date| English.date(date))
+        ));
+
+        let content = page(
+            "CalendarView",
+            [widget_example(
+                "A CalendarView. Click the heading to see the months of the year or the years of the decade, and use the arrow keys, Page Up and Page Down to move around.",
+                calendar_view,
+                Some(output),
+                Some(options),
+            )],
+        );
+
+        scrollable::standard(Container::new(content).padding(Padding::ZERO.right(16)))
+            .height(Length::Fill)
+            .into()
+    }
+}
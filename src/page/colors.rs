@@ -0,0 +1,123 @@
+use crate::{
+    page::page,
+    theme::{tokens, Palette},
+    widget::{scrollable, text, text_input, Column, Container, Element, Row},
+};
+
+use iced::{
+    alignment::Vertical,
+    border::Radius,
+    widget::{column, container, Space},
+    Border, Color, Length, Padding,
+};
+
+// Sections follow the order of the WinUI Gallery colour page
+const CATEGORIES: [&str; 5] = ["Text", "Fill", "Stroke", "Background", "Status"];
+
+#[derive(Clone, Debug, Default)]
+pub struct Colors {
+    filter: String,
+}
+
+#[derive(Clone, Debug)]
+pub enum Message {
+    FilterChanged(String),
+}
+
+impl Colors {
+    pub fn update(&mut self, message: Message) {
+        match message {
+            Message::FilterChanged(filter) => self.filter = filter,
+        }
+    }
+
+    // Unlike other pages the hex values depend on the palette so it is passed in
+    pub fn view<'a>(&'a self, palette: &Palette) -> Element<'a, Message> {
+        let filter = self.filter.to_lowercase();
+
+        let sections = CATEGORIES.into_iter().filter_map(|heading| {
+            let swatches: Vec<Element<Message>> = palette
+                .tokens()
+                .filter(|(name, _)| category(name) == heading)
+                .filter(|(name, _)| name.to_lowercase().contains(&filter))
+                .map(|(name, colour)| swatch(name, colour))
+                .collect();
+
+            if swatches.is_empty() {
+                return None;
+            }
+
+            Some(
+                column![
+                    text::subtitle1(heading),
+                    Column::from_vec(swatches).spacing(4)
+                ]
+                .spacing(12)
+                .into(),
+            )
+        });
+
+        let content = page(
+            "Colors",
+            [text_input::standard("Filter tokens", &self.filter)
+                .on_input(Message::FilterChanged)
+                .width(320)
+                .into()]
+            .into_iter()
+            .chain(sections),
+        );
+
+        scrollable::standard(Container::new(content).padding(Padding::ZERO.right(16)))
+            .height(Length::Fill)
+            .into()
+    }
+}
+
+fn category(name: &str) -> &'static str {
+    if name.starts_with("System") {
+        "Status"
+    } else if name.contains("Stroke") {
+        "Stroke"
+    } else if name.contains("TextFill") || name.starts_with("TextOnAccent") {
+        "Text"
+    } else if [
+        "Layer",
+        "SolidBackground",
+        "CardBackground",
+        "Smoke",
+        "Acrylic",
+    ]
+    .iter()
+    .any(|prefix| name.starts_with(prefix))
+    {
+        "Background"
+    } else {
+        "Fill"
+    }
+}
+
+fn swatch<'a>(name: &'static str, colour: Color) -> Element<'a, Message> {
+    Container::new(
+        Row::new()
+            .push(
+                // Drawn over the card background so translucent tokens show as used
+                Container::new(Space::new(64, 32)).style(move |theme| {
+                    container::Style::default()
+                        .background(colour)
+                        .border(Border {
+                            color: theme.palette().control_stroke_color_default,
+                            width: 1.0,
+                            radius: Radius::new(4),
+                        })
+                }),
+            )
+            .push(text::body1(name).width(Length::Fill))
+            .push(text::caption1(tokens::to_hex(colour)))
+            .spacing(16)
+            .align_y(Vertical::Center),
+    )
+    .width(Length::Fill)
+    .padding([8, 12])
+    .class("card")
+    .into()
+}
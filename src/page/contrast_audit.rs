@@ -0,0 +1,128 @@
+use crate::{
+    page::{page, widget_example},
+    style::audit,
+    theme::{tokens, Appearance, Theme},
+    widget::{pick_list, scrollable, text, toggler, Column, Container, Element, Row},
+};
+
+use iced::{
+    alignment::Vertical,
+    border::Radius,
+    widget::{container, row, Space},
+    Border, Color, Length, Padding,
+};
+
+#[derive(Clone, Debug)]
+pub struct ContrastAudit {
+    theme: Theme,
+    show_passing: bool,
+}
+
+impl Default for ContrastAudit {
+    fn default() -> Self {
+        Self {
+            theme: Appearance::Dark.into(),
+            show_passing: false,
+        }
+    }
+}
+
+#[derive(Clone, Debug)]
+pub enum Message {
+    ThemeSelected(Theme),
+    ShowPassingToggled(bool),
+}
+
+impl ContrastAudit {
+    pub fn update(&mut self, message: Message) {
+        match message {
+            Message::ThemeSelected(theme) => self.theme = theme,
+            Message::ShowPassingToggled(show_passing) => self.show_passing = show_passing,
+        }
+    }
+
+    pub fn view(&self) -> Element<'_, Message> {
+        let checks = audit::audit(&self.theme);
+        let failing = checks.iter().filter(|check| !check.passes()).count();
+
+        let options = Column::new()
+            .push(pick_list::standard(
+                audit::themes(),
+                Some(self.theme.clone()),
+                Message::ThemeSelected,
+            ))
+            .push(
+                toggler::standard(self.show_passing)
+                    .label("Show passing pairs")
+                    .on_toggle(Message::ShowPassingToggled),
+            )
+            .spacing(12);
+
+        let rows = checks
+            .into_iter()
+            .filter(|check| self.show_passing || !check.passes())
+            .map(check_row);
+
+        let content = page(
+            "Contrast Audit",
+            [widget_example(
+                "Foreground and background pairs produced by the style functions, checked \
+                against WCAG 2.x. Text needs 4.5:1, other graphics and disabled states 3:1.",
+                Column::with_children(rows).spacing(4),
+                Some(text::body1(format!("{failing} failing"))),
+                Some(options),
+            )],
+        );
+
+        scrollable::standard(Container::new(content).padding(Padding::ZERO.right(16)))
+            .height(Length::Fill)
+            .into()
+    }
+}
+
+fn check_row<'a>(check: audit::Check) -> Element<'a, Message> {
+    let swatch = |colour: Color| {
+        Container::new(Space::new(20, 20)).style(move |theme: &Theme| {
+            container::Style::default()
+                .background(colour)
+                .border(Border {
+                    color: theme.palette().control_stroke_color_default,
+                    width: 1.0,
+                    radius: Radius::new(4),
+                })
+        })
+    };
+
+    Container::new(
+        Row::new()
+            .push(
+                text::body1(format!(
+                    "{} · {} · {}",
+                    check.style, check.status, check.part
+                ))
+                .width(Length::Fill),
+            )
+            .push(row![swatch(check.foreground), swatch(check.background)].spacing(4))
+            .push(
+                text::caption1(format!(
+                    "{} on {}",
+                    tokens::to_hex(check.foreground),
+                    tokens::to_hex(check.background)
+                ))
+                .width(170),
+            )
+            .push(
+                text::body1(format!(
+                    "{:.2}:1 {}",
+                    check.ratio,
+                    if check.passes() { "✓" } else { "✗" }
+                ))
+                .width(80),
+            )
+            .spacing(12)
+            .align_y(Vertical::Center),
+    )
+    .padding([8, 12])
+    .class("card")
+    .into()
+}
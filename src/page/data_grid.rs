@@ -0,0 +1,238 @@
+use crate::{
+    page::{page, widget_example},
+    widget::{
+        checkbox,
+        data_grid::{
+            Column as GridColumn, DataGrid as DataGridWidget, Editor, SortDirection, Value, Width,
+        },
+        list_view::{Action, Selection},
+        scrollable, text, Column, Container, Element,
+    },
+};
+
+use iced::{alignment::Horizontal, widget::combo_box::State, Length, Padding};
+
+use std::cmp::Ordering;
+
+// Enough rows that reading every one of them each frame would be noticeably slow
+const PRODUCTS: usize = 10_000;
+
+const CATEGORIES: [&str; 6] = [
+    "Audio",
+    "Cables",
+    "Computers",
+    "Furniture",
+    "Lighting",
+    "Storage",
+];
+const ITEMS: [&str; 8] = [
+    "Adapter",
+    "Bracket",
+    "Charger",
+    "Desk",
+    "Drive",
+    "Headphones",
+    "Lamp",
+    "Monitor",
+];
+const SUPPLIERS: [&str; 5] = [
+    "Contoso",
+    "Fabrikam",
+    "Northwind Traders",
+    "Tailspin Toys",
+    "Wide World Importers",
+];
+
+#[derive(Clone, Debug)]
+struct Product {
+    name: String,
+    category: String,
+    sku: String,
+    price: f64,
+    quantity: f64,
+    in_stock: bool,
+    supplier: String,
+    notes: String,
+}
+
+impl Product {
+    fn value(&self, column: usize) -> Value {
+        match column {
+            0 => Value::Text(self.name.clone()),
+            1 => Value::Text(self.category.clone()),
+            2 => Value::Text(self.sku.clone()),
+            3 => Value::Number(self.price),
+            4 => Value::Number(self.quantity),
+            5 => Value::Bool(self.in_stock),
+            6 => Value::Text(self.supplier.clone()),
+            _ => Value::Text(self.notes.clone()),
+        }
+    }
+
+    fn set(&mut self, column: usize, value: Value) {
+        match (column, value) {
+            (0, Value::Text(name)) => self.name = name,
+            (1, Value::Text(category)) => self.category = category,
+            (3, Value::Number(price)) => self.price = price,
+            (4, Value::Number(quantity)) => self.quantity = quantity,
+            (5, Value::Bool(in_stock)) => self.in_stock = in_stock,
+            (7, Value::Text(notes)) => self.notes = notes,
+            _ => {}
+        }
+    }
+}
+
+#[derive(Clone, Debug)]
+pub struct DataGrid {
+    products: Vec<Product>,
+    selection: Selection,
+    sort: Option<(usize, SortDirection)>,
+    categories: State<String>,
+    frozen: bool,
+    edits: usize,
+}
+
+impl Default for DataGrid {
+    fn default() -> Self {
+        Self {
+            products: (0..PRODUCTS).map(product).collect(),
+            selection: Selection::new(),
+            sort: None,
+            categories: State::new(CATEGORIES.iter().map(ToString::to_string).collect()),
+            frozen: true,
+            edits: 0,
+        }
+    }
+}
+
+#[derive(Clone, Debug)]
+pub enum Message {
+    Action(Action),
+    Sort(usize, SortDirection),
+    Edit(usize, usize, Value),
+    FrozenToggled(bool),
+}
+
+impl DataGrid {
+    pub fn update(&mut self, message: Message) {
+        match message {
+            Message::Action(action) => self.selection.perform(action),
+            Message::Sort(column, direction) => {
+                self.products.sort_by(|a, b| {
+                    let ordering = compare(&a.value(column), &b.value(column));
+
+                    match direction {
+                        SortDirection::Ascending => ordering,
+                        SortDirection::Descending => ordering.reverse(),
+                    }
+                });
+                self.sort = Some((column, direction));
+                // The selection is by position, which sorting has changed
+                self.selection.clear();
+            }
+            Message::Edit(row, column, value) => {
+                if let Some(product) = self.products.get_mut(row) {
+                    product.set(column, value);
+                    self.edits += 1;
+                }
+            }
+            Message::FrozenToggled(frozen) => self.frozen = frozen,
+        }
+    }
+
+    pub fn view(&self) -> Element<'_, Message> {
+        let columns = [
+            GridColumn::new("Name")
+                .width(Width::Fill(1))
+                .min_width(160.0)
+                .editor(Editor::Text),
+            GridColumn::new("Category").editor(Editor::Choice(&self.categories)),
+            GridColumn::new("SKU").width(Width::Fixed(110.0)),
+            GridColumn::new("Price")
+                .align(Horizontal::Right)
+                .decimals(2)
+                .editor(Editor::Number(0.0..=10_000.0)),
+            GridColumn::new("Quantity")
+                .align(Horizontal::Right)
+                .editor(Editor::Number(0.0..=1_000.0)),
+            GridColumn::new("In stock")
+                .align(Horizontal::Center)
+                .editor(Editor::Check),
+            GridColumn::new("Supplier"),
+            GridColumn::new("Notes")
+                .width(Width::Fixed(240.0))
+                .sortable(false)
+                .editor(Editor::Text),
+        ];
+
+        let mut grid = DataGridWidget::new(
+            columns,
+            self.products.len(),
+            &self.selection,
+            |row, column| self.products[row].value(column),
+        )
+        .on_action(Message::Action)
+        .on_sort(Message::Sort)
+        .on_edit(Message::Edit)
+        .frozen_columns(if self.frozen { 1 } else { 0 })
+        .height(400);
+
+        if let Some((column, direction)) = self.sort {
+            grid = grid.sort(column, direction);
+        }
+
+        let options = checkbox::two_state("Freeze the Name column", self.frozen)
+            .on_toggle(Message::FrozenToggled);
+
+        let output = Column::new()
+            .push(text::body1(format!(
+                "{} of {} products selected",
+                self.selection.len(),
+                self.products.len()
+            )))
+            .push(text::body1(format!("{} edits made", self.edits)))
+            .spacing(4)
+            .width(200);
+
+        let content = page(
+            "DataGrid",
+            [widget_example(
+                "A DataGrid of products. Click a header to sort, drag its edge to resize the column and double click, press F2 or start typing to edit a cell. Tab moves on to the next cell and Esc cancels the edit.",
+                grid,
+                Some(output),
+                Some(options),
+            )],
+        );
+
+        scrollable::standard(Container::new(content).padding(Padding::ZERO.right(16)))
+            .height(Length::Fill)
+            .into()
+    }
+}
+
+fn compare(a: &Value, b: &Value) -> Ordering {
+    match (a, b) {
+        (Value::Text(a), Value::Text(b)) => a.to_lowercase().cmp(&b.to_lowercase()),
+        (Value::Number(a), Value::Number(b)) => a.total_cmp(b),
+        (Value::Bool(a), Value::Bool(b)) => a.cmp(b),
+        _ => Ordering::Equal,
+    }
+}
+
+// Made-up but repeatable products
+fn product(index: usize) -> Product {
+    let seed = index.wrapping_mul(2_654_435_761) >> 7;
+    let item = ITEMS[seed % ITEMS.len()];
+    let category = CATEGORIES[(seed / 7) % CATEGORIES.len()];
+
+    Product {
+        name: format!("{item} {}", index + 1),
+        category: category.to_owned(),
+        sku: format!("{}-{:05}", &category[..3].to_uppercase(), index + 1),
+        price: (seed % 50_000) as f64 / 100.0,
+        quantity: (seed / 11 % 250) as f64,
+        in_stock: !seed.is_multiple_of(5),
+        supplier: SUPPLIERS[(seed / 13) % SUPPLIERS.len()].to_owned(),
+        notes: String::new(),
+    }
+}
@@ -1,21 +1,37 @@
 use crate::{
     page::{page, widget_example},
-    widget::{button, checkbox, dialog, text, Element},
+    widget::{
+        button, checkbox,
+        dialog::{ContentDialog, DefaultButton},
+        flyout::{Flyout, Placement},
+        radio, text, text_input, Column, Element,
+    },
 };
 
 use iced::widget::column;
 
+const TERMS: &str = "Lorem ipsum dolor sit amet, consectetur adipiscing elit, sed do eiusmod \
+    tempor incididunt ut labore et dolore magna aliqua. Ut enim ad minim veniam, quis nostrud \
+    exercitation ullamco laboris nisi ut aliquip ex ea commodo consequat. Duis aute irure dolor \
+    in reprehenderit in voluptate velit esse cillum dolore eu fugiat nulla pariatur. Excepteur \
+    sint occaecat cupidatat non proident, sunt in culpa qui officia deserunt mollit anim id est \
+    laborum.";
+
 #[derive(Clone, Debug, Default, PartialEq)]
 pub struct Dialog {
     is_checked: bool,
     action: Action,
     dialog_open: bool,
-}
-
-impl Dialog {
-    pub fn is_dialog_open(&self) -> bool {
-        self.dialog_open
-    }
+    default_button: DefaultButton,
+    terms_open: bool,
+    details_open: bool,
+    terms_accepted: Option<bool>,
+    name: String,
+    email: String,
+    flyout_open: bool,
+    placement: Placement,
+    has_tail: bool,
+    cart_emptied: bool,
 }
 
 #[derive(Clone, Debug)]
@@ -23,6 +39,18 @@ pub enum Message {
     Opened,
     Toggled(bool),
     Action(Action),
+    DefaultButtonSelected(DefaultButton),
+    TermsOpened,
+    TermsAnswered(bool),
+    DetailsOpened,
+    DetailsClosed,
+    NameChanged(String),
+    EmailChanged(String),
+    FlyoutToggled,
+    FlyoutDismissed,
+    CartEmptied,
+    PlacementSelected(Placement),
+    TailToggled(bool),
 }
 
 #[derive(Clone, Debug, Default, PartialEq)]
@@ -43,51 +71,174 @@ impl Dialog {
                 self.action = action;
                 self.dialog_open = false;
             }
+            Message::DefaultButtonSelected(default_button) => self.default_button = default_button,
+            Message::TermsOpened => self.terms_open = true,
+            Message::TermsAnswered(is_accepted) => {
+                self.terms_accepted = Some(is_accepted);
+                self.terms_open = false;
+                self.details_open = false;
+            }
+            Message::DetailsOpened => self.details_open = true,
+            Message::DetailsClosed => self.details_open = false,
+            Message::NameChanged(name) => self.name = name,
+            Message::EmailChanged(email) => self.email = email,
+            Message::FlyoutToggled => self.flyout_open = !self.flyout_open,
+            Message::FlyoutDismissed => self.flyout_open = false,
+            Message::CartEmptied => {
+                self.cart_emptied = true;
+                self.flyout_open = false;
+            }
+            Message::PlacementSelected(placement) => self.placement = placement,
+            Message::TailToggled(has_tail) => self.has_tail = has_tail,
         }
     }
 
     pub fn view(&self) -> Element<Message> {
-        page(
-            "Dialog",
-            [widget_example(
-                "A basic dialog with content.",
-                button::standard(text::body1("Show dialog")).on_press(Message::Opened),
-                Some(text::body1(match self.action {
-                    Action::None => "",
-                    Action::Saved => "User saved their work",
-                    Action::NotSaved => "User did not save their work",
-                    Action::Cancelled => "User cancelled the dialog",
-                })),
-                None::<Element<Message>>,
-            )],
+        let flyout = Flyout::new(
+            button::standard(text::body1("Empty cart")).on_press(Message::FlyoutToggled),
+            column![
+                text::body1("All items will be removed. Do you want to continue?"),
+                button::standard(text::body1("Yes, empty my cart")).on_press(Message::CartEmptied),
+            ]
+            .spacing(12),
+            self.flyout_open,
         )
-    }
+        .on_dismiss(Message::FlyoutDismissed)
+        .placement(self.placement)
+        .tail(self.has_tail)
+        .width(280);
 
-    pub fn dialog(&self) -> Element<'static, Message>
-    where
-        Message: Clone,
-    {
-        dialog::content(
-            Some("Save your work?"),
-            column![
-                text::body1("Lorem ipsum dolor sit amet, adipisicing elit."),
-                checkbox::two_state("Upload your content to the cloud", self.is_checked)
-                    .on_toggle(Message::Toggled)
+        let placements = Column::with_children(Placement::ALL.map(|placement| {
+            radio::standard(
+                placement.to_string(),
+                placement,
+                Some(self.placement),
+                Message::PlacementSelected,
+            )
+            .into()
+        }))
+        .spacing(8);
+
+        let default_buttons = Column::with_children(
+            [
+                ("None", DefaultButton::None),
+                ("Primary", DefaultButton::Primary),
+                ("Secondary", DefaultButton::Secondary),
+                ("Close", DefaultButton::Close),
             ]
-            .spacing(4),
-            Some(dialog::Button {
-                text: String::from("Save"),
-                on_press: Message::Action(Action::Saved),
-            }),
-            Some(dialog::Button {
-                text: String::from("Don't Save"),
-                on_press: Message::Action(Action::NotSaved),
+            .map(|(label, default_button)| {
+                radio::standard(
+                    label,
+                    default_button,
+                    Some(self.default_button),
+                    Message::DefaultButtonSelected,
+                )
+                .into()
             }),
-            dialog::Button {
-                text: String::from("Cancel"),
-                on_press: Message::Action(Action::Cancelled),
-            },
         )
-        .into()
+        .spacing(8);
+
+        page(
+            "Dialog",
+            [
+                widget_example(
+                    "A basic dialog with content.",
+                    button::standard(text::body1("Show dialog")).on_press(Message::Opened),
+                    Some(text::body1(match self.action {
+                        Action::None => "",
+                        Action::Saved => "User saved their work",
+                        Action::NotSaved => "User did not save their work",
+                        Action::Cancelled => "User cancelled the dialog",
+                    })),
+                    Some(
+                        Column::new()
+                            .push(text::body1("Default button"))
+                            .push(default_buttons)
+                            .spacing(12)
+                            .width(160),
+                    ),
+                ),
+                widget_example(
+                    "A dialog with scrolling content that can open a second dialog on top of it.",
+                    button::standard(text::body1("Show terms")).on_press(Message::TermsOpened),
+                    Some(text::body1(match self.terms_accepted {
+                        None => "",
+                        Some(true) => "User accepted the terms",
+                        Some(false) => "User declined the terms",
+                    })),
+                    None::<Element<Message>>,
+                ),
+                widget_example(
+                    "A light-dismiss Flyout, click outside of it or press Esc to close it.",
+                    flyout,
+                    Some(text::body1(if self.cart_emptied {
+                        "Your cart is empty"
+                    } else {
+                        ""
+                    })),
+                    Some(
+                        Column::new()
+                            .push(
+                                checkbox::two_state("Tail", self.has_tail)
+                                    .on_toggle(Message::TailToggled),
+                            )
+                            .push(text::body1("Placement"))
+                            .push(placements)
+                            .spacing(12)
+                            .width(160),
+                    ),
+                ),
+            ],
+        )
+    }
+
+    // The open dialogs, bottom first
+    pub fn dialogs(&self) -> Vec<ContentDialog<'_, Message>> {
+        let save = self.dialog_open.then(|| {
+            ContentDialog::new(
+                column![
+                    text::body1("Lorem ipsum dolor sit amet, adipisicing elit."),
+                    checkbox::two_state("Upload your content to the cloud", self.is_checked)
+                        .on_toggle(Message::Toggled)
+                ]
+                .spacing(4),
+            )
+            .title("Save your work?")
+            .primary_button("Save", Message::Action(Action::Saved))
+            .secondary_button("Don't Save", Message::Action(Action::NotSaved))
+            .close_button("Cancel", Message::Action(Action::Cancelled))
+            .default_button(self.default_button)
+        });
+
+        let terms = self.terms_open.then(|| {
+            ContentDialog::new(
+                column![
+                    text::body1(TERMS),
+                    text::body1(TERMS),
+                    text::body1(TERMS),
+                    text_input::standard("Name", &self.name).on_input(Message::NameChanged),
+                    text_input::standard("Email", &self.email).on_input(Message::EmailChanged),
+                    button::hyperlink("More about these terms").on_press(Message::DetailsOpened),
+                ]
+                .spacing(12)
+                .padding(iced::Padding::ZERO.right(16)),
+            )
+            .title("Terms of use")
+            .primary_button("Accept", Message::TermsAnswered(true))
+            .close_button("Decline", Message::TermsAnswered(false))
+            .default_button(DefaultButton::Primary)
+        });
+
+        let details = (self.terms_open && self.details_open).then(|| {
+            ContentDialog::new(text::body1(
+                "These terms apply to every sample in the gallery. Closing this dialog returns \
+                to the terms underneath it.",
+            ))
+            .title("More about these terms")
+            .close_button("Close", Message::DetailsClosed)
+            .default_button(DefaultButton::Close)
+        });
+
+        [save, terms, details].into_iter().flatten().collect()
     }
 }
@@ -1,11 +1,10 @@
 use crate::{
     fluent_icon::FluentIcon,
     page::{page, widget_example},
-    style,
-    widget::{button, text, Column, Container, DropDown, Element},
+    widget::{button, flyout::Flyout, text, Column, Element},
 };
 
-use iced::{widget::row, Length};
+use iced::widget::row;
 
 #[derive(Clone, Debug, PartialEq)]
 pub enum OpenButton {
@@ -37,31 +36,29 @@ impl DropDownButton {
 
     pub fn view(&self) -> Element<'static, Message> {
         let simple_underlay = underlay(OpenButton::Simple, text::body1("Email"));
-        let simple_overlay = overlay([
+        let simple_overlay = Column::with_children([
             simple_button("Send"),
             simple_button("Reply"),
             simple_button("Reply All"),
-        ])
-        .width(84);
-        let simple_drop_down = DropDown::new(
+        ]);
+        let simple_drop_down = flyout(
             simple_underlay,
             simple_overlay,
             self.is_open(OpenButton::Simple),
         )
-        .width(Length::Shrink)
-        .on_dismiss(Message::DropDownDismissed);
+        .width(84);
 
-        let icon_underlay = underlay(OpenButton::Icon, text::icon(FluentIcon::Mail.codepoint()));
-        let icon_overlay = overlay([
-            icon_button(FluentIcon::SendFluentIconll, "Send"),
+        let icon_underlay = underlay(
+            OpenButton::Icon,
+            text::icon(FluentIcon::Mail.codepoint(), 16.0),
+        );
+        let icon_overlay = Column::with_children([
+            icon_button(FluentIcon::SendFill, "Send"),
             icon_button(FluentIcon::MailReply, "Reply"),
             icon_button(FluentIcon::MailReplyAll, "Reply All"),
-        ])
-        .width(112);
+        ]);
         let icon_drop_down =
-            DropDown::new(icon_underlay, icon_overlay, self.is_open(OpenButton::Icon))
-                .width(Length::Shrink)
-                .on_dismiss(Message::DropDownDismissed);
+            flyout(icon_underlay, icon_overlay, self.is_open(OpenButton::Icon)).width(112);
 
         page(
             "Drop Down Button",
@@ -96,10 +93,14 @@ fn underlay<'a>(id: OpenButton, content: impl Into<Element<'a, Message>>) -> Ele
         .into()
 }
 
-fn overlay<'a>(items: impl IntoIterator<Item = Element<'a, Message>>) -> Container<'a, Message> {
-    Container::new(Column::with_children(items))
+fn flyout<'a>(
+    underlay: Element<'a, Message>,
+    overlay: Column<'a, Message>,
+    is_open: bool,
+) -> Flyout<'a, Message> {
+    Flyout::new(underlay, overlay, is_open)
         .padding(6)
-        .style(style::container::overlay)
+        .on_dismiss(Message::DropDownDismissed)
 }
 
 fn simple_button(label: &str) -> Element<Message> {
@@ -0,0 +1,179 @@
+use crate::{
+    fluent_icon::FluentIcon,
+    page::page,
+    widget::{
+        button,
+        list_view::{Action, ItemHeight, ListView, Selection},
+        text, text_input, Column, Container, Element, Row,
+    },
+};
+
+use iced::{
+    alignment::{Horizontal, Vertical},
+    clipboard,
+    widget::text::Wrapping,
+    Length, Task,
+};
+
+const CELL_WIDTH: f32 = 96.0;
+const CELL_HEIGHT: f32 = 80.0;
+const DETAIL_WIDTH: f32 = 280.0;
+const PREVIEW_SIZES: [u16; 5] = [16, 20, 24, 32, 48];
+
+#[derive(Clone, Debug)]
+pub struct Iconography {
+    query: String,
+    results: Vec<FluentIcon>,
+    selection: Selection,
+    selected: Option<FluentIcon>,
+}
+
+impl Default for Iconography {
+    fn default() -> Self {
+        Self {
+            query: String::new(),
+            results: FluentIcon::all().collect(),
+            selection: Selection::new(),
+            selected: None,
+        }
+    }
+}
+
+#[derive(Clone, Debug)]
+pub enum Message {
+    QueryChanged(String),
+    GridAction(Action),
+    CopyPressed,
+}
+
+impl Iconography {
+    pub fn update(&mut self, message: Message) -> Task<Message> {
+        match message {
+            Message::QueryChanged(query) => {
+                self.results = FluentIcon::search(&query).collect();
+                self.query = query;
+                self.selection.clear();
+            }
+            // The details stay up while the search changes, until another icon is
+            // picked from the results
+            Message::GridAction(action) => {
+                self.selection.perform(action);
+
+                if let Some(index) = self.selection.iter().next() {
+                    self.selected = Some(self.results[index]);
+                }
+            }
+            Message::CopyPressed => {
+                if let Some(icon) = self.selected {
+                    return clipboard::write(format!("FluentIcon::{icon:?}"));
+                }
+            }
+        }
+
+        Task::none()
+    }
+
+    pub fn view(&self) -> Element<'_, Message> {
+        let search = Row::new()
+            .push(
+                text_input::standard("Search icons by name or tag", &self.query)
+                    .on_input(Message::QueryChanged)
+                    .width(320),
+            )
+            .push(text::caption1(format!("{} icons", self.results.len())))
+            .spacing(12)
+            .align_y(Vertical::Center);
+
+        let grid = Container::new(
+            ListView::new(self.results.len(), &self.selection, |index| {
+                cell(self.results[index])
+            })
+            .grid(CELL_WIDTH)
+            .item_height(ItemHeight::Fixed(CELL_HEIGHT))
+            .on_action(Message::GridAction),
+        )
+        .padding(4)
+        .width(Length::Fill)
+        .height(Length::Fill)
+        .class("card");
+
+        page(
+            "Iconography",
+            [
+                search.into(),
+                Row::new()
+                    .push(grid)
+                    .push(self.detail())
+                    .spacing(12)
+                    .height(Length::Fill)
+                    .into(),
+            ],
+        )
+    }
+
+    fn detail(&self) -> Element<'_, Message> {
+        let content: Element<'_, Message> = match self.selected {
+            Some(icon) => {
+                let previews = PREVIEW_SIZES.into_iter().map(|size| {
+                    Column::new()
+                        .push(
+                            Container::new(text::icon(icon.codepoint(), f32::from(size)))
+                                .align_bottom(48),
+                        )
+                        .push(text::caption1(format!("{size}px")))
+                        .spacing(4)
+                        .align_x(Horizontal::Center)
+                        .into()
+                });
+
+                let identifier = format!("FluentIcon::{icon:?}");
+
+                Column::new()
+                    .push(text::subtitle2(icon.name()))
+                    .push(Row::with_children(previews).spacing(12))
+                    .push(property(
+                        "Codepoint",
+                        format!("U+{:04X}", u32::from(icon.codepoint())),
+                    ))
+                    .push(property("Rust", identifier))
+                    .push_maybe(
+                        (!icon.tags().is_empty()).then(|| property("Tags", icon.tags().join(", "))),
+                    )
+                    .push(button::standard(text::body1("Copy")).on_press(Message::CopyPressed))
+                    .spacing(16)
+                    .into()
+            }
+            None => text::body1("Select an icon to see its details.").into(),
+        };
+
+        Container::new(content)
+            .padding(16)
+            .width(DETAIL_WIDTH)
+            .height(Length::Fill)
+            .class("card")
+            .into()
+    }
+}
+
+fn cell<'a>(icon: FluentIcon) -> Element<'a, Message> {
+    Column::new()
+        .push(text::icon(icon.codepoint(), 24.0))
+        .push(
+            Container::new(text::caption2(icon.name()).wrapping(Wrapping::None))
+                .width(Length::Fill)
+                .align_x(Horizontal::Center)
+                .clip(true),
+        )
+        .spacing(12)
+        .padding([0, 4])
+        .align_x(Horizontal::Center)
+        .into()
+}
+
+fn property<'a>(label: &'a str, value: String) -> Element<'a, Message> {
+    Column::new()
+        .push(text::caption1(label))
+        .push(text::body1(value))
+        .spacing(2)
+        .into()
+}
@@ -0,0 +1,186 @@
+use crate::{
+    page::{page, widget_example},
+    widget::{
+        button,
+        info_bar::{InfoBar as InfoBarWidget, Severity},
+        scrollable, text, Checkbox, Column, Container, Element, Radio,
+    },
+};
+
+use iced::{Length, Padding};
+
+const SHORT_MESSAGE: &str = "A short essential app message.";
+const LONG_MESSAGE: &str = "A long essential app message for your users to be informed of, \
+    acknowledge, or take action on. Lorem ipsum dolor sit amet, consectetur adipiscing elit, \
+    sed do eiusmod tempor incididunt ut labore et dolore magna aliqua.";
+
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub enum Action {
+    #[default]
+    None,
+    Button,
+    Hyperlink,
+}
+
+#[derive(Clone, Debug)]
+pub struct InfoBar {
+    severity: Severity,
+    is_open: bool,
+    long_message: bool,
+    action: Action,
+    action_is_open: bool,
+    last_action: Option<&'static str>,
+}
+
+impl Default for InfoBar {
+    fn default() -> Self {
+        Self {
+            severity: Severity::default(),
+            is_open: true,
+            long_message: false,
+            action: Action::default(),
+            action_is_open: true,
+            last_action: None,
+        }
+    }
+}
+
+#[derive(Clone, Debug)]
+pub enum Message {
+    SeveritySelected(Severity),
+    IsOpenToggled(bool),
+    Closed,
+    LongMessageToggled(bool),
+    ActionSelected(Action),
+    ActionIsOpenToggled(bool),
+    ActionClosed,
+    ActionPressed(&'static str),
+}
+
+impl InfoBar {
+    pub fn update(&mut self, message: Message) {
+        match message {
+            Message::SeveritySelected(severity) => self.severity = severity,
+            Message::IsOpenToggled(is_open) => self.is_open = is_open,
+            Message::Closed => self.is_open = false,
+            Message::LongMessageToggled(long_message) => self.long_message = long_message,
+            Message::ActionSelected(action) => self.action = action,
+            Message::ActionIsOpenToggled(is_open) => self.action_is_open = is_open,
+            Message::ActionClosed => self.action_is_open = false,
+            Message::ActionPressed(label) => self.last_action = Some(label),
+        }
+    }
+
+    pub fn view(&self) -> Element<'_, Message> {
+        let severities = Column::with_children(Severity::ALL.map(|severity| {
+            Radio::new(
+                severity.to_string(),
+                severity,
+                Some(self.severity),
+                Message::SeveritySelected,
+            )
+            .into()
+        }))
+        .spacing(8);
+
+        let severity_example = widget_example(
+            "A closable InfoBar with options to change its Severity.",
+            InfoBarWidget::new(
+                self.severity,
+                "Title",
+                "Essential app message for your users to be informed of, acknowledge, or take action on.",
+            )
+            .on_close(Message::Closed)
+            .open(self.is_open),
+            None::<Element<Message>>,
+            Some(
+                Column::new()
+                    .push(
+                        Checkbox::new("Is open", self.is_open).on_toggle(Message::IsOpenToggled),
+                    )
+                    .push(text::body1("Severity"))
+                    .push(severities)
+                    .spacing(12)
+                    .width(160),
+            ),
+        );
+
+        let actions = Column::with_children(
+            [
+                ("None", Action::None),
+                ("Button", Action::Button),
+                ("Hyperlink", Action::Hyperlink),
+            ]
+            .map(|(label, action)| {
+                Radio::new(label, action, Some(self.action), Message::ActionSelected).into()
+            }),
+        )
+        .spacing(8);
+
+        let mut action_bar = InfoBarWidget::new(
+            Severity::Informational,
+            "Title",
+            if self.long_message {
+                LONG_MESSAGE
+            } else {
+                SHORT_MESSAGE
+            },
+        )
+        .on_close(Message::ActionClosed)
+        .open(self.action_is_open);
+
+        action_bar = match self.action {
+            Action::None => action_bar,
+            Action::Button => action_bar.action(
+                button::standard(text::body1("Action"))
+                    .on_press(Message::ActionPressed("Action button pressed")),
+            ),
+            Action::Hyperlink => action_bar.action(
+                button::hyperlink("Informational link")
+                    .on_press(Message::ActionPressed("Hyperlink pressed")),
+            ),
+        };
+
+        let action_example = widget_example(
+            "A closable InfoBar with a long and short message and action button.",
+            Column::new()
+                .push(action_bar)
+                .push_maybe(self.last_action.map(text::caption1))
+                .spacing(8)
+                .width(Length::Fill),
+            None::<Element<Message>>,
+            Some(
+                Column::new()
+                    .push(
+                        Checkbox::new("Is open", self.action_is_open)
+                            .on_toggle(Message::ActionIsOpenToggled),
+                    )
+                    .push(
+                        Checkbox::new("Long message", self.long_message)
+                            .on_toggle(Message::LongMessageToggled),
+                    )
+                    .push(text::body1("Action"))
+                    .push(actions)
+                    .spacing(12)
+                    .width(160),
+            ),
+        );
+
+        let content = page(
+            "InfoBar",
+            [
+                text::body1(
+                    "Importing or exporting a theme file in Settings shows an InfoBar pinned to \
+                    the top of the gallery.",
+                )
+                .into(),
+                severity_example,
+                action_example,
+            ],
+        );
+
+        scrollable::standard(Container::new(content).padding(Padding::ZERO.right(16)))
+            .height(Length::Fill)
+            .into()
+    }
+}
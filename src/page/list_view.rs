@@ -0,0 +1,169 @@
+use crate::{
+    fluent_icon::FluentIcon,
+    page::{page, widget_example},
+    widget::{
+        list_view::{Action, ItemHeight, ListView as ListViewWidget, Selection, SelectionMode},
+        pick_list, scrollable, text, Column, Container, Element,
+    },
+};
+
+use iced::{alignment::Horizontal, widget::text::Wrapping, Length, Padding};
+
+// Enough lines that laying every one of them out would be noticeably slow
+const LOG_LINES: usize = 100_000;
+const ICON_WIDTH: f32 = 96.0;
+const ICON_HEIGHT: f32 = 80.0;
+
+const MESSAGES: [&str; 6] = [
+    "Connection established",
+    "Cache miss, fetching from the origin server",
+    "Request completed in 12 ms",
+    "Retrying after a timeout while waiting for the upstream service to respond to the health check that was scheduled on startup",
+    "Configuration reloaded",
+    "Disk usage above 80% on the data volume, consider removing old snapshots or moving them to cold storage before the next backup runs",
+];
+
+#[derive(Clone, Debug)]
+pub struct ListView {
+    log: Vec<String>,
+    log_selection: Selection,
+    selection_mode: SelectionMode,
+    opened: Option<usize>,
+    icons: Vec<FluentIcon>,
+    icon_selection: Selection,
+}
+
+impl Default for ListView {
+    fn default() -> Self {
+        Self {
+            log: (0..LOG_LINES).map(log_line).collect(),
+            log_selection: Selection::new(),
+            selection_mode: SelectionMode::Extended,
+            opened: None,
+            icons: FluentIcon::all().collect(),
+            icon_selection: Selection::new(),
+        }
+    }
+}
+
+#[derive(Clone, Debug)]
+pub enum Message {
+    LogAction(Action),
+    LogActivated(usize),
+    SelectionModeSelected(SelectionMode),
+    IconAction(Action),
+}
+
+impl ListView {
+    pub fn update(&mut self, message: Message) {
+        match message {
+            Message::LogAction(action) => self.log_selection.perform(action),
+            Message::LogActivated(index) => self.opened = Some(index),
+            Message::SelectionModeSelected(selection_mode) => {
+                self.selection_mode = selection_mode;
+                self.log_selection.clear();
+            }
+            Message::IconAction(action) => self.icon_selection.perform(action),
+        }
+    }
+
+    pub fn view(&self) -> Element<'_, Message> {
+        let log = ListViewWidget::new(self.log.len(), &self.log_selection, |index| {
+            text::body1(&self.log[index]).into()
+        })
+        .on_action(Message::LogAction)
+        .on_activate(Message::LogActivated)
+        .selection_mode(self.selection_mode)
+        .height(320);
+
+        let log_options = Column::new()
+            .push(text::body1("Selection mode"))
+            .push(pick_list::standard(
+                SelectionMode::ALL,
+                Some(self.selection_mode),
+                Message::SelectionModeSelected,
+            ))
+            .spacing(8)
+            .width(180);
+
+        let log_output = Column::new()
+            .push(text::body1(format!(
+                "{} of {} lines selected",
+                self.log_selection.len(),
+                self.log.len()
+            )))
+            .push_maybe(
+                self.opened
+                    .map(|index| text::body1(format!("Opened line {}", index + 1))),
+            )
+            .spacing(4)
+            .width(220);
+
+        let icons = ListViewWidget::new(self.icons.len(), &self.icon_selection, |index| {
+            icon_cell(self.icons[index])
+        })
+        .grid(ICON_WIDTH)
+        .item_height(ItemHeight::Fixed(ICON_HEIGHT))
+        .on_action(Message::IconAction)
+        .selection_mode(SelectionMode::Multiple)
+        .height(320);
+
+        let icons_output = text::body1(format!("{} icons selected", self.icon_selection.len()));
+
+        let content = page(
+            "ListView",
+            [
+                widget_example(
+                    "A ListView of log lines that wrap to different heights, measured as they scroll into view. Hold Ctrl or Shift to add to the selection in extended mode and press Enter or double click to open a line.",
+                    log,
+                    Some(log_output),
+                    Some(log_options),
+                ),
+                widget_example(
+                    "A ListView laid out as a grid, with as many columns as fit the width.",
+                    icons,
+                    Some(icons_output),
+                    None::<Element<Message>>,
+                ),
+            ],
+        );
+
+        scrollable::standard(Container::new(content).padding(Padding::ZERO.right(16)))
+            .height(Length::Fill)
+            .into()
+    }
+}
+
+fn icon_cell<'a>(icon: FluentIcon) -> Element<'a, Message> {
+    Column::new()
+        .push(text::icon(icon.codepoint(), 24.0))
+        .push(
+            Container::new(text::caption2(icon.name()).wrapping(Wrapping::None))
+                .width(Length::Fill)
+                .align_x(Horizontal::Center)
+                .clip(true),
+        )
+        .spacing(12)
+        .padding([0, 4])
+        .align_x(Horizontal::Center)
+        .into()
+}
+
+// Made-up but repeatable log lines, with a few long ones mixed in
+fn log_line(index: usize) -> String {
+    let seed = index.wrapping_mul(2_654_435_761) >> 7;
+    let level = match seed % 10 {
+        0 => "ERROR",
+        1 | 2 => "WARN",
+        _ => "INFO",
+    };
+    let seconds = index * 3 + seed % 3;
+
+    format!(
+        "{:02}:{:02}:{:02}  {level:<5}  {}",
+        seconds / 3600 % 24,
+        seconds / 60 % 60,
+        seconds % 60,
+        MESSAGES[seed % MESSAGES.len()]
+    )
+}
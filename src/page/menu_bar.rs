@@ -1,6 +1,5 @@
 use crate::{
     page::{page, widget_example},
-    style,
     widget::{button, menu, text, Element, Menu, MenuItem},
 };
 
@@ -306,7 +305,7 @@ fn menu_bar_item<'a>(label: &'a str, menu: Menu<'a, Message>) -> MenuItem<'a, Me
     MenuItem::with_menu(
         button::standard(text::body1(label))
             .width(Length::Shrink)
-            .style(style::button::menu_item)
+            .class("menu_item")
             // NoOp message required for button to look active
             .on_press(Message::NoOp),
         menu,
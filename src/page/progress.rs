@@ -0,0 +1,138 @@
+use crate::{
+    page::{page, widget_example},
+    widget::{
+        self,
+        progress::{ProgressBar, ProgressRing, Status},
+        scrollable, text, Checkbox, Column, Container, Element, Radio, Row,
+    },
+};
+
+use iced::{alignment::Vertical, Length, Padding};
+
+#[derive(Clone, Debug)]
+pub struct Progress {
+    is_indeterminate: bool,
+    value: f32,
+    status: Status,
+}
+
+impl Default for Progress {
+    fn default() -> Self {
+        Self {
+            is_indeterminate: true,
+            value: 50.0,
+            status: Status::default(),
+        }
+    }
+}
+
+#[derive(Clone, Debug)]
+pub enum Message {
+    IndeterminateToggled(bool),
+    ValueChanged(f32),
+    StatusSelected(Status),
+}
+
+impl Progress {
+    pub fn update(&mut self, message: Message) {
+        match message {
+            Message::IndeterminateToggled(is_indeterminate) => {
+                self.is_indeterminate = is_indeterminate
+            }
+            Message::ValueChanged(value) => self.value = value,
+            Message::StatusSelected(status) => self.status = status,
+        }
+    }
+
+    pub fn view(&self) -> Element<'_, Message> {
+        let bar = if self.is_indeterminate {
+            ProgressBar::indeterminate()
+        } else {
+            ProgressBar::new(0.0..=100.0, self.value)
+        };
+
+        let rings = Row::with_children([16.0, 32.0, 64.0].map(|size| {
+            let ring = if self.is_indeterminate {
+                ProgressRing::indeterminate()
+            } else {
+                ProgressRing::new(0.0..=100.0, self.value)
+            };
+
+            ring.size(size)
+                .thickness((size / 8.0).max(2.0))
+                .status(self.status)
+                .into()
+        }))
+        .spacing(24)
+        .align_y(Vertical::Center);
+
+        let statuses = Column::with_children(Status::ALL.map(|status| {
+            Radio::new(
+                status.to_string(),
+                status,
+                Some(self.status),
+                Message::StatusSelected,
+            )
+            .into()
+        }))
+        .spacing(8);
+
+        let options = Column::new()
+            .push(
+                Checkbox::new("Indeterminate", self.is_indeterminate)
+                    .on_toggle(Message::IndeterminateToggled),
+            )
+            .push(text::body1(format!("Value: {}", self.value)))
+            .push(widget::slider::standard(
+                0.0..=100.0,
+                self.value,
+                Message::ValueChanged,
+            ))
+            .push(text::body1("Status"))
+            .push(statuses)
+            .spacing(12)
+            .width(200);
+
+        let example = widget_example(
+            "A ProgressBar and ProgressRings with options to change their value and status.",
+            Column::new()
+                .push(bar.status(self.status).width(240))
+                .push(rings)
+                .spacing(32),
+            None::<Element<Message>>,
+            Some(options),
+        );
+
+        let statuses_example = widget_example(
+            "Indeterminate ProgressBars in each status.",
+            Column::with_children(Status::ALL.map(|status| {
+                Column::new()
+                    .push(text::caption1(status.to_string()))
+                    .push(ProgressBar::indeterminate().status(status).width(240))
+                    .spacing(8)
+                    .into()
+            }))
+            .spacing(16),
+            None::<Element<Message>>,
+            None::<Element<Message>>,
+        );
+
+        let content = page(
+            "Progress",
+            [
+                text::body1(
+                    "ProgressBar and ProgressRing show that work is under way. Use the \
+                    indeterminate form when the amount of work isn't known, it only animates \
+                    while it's on screen and the status is Running.",
+                )
+                .into(),
+                example,
+                statuses_example,
+            ],
+        );
+
+        scrollable::standard(Container::new(content).padding(Padding::ZERO.right(16)))
+            .height(Length::Fill)
+            .into()
+    }
+}
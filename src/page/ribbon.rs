@@ -1,7 +1,7 @@
 use crate::{
     fluent_icon::FluentIcon,
+    icon::Icon,
     page::{page, widget_example},
-    style,
     widget::{button, ribbon, text, Checkbox, Element, Radio},
 };
 
@@ -38,6 +38,16 @@ pub enum Presentation {
     Compact,
 }
 
+// Where the ribbon's icons come from: the icon font or the SVG sets the Aurora
+// demos use
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub enum IconSet {
+    #[default]
+    Font,
+    Tango,
+    Material,
+}
+
 // ---------------------------------------------------------------------------
 // Page state
 // ---------------------------------------------------------------------------
@@ -75,6 +85,8 @@ pub struct Ribbon {
     // Contextual task group visibility
     contextual_group_visible: bool,
 
+    icon_set: IconSet,
+
     // Feedback
     last_action: Option<&'static str>,
 }
@@ -143,6 +155,8 @@ pub enum Message {
 
     // Contextual group
     ToggleContextualGroup,
+
+    IconSetSelected(IconSet),
 }
 
 impl Message {
@@ -250,9 +264,7 @@ impl Ribbon {
             | Message::ContactPressed => {}
 
             // Preferences band
-            Message::AccessibilityPressed
-            | Message::FontPrefPressed
-            | Message::ThemesPressed => {}
+            Message::AccessibilityPressed | Message::FontPrefPressed | Message::ThemesPressed => {}
 
             // Show/Hide
             Message::ToggleRuler => self.show_ruler = !self.show_ruler,
@@ -266,9 +278,22 @@ impl Ribbon {
             Message::ToggleContextualGroup => {
                 self.contextual_group_visible = !self.contextual_group_visible
             }
+
+            Message::IconSetSelected(icon_set) => self.icon_set = icon_set,
         }
     }
 
+    // Material has no counterpart for some commands, those and any icon whose file
+    // isn't bundled keep the font glyph
+    fn icon(&self, glyph: FluentIcon, tango: &str, material: Option<&str>) -> Icon {
+        match self.icon_set {
+            IconSet::Font => None,
+            IconSet::Tango => Icon::tango(tango),
+            IconSet::Material => material.and_then(Icon::material),
+        }
+        .unwrap_or_else(|| glyph.into())
+    }
+
     // -----------------------------------------------------------------------
     // View
     // -----------------------------------------------------------------------
@@ -289,11 +314,25 @@ impl Ribbon {
         let full_ribbon = ribbon::ribbon_bar::ribbon(tab_bar, band_content);
 
         // Contextual group toggle checkbox
-        let contextual_toggle = Checkbox::new(
-            "Show contextual tabs",
-            self.contextual_group_visible,
-        )
-        .on_toggle(|_| Message::ToggleContextualGroup);
+        let contextual_toggle =
+            Checkbox::new("Show contextual tabs", self.contextual_group_visible)
+                .on_toggle(|_| Message::ToggleContextualGroup);
+
+        let icon_sets = [
+            ("Icon font", IconSet::Font),
+            ("Tango", IconSet::Tango),
+            ("Material", IconSet::Material),
+        ]
+        .into_iter()
+        .map(|(label, icon_set)| {
+            Radio::new(
+                label,
+                icon_set,
+                Some(self.icon_set),
+                Message::IconSetSelected,
+            )
+            .into()
+        });
 
         let status_text = if let Some(action) = self.last_action {
             format!("Last action: {action}")
@@ -303,25 +342,24 @@ impl Ribbon {
 
         page(
             "Ribbon",
-            [
-                widget_example(
-                    "A full ribbon component with tasks, bands, and adaptive controls.",
-                    column![
-                        full_ribbon,
-                        Container::new(text::body1(status_text))
-                            .padding([8, 12])
-                            .width(Length::Fill),
-                    ]
-                    .spacing(8)
-                    .width(Length::Fill),
-                    Some(
-                        column![contextual_toggle,]
-                            .spacing(8)
-                            .width(160),
-                    ),
-                    None::<Element<Message>>,
+            [widget_example(
+                "A full ribbon component with tasks, bands, and adaptive controls.",
+                column![
+                    full_ribbon,
+                    Container::new(text::body1(status_text))
+                        .padding([8, 12])
+                        .width(Length::Fill),
+                ]
+                .spacing(8)
+                .width(Length::Fill),
+                Some(
+                    column![contextual_toggle, text::body1("Icons")]
+                        .extend(icon_sets)
+                        .spacing(8)
+                        .width(160),
                 ),
-            ],
+                None::<Element<Message>>,
+            )],
         )
     }
 
@@ -386,16 +424,34 @@ impl Ribbon {
 
     fn build_clipboard_band(&self) -> Element<Message> {
         let paste_flyout = Container::new(column![
-            button::menu_icon("Paste Special", FluentIcon::Paste)
-                .on_press(Message::PastePressed),
-            button::menu_icon("Paste as Text", FluentIcon::Paste)
-                .on_press(Message::PastePressed),
+            button::menu_icon(
+                "Paste Special",
+                self.icon(
+                    FluentIcon::Paste,
+                    "edit-paste",
+                    Some("content_paste_black_24dp")
+                )
+            )
+            .on_press(Message::PastePressed),
+            button::menu_icon(
+                "Paste as Text",
+                self.icon(
+                    FluentIcon::Paste,
+                    "edit-paste",
+                    Some("content_paste_black_24dp")
+                )
+            )
+            .on_press(Message::PastePressed),
         ])
-        .style(style::container::overlay);
+        .class("overlay");
 
         let paste_btn = ribbon::split_button::large(
             "Paste",
-            FluentIcon::Paste,
+            self.icon(
+                FluentIcon::Paste,
+                "edit-paste",
+                Some("content_paste_black_24dp"),
+            ),
             paste_flyout,
             Message::PastePressed,
             Message::PasteFlyoutOpened,
@@ -405,18 +461,37 @@ impl Ribbon {
         .width(68);
 
         let medium_buttons = column![
-            ribbon::button::medium("Cut", FluentIcon::Cut)
-                .on_press(Message::CutPressed),
-            ribbon::button::medium("Copy", FluentIcon::Copy)
-                .on_press(Message::CopyPressed),
-            ribbon::button::medium("Format", FluentIcon::Edit)
-                .on_press(Message::FormatPressed),
+            ribbon::button::medium(
+                "Cut",
+                self.icon(FluentIcon::Cut, "edit-cut", Some("content_cut_black_24dp"))
+            )
+            .on_press(Message::CutPressed),
+            ribbon::button::medium(
+                "Copy",
+                self.icon(
+                    FluentIcon::Copy,
+                    "edit-copy",
+                    Some("content_copy_black_24dp")
+                )
+            )
+            .on_press(Message::CopyPressed),
+            ribbon::button::medium(
+                "Format",
+                self.icon(
+                    FluentIcon::Edit,
+                    "accessories-text-editor",
+                    Some("mode_edit_black_24dp")
+                )
+            )
+            .on_press(Message::FormatPressed),
         ]
         .spacing(0);
 
         ribbon::band::band(
             "Clipboard",
-            row![paste_btn, medium_buttons,].spacing(4).height(Length::Fill),
+            row![paste_btn, medium_buttons,]
+                .spacing(4)
+                .height(Length::Fill),
         )
     }
 
@@ -427,51 +502,91 @@ impl Ribbon {
     fn build_font_band(&self) -> Element<Message> {
         // Font style strip: Bold, Italic, Underline, Strikethrough
         let style_strip = ribbon::button_strip::horizontal(vec![
-            ribbon::button_strip::icon_button(FluentIcon::Bold, self.bold)
-                .on_press(Message::ToggleBold)
-                .into(),
-            ribbon::button_strip::icon_button(FluentIcon::Italic, self.italic)
-                .on_press(Message::ToggleItalic)
-                .into(),
-            ribbon::button_strip::icon_button(FluentIcon::Underline, self.underline)
-                .on_press(Message::ToggleUnderline)
-                .into(),
-            ribbon::button_strip::icon_button(FluentIcon::Strikethrough, self.strikethrough)
-                .on_press(Message::ToggleStrikethrough)
-                .into(),
+            ribbon::button_strip::icon_button(
+                self.icon(
+                    FluentIcon::Bold,
+                    "format-text-bold",
+                    Some("format_bold_black_24dp"),
+                ),
+                self.bold,
+            )
+            .on_press(Message::ToggleBold)
+            .into(),
+            ribbon::button_strip::icon_button(
+                self.icon(
+                    FluentIcon::Italic,
+                    "format-text-italic",
+                    Some("format_italic_black_24dp"),
+                ),
+                self.italic,
+            )
+            .on_press(Message::ToggleItalic)
+            .into(),
+            ribbon::button_strip::icon_button(
+                self.icon(
+                    FluentIcon::Underline,
+                    "format-text-underline",
+                    Some("format_underlined_black_24dp"),
+                ),
+                self.underline,
+            )
+            .on_press(Message::ToggleUnderline)
+            .into(),
+            ribbon::button_strip::icon_button(
+                self.icon(
+                    FluentIcon::Strikethrough,
+                    "format-text-strikethrough",
+                    Some("format_strikethrough_black_24dp"),
+                ),
+                self.strikethrough,
+            )
+            .on_press(Message::ToggleStrikethrough)
+            .into(),
         ]);
 
         // Alignment strip: Left, Center, Right
         let align_strip = ribbon::button_strip::horizontal(vec![
-            ribbon::button_strip::icon_button(FluentIcon::AlignLeft, self.align_left)
-                .on_press(Message::AlignLeftPressed)
-                .into(),
-            ribbon::button_strip::icon_button(FluentIcon::AlignCenter, self.align_center)
-                .on_press(Message::AlignCenterPressed)
-                .into(),
-            ribbon::button_strip::icon_button(FluentIcon::AlignRight, self.align_right)
-                .on_press(Message::AlignRightPressed)
-                .into(),
+            ribbon::button_strip::icon_button(
+                self.icon(FluentIcon::AlignLeft, "format-justify-left", None),
+                self.align_left,
+            )
+            .on_press(Message::AlignLeftPressed)
+            .into(),
+            ribbon::button_strip::icon_button(
+                self.icon(FluentIcon::AlignCenter, "format-justify-center", None),
+                self.align_center,
+            )
+            .on_press(Message::AlignCenterPressed)
+            .into(),
+            ribbon::button_strip::icon_button(
+                self.icon(FluentIcon::AlignRight, "format-justify-right", None),
+                self.align_right,
+            )
+            .on_press(Message::AlignRightPressed)
+            .into(),
         ]);
 
         // Font size strip: Decrease, Increase
         let size_strip = ribbon::button_strip::horizontal(vec![
-            ribbon::button_strip::icon_button(FluentIcon::FontDecrease, false)
-                .on_press(Message::FontDecreasePressed)
-                .into(),
-            ribbon::button_strip::icon_button(FluentIcon::FontIncrease, false)
-                .on_press(Message::FontIncreasePressed)
-                .into(),
+            ribbon::button_strip::icon_button(
+                self.icon(FluentIcon::FontDecrease, "view-zoom-out", None),
+                false,
+            )
+            .on_press(Message::FontDecreasePressed)
+            .into(),
+            ribbon::button_strip::icon_button(
+                self.icon(FluentIcon::FontIncrease, "view-zoom-in", None),
+                false,
+            )
+            .on_press(Message::FontIncreasePressed)
+            .into(),
         ]);
 
         ribbon::band::band(
             "Font",
-            column![
-                row![style_strip, size_strip,].spacing(8),
-                align_strip,
-            ]
-            .spacing(4)
-            .height(Length::Fill),
+            column![row![style_strip, size_strip,].spacing(8), align_strip,]
+                .spacing(4)
+                .height(Length::Fill),
         )
     }
 
@@ -483,7 +598,7 @@ impl Ribbon {
         // Save location toggles (Top priority)
         let local_btn = ribbon::button::toggle_large(
             "Local",
-            FluentIcon::Save,
+            self.icon(FluentIcon::Save, "document-save", Some("storage-24px")),
             self.save_location == DocumentSaveLocation::Local,
         )
         .on_press(Message::SaveLocationChanged(DocumentSaveLocation::Local))
@@ -491,7 +606,7 @@ impl Ribbon {
 
         let remote_btn = ribbon::button::toggle_large(
             "Remote",
-            FluentIcon::Share,
+            self.icon(FluentIcon::Share, "folder-remote", Some("backup-24px")),
             self.save_location == DocumentSaveLocation::Remote,
         )
         .on_press(Message::SaveLocationChanged(DocumentSaveLocation::Remote))
@@ -499,7 +614,11 @@ impl Ribbon {
 
         let saved_btn = ribbon::button::toggle_large(
             "Saved",
-            FluentIcon::Document,
+            self.icon(
+                FluentIcon::Document,
+                "folder-saved-search",
+                Some("archive_black_24dp"),
+            ),
             self.save_location == DocumentSaveLocation::Saved,
         )
         .on_press(Message::SaveLocationChanged(DocumentSaveLocation::Saved))
@@ -507,12 +626,25 @@ impl Ribbon {
 
         // Document operations (Medium priority)
         let ops = column![
-            ribbon::button::medium("New", FluentIcon::Add)
-                .on_press(Message::DocumentNewPressed),
-            ribbon::button::medium("Open", FluentIcon::NewWindow)
-                .on_press(Message::DocumentOpenPressed),
-            ribbon::button::medium("Print", FluentIcon::Print)
-                .on_press(Message::DocumentPrintPressed),
+            ribbon::button::medium(
+                "New",
+                self.icon(FluentIcon::Add, "document-new", Some("add_black_24dp"))
+            )
+            .on_press(Message::DocumentNewPressed),
+            ribbon::button::medium(
+                "Open",
+                self.icon(
+                    FluentIcon::NewWindow,
+                    "document-open",
+                    Some("folder_open_black_24dp")
+                )
+            )
+            .on_press(Message::DocumentOpenPressed),
+            ribbon::button::medium(
+                "Print",
+                self.icon(FluentIcon::Print, "document-print", None)
+            )
+            .on_press(Message::DocumentPrintPressed),
         ]
         .spacing(0);
 
@@ -529,17 +661,30 @@ impl Ribbon {
     // -----------------------------------------------------------------------
 
     fn build_find_band(&self) -> Element<Message> {
-        let search_btn = ribbon::button::large("Search", FluentIcon::Search)
-            .on_press(Message::SearchPressed)
-            .width(52);
+        let search_btn = ribbon::button::large(
+            "Search",
+            self.icon(FluentIcon::Search, "system-search", None),
+        )
+        .on_press(Message::SearchPressed)
+        .width(52);
 
         let medium_cmds = column![
-            ribbon::button::medium("Find", FluentIcon::Search)
+            ribbon::button::medium("Find", self.icon(FluentIcon::Search, "edit-find", None))
                 .on_press(Message::FindPressed),
-            ribbon::button::medium("Replace", FluentIcon::Edit)
-                .on_press(Message::FindReplacePressed),
-            ribbon::button::medium("Select All", FluentIcon::SelectAll)
-                .on_press(Message::SelectAllPressed),
+            ribbon::button::medium(
+                "Replace",
+                self.icon(FluentIcon::Edit, "edit-find-replace", None)
+            )
+            .on_press(Message::FindReplacePressed),
+            ribbon::button::medium(
+                "Select All",
+                self.icon(
+                    FluentIcon::SelectAll,
+                    "edit-select-all",
+                    Some("select_all-24px")
+                )
+            )
+            .on_press(Message::SelectAllPressed),
         ]
         .spacing(0);
 
@@ -574,23 +719,54 @@ impl Ribbon {
     // -----------------------------------------------------------------------
 
     fn build_action_band(&self) -> Element<Message> {
-        let address_btn = ribbon::button::large("Address\nBook", FluentIcon::Mail)
-            .on_press(Message::AddressBookPressed)
-            .width(56);
+        let address_btn = ribbon::button::large(
+            "Address\nBook",
+            self.icon(
+                FluentIcon::Mail,
+                "address-book-new",
+                Some("account_box-24px"),
+            ),
+        )
+        .on_press(Message::AddressBookPressed)
+        .width(56);
 
         let medium_cmds = column![
-            ribbon::button::medium("Document", FluentIcon::Document)
-                .on_press(Message::DocumentActionPressed),
-            ribbon::button::medium("Appointment", FluentIcon::Calendar)
-                .on_press(Message::AppointmentPressed),
-            ribbon::button::medium("Bookmark", FluentIcon::Like)
-                .on_press(Message::BookmarkPressed),
+            ribbon::button::medium(
+                "Document",
+                self.icon(
+                    FluentIcon::Document,
+                    "x-office-document",
+                    Some("drafts_black_24dp")
+                )
+            )
+            .on_press(Message::DocumentActionPressed),
+            ribbon::button::medium(
+                "Appointment",
+                self.icon(
+                    FluentIcon::Calendar,
+                    "appointment-new",
+                    Some("alarm_black_24dp")
+                )
+            )
+            .on_press(Message::AppointmentPressed),
+            ribbon::button::medium(
+                "Bookmark",
+                self.icon(
+                    FluentIcon::Like,
+                    "bookmark-new",
+                    Some("star_border_black_24dp")
+                )
+            )
+            .on_press(Message::BookmarkPressed),
         ]
         .spacing(0);
 
-        let contact_btn = ribbon::button::large("Contact", FluentIcon::Group)
-            .on_press(Message::ContactPressed)
-            .width(52);
+        let contact_btn = ribbon::button::large(
+            "Contact",
+            self.icon(FluentIcon::Group, "contact-new", Some("person_black_24dp")),
+        )
+        .on_press(Message::ContactPressed)
+        .width(52);
 
         ribbon::band::band(
             "Action",
@@ -605,23 +781,38 @@ impl Ribbon {
     // -----------------------------------------------------------------------
 
     fn build_preferences_band(&self) -> Element<Message> {
-        let font_btn = ribbon::button::large("Font", FluentIcon::Font)
-            .on_press(Message::FontPrefPressed)
-            .width(48);
+        let font_btn = ribbon::button::large(
+            "Font",
+            self.icon(FluentIcon::Font, "preferences-desktop-font", None),
+        )
+        .on_press(Message::FontPrefPressed)
+        .width(48);
 
         let medium_cmds = column![
-            ribbon::button::medium("Accessibility", FluentIcon::Settings)
-                .on_press(Message::AccessibilityPressed),
-            ribbon::button::medium("Themes", FluentIcon::Color)
-                .on_press(Message::ThemesPressed),
+            ribbon::button::medium(
+                "Accessibility",
+                self.icon(
+                    FluentIcon::Settings,
+                    "preferences-desktop-accessibility",
+                    Some("accessibility_new-24px")
+                )
+            )
+            .on_press(Message::AccessibilityPressed),
+            ribbon::button::medium(
+                "Themes",
+                self.icon(
+                    FluentIcon::Color,
+                    "preferences-desktop-theme",
+                    Some("brightness_medium-24px")
+                )
+            )
+            .on_press(Message::ThemesPressed),
         ]
         .spacing(0);
 
         ribbon::band::band(
             "Preferences",
-            row![font_btn, medium_cmds,]
-                .spacing(4)
-                .height(Length::Fill),
+            row![font_btn, medium_cmds,].spacing(4).height(Length::Fill),
         )
     }
 
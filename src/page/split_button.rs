@@ -1,6 +1,5 @@
 use crate::{
     page::{page, widget_example},
-    style,
     widget::{button, Button, Container, Element, Wrap},
 };
 
@@ -88,7 +87,7 @@ impl SplitButton {
                     button::split(
                         Button::new(vertical_space()).width(32).height(32).style(
                             move |theme, status| {
-                                let base = style::button::secondary(theme, status);
+                                let base = theme.classes().button("secondary")(theme, status);
 
                                 Style {
                                     background: Some(self.colour1.into()),
@@ -112,7 +111,7 @@ impl SplitButton {
                     "A Split Button with text.",
                     button::split(
                         button::standard("Choose color")
-                            .style(style::button::split_content)
+                            .class("split_content")
                             .on_press(Message::NoOp),
                         colour_grid(&COLOURS[..], Message::Colour2Selected),
                         Message::SplitButton2Pressed,
@@ -198,7 +197,8 @@ where
                         .height(32)
                         .on_press(on_press(*colour))
                         .style(move |theme, status| {
-                            style::button::secondary(theme, status).with_background(*colour)
+                            theme.classes().button("secondary")(theme, status)
+                                .with_background(*colour)
                         }),
                 )
             })
@@ -208,6 +208,5 @@ where
     .width(168)
     .height(168)
     .padding(Padding::new(24.0).right(12))
-    .style(style::container::overlay)
     .into()
 }
@@ -0,0 +1,85 @@
+use crate::{
+    page::{page, widget_example},
+    widget::{button, scrollable, split_view, text, Column, Container, Element},
+};
+
+use iced::{alignment::Vertical, widget::column, Length};
+
+const DEFAULT_POSITION: f32 = 200.0;
+
+const ITEMS: [(&str, &str); 5] = [
+    ("Inbox", "Messages that have not been filed yet."),
+    ("Drafts", "Messages that are still being written."),
+    ("Sent", "Messages that have been delivered."),
+    ("Archive", "Messages kept for later reference."),
+    ("Junk", "Messages that were filtered as spam."),
+];
+
+#[derive(Clone, Debug)]
+pub struct SplitView {
+    position: f32,
+    selected: usize,
+}
+
+impl Default for SplitView {
+    fn default() -> Self {
+        Self {
+            position: DEFAULT_POSITION,
+            selected: 0,
+        }
+    }
+}
+
+#[derive(Clone, Debug)]
+pub enum Message {
+    Resized(f32),
+    Selected(usize),
+}
+
+impl SplitView {
+    pub fn update(&mut self, message: Message) {
+        match message {
+            Message::Resized(position) => self.position = position,
+            Message::Selected(index) => self.selected = index,
+        }
+    }
+
+    pub fn view(&self) -> Element<'_, Message> {
+        let master = scrollable::standard(Column::with_children(ITEMS.iter().enumerate().map(
+            |(index, (label, _))| {
+                button::standard(text::body1(*label).align_y(Vertical::Center))
+                    .width(Length::Fill)
+                    .class(if index == self.selected {
+                        "primary"
+                    } else {
+                        "transparent"
+                    })
+                    .on_press(Message::Selected(index))
+                    .into()
+            },
+        )))
+        .height(Length::Fill);
+
+        let (title, description) = ITEMS[self.selected];
+
+        let detail =
+            Container::new(column![text::subtitle1(title), text::body1(description)].spacing(8))
+                .padding([0, 12])
+                .width(Length::Fill);
+
+        page(
+            "Split View",
+            [widget_example(
+                "A master/detail layout with a resizable pane. Double-click the divider to reset it.",
+                split_view::SplitView::new(master, detail, self.position)
+                    .limits(120.0, 400.0)
+                    .default_position(DEFAULT_POSITION)
+                    .height(220)
+                    .on_resize(Message::Resized)
+                    .class("divider"),
+                Some(text::body1(format!("Pane width: {:.0}px", self.position))),
+                None::<Element<Message>>,
+            )],
+        )
+    }
+}
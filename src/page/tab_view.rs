@@ -0,0 +1,173 @@
+use crate::{
+    fluent_icon::FluentIcon,
+    page::{page, widget_example},
+    widget::{
+        checkbox, scrollable,
+        tab_view::{Tab, TabView as TabViewWidget},
+        text, text_input, Column, Container, Element,
+    },
+};
+
+use iced::{Length, Padding};
+
+#[derive(Clone, Debug)]
+struct Document {
+    title: String,
+    notes: String,
+}
+
+impl Document {
+    fn new(number: usize) -> Self {
+        Self {
+            title: format!("Document {number}"),
+            notes: String::new(),
+        }
+    }
+}
+
+#[derive(Clone, Debug)]
+pub struct TabView {
+    documents: Vec<Document>,
+    selected: usize,
+    // Numbers the documents so a new tab never repeats a title still open
+    created: usize,
+    is_closable: bool,
+    can_reorder: bool,
+    can_add: bool,
+    last_action: Option<String>,
+}
+
+impl Default for TabView {
+    fn default() -> Self {
+        Self {
+            documents: (1..=3).map(Document::new).collect(),
+            selected: 0,
+            created: 3,
+            is_closable: true,
+            can_reorder: true,
+            can_add: true,
+            last_action: None,
+        }
+    }
+}
+
+#[derive(Clone, Debug)]
+pub enum Message {
+    Selected(usize),
+    Closed(usize),
+    Added,
+    Reordered(usize, usize),
+    NotesChanged(usize, String),
+    ClosableToggled(bool),
+    ReorderToggled(bool),
+    AddToggled(bool),
+}
+
+impl TabView {
+    pub fn update(&mut self, message: Message) {
+        match message {
+            Message::Selected(index) => self.selected = index,
+            Message::Closed(index) => {
+                let document = self.documents.remove(index);
+
+                if self.selected > index || self.selected == self.documents.len() {
+                    self.selected = self.selected.saturating_sub(1);
+                }
+
+                self.last_action = Some(format!("Closed {}", document.title));
+            }
+            Message::Added => {
+                self.created += 1;
+                self.documents.push(Document::new(self.created));
+                self.selected = self.documents.len() - 1;
+            }
+            Message::Reordered(from, to) => {
+                let document = self.documents.remove(from);
+
+                self.last_action = Some(format!("Moved {} to position {}", document.title, to + 1));
+                self.documents.insert(to, document);
+
+                self.selected = if self.selected == from {
+                    to
+                } else if from < self.selected && self.selected <= to {
+                    self.selected - 1
+                } else if to <= self.selected && self.selected < from {
+                    self.selected + 1
+                } else {
+                    self.selected
+                };
+            }
+            Message::NotesChanged(index, notes) => self.documents[index].notes = notes,
+            Message::ClosableToggled(is_closable) => self.is_closable = is_closable,
+            Message::ReorderToggled(can_reorder) => self.can_reorder = can_reorder,
+            Message::AddToggled(can_add) => self.can_add = can_add,
+        }
+    }
+
+    pub fn view(&self) -> Element<'_, Message> {
+        let tabs = self.documents.iter().enumerate().map(|(index, document)| {
+            let content = Column::new()
+                .push(text::subtitle1(&document.title))
+                .push(
+                    text_input::standard("Write some notes", &document.notes)
+                        .on_input(move |notes| Message::NotesChanged(index, notes)),
+                )
+                .spacing(12)
+                .padding(16);
+
+            Tab::new(&document.title, content)
+                .icon(FluentIcon::Document)
+                .closable(self.is_closable)
+        });
+
+        let mut tab_view = TabViewWidget::new(self.selected, Message::Selected)
+            .extend(tabs)
+            .on_close(Message::Closed)
+            .height(240);
+
+        if self.can_reorder {
+            tab_view = tab_view.on_reorder(Message::Reordered);
+        }
+
+        if self.can_add {
+            tab_view = tab_view.on_add(Message::Added);
+        }
+
+        let options = Column::new()
+            .push(
+                checkbox::two_state("Closable tabs", self.is_closable)
+                    .on_toggle(Message::ClosableToggled),
+            )
+            .push(
+                checkbox::two_state("Reorderable tabs", self.can_reorder)
+                    .on_toggle(Message::ReorderToggled),
+            )
+            .push(
+                checkbox::two_state("Add tab button", self.can_add).on_toggle(Message::AddToggled),
+            )
+            .spacing(8)
+            .width(180);
+
+        let output = Column::new()
+            .push(text::body1(format!(
+                "{} tabs open, Ctrl+Tab and Ctrl+Shift+Tab switch between them and Ctrl+W closes the selected one.",
+                self.documents.len()
+            )))
+            .push_maybe(self.last_action.as_deref().map(text::body1))
+            .spacing(4);
+
+        let content = page(
+            "TabView",
+            [widget_example(
+                "A TabView with closable, reorderable tabs. Add enough tabs and they scroll, middle-click a tab to close it.",
+                tab_view,
+                Some(output),
+                Some(options),
+            )],
+        );
+
+        scrollable::standard(Container::new(content).padding(Padding::ZERO.right(16)))
+            .height(Length::Fill)
+            .into()
+    }
+}
@@ -0,0 +1,141 @@
+use crate::{
+    page::{page, widget_example},
+    widget::{
+        button, flyout::Placement, radio, scrollable,
+        teaching_tip::TeachingTip as TeachingTipWidget, text, Column, Container, Element,
+    },
+};
+
+use iced::{widget::image, ContentFit, Length, Padding};
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum Tip {
+    Basic,
+    Hero,
+    LightDismiss,
+}
+
+#[derive(Clone, Debug, Default)]
+pub struct TeachingTip {
+    open: Option<Tip>,
+    placement: Placement,
+    last_action: Option<&'static str>,
+}
+
+#[derive(Clone, Debug)]
+pub enum Message {
+    Toggled(Tip),
+    Closed,
+    PlacementSelected(Placement),
+    ActionPressed,
+}
+
+impl TeachingTip {
+    pub fn update(&mut self, message: Message) {
+        match message {
+            Message::Toggled(tip) => {
+                self.open = if self.open == Some(tip) {
+                    None
+                } else {
+                    Some(tip)
+                }
+            }
+            Message::Closed => self.open = None,
+            Message::PlacementSelected(placement) => self.placement = placement,
+            Message::ActionPressed => {
+                self.last_action = Some("Action button pressed");
+                self.open = None;
+            }
+        }
+    }
+
+    pub fn view(&self) -> Element<'_, Message> {
+        let placements = Column::with_children(Placement::ALL.map(|placement| {
+            radio::standard(
+                placement.to_string(),
+                placement,
+                Some(self.placement),
+                Message::PlacementSelected,
+            )
+            .into()
+        }))
+        .spacing(8);
+
+        let basic = TeachingTipWidget::new(
+            target("Show TeachingTip", Tip::Basic),
+            "Change themes without hassle",
+        )
+        .subtitle("It's easier than ever to see control samples in both light and dark theme!")
+        .on_close(Message::Closed)
+        .open(self.open == Some(Tip::Basic))
+        .placement(self.placement);
+
+        let hero = TeachingTipWidget::new(
+            target("Show TeachingTip with hero", Tip::Hero),
+            "Compact tips",
+        )
+        .subtitle("Tips can show a hero image and buttons for the next step.")
+        .hero(
+            image(format!(
+                "{}/assets/images/treetops.jpg",
+                env!("CARGO_MANIFEST_DIR")
+            ))
+            .width(Length::Fill)
+            .height(120)
+            .content_fit(ContentFit::Cover),
+        )
+        .action("Got it", Message::ActionPressed)
+        .close_button("Dismiss")
+        .on_close(Message::Closed)
+        .open(self.open == Some(Tip::Hero));
+
+        let light_dismiss = TeachingTipWidget::new(
+            target("Show light-dismiss TeachingTip", Tip::LightDismiss),
+            "Saving automatically",
+        )
+        .subtitle("We save your changes as you go - so you never have to.")
+        .on_close(Message::Closed)
+        .light_dismiss(true)
+        .open(self.open == Some(Tip::LightDismiss));
+
+        let content = page(
+            "TeachingTip",
+            [
+                widget_example(
+                    "A TeachingTip on a target, with options to change its placement.",
+                    basic,
+                    None::<Element<Message>>,
+                    Some(
+                        Column::new()
+                            .push(text::body1("Placement"))
+                            .push(placements)
+                            .spacing(12)
+                            .width(160),
+                    ),
+                ),
+                widget_example(
+                    "A TeachingTip with a hero image and buttons.",
+                    hero,
+                    self.last_action.map(text::body1),
+                    None::<Element<Message>>,
+                ),
+                widget_example(
+                    "A light-dismiss TeachingTip, click outside of it or press Esc to close it.",
+                    light_dismiss,
+                    None::<Element<Message>>,
+                    None::<Element<Message>>,
+                ),
+            ],
+        );
+
+        scrollable::standard(Container::new(content).padding(Padding::ZERO.right(16)))
+            .height(Length::Fill)
+            .into()
+    }
+}
+
+fn target(label: &'static str, tip: Tip) -> Element<'static, Message> {
+    button::standard(text::body1(label))
+        .on_press(Message::Toggled(tip))
+        .into()
+}
@@ -81,7 +81,7 @@ impl TextInput {
                             .width(328)
                             .on_input(|_| Message::NoOp)
                             .style(|theme: &Theme, status| {
-                                let style = (<Theme as Catalog>::default())(theme, status);
+                                let style = theme.style(&<Theme as Catalog>::default(), status);
 
                                 match status {
                                     Status::Active => Style {
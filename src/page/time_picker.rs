@@ -0,0 +1,91 @@
+use crate::{
+    date_time::Time,
+    page::{page, widget_example},
+    widget::{
+        pick_list, scrollable, text,
+        time_picker::{Clock, TimePicker as TimePickerWidget},
+        Column, Container, Element,
+    },
+};
+
+use iced::{Length, Padding};
+
+const MINUTE_INCREMENTS: [u8; 4] = [1, 5, 15, 30];
+
+#[derive(Clone, Debug)]
+pub struct TimePicker {
+    time: Option<Time>,
+    clock: Clock,
+    minute_increment: u8,
+}
+
+impl Default for TimePicker {
+    fn default() -> Self {
+        Self {
+            time: None,
+            clock: Clock::default(),
+            minute_increment: 1,
+        }
+    }
+}
+
+#[derive(Clone, Debug)]
+pub enum Message {
+    Changed(Time),
+    ClockSelected(Clock),
+    MinuteIncrementSelected(u8),
+}
+
+impl TimePicker {
+    pub fn update(&mut self, message: Message) {
+        match message {
+            Message::Changed(time) => self.time = Some(time),
+            Message::ClockSelected(clock) => self.clock = clock,
+            Message::MinuteIncrementSelected(minute_increment) => {
+                self.minute_increment = minute_increment;
+            }
+        }
+    }
+
+    pub fn view(&self) -> Element<'_, Message> {
+        let time_picker = TimePickerWidget::new(self.time, Message::Changed)
+            .clock(self.clock)
+            .minute_increment(self.minute_increment);
+
+        let options = Column::new()
+            .push(text::body1("Clock"))
+            .push(pick_list::standard(
+                Clock::ALL,
+                Some(self.clock),
+                Message::ClockSelected,
+            ))
+            .push(text::body1("Minute increment"))
+            .push(pick_list::standard(
+                MINUTE_INCREMENTS,
+                Some(self.minute_increment),
+                Message::MinuteIncrementSelected,
+            ))
+            .spacing(8)
+            .width(180);
+
+        let output = text::body1(format!(
+            "Picked: {}",
+            self.time
+                .map_or("nothing".to_owned(), |time| time.to_string())
+        ));
+
+        let content = page(
+            "TimePicker",
+            [widget_example(
+                "A TimePicker. The hours and minutes loop round, use the wheel, the arrow keys or click a value, then accept it.",
+                time_picker,
+                Some(output),
+                Some(options),
+            )],
+        );
+
+        scrollable::standard(Container::new(content).padding(Padding::ZERO.right(16)))
+            .height(Length::Fill)
+            .into()
+    }
+}
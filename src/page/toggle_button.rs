@@ -1,6 +1,5 @@
 use crate::{
     page::{page, widget_example},
-    style,
     widget::{self, checkbox, text, Element},
 };
 
@@ -34,10 +33,10 @@ impl ToggleButton {
     }
 
     pub fn view(&self) -> Element<Message> {
-        let style = if self.button1_on {
-            style::button::primary
+        let class = if self.button1_on {
+            "primary"
         } else {
-            style::button::secondary
+            "secondary"
         };
 
         page(
@@ -46,7 +45,7 @@ impl ToggleButton {
                 "A simple Toggle Button with text content.",
                 widget::Button::new(text::body1("Toggle Button"))
                     .height(32)
-                    .style(style)
+                    .class(class)
                     .on_press_maybe(self.button1_enabled.then_some(Message::Button1Toggled)),
                 Some(text::body1(format![
                     "Output: {}",
@@ -0,0 +1,246 @@
+use crate::{
+    page::{page, widget_example},
+    theme::{
+        contrast,
+        tonal::{self, Tonal},
+        Appearance, Palette, Theme,
+    },
+    widget::{
+        button, checkbox, pick_list, scrollable, text, toggler, Column, Container, Element, Row,
+    },
+};
+
+use iced::{
+    color,
+    widget::{column, row, text::Style, themer},
+    Color, Length, Padding,
+};
+
+use std::sync::Arc;
+
+// Seeds from Aurora's TonalPaletteSeeds
+const SEEDS: [Seed; 10] = [
+    Seed::new("Aqua", color!(112, 206, 239)),
+    Seed::new("Bottle green", color!(63, 181, 59)),
+    Seed::new("Jade forest", color!(21, 82, 25)),
+    Seed::new("Metallic", color!(180, 185, 190)),
+    Seed::new("Purple", color!(203, 175, 237)),
+    Seed::new("Raspberry", color!(251, 110, 144)),
+    Seed::new("Steel blue", color!(118, 165, 195)),
+    Seed::new("Sunfire red", color!(224, 20, 10)),
+    Seed::new("Sunset", color!(255, 120, 41)),
+    Seed::new("Ultramarine", color!(46, 22, 124)),
+];
+
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct Seed {
+    name: &'static str,
+    colour: Color,
+}
+
+impl Seed {
+    const fn new(name: &'static str, colour: Color) -> Self {
+        Self { name, colour }
+    }
+}
+
+impl std::fmt::Display for Seed {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}", self.name)
+    }
+}
+
+#[derive(Clone, Debug)]
+pub struct TonalPalette {
+    accent: Seed,
+    neutral: Option<Seed>,
+    light: Arc<Palette>,
+    dark: Arc<Palette>,
+    is_checked: bool,
+}
+
+impl Default for TonalPalette {
+    fn default() -> Self {
+        let mut page = Self {
+            accent: SEEDS[6],
+            neutral: None,
+            light: Arc::new(Palette::LIGHT),
+            dark: Arc::new(Palette::DARK),
+            is_checked: true,
+        };
+
+        page.generate();
+        page
+    }
+}
+
+#[derive(Clone, Debug)]
+pub enum Message {
+    AccentSelected(Seed),
+    NeutralSelected(Seed),
+    NeutralCleared,
+    Toggled(bool),
+}
+
+impl TonalPalette {
+    pub fn update(&mut self, message: Message) {
+        match message {
+            Message::AccentSelected(seed) => self.accent = seed,
+            Message::NeutralSelected(seed) => self.neutral = Some(seed),
+            Message::NeutralCleared => self.neutral = None,
+            Message::Toggled(is_checked) => self.is_checked = is_checked,
+        }
+
+        self.generate();
+    }
+
+    fn generate(&mut self) {
+        let mut tonal = Tonal::new(self.accent.colour);
+
+        if let Some(neutral) = self.neutral {
+            tonal = tonal.neutral(neutral.colour);
+        }
+
+        self.light = Arc::new(tonal.light());
+        self.dark = Arc::new(tonal.dark());
+    }
+
+    pub fn view(&self) -> Element<'_, Message> {
+        let seeds = row![
+            text::body1("Accent"),
+            pick_list::standard(SEEDS, Some(self.accent), Message::AccentSelected),
+            text::body1("Neutral"),
+            pick_list::standard(SEEDS, self.neutral, Message::NeutralSelected)
+                .placeholder("Accent hue"),
+            button::standard(text::body1("Clear"))
+                .on_press_maybe(self.neutral.is_some().then_some(Message::NeutralCleared)),
+        ]
+        .spacing(12)
+        .align_y(iced::alignment::Vertical::Center);
+
+        let comparison = |built_in: Theme, generated: &Arc<Palette>| {
+            row![
+                self.preview("Built-in", built_in),
+                self.preview("Generated", Theme::custom(generated.clone())),
+            ]
+            .spacing(12)
+        };
+
+        let content = page(
+            "Tonal Palettes",
+            [
+                widget_example(
+                    "Palettes generated from an accent and an optional neutral seed.",
+                    seeds,
+                    None::<Element<Message>>,
+                    None::<Element<Message>>,
+                ),
+                widget_example(
+                    "Light",
+                    comparison(Appearance::Light.into(), &self.light),
+                    Some(report(&self.light)),
+                    None::<Element<Message>>,
+                ),
+                widget_example(
+                    "Dark",
+                    comparison(Appearance::Dark.into(), &self.dark),
+                    Some(report(&self.dark)),
+                    None::<Element<Message>>,
+                ),
+            ],
+        );
+
+        scrollable::standard(Container::new(content).padding(Padding::ZERO.right(16)))
+            .height(Length::Fill)
+            .into()
+    }
+
+    fn preview<'a>(&self, label: &'a str, theme: Theme) -> Element<'a, Message> {
+        let secondary = |theme: &Theme| Style {
+            color: Some(theme.palette().text_fill_color_secondary),
+        };
+        let accent = |theme: &Theme| Style {
+            color: Some(theme.palette().accent_text_fill_color_primary),
+        };
+
+        let content = Container::new(
+            column![
+                text::subtitle1(label),
+                text::body1("Primary text"),
+                text::body1("Secondary text").style(secondary),
+                text::body1("Accent text").style(accent),
+                row![
+                    button::standard(text::body1("Accent"))
+                        .class("primary")
+                        .on_press(Message::Toggled(!self.is_checked)),
+                    button::standard(text::body1("Standard"))
+                        .on_press(Message::Toggled(!self.is_checked)),
+                ]
+                .spacing(8),
+                checkbox::two_state("Checkbox", self.is_checked).on_toggle(Message::Toggled),
+                toggler::standard(self.is_checked).on_toggle(Message::Toggled),
+            ]
+            .spacing(12),
+        )
+        .padding(16)
+        .width(220)
+        .class("card");
+
+        themer(theme, content)
+            .background(|theme: &Theme| theme.palette().solid_background_fill_color_tertiary.into())
+            .text_color(|theme: &Theme| theme.palette().text_fill_color_primary)
+            .into()
+    }
+}
+
+// Contrast of the generated tokens against their weakest background
+fn report<'a>(palette: &Palette) -> Element<'a, Message> {
+    let backgrounds = tonal::text_backgrounds(palette);
+    let page = palette.solid_background_fill_color_tertiary;
+
+    let lines = [
+        (
+            "Primary text",
+            tonal::min_contrast(palette.text_fill_color_primary, &backgrounds),
+            contrast::AAA,
+        ),
+        (
+            "Secondary text",
+            tonal::min_contrast(palette.text_fill_color_secondary, &backgrounds),
+            contrast::AA,
+        ),
+        (
+            "Accent text",
+            tonal::min_contrast(palette.accent_text_fill_color_primary, &backgrounds),
+            contrast::AA,
+        ),
+        (
+            "Text on accent",
+            contrast::ratio(
+                palette.text_on_accent_fill_color_primary,
+                palette.accent_fill_color_default,
+            ),
+            contrast::AA,
+        ),
+        (
+            "Accent fill",
+            contrast::ratio(
+                contrast::over(palette.accent_fill_color_default, page),
+                page,
+            ),
+            contrast::AA_LARGE,
+        ),
+    ];
+
+    Column::with_children(lines.map(|(label, ratio, target)| {
+        Row::new()
+            .push(text::caption1(label).width(100))
+            .push(text::caption1(format!(
+                "{ratio:.1}:1 {}",
+                if ratio >= target { "✓" } else { "✗" }
+            )))
+            .into()
+    }))
+    .spacing(4)
+    .into()
+}
@@ -0,0 +1,141 @@
+use crate::{
+    fluent_icon::FluentIcon,
+    page::{page, widget_example},
+    widget::{
+        pick_list, scrollable, text,
+        tree_view::{Action, Content, Item, NodeId, SelectionMode, TreeView as TreeViewWidget},
+        Column, Container, Element,
+    },
+};
+
+use iced::{Length, Padding};
+
+// Enough files to show the tree only drawing the rows in view
+const LARGE_FOLDER: usize = 5000;
+
+#[derive(Clone, Debug)]
+pub struct TreeView {
+    content: Content,
+    selection_mode: SelectionMode,
+    loads: usize,
+}
+
+impl Default for TreeView {
+    fn default() -> Self {
+        let mut content = Content::new();
+
+        for folder in ["Documents", "Music", "Pictures", "Large folder"] {
+            content.push(None, folder_item(folder));
+        }
+
+        Self {
+            content,
+            selection_mode: SelectionMode::default(),
+            loads: 0,
+        }
+    }
+}
+
+#[derive(Clone, Debug)]
+pub enum Message {
+    Action(Action),
+    Load(NodeId),
+    SelectionModeSelected(SelectionMode),
+}
+
+impl TreeView {
+    pub fn update(&mut self, message: Message) {
+        match message {
+            Message::Action(action) => self.content.perform(action),
+            // A real application would fetch these in a `Task`, the node shows a
+            // loading row until they arrive
+            Message::Load(id) => {
+                self.loads += 1;
+
+                let items = children(self.content.label(id), depth(&self.content, id));
+                self.content.load(id, items);
+            }
+            Message::SelectionModeSelected(selection_mode) => self.selection_mode = selection_mode,
+        }
+    }
+
+    pub fn view(&self) -> Element<'_, Message> {
+        let tree_view = TreeViewWidget::new(&self.content, Message::Action)
+            .on_load(Message::Load)
+            .selection_mode(self.selection_mode)
+            .height(320);
+
+        let options = Column::new()
+            .push(text::body1("Selection mode"))
+            .push(pick_list::standard(
+                SelectionMode::ALL,
+                Some(self.selection_mode),
+                Message::SelectionModeSelected,
+            ))
+            .spacing(8)
+            .width(180);
+
+        let selection = match self.selection_mode {
+            SelectionMode::None => None,
+            SelectionMode::Single => Some(format!(
+                "Selected: {}",
+                self.content
+                    .selected()
+                    .map_or("nothing", |id| self.content.label(id))
+            )),
+            SelectionMode::Multiple => {
+                Some(format!("{} nodes checked", self.content.checked().count()))
+            }
+        };
+
+        let output = Column::new()
+            .push_maybe(selection.map(text::body1))
+            .push(text::body1(format!(
+                "{} nodes, {} folders loaded",
+                self.content.len(),
+                self.loads
+            )))
+            .spacing(4)
+            .width(220);
+
+        let content = page(
+            "TreeView",
+            [widget_example(
+                "A TreeView whose folders load their children when first expanded. Use the arrow keys, Home and End to move around and type to jump to a node.",
+                tree_view,
+                Some(output),
+                Some(options),
+            )],
+        );
+
+        scrollable::standard(Container::new(content).padding(Padding::ZERO.right(16)))
+            .height(Length::Fill)
+            .into()
+    }
+}
+
+fn folder_item(label: impl Into<String>) -> Item {
+    Item::new(label).icon(FluentIcon::Folder).lazy()
+}
+
+fn depth(content: &Content, id: NodeId) -> usize {
+    std::iter::successors(content.parent(id), |id| content.parent(*id)).count()
+}
+
+// Made-up folder contents, a few levels of subfolders with some files in each
+fn children(folder: &str, depth: usize) -> Vec<Item> {
+    if folder == "Large folder" {
+        return (1..=LARGE_FOLDER)
+            .map(|number| Item::new(format!("File {number:04}.txt")).icon(FluentIcon::Document))
+            .collect();
+    }
+
+    let folders = if depth < 3 { 3 } else { 0 };
+
+    (1..=folders)
+        .map(|number| folder_item(format!("{folder} {number}")))
+        .chain((1..=4).map(|number| {
+            Item::new(format!("{} {number}.txt", folder.to_lowercase())).icon(FluentIcon::Document)
+        }))
+        .collect()
+}
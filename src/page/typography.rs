@@ -0,0 +1,90 @@
+use crate::{
+    page::{page, widget_example},
+    widget::{scrollable, text, text_input, Column, Container, Element, Row, Text},
+};
+
+use iced::{alignment::Vertical, Length, Padding};
+
+type Style = fn(String) -> Text<'static>;
+
+// The Fluent 2 ramp from largest to smallest, sizes and line heights at 100%
+const RAMP: [(&str, Style, &str); 13] = [
+    ("Display", text::display, "Semibold, 68/92"),
+    ("Large title", text::large_title, "Semibold, 40/52"),
+    ("Title 1", text::title1, "Semibold, 32/40"),
+    ("Title 2", text::title2, "Semibold, 28/36"),
+    ("Title 3", text::title3, "Semibold, 24/32"),
+    ("Subtitle 1", text::subtitle1, "Semibold, 20/26"),
+    ("Subtitle 2", text::subtitle2, "Semibold, 16/22"),
+    ("Body 2", text::body2, "Regular, 16/22"),
+    ("Body 1 strong", text::body1_strong, "Semibold, 14/20"),
+    ("Body 1 bold", text::bold, "Bold, 14/20"),
+    ("Body 1", text::body1, "Regular, 14/20"),
+    ("Caption 1", text::caption1, "Regular, 12/16"),
+    ("Caption 2", text::caption2, "Regular, 10/14"),
+];
+
+#[derive(Clone, Debug)]
+pub struct Typography {
+    sample: String,
+}
+
+impl Default for Typography {
+    fn default() -> Self {
+        Self {
+            sample: String::from("The quick brown fox"),
+        }
+    }
+}
+
+#[derive(Clone, Debug)]
+pub enum Message {
+    SampleChanged(String),
+}
+
+impl Typography {
+    pub fn update(&mut self, message: Message) {
+        match message {
+            Message::SampleChanged(sample) => self.sample = sample,
+        }
+    }
+
+    pub fn view(&self) -> Element<'_, Message> {
+        let rows = RAMP.into_iter().map(|(name, style, details)| {
+            Container::new(
+                Row::new()
+                    .push(
+                        Column::new()
+                            .push(text::body1_strong(name))
+                            .push(text::caption1(details))
+                            .width(160),
+                    )
+                    .push(style(self.sample.clone()).width(Length::Fill))
+                    .spacing(12)
+                    .align_y(Vertical::Center),
+            )
+            .padding([8, 12])
+            .width(Length::Fill)
+            .class("card")
+            .into()
+        });
+
+        let content = page(
+            "Typography",
+            [widget_example(
+                "Every style in the type ramp. Sizes follow the text size setting.",
+                Column::with_children(rows).spacing(4),
+                Some(text::caption1(format!("Text size {}%", text::scale()))),
+                Some(
+                    text_input::standard("Sample text", &self.sample)
+                        .on_input(Message::SampleChanged)
+                        .width(200),
+                ),
+            )],
+        );
+
+        scrollable::standard(Container::new(content).padding(Padding::ZERO.right(16)))
+            .height(Length::Fill)
+            .into()
+    }
+}
@@ -1,16 +1,26 @@
+pub mod audit;
+pub mod breadcrumb_bar;
 pub mod button;
+pub mod calendar_view;
 pub mod checkbox;
 pub mod combo_box;
 pub mod container;
+pub mod data_grid;
+pub mod info_bar;
+pub mod list_item;
 pub mod menu;
 pub mod menu_bar;
 pub mod number_input;
 pub mod pick_list;
+pub mod progress;
 pub mod quad;
 pub mod radio;
 pub mod scrollable;
 pub mod slider;
+pub mod split_view;
 pub mod svg;
+pub mod tab_view;
 pub mod text_input;
+pub mod time_picker;
 pub mod toggler;
-pub mod underline;
\ No newline at end of file
+pub mod underline;
@@ -0,0 +1,714 @@
+// Checks the colour pairs produced by the style functions against WCAG 2.x:
+// https://www.w3.org/TR/WCAG21/#contrast-minimum
+// https://www.w3.org/TR/WCAG21/#non-text-contrast
+//
+// Every style function is called for each of its statuses. Translucent fills are
+// flattened onto the layer the widget normally sits on before comparing, and
+// translucent foregrounds onto the flattened fill.
+use crate::{
+    style,
+    theme::{
+        accent,
+        contrast::{self, over},
+        high_contrast::Contrast,
+        Appearance, Builder, Palette, Theme,
+    },
+    widget::{info_bar::Severity, progress::Status as Progress},
+};
+
+use iced::{
+    widget::{
+        button, checkbox, container, pick_list, radio, scrollable, slider, text_input, toggler,
+    },
+    Background, Color,
+};
+
+use std::{fmt::Debug, sync::Arc};
+
+// WCAG exempts inactive controls, they are still held to 3:1 so disabled
+// states stay legible
+const DISABLED: f32 = contrast::AA_LARGE;
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+enum Role {
+    Text,
+    Graphic,
+}
+
+impl Role {
+    fn target(self, is_disabled: bool) -> f32 {
+        match self {
+            _ if is_disabled => DISABLED,
+            Role::Text => contrast::AA,
+            Role::Graphic => contrast::AA_LARGE,
+        }
+    }
+}
+
+#[derive(Clone, Debug)]
+pub struct Check {
+    pub style: &'static str,
+    pub status: String,
+    pub part: &'static str,
+    pub foreground: Color,
+    pub background: Color,
+    pub ratio: f32,
+    pub target: f32,
+}
+
+impl Check {
+    pub fn passes(&self) -> bool {
+        self.ratio >= self.target
+    }
+}
+
+// Every theme the gallery ships. The purple accent swatch stands in for the
+// palettes `Builder` derives from an accent colour.
+pub fn themes() -> Vec<Theme> {
+    let accented = |palette: Palette| {
+        Theme::custom(Arc::new(
+            Builder::new(palette).accent(accent::SWATCHES[4]).build(),
+        ))
+    };
+
+    [Appearance::Light, Appearance::Dark]
+        .into_iter()
+        .chain(Contrast::ALL.map(Appearance::HighContrast))
+        .map(Theme::from)
+        .chain([accented(Palette::LIGHT), accented(Palette::DARK)])
+        .collect()
+}
+
+pub fn audit(theme: &Theme) -> Vec<Check> {
+    let palette = theme.palette();
+    let window = palette.solid_background_fill_color_base;
+
+    // Pages are drawn on a layer above the window, flyouts on their own surface
+    let page = over(palette.solid_background_fill_color_tertiary, window);
+    let flyout = over(palette.acrylic_in_app_fill_color_default_fallback, window);
+
+    // Widgets without a text colour inherit the application's
+    let inherited = |colour: Option<Color>| colour.unwrap_or(palette.text_fill_color_primary);
+
+    let mut checks = Vec::new();
+
+    for (name, style, layer) in [
+        ("button::primary", style::button::primary as ButtonFn, page),
+        ("button::secondary", style::button::secondary, page),
+        ("button::transparent", style::button::transparent, page),
+        ("button::hyperlink", style::button::hyperlink, page),
+        ("button::flyout", style::button::flyout, flyout),
+        ("button::menu_item", style::button::menu_item, flyout),
+        ("button::nav_item", style::button::nav_item, window),
+        ("button::split_content", style::button::split_content, page),
+        (
+            "button::split_indicator",
+            style::button::split_indicator,
+            page,
+        ),
+    ] {
+        for status in BUTTON {
+            let s = style(theme, status);
+            let mut scope = Scope::new(&mut checks, name, &status);
+            scope.is_disabled = status == button::Status::Disabled;
+
+            scope.text("text", s.text_color, fill(s.background, layer));
+        }
+    }
+
+    for status in CHECKBOX {
+        let s = style::checkbox::primary(theme, status);
+        let mut scope = Scope::new(&mut checks, "checkbox::primary", &status);
+        let is_checked = match status {
+            checkbox::Status::Active { is_checked } | checkbox::Status::Hovered { is_checked } => {
+                is_checked
+            }
+            checkbox::Status::Disabled { is_checked } => {
+                scope.is_disabled = true;
+                is_checked
+            }
+        };
+
+        scope.text("label", inherited(s.text_color), page);
+
+        if is_checked {
+            scope.graphic("check", s.icon_color, fill(Some(s.background), page));
+        } else {
+            scope.graphic("box", s.border.color, page);
+        }
+    }
+
+    for status in RADIO {
+        let s = style::radio::default(theme, status);
+        let mut scope = Scope::new(&mut checks, "radio::default", &status);
+
+        scope.text("label", inherited(s.text_color), page);
+
+        match status {
+            radio::Status::Active { is_selected: true }
+            | radio::Status::Hovered { is_selected: true } => {
+                scope.graphic("dot", s.dot_color, fill(Some(s.background), page))
+            }
+            _ => scope.graphic("circle", s.border_color, page),
+        }
+    }
+
+    for status in TOGGLER {
+        let s = style::toggler::default(theme, status);
+        let mut scope = Scope::new(&mut checks, "toggler::default", &status);
+        scope.is_disabled = status == toggler::Status::Disabled;
+
+        scope.graphic("knob", s.foreground, fill(Some(s.background.into()), page));
+
+        // When on the filled track is what identifies the control
+        if !matches!(
+            status,
+            toggler::Status::Active { is_toggled: true }
+                | toggler::Status::Hovered { is_toggled: true }
+        ) {
+            scope.graphic("track", s.background_border_color, page);
+        }
+    }
+
+    for status in TEXT_INPUT {
+        let s = style::text_input::default(theme, status);
+        let background = fill(Some(s.background), page);
+        let mut scope = Scope::new(&mut checks, "text_input::default", &status);
+        scope.is_disabled = status == text_input::Status::Disabled;
+
+        scope.text("value", s.value, background);
+        scope.text("placeholder", s.placeholder, background);
+
+        if status == text_input::Status::Focused {
+            let underline = style::underline::default(theme, status);
+
+            Scope::new(&mut checks, "underline::default", &status).graphic(
+                "underline",
+                underline.colour,
+                page,
+            );
+        }
+    }
+
+    for status in PICK_LIST {
+        let s = style::pick_list::default(theme, status);
+        let background = fill(Some(s.background), page);
+        let mut scope = Scope::new(&mut checks, "pick_list::default", &status);
+
+        scope.text("text", s.text_color, background);
+        scope.text("placeholder", s.placeholder_color, background);
+        scope.graphic("chevron", s.handle_color, background);
+    }
+
+    for status in SLIDER {
+        let s = style::slider::default(theme, status);
+
+        if let (Background::Color(colour), _) = s.rail.backgrounds {
+            Scope::new(&mut checks, "slider::default", &status).graphic("rail", colour, page);
+        }
+    }
+
+    let menu = style::menu::default(theme);
+    let background = fill(Some(menu.background), window);
+    let mut scope = Scope::new(&mut checks, "menu::default", &"Active");
+
+    scope.text("text", menu.text_color, background);
+    scope.text(
+        "selected text",
+        menu.selected_text_color,
+        fill(Some(menu.selected_background), background),
+    );
+
+    for (name, style) in [
+        ("container::card", style::container::card as ContainerFn),
+        ("container::overlay", style::container::overlay),
+        ("container::dialog", style::container::dialog),
+    ] {
+        let s = style(theme);
+
+        Scope::new(&mut checks, name, &"Active").text(
+            "text",
+            inherited(s.text_color),
+            fill(s.background, page),
+        );
+    }
+
+    for status in ICED_AW {
+        let s = style::number_input::default(theme, status);
+        let mut scope = Scope::new(&mut checks, "number_input::default", &status);
+        scope.is_disabled = status == iced_aw::style::Status::Disabled;
+
+        scope.graphic("icon", s.icon_color, fill(s.button_background, page));
+    }
+
+    for status in SCROLLABLE {
+        let s = style::scrollable::default(theme, status);
+        let rail = fill(s.vertical_rail.background, page);
+
+        Scope::new(&mut checks, "scrollable::default", &status).graphic(
+            "scroller",
+            s.vertical_rail.scroller.color,
+            rail,
+        );
+    }
+
+    for status in ICED_AW {
+        let s = style::menu_bar::default(theme, status);
+        let bar = fill(Some(s.bar_background), window);
+        let mut scope = Scope::new(&mut checks, "menu_bar::default", &status);
+        scope.is_disabled = status == iced_aw::style::Status::Disabled;
+
+        // The bar's items are menu item buttons drawn over it
+        let item = match status {
+            iced_aw::style::Status::Disabled => button::Status::Disabled,
+            _ => button::Status::Active,
+        };
+
+        scope.text(
+            "text",
+            style::button::menu_item(theme, item).text_color,
+            bar,
+        );
+
+        if s.bar_border.width > 0.0 {
+            scope.graphic("border", s.bar_border.color, window);
+        }
+    }
+
+    for (name, style) in [
+        (
+            "split_view::default",
+            style::split_view::default as SplitViewFn,
+        ),
+        ("split_view::divider", style::split_view::divider),
+    ] {
+        for status in SPLIT_VIEW {
+            let s = style(theme, status);
+
+            Scope::new(&mut checks, name, &status).graphic("handle", s.handle_colour, page);
+        }
+    }
+
+    if let Some(colour) = style::svg::icon(theme, iced::widget::svg::Status::Idle).color {
+        Scope::new(&mut checks, "svg::icon", &"Idle").graphic("icon", colour, page);
+    }
+
+    for severity in Severity::ALL {
+        let s = style::info_bar::container(theme, severity);
+        let background = fill(s.background, page);
+        let circle = over(style::info_bar::icon(theme, severity), background);
+        let mut scope = Scope::new(&mut checks, "info_bar::container", &severity);
+
+        scope.text("text", inherited(s.text_color), background);
+        scope.graphic("icon", circle, background);
+        scope.graphic("glyph", style::info_bar::icon_glyph(theme), circle);
+    }
+
+    for status in Progress::ALL {
+        let s = style::progress::default(theme, status);
+        let mut scope = Scope::new(&mut checks, "progress::default", &status);
+
+        scope.graphic("indicator", s.indicator, page);
+    }
+
+    for status in TAB_VIEW {
+        let s = style::tab_view::default(theme, status);
+
+        // The selected tab is drawn on the page, the others on the window
+        let layer = match status {
+            style::tab_view::Status::Selected => page,
+            _ => window,
+        };
+
+        Scope::new(&mut checks, "tab_view::default", &status).text(
+            "text",
+            s.text_color,
+            over(s.background, layer),
+        );
+    }
+
+    for status in DATA_GRID {
+        let s = style::data_grid::default(theme, status);
+        let background = over(s.background, page);
+        let row = over(s.row_background, background);
+        let alternate = over(s.row_background, over(s.alternate_background, background));
+        let header = over(s.header_background, background);
+        let mut scope = Scope::new(&mut checks, "data_grid::default", &status);
+
+        scope.text("text", s.text_color, row);
+        scope.text("alternate text", s.text_color, alternate);
+        scope.text("header", s.header_text_color, header);
+        scope.text(
+            "hovered header",
+            s.header_hovered_text_color,
+            over(s.header_hovered_background, background),
+        );
+        scope.graphic("focus", s.focus, row);
+    }
+
+    for status in CALENDAR_VIEW {
+        let s = style::calendar_view::default(theme, status);
+        let background = over(s.background, flyout);
+        let item = over(s.item_background, background);
+        let today = over(s.today_background, background);
+        let mut scope = Scope::new(&mut checks, "calendar_view::default", &status);
+        scope.is_disabled = status == style::calendar_view::Status::Disabled;
+
+        scope.text("header", s.header_text_color, background);
+        scope.text("weekday", s.weekday_text_color, background);
+        scope.text("text", s.text_color, item);
+        scope.text("out of scope text", s.out_of_scope_text_color, item);
+        scope.text("today", s.today_text_color, today);
+        scope.graphic("selected", s.selected_border_color, item);
+        scope.graphic("focus", s.focus, background);
+    }
+
+    for status in TIME_PICKER {
+        let s = style::time_picker::default(theme, status);
+        let field = over(s.background, page);
+        let highlight = over(s.highlight_background, flyout);
+        let mut scope = Scope::new(&mut checks, "time_picker::default", &status);
+
+        scope.text("text", s.text_color, field);
+        scope.text("placeholder", s.placeholder_color, field);
+        scope.text("item", s.text_color, over(s.item_background, flyout));
+        scope.text("highlighted item", s.highlight_text_color, highlight);
+        scope.graphic("focus", s.focus, field);
+    }
+
+    for status in BREADCRUMB_BAR {
+        let s = style::breadcrumb_bar::default(theme, status);
+
+        Scope::new(&mut checks, "breadcrumb_bar::default", &status).text(
+            "text",
+            s.text_color,
+            over(s.background, page),
+        );
+    }
+
+    for status in LIST_ITEM {
+        let s = style::list_item::default(theme, status);
+        let background = over(s.background, page);
+        let mut scope = Scope::new(&mut checks, "list_item::default", &status);
+
+        scope.text("text", s.text_color, background);
+        scope.graphic("focus", s.focus, background);
+
+        if matches!(
+            status,
+            style::list_item::Status::Selected | style::list_item::Status::SelectedHovered
+        ) {
+            scope.graphic("indicator", s.indicator, background);
+        }
+    }
+
+    checks
+}
+
+type ButtonFn = fn(&Theme, button::Status) -> button::Style;
+type ContainerFn = fn(&Theme) -> container::Style;
+type SplitViewFn = fn(&Theme, style::split_view::Status) -> style::split_view::Style;
+
+const BUTTON: [button::Status; 4] = [
+    button::Status::Active,
+    button::Status::Hovered,
+    button::Status::Pressed,
+    button::Status::Disabled,
+];
+
+const CHECKBOX: [checkbox::Status; 6] = [
+    checkbox::Status::Active { is_checked: false },
+    checkbox::Status::Active { is_checked: true },
+    checkbox::Status::Hovered { is_checked: false },
+    checkbox::Status::Hovered { is_checked: true },
+    checkbox::Status::Disabled { is_checked: false },
+    checkbox::Status::Disabled { is_checked: true },
+];
+
+const RADIO: [radio::Status; 4] = [
+    radio::Status::Active { is_selected: false },
+    radio::Status::Active { is_selected: true },
+    radio::Status::Hovered { is_selected: false },
+    radio::Status::Hovered { is_selected: true },
+];
+
+const TOGGLER: [toggler::Status; 5] = [
+    toggler::Status::Active { is_toggled: false },
+    toggler::Status::Active { is_toggled: true },
+    toggler::Status::Hovered { is_toggled: false },
+    toggler::Status::Hovered { is_toggled: true },
+    toggler::Status::Disabled,
+];
+
+const TEXT_INPUT: [text_input::Status; 4] = [
+    text_input::Status::Active,
+    text_input::Status::Hovered,
+    text_input::Status::Focused,
+    text_input::Status::Disabled,
+];
+
+const PICK_LIST: [pick_list::Status; 3] = [
+    pick_list::Status::Active,
+    pick_list::Status::Hovered,
+    pick_list::Status::Opened,
+];
+
+const SLIDER: [slider::Status; 3] = [
+    slider::Status::Active,
+    slider::Status::Hovered,
+    slider::Status::Dragged,
+];
+
+const ICED_AW: [iced_aw::style::Status; 6] = [
+    iced_aw::style::Status::Active,
+    iced_aw::style::Status::Hovered,
+    iced_aw::style::Status::Pressed,
+    iced_aw::style::Status::Disabled,
+    iced_aw::style::Status::Focused,
+    iced_aw::style::Status::Selected,
+];
+
+const SCROLLABLE: [scrollable::Status; 3] = [
+    scrollable::Status::Active,
+    scrollable::Status::Hovered {
+        is_horizontal_scrollbar_hovered: false,
+        is_vertical_scrollbar_hovered: true,
+    },
+    scrollable::Status::Dragged {
+        is_horizontal_scrollbar_dragged: false,
+        is_vertical_scrollbar_dragged: true,
+    },
+];
+
+const SPLIT_VIEW: [style::split_view::Status; 3] = [
+    style::split_view::Status::Active,
+    style::split_view::Status::Hovered,
+    style::split_view::Status::Dragged,
+];
+
+const TAB_VIEW: [style::tab_view::Status; 4] = [
+    style::tab_view::Status::Active,
+    style::tab_view::Status::Hovered,
+    style::tab_view::Status::Pressed,
+    style::tab_view::Status::Selected,
+];
+
+const DATA_GRID: [style::data_grid::Status; 4] = [
+    style::data_grid::Status::Active,
+    style::data_grid::Status::Hovered,
+    style::data_grid::Status::Selected,
+    style::data_grid::Status::SelectedHovered,
+];
+
+const CALENDAR_VIEW: [style::calendar_view::Status; 4] = [
+    style::calendar_view::Status::Active,
+    style::calendar_view::Status::Hovered,
+    style::calendar_view::Status::Pressed,
+    style::calendar_view::Status::Disabled,
+];
+
+const TIME_PICKER: [style::time_picker::Status; 3] = [
+    style::time_picker::Status::Active,
+    style::time_picker::Status::Hovered,
+    style::time_picker::Status::Pressed,
+];
+
+const BREADCRUMB_BAR: [style::breadcrumb_bar::Status; 4] = [
+    style::breadcrumb_bar::Status::Active,
+    style::breadcrumb_bar::Status::Hovered,
+    style::breadcrumb_bar::Status::Pressed,
+    style::breadcrumb_bar::Status::Current,
+];
+
+const LIST_ITEM: [style::list_item::Status; 5] = [
+    style::list_item::Status::Active,
+    style::list_item::Status::Hovered,
+    style::list_item::Status::Pressed,
+    style::list_item::Status::Selected,
+    style::list_item::Status::SelectedHovered,
+];
+
+// Gradients aren't used by any style so they are treated as see-through
+fn fill(background: Option<Background>, layer: Color) -> Color {
+    match background {
+        Some(Background::Color(colour)) => over(colour, layer),
+        _ => layer,
+    }
+}
+
+// Collects the checks for one style function in one status
+struct Scope<'a> {
+    checks: &'a mut Vec<Check>,
+    style: &'static str,
+    status: String,
+    is_disabled: bool,
+}
+
+impl<'a> Scope<'a> {
+    fn new(checks: &'a mut Vec<Check>, style: &'static str, status: &dyn Debug) -> Self {
+        Self {
+            checks,
+            style,
+            status: format!("{status:?}").replace('"', ""),
+            is_disabled: false,
+        }
+    }
+
+    fn text(&mut self, part: &'static str, foreground: Color, background: Color) {
+        self.push(part, Role::Text, foreground, background);
+    }
+
+    fn graphic(&mut self, part: &'static str, foreground: Color, background: Color) {
+        self.push(part, Role::Graphic, foreground, background);
+    }
+
+    fn push(&mut self, part: &'static str, role: Role, foreground: Color, background: Color) {
+        // Fully transparent parts aren't drawn
+        if foreground.a == 0.0 {
+            return;
+        }
+
+        let foreground = over(foreground, background);
+
+        self.checks.push(Check {
+            style: self.style,
+            status: self.status.clone(),
+            part,
+            foreground,
+            background,
+            ratio: contrast::ratio(foreground, background),
+            target: role.target(self.is_disabled),
+        });
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn failures(theme: &Theme) -> Vec<Check> {
+        audit(theme)
+            .into_iter()
+            .filter(|check| !check.passes())
+            .collect()
+    }
+
+    // Pairs that fall short in WinUI's own resources and are kept as they are. The
+    // disabled states are exempt from WCAG, pressed text is only seen for the
+    // length of a click and the pane divider is decorative.
+    const KNOWN_FAILURES: &[(&str, &str, &str)] = &[
+        ("button::primary", "Pressed", "text"),
+        ("button::primary", "Disabled", "text"),
+        ("button::secondary", "Disabled", "text"),
+        ("button::transparent", "Disabled", "text"),
+        ("button::hyperlink", "Disabled", "text"),
+        ("button::flyout", "Disabled", "text"),
+        ("button::nav_item", "Disabled", "text"),
+        ("button::split_content", "Disabled", "text"),
+        ("button::split_indicator", "Disabled", "text"),
+        (
+            "checkbox::primary",
+            "Disabled { is_checked: false }",
+            "label",
+        ),
+        ("checkbox::primary", "Disabled { is_checked: false }", "box"),
+        (
+            "checkbox::primary",
+            "Disabled { is_checked: true }",
+            "label",
+        ),
+        (
+            "checkbox::primary",
+            "Disabled { is_checked: true }",
+            "check",
+        ),
+        ("toggler::default", "Disabled", "knob"),
+        ("toggler::default", "Disabled", "track"),
+        ("text_input::default", "Disabled", "value"),
+        ("text_input::default", "Disabled", "placeholder"),
+        ("split_view::divider", "Active", "handle"),
+        ("calendar_view::default", "Pressed", "today"),
+        ("calendar_view::default", "Disabled", "text"),
+        ("calendar_view::default", "Disabled", "out of scope text"),
+        ("breadcrumb_bar::default", "Pressed", "text"),
+    ];
+
+    // Style modules left out of the audit. Anything else under src/style has to
+    // show up in it.
+    const UNAUDITED: &[&str] = &[
+        "audit",
+        // Only implements the catalog, iced draws it with the text input and menu
+        "combo_box",
+        // Separators are decorative
+        "quad",
+    ];
+
+    fn is_known(check: &Check) -> bool {
+        KNOWN_FAILURES.iter().any(|(style, status, part)| {
+            check.style == *style && check.status == *status && check.part == *part
+        })
+    }
+
+    #[test]
+    fn themes_meet_their_targets() {
+        for theme in themes() {
+            let unexpected: Vec<String> = failures(&theme)
+                .iter()
+                .filter(|check| !is_known(check))
+                .map(|check| {
+                    format!(
+                        "{} {} {} {:.2}:1 (needs {}:1)",
+                        check.style, check.status, check.part, check.ratio, check.target
+                    )
+                })
+                .collect();
+
+            assert!(unexpected.is_empty(), "{theme}:\n{}", unexpected.join("\n"));
+        }
+    }
+
+    // Keeps the allow-list from outliving the failures it was written for
+    #[test]
+    fn known_failures_still_fail() {
+        for (style, status, part) in KNOWN_FAILURES {
+            let fails = themes().iter().any(|theme| {
+                failures(theme).iter().any(|check| {
+                    check.style == *style && check.status == *status && check.part == *part
+                })
+            });
+
+            assert!(fails, "{style} {status} {part} passes everywhere");
+        }
+    }
+
+    #[test]
+    fn high_contrast_themes_all_pass() {
+        for contrast in Contrast::ALL {
+            let theme = Theme::from(Appearance::HighContrast(contrast));
+
+            assert!(failures(&theme).is_empty(), "{theme} has failing pairs");
+        }
+    }
+
+    #[test]
+    fn every_style_module_is_audited() {
+        let checks: Vec<Check> = themes().iter().flat_map(audit).collect();
+        let directory = concat!(env!("CARGO_MANIFEST_DIR"), "/src/style");
+
+        for entry in std::fs::read_dir(directory).unwrap() {
+            let path = entry.unwrap().path();
+            let module = path.file_stem().unwrap().to_str().unwrap();
+
+            if UNAUDITED.contains(&module) {
+                continue;
+            }
+
+            let prefix = format!("{module}::");
+
+            assert!(
+                checks.iter().any(|check| check.style.starts_with(&prefix)),
+                "style::{module} is not audited"
+            );
+        }
+    }
+}
@@ -0,0 +1,66 @@
+use crate::theme::Theme;
+
+use iced::Color;
+
+// BreadcrumbBar resources from
+// https://github.com/microsoft/microsoft-ui-xaml/blob/main/src/controls/dev/Breadcrumb/BreadcrumbBar_themeresources.xaml
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum Status {
+    Active,
+    Hovered,
+    Pressed,
+    // The last item, the page the user is on
+    Current,
+}
+
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct Style {
+    pub text_color: Color,
+    // Behind a separator or the ellipsis while their menu is hovered or open
+    pub background: Color,
+}
+
+pub type StyleFn = Box<dyn Fn(&Theme, Status) -> Style>;
+
+// Items are plain text that only change colour, the current one stands out in the
+// primary text colour
+pub fn default(theme: &Theme, status: Status) -> Style {
+    if let Some(colours) = theme.high_contrast() {
+        let (text_color, background) = match status {
+            Status::Active | Status::Current => (colours.window_text, Color::TRANSPARENT),
+            Status::Hovered | Status::Pressed => (colours.highlight_text, colours.highlight),
+        };
+
+        return Style {
+            text_color,
+            background,
+        };
+    }
+
+    let palette = theme.palette();
+
+    let (text_color, background) = match status {
+        Status::Active => (
+            palette.text_fill_color_secondary,
+            palette.subtle_fill_color_transparent,
+        ),
+        Status::Hovered => (
+            palette.text_fill_color_primary,
+            palette.subtle_fill_color_secondary,
+        ),
+        Status::Pressed => (
+            palette.text_fill_color_tertiary,
+            palette.subtle_fill_color_tertiary,
+        ),
+        Status::Current => (
+            palette.text_fill_color_primary,
+            palette.subtle_fill_color_transparent,
+        ),
+    };
+
+    Style {
+        text_color,
+        background,
+    }
+}
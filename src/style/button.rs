@@ -1,22 +1,26 @@
-use crate::theme::Theme;
+use crate::theme::{class, Theme};
 
 use iced::widget::button::{self, Status, Style, StyleFn};
 use iced::Color;
 use iced::{border::Radius, Border, Shadow};
 
 impl button::Catalog for Theme {
-    type Class<'a> = StyleFn<'a, Self>;
+    type Class<'a> = class::Class<StyleFn<'a, Self>>;
 
     fn default<'a>() -> Self::Class<'a> {
-        Box::new(secondary)
+        class::DEFAULT_BUTTON.into()
     }
 
     fn style(&self, class: &Self::Class<'_>, status: Status) -> Style {
-        class(self, status)
+        class.resolve(self, status)
     }
 }
 
 pub fn primary(theme: &Theme, status: Status) -> Style {
+    if let Some(colours) = theme.high_contrast() {
+        return high_contrast::accent(colours, status);
+    }
+
     let palette = theme.palette();
     let base = Style {
         background: Some(palette.accent_fill_color_default.into()),
@@ -48,6 +52,10 @@ pub fn primary(theme: &Theme, status: Status) -> Style {
 }
 
 pub fn secondary(theme: &Theme, status: Status) -> Style {
+    if let Some(colours) = theme.high_contrast() {
+        return high_contrast::standard(colours, status);
+    }
+
     let palette = theme.palette();
     let base = Style {
         background: Some(palette.control_fill_color_default.into()),
@@ -77,7 +85,10 @@ pub fn secondary(theme: &Theme, status: Status) -> Style {
 }
 
 pub fn split_content(theme: &Theme, status: Status) -> Style {
-    let base = secondary(theme, status);
+    let base = match theme.high_contrast() {
+        Some(colours) => high_contrast::standard(colours, status),
+        None => secondary(theme, status),
+    };
 
     Style {
         border: Border {
@@ -89,7 +100,10 @@ pub fn split_content(theme: &Theme, status: Status) -> Style {
 }
 
 pub fn split_indicator(theme: &Theme, status: Status) -> Style {
-    let base = secondary(theme, status);
+    let base = match theme.high_contrast() {
+        Some(colours) => high_contrast::standard(colours, status),
+        None => secondary(theme, status),
+    };
 
     Style {
         border: Border {
@@ -101,6 +115,10 @@ pub fn split_indicator(theme: &Theme, status: Status) -> Style {
 }
 
 pub fn transparent(theme: &Theme, status: Status) -> Style {
+    if let Some(colours) = theme.high_contrast() {
+        return high_contrast::subtle(colours, status);
+    }
+
     let palette = theme.palette();
     let base = Style {
         background: Some(palette.subtle_fill_color_transparent.into()),
@@ -131,7 +149,31 @@ pub fn transparent(theme: &Theme, status: Status) -> Style {
     }
 }
 
+// HyperlinkButton, a transparent button with accent coloured text
+pub fn hyperlink(theme: &Theme, status: Status) -> Style {
+    if let Some(colours) = theme.high_contrast() {
+        return high_contrast::hyperlink(colours, status);
+    }
+
+    let palette = theme.palette();
+    let base = transparent(theme, status);
+
+    Style {
+        text_color: match status {
+            Status::Active => palette.accent_text_fill_color_primary,
+            Status::Hovered => palette.accent_text_fill_color_secondary,
+            Status::Pressed => palette.accent_text_fill_color_tertiary,
+            Status::Disabled => palette.accent_text_fill_color_disabled,
+        },
+        ..base
+    }
+}
+
 pub fn flyout(theme: &Theme, status: Status) -> Style {
+    if let Some(colours) = theme.high_contrast() {
+        return high_contrast::subtle(colours, status);
+    }
+
     let palette = theme.palette();
     let base = secondary(theme, status);
 
@@ -146,6 +188,10 @@ pub fn flyout(theme: &Theme, status: Status) -> Style {
 }
 
 pub fn menu_item(theme: &Theme, status: Status) -> Style {
+    if let Some(colours) = theme.high_contrast() {
+        return high_contrast::subtle(colours, status);
+    }
+
     let palette = theme.palette();
     let base = Style {
         background: Some(palette.subtle_fill_color_transparent.into()),
@@ -176,6 +222,10 @@ pub fn menu_item(theme: &Theme, status: Status) -> Style {
 }
 
 pub fn nav_item(theme: &Theme, status: Status) -> Style {
+    if let Some(colours) = theme.high_contrast() {
+        return high_contrast::subtle(colours, status);
+    }
+
     let palette = theme.palette();
 
     let background = match status {
@@ -199,3 +249,73 @@ pub fn nav_item(theme: &Theme, status: Status) -> Style {
         shadow: Shadow::default(),
     }
 }
+
+// Solid, outlined variants used by the contrast themes. Hovered and pressed controls
+// swap to the highlight colours as in WinUI's HighContrast resources.
+pub mod high_contrast {
+    use crate::theme::high_contrast::SystemColors;
+
+    use iced::widget::button::{Status, Style};
+    use iced::{border::Radius, Border, Color, Shadow};
+
+    pub const BORDER_WIDTH: f32 = 2.0;
+
+    fn style(background: Color, text_color: Color, border_color: Color) -> Style {
+        Style {
+            background: Some(background.into()),
+            text_color,
+            border: Border {
+                color: border_color,
+                radius: Radius::new(4),
+                width: BORDER_WIDTH,
+            },
+            shadow: Shadow::default(),
+        }
+    }
+
+    pub fn standard(colours: &SystemColors, status: Status) -> Style {
+        match status {
+            Status::Active => style(
+                colours.button_face,
+                colours.button_text,
+                colours.button_text,
+            ),
+            Status::Hovered => style(colours.highlight, colours.highlight_text, colours.highlight),
+            Status::Pressed => style(colours.highlight_text, colours.highlight, colours.highlight),
+            Status::Disabled => style(colours.button_face, colours.gray_text, colours.gray_text),
+        }
+    }
+
+    pub fn accent(colours: &SystemColors, status: Status) -> Style {
+        match status {
+            Status::Active => style(colours.highlight, colours.highlight_text, colours.highlight),
+            Status::Hovered | Status::Pressed => {
+                style(colours.highlight_text, colours.highlight, colours.highlight)
+            }
+            Status::Disabled => style(colours.button_face, colours.gray_text, colours.gray_text),
+        }
+    }
+
+    pub fn hyperlink(colours: &SystemColors, status: Status) -> Style {
+        let transparent = Color::TRANSPARENT;
+
+        match status {
+            Status::Active => style(transparent, colours.hotlight, transparent),
+            Status::Hovered | Status::Pressed => {
+                style(colours.highlight, colours.highlight_text, colours.highlight)
+            }
+            Status::Disabled => style(transparent, colours.gray_text, transparent),
+        }
+    }
+
+    pub fn subtle(colours: &SystemColors, status: Status) -> Style {
+        let transparent = Color::TRANSPARENT;
+
+        match status {
+            Status::Active => style(transparent, colours.button_text, transparent),
+            Status::Hovered => style(colours.highlight, colours.highlight_text, colours.highlight),
+            Status::Pressed => style(colours.highlight_text, colours.highlight, colours.highlight),
+            Status::Disabled => style(transparent, colours.gray_text, transparent),
+        }
+    }
+}
@@ -0,0 +1,106 @@
+use crate::theme::Theme;
+
+use iced::Color;
+
+// CalendarView resources from
+// https://github.com/microsoft/microsoft-ui-xaml/blob/main/src/controls/dev/CommonStyles/CalendarView_themeresources.xaml
+
+// The status of a day, month or year in the calendar, and of the header and
+// navigation buttons
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum Status {
+    Active,
+    Hovered,
+    Pressed,
+    // Outside the minimum and maximum dates, or a blackout date
+    Disabled,
+}
+
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct Style {
+    pub background: Color,
+    pub border_color: Color,
+    pub header_text_color: Color,
+    pub weekday_text_color: Color,
+    // Behind an item or button for its status
+    pub item_background: Color,
+    pub text_color: Color,
+    // Days of the months either side of the one shown, and so on in the year and
+    // decade views
+    pub out_of_scope_text_color: Color,
+    pub today_background: Color,
+    pub today_text_color: Color,
+    pub selected_border_color: Color,
+    pub focus: Color,
+}
+
+pub type StyleFn = Box<dyn Fn(&Theme, Status) -> Style>;
+
+pub fn default(theme: &Theme, status: Status) -> Style {
+    if let Some(colours) = theme.high_contrast() {
+        let (item_background, text_color) = match status {
+            Status::Active => (Color::TRANSPARENT, colours.window_text),
+            Status::Hovered | Status::Pressed => (colours.highlight, colours.highlight_text),
+            Status::Disabled => (Color::TRANSPARENT, colours.gray_text),
+        };
+
+        return Style {
+            background: colours.window,
+            border_color: colours.window_text,
+            header_text_color: colours.window_text,
+            weekday_text_color: colours.window_text,
+            item_background,
+            text_color,
+            out_of_scope_text_color: text_color,
+            today_background: colours.highlight,
+            today_text_color: colours.highlight_text,
+            selected_border_color: match status {
+                Status::Hovered | Status::Pressed => colours.highlight_text,
+                Status::Active | Status::Disabled => colours.highlight,
+            },
+            focus: colours.window_text,
+        };
+    }
+
+    let palette = theme.palette();
+
+    let (item_background, today_background) = match status {
+        Status::Active | Status::Disabled => (
+            palette.subtle_fill_color_transparent,
+            palette.accent_fill_color_default,
+        ),
+        Status::Hovered => (
+            palette.subtle_fill_color_secondary,
+            palette.accent_fill_color_secondary,
+        ),
+        Status::Pressed => (
+            palette.subtle_fill_color_tertiary,
+            palette.accent_fill_color_tertiary,
+        ),
+    };
+
+    let (text_color, out_of_scope_text_color) = match status {
+        Status::Disabled => (
+            palette.text_fill_color_disabled,
+            palette.text_fill_color_disabled,
+        ),
+        _ => (
+            palette.text_fill_color_primary,
+            palette.text_fill_color_secondary,
+        ),
+    };
+
+    Style {
+        background: palette.card_background_fill_color_default,
+        border_color: palette.card_stroke_color_default,
+        header_text_color: palette.text_fill_color_primary,
+        weekday_text_color: palette.text_fill_color_primary,
+        item_background,
+        text_color,
+        out_of_scope_text_color,
+        today_background,
+        today_text_color: palette.text_on_accent_fill_color_primary,
+        selected_border_color: palette.accent_fill_color_default,
+        focus: palette.focus_stroke_color_outer,
+    }
+}
@@ -1,4 +1,4 @@
-use crate::theme::Theme;
+use crate::theme::{class, Theme};
 
 use iced::{
     border::Radius,
@@ -9,18 +9,22 @@ use iced::{
 pub type StyleFn<'a, Theme> = Box<dyn Fn(&Theme, Status) -> Style + 'a>;
 
 impl Catalog for Theme {
-    type Class<'a> = StyleFn<'a, Self>;
+    type Class<'a> = class::Class<StyleFn<'a, Self>>;
 
     fn default<'a>() -> Self::Class<'a> {
-        Box::new(primary)
+        class::DEFAULT_CHECKBOX.into()
     }
 
     fn style(&self, class: &Self::Class<'_>, status: Status) -> Style {
-        class(self, status)
+        class.resolve(self, status)
     }
 }
 
 pub fn primary(theme: &Theme, status: Status) -> Style {
+    if let Some(colours) = theme.high_contrast() {
+        return high_contrast::primary(colours, status);
+    }
+
     let palette = theme.palette();
 
     match status {
@@ -76,3 +80,63 @@ pub fn primary(theme: &Theme, status: Status) -> Style {
         },
     }
 }
+
+pub mod high_contrast {
+    use crate::theme::high_contrast::SystemColors;
+
+    use iced::{
+        border::Radius,
+        widget::checkbox::{Status, Style},
+        Border,
+    };
+
+    pub fn primary(colours: &SystemColors, status: Status) -> Style {
+        let (background, icon_color, border_color, text_color) = match status {
+            Status::Active { is_checked: true } => (
+                colours.highlight,
+                colours.highlight_text,
+                colours.highlight,
+                colours.window_text,
+            ),
+            Status::Active { is_checked: false } => (
+                colours.window,
+                colours.highlight_text,
+                colours.window_text,
+                colours.window_text,
+            ),
+            Status::Hovered { is_checked: true } => (
+                colours.highlight_text,
+                colours.highlight,
+                colours.highlight,
+                colours.window_text,
+            ),
+            Status::Hovered { is_checked: false } => (
+                colours.window,
+                colours.highlight_text,
+                colours.highlight,
+                colours.window_text,
+            ),
+            Status::Disabled { is_checked } => (
+                if is_checked {
+                    colours.gray_text
+                } else {
+                    colours.window
+                },
+                colours.window,
+                colours.gray_text,
+                colours.gray_text,
+            ),
+        };
+
+        Style {
+            background: background.into(),
+            icon_color,
+            border: Border {
+                color: border_color,
+                radius: Radius::new(4),
+                width: 2.0,
+            },
+            text_color: Some(text_color),
+        }
+    }
+}
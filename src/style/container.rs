@@ -1,4 +1,4 @@
-use crate::theme::Theme;
+use crate::theme::{class, Theme};
 
 use iced::{
     border::Radius,
@@ -10,14 +10,14 @@ use iced::{
 };
 
 impl container::Catalog for Theme {
-    type Class<'a> = StyleFn<'a, Self>;
+    type Class<'a> = class::Class<StyleFn<'a, Self>>;
 
     fn default<'a>() -> Self::Class<'a> {
-        Box::new(transparent)
+        class::DEFAULT_CONTAINER.into()
     }
 
     fn style(&self, class: &Self::Class<'_>) -> Style {
-        class(self)
+        class.resolve(self)
     }
 }
 
@@ -26,6 +26,10 @@ pub fn transparent(_theme: &Theme) -> Style {
 }
 
 pub fn card(theme: &Theme) -> Style {
+    if let Some(colours) = theme.high_contrast() {
+        return high_contrast::surface(colours);
+    }
+
     let palette = theme.palette();
 
     Style::default()
@@ -38,6 +42,10 @@ pub fn card(theme: &Theme) -> Style {
 }
 
 pub fn overlay(theme: &Theme) -> Style {
+    if let Some(colours) = theme.high_contrast() {
+        return high_contrast::surface(colours);
+    }
+
     let palette = theme.palette();
 
     Style {
@@ -62,6 +70,10 @@ pub fn overlay(theme: &Theme) -> Style {
 }
 
 pub fn dialog(theme: &Theme) -> Style {
+    if let Some(colours) = theme.high_contrast() {
+        return high_contrast::surface(colours);
+    }
+
     let palette = theme.palette();
 
     Style {
@@ -84,3 +96,21 @@ pub fn dialog(theme: &Theme) -> Style {
         },
     }
 }
+
+pub mod high_contrast {
+    use crate::theme::high_contrast::SystemColors;
+
+    use iced::{border::Radius, widget::container::Style, Border};
+
+    // Shadows are dropped, surfaces are told apart by a solid border instead
+    pub fn surface(colours: &SystemColors) -> Style {
+        Style::default()
+            .color(colours.window_text)
+            .background(colours.window)
+            .border(Border {
+                color: colours.window_text,
+                radius: Radius::new(8),
+                width: 2.0,
+            })
+    }
+}
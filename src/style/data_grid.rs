@@ -0,0 +1,84 @@
+use crate::theme::Theme;
+
+use iced::Color;
+
+// DataGrid resources from the Windows Community Toolkit, restyled with the
+// Fluent 2 tokens the rest of the crate uses
+// https://github.com/CommunityToolkit/Windows/blob/main/components/DataGrid/src/DataGrid/DataGrid.xaml
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum Status {
+    Active,
+    Hovered,
+    Selected,
+    SelectedHovered,
+}
+
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct Style {
+    pub background: Color,
+    pub border_color: Color,
+    // Behind every other row, under the row's own background
+    pub alternate_background: Color,
+    // The row's background for its status
+    pub row_background: Color,
+    pub text_color: Color,
+    pub header_background: Color,
+    pub header_hovered_background: Color,
+    pub header_text_color: Color,
+    pub header_hovered_text_color: Color,
+    // Between the headers and the rows and after the frozen columns
+    pub divider: Color,
+    // The ring around the current cell
+    pub focus: Color,
+}
+
+pub type StyleFn = Box<dyn Fn(&Theme, Status) -> Style>;
+
+pub fn default(theme: &Theme, status: Status) -> Style {
+    if let Some(colours) = theme.high_contrast() {
+        let (row_background, text_color) = match status {
+            Status::Active => (Color::TRANSPARENT, colours.window_text),
+            Status::Hovered | Status::Selected | Status::SelectedHovered => {
+                (colours.highlight, colours.highlight_text)
+            }
+        };
+
+        return Style {
+            background: colours.window,
+            border_color: colours.window_text,
+            alternate_background: Color::TRANSPARENT,
+            row_background,
+            text_color,
+            header_background: colours.window,
+            header_hovered_background: colours.highlight,
+            header_text_color: colours.window_text,
+            header_hovered_text_color: colours.highlight_text,
+            divider: colours.window_text,
+            focus: text_color,
+        };
+    }
+
+    let palette = theme.palette();
+
+    let row_background = match status {
+        Status::Active => palette.subtle_fill_color_transparent,
+        Status::Hovered => palette.subtle_fill_color_secondary,
+        Status::Selected => palette.accent_fill_color_default.scale_alpha(0.2),
+        Status::SelectedHovered => palette.accent_fill_color_default.scale_alpha(0.3),
+    };
+
+    Style {
+        background: palette.card_background_fill_color_default,
+        border_color: palette.card_stroke_color_default,
+        alternate_background: palette.subtle_fill_color_secondary.scale_alpha(0.5),
+        row_background,
+        text_color: palette.text_fill_color_primary,
+        header_background: palette.card_background_fill_color_secondary,
+        header_hovered_background: palette.subtle_fill_color_secondary,
+        header_text_color: palette.text_fill_color_secondary,
+        header_hovered_text_color: palette.text_fill_color_secondary,
+        divider: palette.divider_stroke_color_default,
+        focus: palette.focus_stroke_color_outer,
+    }
+}
@@ -0,0 +1,55 @@
+use crate::{theme::Theme, widget::info_bar::Severity};
+
+use iced::{border::Radius, widget::container::Style, Border, Color};
+
+// InfoBar resources from
+// https://github.com/microsoft/microsoft-ui-xaml/blob/main/src/controls/dev/InfoBar/InfoBar_themeresources.xaml
+
+pub fn container(theme: &Theme, severity: Severity) -> Style {
+    if let Some(colours) = theme.high_contrast() {
+        return super::container::high_contrast::surface(colours);
+    }
+
+    let palette = theme.palette();
+
+    let background = match severity {
+        Severity::Informational => palette.system_fill_color_attention_background,
+        Severity::Success => palette.system_fill_color_success_background,
+        Severity::Warning => palette.system_fill_color_caution_background,
+        Severity::Error => palette.system_fill_color_critical_background,
+    };
+
+    Style::default()
+        .color(palette.text_fill_color_primary)
+        .background(background)
+        .border(Border {
+            color: palette.card_stroke_color_default,
+            radius: Radius::new(4),
+            width: 1.0,
+        })
+}
+
+// The circle behind the severity symbol
+pub fn icon(theme: &Theme, severity: Severity) -> Color {
+    if let Some(colours) = theme.high_contrast() {
+        return colours.window_text;
+    }
+
+    let palette = theme.palette();
+
+    match severity {
+        Severity::Informational => palette.system_fill_color_attention,
+        Severity::Success => palette.system_fill_color_success,
+        Severity::Warning => palette.system_fill_color_caution,
+        Severity::Error => palette.system_fill_color_critical,
+    }
+}
+
+// The symbol itself, knocked out of the circle
+pub fn icon_glyph(theme: &Theme) -> Color {
+    if let Some(colours) = theme.high_contrast() {
+        return colours.window;
+    }
+
+    theme.palette().text_fill_color_inverse
+}
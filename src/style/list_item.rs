@@ -0,0 +1,78 @@
+use crate::theme::Theme;
+
+use iced::Color;
+
+// Rows of TreeView and ListView, which WinUI draws with the same presenter. From
+// https://github.com/microsoft/microsoft-ui-xaml/blob/main/src/controls/dev/CommonStyles/ListViewItem_themeresources.xaml
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum Status {
+    Active,
+    Hovered,
+    Pressed,
+    Selected,
+    SelectedHovered,
+}
+
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct Style {
+    pub background: Color,
+    pub text_color: Color,
+    // The pill on the leading edge of a selected row
+    pub indicator: Color,
+    // The ring around the row with keyboard focus
+    pub focus: Color,
+}
+
+pub type StyleFn = Box<dyn Fn(&Theme, Status) -> Style>;
+
+pub fn default(theme: &Theme, status: Status) -> Style {
+    if let Some(colours) = theme.high_contrast() {
+        let (background, text_color) = match status {
+            Status::Active => (Color::TRANSPARENT, colours.window_text),
+            Status::Hovered | Status::Pressed | Status::SelectedHovered => {
+                (colours.highlight, colours.highlight_text)
+            }
+            Status::Selected => (colours.window, colours.window_text),
+        };
+
+        return Style {
+            background,
+            text_color,
+            // The pill sits on the item's own background when it is hovered
+            indicator: match status {
+                Status::SelectedHovered => colours.highlight_text,
+                _ => colours.highlight,
+            },
+            focus: text_color,
+        };
+    }
+
+    let palette = theme.palette();
+
+    let (background, text_color) = match status {
+        Status::Active => (
+            palette.subtle_fill_color_transparent,
+            palette.text_fill_color_primary,
+        ),
+        Status::Hovered | Status::Selected => (
+            palette.subtle_fill_color_secondary,
+            palette.text_fill_color_primary,
+        ),
+        Status::Pressed => (
+            palette.subtle_fill_color_tertiary,
+            palette.text_fill_color_secondary,
+        ),
+        Status::SelectedHovered => (
+            palette.subtle_fill_color_tertiary,
+            palette.text_fill_color_primary,
+        ),
+    };
+
+    Style {
+        background,
+        text_color,
+        indicator: palette.accent_fill_color_default,
+        focus: palette.focus_stroke_color_outer,
+    }
+}
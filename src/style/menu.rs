@@ -1,27 +1,31 @@
-use crate::theme::Theme;
+use crate::theme::{class, Theme};
 
 use iced::{
     widget::overlay::menu::{Catalog, Style},
-    Border
+    Border,
 };
 
 pub type StyleFn<'a, Theme> = Box<dyn Fn(&Theme) -> Style + 'a>;
 
 impl Catalog for Theme {
-    type Class<'a> = StyleFn<'a, Self>;
+    type Class<'a> = class::Class<StyleFn<'a, Self>>;
 
-    fn default<'a>() -> StyleFn<'a, Self> {
-        Box::new(default)
+    fn default<'a>() -> class::Class<StyleFn<'a, Self>> {
+        class::DEFAULT.into()
     }
 
-    fn style(&self, class: &StyleFn<'_, Self>) -> Style {
-        class(self)
+    fn style(&self, class: &class::Class<StyleFn<'_, Self>>) -> Style {
+        class.resolve(self)
     }
 }
 
 pub fn default(theme: &Theme) -> Style {
+    if let Some(colours) = theme.high_contrast() {
+        return high_contrast::default(colours);
+    }
+
     let palette = theme.palette();
-    
+
     Style {
         background: palette.acrylic_in_app_fill_color_default_fallback.into(),
         border: Border {
@@ -33,4 +37,24 @@ pub fn default(theme: &Theme) -> Style {
         selected_background: palette.subtle_fill_color_secondary.into(),
         selected_text_color: palette.text_fill_color_primary,
     }
-}
\ No newline at end of file
+}
+
+pub mod high_contrast {
+    use crate::theme::high_contrast::SystemColors;
+
+    use iced::{widget::overlay::menu::Style, Border};
+
+    pub fn default(colours: &SystemColors) -> Style {
+        Style {
+            background: colours.window.into(),
+            border: Border {
+                color: colours.window_text,
+                width: 2.0,
+                radius: 4.0.into(),
+            },
+            text_color: colours.window_text,
+            selected_background: colours.highlight.into(),
+            selected_text_color: colours.highlight_text,
+        }
+    }
+}
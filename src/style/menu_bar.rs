@@ -1,4 +1,4 @@
-use crate::theme::Theme;
+use crate::theme::{class, Theme};
 
 use iced::{border::Radius, Border, Color, Padding, Shadow, Vector};
 
@@ -10,18 +10,22 @@ use iced_aw::{
 type StyleFn<'a> = Box<dyn Fn(&Theme, Status) -> Style + 'a>;
 
 impl Catalog for Theme {
-    type Class<'a> = StyleFn<'a>;
+    type Class<'a> = class::Class<StyleFn<'a>>;
 
     fn default<'a>() -> Self::Class<'a> {
-        Box::new(default)
+        class::DEFAULT.into()
     }
 
     fn style(&self, class: &Self::Class<'_>, status: Status) -> Style {
-        class(self, status)
+        class.resolve(self, status)
     }
 }
 
 pub fn default(theme: &Theme, status: Status) -> Style {
+    if let Some(colours) = theme.high_contrast() {
+        return high_contrast::default(colours, status);
+    }
+
     let palette = theme.palette();
 
     let base = Style {
@@ -81,3 +85,47 @@ pub fn default(theme: &Theme, status: Status) -> Style {
         Status::Selected => focussed,
     }
 }
+
+pub mod high_contrast {
+    use crate::theme::high_contrast::SystemColors;
+
+    use iced::{border::Radius, Border, Color, Padding, Shadow};
+
+    use iced_aw::{menu::Style, style::Status};
+
+    pub fn default(colours: &SystemColors, status: Status) -> Style {
+        let base = Style {
+            bar_background: Color::TRANSPARENT.into(),
+            bar_border: Border {
+                color: Color::TRANSPARENT,
+                width: 0.0,
+                radius: Radius::new(4),
+            },
+            bar_shadow: Shadow::default(),
+            bar_background_expand: Padding::default(),
+            menu_background: colours.window.into(),
+            menu_border: Border {
+                color: colours.window_text,
+                width: 2.0,
+                radius: Radius::new(8),
+            },
+            menu_shadow: Shadow::default(),
+            menu_background_expand: Padding::default(),
+            path: colours.highlight.into(),
+            path_border: Border::default(),
+        };
+
+        // The items highlight themselves, a highlighted bar would hide their text
+        match status {
+            Status::Active | Status::Disabled | Status::Hovered | Status::Pressed => base,
+            Status::Focused | Status::Selected => Style {
+                bar_border: Border {
+                    color: colours.window_text,
+                    width: 3.0,
+                    radius: Radius::new(4),
+                },
+                ..base
+            },
+        }
+    }
+}
@@ -1,4 +1,4 @@
-use crate::theme::Theme;
+use crate::theme::{class, Theme};
 
 use iced_aw::{
     number_input::{Catalog, Style},
@@ -6,24 +6,28 @@ use iced_aw::{
 };
 
 impl Catalog for Theme {
-    type Class<'a> = StyleFn<'a, Self, Style>;
+    type Class<'a> = class::Class<StyleFn<'a, Self, Style>>;
 
     fn default<'a>() -> Self::Class<'a> {
-        Box::new(default)
+        class::DEFAULT.into()
     }
 
     fn style(&self, class: &Self::Class<'_>, status: Status) -> Style {
-        class(self, status)
+        class.resolve(self, status)
     }
 }
 
 impl ExtendedCatalog for Theme {
     fn style(&self, class: &<Self as self::Catalog>::Class<'_>, status: Status) -> Style {
-        class(self, status)
+        class.resolve(self, status)
     }
 }
 
 pub fn default(theme: &Theme, status: Status) -> Style {
+    if let Some(colours) = theme.high_contrast() {
+        return high_contrast::default(colours, status);
+    }
+
     let palette = theme.palette();
     let base = Style {
         button_background: Some(palette.control_fill_color_transparent.into()),
@@ -45,3 +49,25 @@ pub fn default(theme: &Theme, status: Status) -> Style {
         Status::Selected => base,
     }
 }
+
+pub mod high_contrast {
+    use crate::theme::high_contrast::SystemColors;
+
+    use iced::Color;
+    use iced_aw::{number_input::Style, style::Status};
+
+    pub fn default(colours: &SystemColors, status: Status) -> Style {
+        let (background, icon_color) = match status {
+            Status::Hovered | Status::Pressed => (colours.highlight, colours.highlight_text),
+            Status::Disabled => (Color::TRANSPARENT, colours.gray_text),
+            Status::Active | Status::Focused | Status::Selected => {
+                (Color::TRANSPARENT, colours.button_text)
+            }
+        };
+
+        Style {
+            button_background: Some(background.into()),
+            icon_color,
+        }
+    }
+}
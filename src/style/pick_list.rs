@@ -1,25 +1,29 @@
-use crate::theme::Theme;
+use crate::theme::{class, Theme};
 
 use iced::{
     widget::pick_list::{Catalog, Status, Style},
-    Border
+    Border,
 };
 
 pub type StyleFn<'a, Theme> = Box<dyn Fn(&Theme, Status) -> Style + 'a>;
 
 impl Catalog for Theme {
-    type Class<'a> = StyleFn<'a, Self>;
+    type Class<'a> = class::Class<StyleFn<'a, Self>>;
 
-    fn default<'a>() -> StyleFn<'a, Self> {
-        Box::new(default)
+    fn default<'a>() -> class::Class<StyleFn<'a, Self>> {
+        class::DEFAULT.into()
     }
 
-    fn style(&self, class: &StyleFn<'_, Self>, status: Status) -> Style {
-        class(self, status)
+    fn style(&self, class: &class::Class<StyleFn<'_, Self>>, status: Status) -> Style {
+        class.resolve(self, status)
     }
 }
 
 pub fn default(theme: &Theme, status: Status) -> Style {
+    if let Some(colours) = theme.high_contrast() {
+        return high_contrast::default(colours, status);
+    }
+
     let palette = theme.palette();
 
     let base = Style {
@@ -43,3 +47,40 @@ pub fn default(theme: &Theme, status: Status) -> Style {
         Status::Opened => base,
     }
 }
+
+pub mod high_contrast {
+    use crate::theme::high_contrast::SystemColors;
+
+    use iced::{
+        widget::pick_list::{Status, Style},
+        Border,
+    };
+
+    pub fn default(colours: &SystemColors, status: Status) -> Style {
+        let (background, text_color, border_color) = match status {
+            Status::Active => (
+                colours.button_face,
+                colours.button_text,
+                colours.button_text,
+            ),
+            Status::Hovered => (colours.highlight, colours.highlight_text, colours.highlight),
+            Status::Opened => (colours.button_face, colours.button_text, colours.highlight),
+        };
+
+        Style {
+            text_color,
+            background: background.into(),
+            placeholder_color: if status == Status::Hovered {
+                text_color
+            } else {
+                colours.gray_text
+            },
+            handle_color: text_color,
+            border: Border {
+                radius: 4.0.into(),
+                width: 2.0,
+                color: border_color,
+            },
+        }
+    }
+}
@@ -0,0 +1,35 @@
+use crate::{theme::Theme, widget::progress::Status};
+
+use iced::Color;
+
+// ProgressBar and ProgressRing resources from
+// https://github.com/microsoft/microsoft-ui-xaml/blob/main/src/controls/dev/ProgressBar/ProgressBar_themeresources.xaml
+// https://github.com/microsoft/microsoft-ui-xaml/blob/main/src/controls/dev/ProgressRing/ProgressRing_themeresources.xaml
+
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct Style {
+    pub track: Color,
+    pub indicator: Color,
+}
+
+pub type StyleFn = Box<dyn Fn(&Theme, Status) -> Style>;
+
+pub fn default(theme: &Theme, status: Status) -> Style {
+    if let Some(colours) = theme.high_contrast() {
+        return Style {
+            track: colours.window_text,
+            indicator: colours.highlight,
+        };
+    }
+
+    let palette = theme.palette();
+
+    Style {
+        track: palette.control_strong_stroke_color_default,
+        indicator: match status {
+            Status::Running => palette.accent_fill_color_default,
+            Status::Paused => palette.system_fill_color_caution,
+            Status::Error => palette.system_fill_color_critical,
+        },
+    }
+}
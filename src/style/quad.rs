@@ -1,4 +1,4 @@
-use crate::theme;
+use crate::theme::{self, class};
 
 use iced::{Background, Border, Shadow};
 
@@ -25,22 +25,29 @@ pub trait Catalog {
 }
 
 impl Catalog for theme::Theme {
-    type Class<'a> = StyleFn<'a, Self>;
+    type Class<'a> = class::Class<StyleFn<'a, Self>>;
 
     fn default<'a>() -> Self::Class<'a> {
-        Box::new(|_, _| Style::default())
+        class::DEFAULT.into()
     }
 
     fn style(&self, class: &Self::Class<'_>, status: Status) -> Style {
-        class(self, status)
+        class.resolve(self, status)
     }
 }
 
+pub fn default(_theme: &theme::Theme, _status: Status) -> Style {
+    Style::default()
+}
+
 pub fn separator(theme: &theme::Theme, _status: Status) -> Style {
-    let palette = theme.palette();
+    let colour = match theme.high_contrast() {
+        Some(colours) => colours.window_text,
+        None => theme.palette().divider_stroke_color_default,
+    };
 
     Style {
-        background: Some(palette.divider_stroke_color_default.into()),
+        background: Some(colour.into()),
         border: Border::default(),
         shadow: Shadow::default(),
     }
@@ -1,22 +1,26 @@
-use crate::theme::Theme;
+use crate::theme::{class, Theme};
 
 use iced::widget::radio::{Catalog, Status, Style};
 
 pub type StyleFn<'a, Theme> = Box<dyn Fn(&Theme, Status) -> Style + 'a>;
 
 impl Catalog for Theme {
-    type Class<'a> = StyleFn<'a, Self>;
+    type Class<'a> = class::Class<StyleFn<'a, Self>>;
 
     fn default<'a>() -> Self::Class<'a> {
-        Box::new(default)
+        class::DEFAULT.into()
     }
 
     fn style(&self, class: &Self::Class<'_>, status: Status) -> Style {
-        class(self, status)
+        class.resolve(self, status)
     }
 }
 
 pub fn default(theme: &Theme, status: Status) -> Style {
+    if let Some(colours) = theme.high_contrast() {
+        return high_contrast::default(colours, status);
+    }
+
     let palette = theme.palette();
     let base = Style {
         background: palette.control_alt_fill_color_secondary.into(),
@@ -54,3 +58,29 @@ pub fn default(theme: &Theme, status: Status) -> Style {
         }
     }
 }
+
+pub mod high_contrast {
+    use crate::theme::high_contrast::SystemColors;
+
+    use iced::widget::radio::{Status, Style};
+
+    pub fn default(colours: &SystemColors, status: Status) -> Style {
+        let (background, border_color) = match status {
+            Status::Active { is_selected: true } => (colours.highlight, colours.highlight),
+            Status::Active { is_selected: false } => (colours.window, colours.window_text),
+            Status::Hovered { is_selected: true } => (colours.highlight_text, colours.highlight),
+            Status::Hovered { is_selected: false } => (colours.window, colours.highlight),
+        };
+
+        Style {
+            background: background.into(),
+            dot_color: match status {
+                Status::Hovered { .. } => colours.highlight,
+                Status::Active { .. } => colours.highlight_text,
+            },
+            border_width: 2.0,
+            border_color,
+            text_color: Some(colours.window_text),
+        }
+    }
+}
@@ -1,4 +1,7 @@
-use crate::{style::container, theme::Theme};
+use crate::{
+    style::container,
+    theme::{class, Theme},
+};
 
 use iced::{
     border,
@@ -8,21 +11,25 @@ use iced::{
 pub type StyleFn<'a, Theme> = Box<dyn Fn(&Theme, Status) -> Style + 'a>;
 
 impl Catalog for Theme {
-    type Class<'a> = StyleFn<'a, Self>;
+    type Class<'a> = class::Class<StyleFn<'a, Self>>;
 
     fn default<'a>() -> Self::Class<'a> {
-        Box::new(default)
+        class::DEFAULT.into()
     }
 
     fn style(&self, class: &Self::Class<'_>, status: Status) -> Style {
-        class(self, status)
+        class.resolve(self, status)
     }
 }
 
 // ScrollBar colours specified here:
 // https://github.com/microsoft/microsoft-ui-xaml/blob/4c50e610e537aca92afc950c4be1ffb60c2f99d5/dev/CommonStyles/ScrollBar_themeresources.xaml
 
-pub fn default(theme: &Theme, _status: Status) -> Style {
+pub fn default(theme: &Theme, status: Status) -> Style {
+    if let Some(colours) = theme.high_contrast() {
+        return high_contrast::default(colours, status);
+    }
+
     let palette = theme.palette();
 
     let scrollbar = Rail {
@@ -41,3 +48,61 @@ pub fn default(theme: &Theme, _status: Status) -> Style {
         gap: None,
     }
 }
+
+pub mod high_contrast {
+    use crate::theme::high_contrast::SystemColors;
+
+    use iced::{
+        border,
+        widget::{
+            container,
+            scrollable::{Rail, Scroller, Status, Style},
+        },
+        Border,
+    };
+
+    pub fn default(colours: &SystemColors, status: Status) -> Style {
+        let (horizontal, vertical) = match status {
+            Status::Active => (false, false),
+            Status::Hovered {
+                is_horizontal_scrollbar_hovered,
+                is_vertical_scrollbar_hovered,
+            } => (
+                is_horizontal_scrollbar_hovered,
+                is_vertical_scrollbar_hovered,
+            ),
+            Status::Dragged {
+                is_horizontal_scrollbar_dragged,
+                is_vertical_scrollbar_dragged,
+            } => (
+                is_horizontal_scrollbar_dragged,
+                is_vertical_scrollbar_dragged,
+            ),
+        };
+
+        // The thumb takes the highlight while it's under the pointer or dragged
+        let rail = |is_active: bool| Rail {
+            background: Some(colours.window.into()),
+            border: Border {
+                color: colours.window_text,
+                width: 1.0,
+                radius: 8.0.into(),
+            },
+            scroller: Scroller {
+                color: if is_active {
+                    colours.highlight
+                } else {
+                    colours.window_text
+                },
+                border: border::rounded(8),
+            },
+        };
+
+        Style {
+            container: container::Style::default(),
+            vertical_rail: rail(vertical),
+            horizontal_rail: rail(horizontal),
+            gap: None,
+        }
+    }
+}
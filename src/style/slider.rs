@@ -1,4 +1,4 @@
-use crate::theme::Theme;
+use crate::theme::{class, Theme};
 
 use iced::{
     widget::slider::{Catalog, Handle, HandleShape, Rail, Status, Style},
@@ -8,18 +8,22 @@ use iced::{
 pub type StyleFn<'a, Theme> = Box<dyn Fn(&Theme, Status) -> Style + 'a>;
 
 impl Catalog for Theme {
-    type Class<'a> = StyleFn<'a, Self>;
+    type Class<'a> = class::Class<StyleFn<'a, Self>>;
 
     fn default<'a>() -> Self::Class<'a> {
-        Box::new(default)
+        class::DEFAULT.into()
     }
 
     fn style(&self, class: &Self::Class<'_>, status: Status) -> Style {
-        class(self, status)
+        class.resolve(self, status)
     }
 }
 
 pub fn default(theme: &Theme, status: Status) -> Style {
+    if let Some(colours) = theme.high_contrast() {
+        return high_contrast::default(colours, status);
+    }
+
     let palette = theme.palette();
 
     Style {
@@ -51,3 +55,37 @@ pub fn default(theme: &Theme, status: Status) -> Style {
         },
     }
 }
+
+pub mod high_contrast {
+    use crate::theme::high_contrast::SystemColors;
+
+    use iced::{
+        widget::slider::{Handle, HandleShape, Rail, Status, Style},
+        Border,
+    };
+
+    pub fn default(colours: &SystemColors, status: Status) -> Style {
+        let fill = match status {
+            Status::Active => colours.highlight,
+            Status::Hovered | Status::Dragged => colours.hotlight,
+        };
+
+        Style {
+            rail: Rail {
+                backgrounds: (fill.into(), colours.window_text.into()),
+                width: 4.0,
+                border: Border {
+                    radius: 2.0.into(),
+                    width: 0.0,
+                    color: fill,
+                },
+            },
+            handle: Handle {
+                shape: HandleShape::Circle { radius: 11.0 },
+                background: fill.into(),
+                border_color: colours.window_text,
+                border_width: 2.0,
+            },
+        }
+    }
+}
@@ -0,0 +1,109 @@
+use crate::theme::{class, Theme};
+
+use iced::Color;
+
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum Status {
+    Active,
+    Hovered,
+    Dragged,
+}
+
+pub struct Style {
+    pub handle_colour: Color,
+    pub handle_width: f32,
+}
+
+pub trait Catalog {
+    type Class<'a>;
+
+    fn default<'a>() -> Self::Class<'a>;
+
+    fn style(&self, class: &Self::Class<'_>, status: Status) -> Style;
+}
+
+pub type StyleFn<'a, Theme> = Box<dyn Fn(&Theme, Status) -> Style + 'a>;
+
+impl Catalog for Theme {
+    type Class<'a> = class::Class<StyleFn<'a, Self>>;
+
+    fn default<'a>() -> Self::Class<'a> {
+        class::DEFAULT.into()
+    }
+
+    fn style(&self, class: &Self::Class<'_>, status: Status) -> Style {
+        class.resolve(self, status)
+    }
+}
+
+// WinUI does not draw a splitter line on the NavigationView pane so the handle
+// only becomes visible once the pointer is over it
+pub fn default(theme: &Theme, status: Status) -> Style {
+    if let Some(colours) = theme.high_contrast() {
+        return high_contrast::default(colours, status);
+    }
+
+    let palette = theme.palette();
+
+    match status {
+        Status::Active => Style {
+            handle_colour: Color::TRANSPARENT,
+            handle_width: 1.0,
+        },
+        Status::Hovered => Style {
+            handle_colour: palette.control_strong_stroke_color_default,
+            handle_width: 2.0,
+        },
+        Status::Dragged => Style {
+            handle_colour: palette.accent_fill_color_default,
+            handle_width: 2.0,
+        },
+    }
+}
+
+pub fn divider(theme: &Theme, status: Status) -> Style {
+    if let Some(colours) = theme.high_contrast() {
+        return high_contrast::divider(colours, status);
+    }
+
+    let palette = theme.palette();
+
+    match status {
+        Status::Active => Style {
+            handle_colour: palette.divider_stroke_color_default,
+            handle_width: 1.0,
+        },
+        _ => default(theme, status),
+    }
+}
+
+pub mod high_contrast {
+    use super::{Status, Style};
+
+    use crate::theme::high_contrast::SystemColors;
+
+    use iced::Color;
+
+    pub fn default(colours: &SystemColors, status: Status) -> Style {
+        match status {
+            Status::Active => Style {
+                handle_colour: Color::TRANSPARENT,
+                handle_width: 1.0,
+            },
+            Status::Hovered | Status::Dragged => Style {
+                handle_colour: colours.highlight,
+                handle_width: 2.0,
+            },
+        }
+    }
+
+    pub fn divider(colours: &SystemColors, status: Status) -> Style {
+        match status {
+            Status::Active => Style {
+                handle_colour: colours.window_text,
+                handle_width: 1.0,
+            },
+            _ => default(colours, status),
+        }
+    }
+}
@@ -1,15 +1,32 @@
-use crate::theme::Theme;
+use crate::theme::{class, Theme};
 
 use iced::widget::svg::{Catalog, Status, Style, StyleFn};
 
 impl Catalog for Theme {
-    type Class<'a> = StyleFn<'a, Self>;
+    type Class<'a> = class::Class<StyleFn<'a, Self>>;
 
     fn default<'a>() -> Self::Class<'a> {
-        Box::new(|_theme, _status| Style::default())
+        class::DEFAULT.into()
     }
 
     fn style(&self, class: &Self::Class<'_>, status: Status) -> Style {
-        class(self, status)
+        class.resolve(self, status)
+    }
+}
+
+// Full colour icons are drawn as they are
+pub fn default(_theme: &Theme, _status: Status) -> Style {
+    Style::default()
+}
+
+// Recolours monochrome icons to match the text around them
+pub fn icon(theme: &Theme, _status: Status) -> Style {
+    let colour = match theme.high_contrast() {
+        Some(colours) => colours.window_text,
+        None => theme.palette().text_fill_color_primary,
+    };
+
+    Style {
+        color: Some(colour),
     }
 }
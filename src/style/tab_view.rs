@@ -0,0 +1,69 @@
+use crate::theme::Theme;
+
+use iced::Color;
+
+// TabView resources from
+// https://github.com/microsoft/microsoft-ui-xaml/blob/main/src/controls/dev/TabView/TabView_themeresources.xaml
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum Status {
+    Active,
+    Hovered,
+    Pressed,
+    Selected,
+}
+
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct Style {
+    pub background: Color,
+    pub text_color: Color,
+    pub border_color: Color,
+    // The line between two tabs that are neither selected nor hovered
+    pub divider: Color,
+}
+
+pub type StyleFn = Box<dyn Fn(&Theme, Status) -> Style>;
+
+// The selected tab shares its background with the content area so the two read
+// as one surface, the other tabs sit on the window background
+pub fn default(theme: &Theme, status: Status) -> Style {
+    if let Some(colours) = theme.high_contrast() {
+        let (background, text_color) = match status {
+            Status::Active => (Color::TRANSPARENT, colours.window_text),
+            Status::Hovered | Status::Pressed => (colours.highlight, colours.highlight_text),
+            Status::Selected => (colours.window, colours.window_text),
+        };
+
+        return Style {
+            background,
+            text_color,
+            border_color: colours.window_text,
+            divider: colours.window_text,
+        };
+    }
+
+    let palette = theme.palette();
+
+    let (background, text_color) = match status {
+        Status::Active => (Color::TRANSPARENT, palette.text_fill_color_secondary),
+        Status::Hovered => (
+            palette.layer_on_mica_base_alt_fill_color_secondary,
+            palette.text_fill_color_primary,
+        ),
+        Status::Pressed => (
+            palette.layer_on_mica_base_alt_fill_color_default,
+            palette.text_fill_color_secondary,
+        ),
+        Status::Selected => (
+            palette.solid_background_fill_color_tertiary,
+            palette.text_fill_color_primary,
+        ),
+    };
+
+    Style {
+        background,
+        text_color,
+        border_color: palette.card_stroke_color_default,
+        divider: palette.divider_stroke_color_default,
+    }
+}
@@ -1,26 +1,30 @@
-use crate::theme::Theme;
+use crate::theme::{class, Theme};
 
 use iced::{
     border::Radius,
     widget::text_input::{Catalog, Status, Style},
-    Border, Color,
+    Border,
 };
 
 pub type StyleFn<'a, Theme> = Box<dyn Fn(&Theme, Status) -> Style + 'a>;
 
 impl Catalog for Theme {
-    type Class<'a> = StyleFn<'a, Self>;
+    type Class<'a> = class::Class<StyleFn<'a, Self>>;
 
     fn default<'a>() -> Self::Class<'a> {
-        Box::new(default)
+        class::DEFAULT.into()
     }
 
     fn style(&self, class: &Self::Class<'_>, status: Status) -> Style {
-        class(self, status)
+        class.resolve(self, status)
     }
 }
 
-fn default(theme: &Theme, status: Status) -> Style {
+pub fn default(theme: &Theme, status: Status) -> Style {
+    if let Some(colours) = theme.high_contrast() {
+        return high_contrast::default(colours, status);
+    }
+
     let palette = theme.palette();
     let base = Style {
         background: palette.control_fill_color_default.into(),
@@ -31,7 +35,7 @@ fn default(theme: &Theme, status: Status) -> Style {
         },
         icon: palette.text_fill_color_primary,
         placeholder: palette.text_fill_color_secondary,
-        selection: palette.accent_fill_color_default,
+        selection: palette.accent_fill_color_selected_text_background,
         value: palette.text_fill_color_primary,
     };
 
@@ -48,8 +52,55 @@ fn default(theme: &Theme, status: Status) -> Style {
         Status::Disabled => Style {
             background: palette.control_fill_color_disabled.into(),
             placeholder: palette.text_fill_color_disabled,
-            value: Color::from_rgba8(254, 254, 254, 0.36),
+            value: palette.text_fill_color_disabled,
             ..base
         },
     }
 }
+
+pub mod high_contrast {
+    use crate::theme::high_contrast::SystemColors;
+
+    use iced::{
+        border::Radius,
+        widget::text_input::{Status, Style},
+        Border,
+    };
+
+    pub fn default(colours: &SystemColors, status: Status) -> Style {
+        let base = Style {
+            background: colours.window.into(),
+            border: Border {
+                color: colours.window_text,
+                width: 1.0,
+                radius: Radius::new(4),
+            },
+            icon: colours.window_text,
+            placeholder: colours.gray_text,
+            selection: colours.highlight,
+            value: colours.window_text,
+        };
+
+        match status {
+            Status::Active => base,
+            Status::Hovered => Style {
+                border: base.border.color(colours.highlight),
+                ..base
+            },
+            // Focus is shown with a thicker border rather than the accent underline
+            Status::Focused => Style {
+                border: Border {
+                    color: colours.highlight,
+                    width: 2.0,
+                    ..base.border
+                },
+                ..base
+            },
+            Status::Disabled => Style {
+                border: base.border.color(colours.gray_text),
+                value: colours.gray_text,
+                ..base
+            },
+        }
+    }
+}
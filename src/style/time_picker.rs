@@ -0,0 +1,97 @@
+use crate::theme::Theme;
+
+use iced::Color;
+
+// TimePicker resources from
+// https://github.com/microsoft/microsoft-ui-xaml/blob/main/src/controls/dev/CommonStyles/TimePicker_themeresources.xaml
+
+// The status of the field, and of an item or button in the flyout
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum Status {
+    Active,
+    Hovered,
+    Pressed,
+}
+
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct Style {
+    pub background: Color,
+    pub border_color: Color,
+    pub text_color: Color,
+    // The hour, minute and AM/PM shown before a time is picked
+    pub placeholder_color: Color,
+    // Between the columns and above the accept and dismiss buttons
+    pub divider_color: Color,
+    // The band across the middle of the flyout that the picked values sit in
+    pub highlight_background: Color,
+    pub highlight_text_color: Color,
+    // Behind an item or button in the flyout for its status
+    pub item_background: Color,
+    pub focus: Color,
+}
+
+pub type StyleFn = Box<dyn Fn(&Theme, Status) -> Style>;
+
+pub fn default(theme: &Theme, status: Status) -> Style {
+    if let Some(colours) = theme.high_contrast() {
+        // The field highlights like a button when it is hovered or pressed
+        let (background, item_background, text_color, placeholder_color) = match status {
+            Status::Active => (
+                colours.window,
+                Color::TRANSPARENT,
+                colours.window_text,
+                colours.gray_text,
+            ),
+            Status::Hovered | Status::Pressed => (
+                colours.highlight,
+                colours.highlight,
+                colours.highlight_text,
+                colours.highlight_text,
+            ),
+        };
+
+        return Style {
+            background,
+            border_color: colours.window_text,
+            text_color,
+            placeholder_color,
+            divider_color: colours.window_text,
+            highlight_background: colours.highlight,
+            highlight_text_color: colours.highlight_text,
+            item_background,
+            focus: text_color,
+        };
+    }
+
+    let palette = theme.palette();
+
+    let (background, item_background, text_color) = match status {
+        Status::Active => (
+            palette.control_fill_color_default,
+            palette.subtle_fill_color_transparent,
+            palette.text_fill_color_primary,
+        ),
+        Status::Hovered => (
+            palette.control_fill_color_secondary,
+            palette.subtle_fill_color_secondary,
+            palette.text_fill_color_primary,
+        ),
+        Status::Pressed => (
+            palette.control_fill_color_tertiary,
+            palette.subtle_fill_color_tertiary,
+            palette.text_fill_color_secondary,
+        ),
+    };
+
+    Style {
+        background,
+        border_color: palette.control_stroke_color_default,
+        text_color,
+        placeholder_color: palette.text_fill_color_secondary,
+        divider_color: palette.divider_stroke_color_default,
+        highlight_background: palette.accent_fill_color_default,
+        highlight_text_color: palette.text_on_accent_fill_color_primary,
+        item_background,
+        focus: palette.focus_stroke_color_outer,
+    }
+}
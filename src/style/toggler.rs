@@ -1,4 +1,4 @@
-use crate::theme::Theme;
+use crate::theme::{class, Theme};
 
 use iced::{
     widget::toggler::{Catalog, Status, Style},
@@ -6,20 +6,24 @@ use iced::{
 };
 
 impl Catalog for Theme {
-    type Class<'a> = StyleFn<'a, Self>;
+    type Class<'a> = class::Class<StyleFn<'a, Self>>;
 
     fn default<'a>() -> Self::Class<'a> {
-        Box::new(default)
+        class::DEFAULT.into()
     }
 
     fn style(&self, class: &Self::Class<'_>, status: Status) -> Style {
-        class(self, status)
+        class.resolve(self, status)
     }
 }
 
 pub type StyleFn<'a, Theme> = Box<dyn Fn(&Theme, Status) -> Style + 'a>;
 
 pub fn default(theme: &Theme, status: Status) -> Style {
+    if let Some(colours) = theme.high_contrast() {
+        return high_contrast::default(colours, status);
+    }
+
     let palette = theme.palette();
     let base = Style {
         background: palette.control_alt_fill_color_secondary,
@@ -66,3 +70,36 @@ pub fn default(theme: &Theme, status: Status) -> Style {
         },
     }
 }
+
+pub mod high_contrast {
+    use crate::theme::high_contrast::SystemColors;
+
+    use iced::widget::toggler::{Status, Style};
+
+    pub fn default(colours: &SystemColors, status: Status) -> Style {
+        let (background, background_border_color, foreground) = match status {
+            Status::Active { is_toggled: true } => {
+                (colours.highlight, colours.highlight, colours.highlight_text)
+            }
+            Status::Active { is_toggled: false } => {
+                (colours.window, colours.window_text, colours.window_text)
+            }
+            Status::Hovered { is_toggled: true } => {
+                (colours.highlight_text, colours.highlight, colours.highlight)
+            }
+            Status::Hovered { is_toggled: false } => {
+                (colours.window, colours.highlight, colours.highlight)
+            }
+            Status::Disabled => (colours.window, colours.gray_text, colours.gray_text),
+        };
+
+        Style {
+            background,
+            background_border_width: 2.0,
+            background_border_color,
+            foreground,
+            foreground_border_width: 0.0,
+            foreground_border_color: foreground,
+        }
+    }
+}
@@ -1,4 +1,4 @@
-use crate::theme::Theme;
+use crate::theme::{class, Theme};
 
 use iced::{widget::text_input::Status, Color};
 
@@ -17,18 +17,22 @@ pub trait Catalog {
 pub type StyleFn<'a, Theme> = Box<dyn Fn(&Theme, Status) -> Style + 'a>;
 
 impl Catalog for Theme {
-    type Class<'a> = StyleFn<'a, Self>;
+    type Class<'a> = class::Class<StyleFn<'a, Self>>;
 
     fn default<'a>() -> Self::Class<'a> {
-        Box::new(default)
+        class::DEFAULT.into()
     }
 
     fn style(&self, class: &Self::Class<'_>, status: Status) -> Style {
-        class(self, status)
+        class.resolve(self, status)
     }
 }
 
 pub fn default(theme: &Theme, status: Status) -> Style {
+    if let Some(colours) = theme.high_contrast() {
+        return high_contrast::default(colours, status);
+    }
+
     let palette = theme.palette();
     let base = Style {
         colour: palette.control_strong_stroke_color_default,
@@ -43,3 +47,19 @@ pub fn default(theme: &Theme, status: Status) -> Style {
         Status::Disabled => base,
     }
 }
+
+pub mod high_contrast {
+    use crate::{style::underline::Style, theme::high_contrast::SystemColors};
+
+    use iced::widget::text_input::Status;
+
+    pub fn default(colours: &SystemColors, status: Status) -> Style {
+        Style {
+            colour: match status {
+                Status::Active => colours.window_text,
+                Status::Hovered | Status::Focused => colours.highlight,
+                Status::Disabled => colours.gray_text,
+            },
+        }
+    }
+}
@@ -1,27 +1,128 @@
+pub mod accent;
+pub mod class;
+pub mod contrast;
+pub mod file;
+pub mod high_contrast;
+pub mod tokens;
+pub mod tonal;
+
 use iced::{
     application, color,
     Color,
 };
 
+use crate::{density::Density, motion::Interpolate};
+
+use class::Registry;
+use high_contrast::{Contrast, SystemColors};
+
+use std::sync::Arc;
+
+// The colours a theme is drawn with
 #[derive(Clone, Default, Debug, PartialEq)]
-pub enum Theme {
+pub enum Appearance {
     Light,
     #[default]
     Dark,
+    Custom(Arc<Palette>),
+    HighContrast(Contrast),
 }
 
-impl Theme {
+impl Appearance {
     pub fn palette(&self) -> &Palette {
         match self {
-            Theme::Dark => &Palette::DARK,
-            Theme::Light => &Palette::LIGHT,
+            Appearance::Dark => &Palette::DARK,
+            Appearance::Light => &Palette::LIGHT,
+            Appearance::Custom(palette) => palette,
+            Appearance::HighContrast(contrast) => contrast.palette(),
+        }
+    }
+
+    pub fn high_contrast(&self) -> Option<&SystemColors> {
+        match self {
+            Appearance::HighContrast(contrast) => Some(contrast.system_colors()),
+            _ => None,
         }
     }
 }
 
+impl std::fmt::Display for Appearance {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Appearance::Custom(palette) if palette.is_dark() => write!(f, "Custom dark"),
+            Appearance::Custom(_) => write!(f, "Custom light"),
+            Appearance::HighContrast(contrast) => write!(f, "{contrast}"),
+            _ => write!(f, "{:?}", self),
+        }
+    }
+}
+
+// An appearance and the style classes widgets are resolved through
+#[derive(Clone, Debug, PartialEq)]
+pub struct Theme {
+    appearance: Appearance,
+    classes: Arc<Registry>,
+    density: Density,
+}
+
+impl Theme {
+    pub fn appearance(&self) -> &Appearance {
+        &self.appearance
+    }
+
+    pub fn palette(&self) -> &Palette {
+        self.appearance.palette()
+    }
+
+    // A theme with `palette` and the built-in style classes
+    pub fn custom(palette: Arc<Palette>) -> Self {
+        Appearance::Custom(palette).into()
+    }
+
+    pub fn classes(&self) -> &Registry {
+        &self.classes
+    }
+
+    // Contrast themes keep an app's classes too, the built-in ones they are
+    // usually derived from switch to the system colours themselves
+    pub fn with_classes(self, classes: Arc<Registry>) -> Self {
+        Self { classes, ..self }
+    }
+
+    pub fn density(&self) -> Density {
+        self.density
+    }
+
+    // Widgets are sized for this while a view is built `density::with` it
+    pub fn with_density(self, density: Density) -> Self {
+        Self { density, ..self }
+    }
+
+    // Style functions switch to their high contrast variants when this is set
+    pub fn high_contrast(&self) -> Option<&SystemColors> {
+        self.appearance.high_contrast()
+    }
+}
+
+impl From<Appearance> for Theme {
+    fn from(appearance: Appearance) -> Self {
+        Self {
+            appearance,
+            classes: Registry::built_in().clone(),
+            density: Density::default(),
+        }
+    }
+}
+
+impl Default for Theme {
+    fn default() -> Self {
+        Appearance::default().into()
+    }
+}
+
 impl std::fmt::Display for Theme {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-      write!(f, "{:?}", self)
+        self.appearance.fmt(f)
     }
 }
 
@@ -57,12 +158,15 @@ macro_rules! from_argb {
 
 // Windows theme colour stored in HKEY_CURRENT_USER\Software\Microsoft\Windows\CurrentVersion\Explorer\Accent
 
+#[derive(Clone, Debug, PartialEq)]
 pub struct Palette {
     // Accent colours are hard-coded as Iced does not provide system theme colours (yet).
     // https://github.com/microsoft/microsoft-ui-xaml/blob/63671e055eadfd74806f9800382b8bd6c9999b4a/dxaml/xcp/components/theminginterop/SystemThemingInterop.cpp#L226
+    // Use `Builder::accent` to derive them from another colour.
     pub accent_fill_color_default: Color,
     pub accent_fill_color_secondary: Color,
     pub accent_fill_color_tertiary: Color,
+    pub accent_fill_color_selected_text_background: Color,
 
     // Acrylic effects are not implemented so use fallback colours:
     // https://github.com/microsoft/microsoft-ui-xaml/blob/4c50e610e537aca92afc950c4be1ffb60c2f99d5/dev/Materials/Acrylic/AcrylicBrush_rs3_themeresources.xaml
@@ -73,11 +177,14 @@ pub struct Palette {
 
     pub text_fill_color_primary: Color,
     pub text_fill_color_secondary: Color,
-    // pub text_fill_color_tertiary: Color,
+    pub text_fill_color_tertiary: Color,
     pub text_fill_color_disabled: Color,
-    // pub text_fill_color_inverse: Color,
-    // pub accent_text_fill_color_disabled: Color,
-    // pub text_on_accent_fill_color_selected_text: Color,
+    pub text_fill_color_inverse: Color,
+    pub accent_text_fill_color_primary: Color,
+    pub accent_text_fill_color_secondary: Color,
+    pub accent_text_fill_color_tertiary: Color,
+    pub accent_text_fill_color_disabled: Color,
+    pub text_on_accent_fill_color_selected_text: Color,
     pub text_on_accent_fill_color_primary: Color,
     pub text_on_accent_fill_color_secondary: Color,
     pub text_on_accent_fill_color_disabled: Color,
@@ -94,62 +201,63 @@ pub struct Palette {
     pub subtle_fill_color_secondary: Color,
     pub subtle_fill_color_tertiary: Color,
     pub subtle_fill_color_disabled: Color,
-    // pub control_alt_fill_color_transparent: Color,
+    pub control_alt_fill_color_transparent: Color,
     pub control_alt_fill_color_secondary: Color,
     pub control_alt_fill_color_tertiary: Color,
-    // pub control_alt_fill_color_quarternary: Color,
+    pub control_alt_fill_color_quarternary: Color,
     pub control_alt_fill_color_disabled: Color,
-    // pub control_on_image_fill_color_default: Color,
-    // pub control_on_image_fill_color_secondary: Color,
-    // pub control_on_image_fill_color_tertiary: Color,
-    // pub control_on_image_fill_color_disabled: Color,
+    pub control_on_image_fill_color_default: Color,
+    pub control_on_image_fill_color_secondary: Color,
+    pub control_on_image_fill_color_tertiary: Color,
+    pub control_on_image_fill_color_disabled: Color,
     pub accent_fill_color_disabled: Color,
     pub control_stroke_color_default: Color,
     pub control_stroke_color_secondary: Color,
-    // pub control_stroke_color_on_accent_default: Color,
+    pub control_stroke_color_on_accent_default: Color,
     pub control_stroke_color_on_accent_secondary: Color,
-    // pub control_stroke_color_on_accent_tertiary: Color,
-    // pub control_stroke_color_on_accent_disabled: Color,
-    // pub control_stroke_color_for_strong_fill_when_on_image: Color,
+    pub control_stroke_color_on_accent_tertiary: Color,
+    pub control_stroke_color_on_accent_disabled: Color,
+    pub control_stroke_color_for_strong_fill_when_on_image: Color,
     pub card_stroke_color_default: Color,
-    // pub card_stroke_color_default_solid: Color,
+    pub card_stroke_color_default_solid: Color,
     pub control_strong_stroke_color_default: Color,
     pub control_strong_stroke_color_disabled: Color,
     pub surface_stroke_color_default: Color,
     pub surface_stroke_color_flyout: Color,
-    // pub surface_stroke_color_inverse: Color,
+    pub surface_stroke_color_inverse: Color,
     pub divider_stroke_color_default: Color,
     pub focus_stroke_color_outer: Color,
-    // pub focus_stroke_color_inner: Color,
+    pub focus_stroke_color_inner: Color,
     pub card_background_fill_color_default: Color,
-    // pub card_background_fill_color_secondary: Color,
+    pub card_background_fill_color_secondary: Color,
     pub smoke_fill_color_default: Color,
-    // pub layer_fill_color_default: Color,
+    pub layer_fill_color_default: Color,
     pub layer_fill_color_alt: Color,
-    // pub layer_on_acrylic_fill_color_default: Color,
-    // pub layer_on_accent_acrylic_fill_color_default: Color,
-    // pub layer_on_mica_base_alt_fill_color_default: Color,
-    // pub layer_on_mica_base_alt_fill_color_secondary: Color,
-    // pub layer_on_mica_base_alt_fill_color_tertiary: Color,
-    // pub layer_on_mica_base_alt_fill_color_transparent: Color,
+    pub layer_on_acrylic_fill_color_default: Color,
+    pub layer_on_accent_acrylic_fill_color_default: Color,
+    pub layer_on_mica_base_alt_fill_color_default: Color,
+    pub layer_on_mica_base_alt_fill_color_secondary: Color,
+    pub layer_on_mica_base_alt_fill_color_tertiary: Color,
+    pub layer_on_mica_base_alt_fill_color_transparent: Color,
     pub solid_background_fill_color_base: Color,
-    // pub solid_background_fill_color_secondary: Color,
+    pub solid_background_fill_color_secondary: Color,
     pub solid_background_fill_color_tertiary: Color,
     pub solid_background_fill_color_quarternary: Color,
-    // pub solid_background_fill_color_transparent: Color,
-    // pub solid_background_fill_color_base_alt: Color,
-    // pub system_fill_color_success: Color,
-    // pub system_fill_color_caution: Color,
-    // pub system_fill_color_critical: Color,
-    // pub system_fill_color_neutral: Color,
-    // pub system_fill_color_solid_neutral: Color,
-    // pub system_fill_color_attention_background: Color,
-    // pub system_fill_color_success_background: Color,
-    // pub system_fill_color_caution_background: Color,
-    // pub system_fill_color_critical_background: Color,
-    // pub system_fill_color_neutral_background: Color,
-    // pub system_fill_color_solid_attention_background: Color,
-    // pub system_fill_color_solid_neutral_background: Color,
+    pub solid_background_fill_color_transparent: Color,
+    pub solid_background_fill_color_base_alt: Color,
+    pub system_fill_color_attention: Color,
+    pub system_fill_color_success: Color,
+    pub system_fill_color_caution: Color,
+    pub system_fill_color_critical: Color,
+    pub system_fill_color_neutral: Color,
+    pub system_fill_color_solid_neutral: Color,
+    pub system_fill_color_attention_background: Color,
+    pub system_fill_color_success_background: Color,
+    pub system_fill_color_caution_background: Color,
+    pub system_fill_color_critical_background: Color,
+    pub system_fill_color_neutral_background: Color,
+    pub system_fill_color_solid_attention_background: Color,
+    pub system_fill_color_solid_neutral_background: Color,
 }
 
 impl Palette {
@@ -157,16 +265,20 @@ impl Palette {
         accent_fill_color_default: from_argb!(0xFF76B9ED),
         accent_fill_color_secondary: from_argb!(0xE676B9ED),
         accent_fill_color_tertiary: from_argb!(0xCC76B9ED),
+        accent_fill_color_selected_text_background: color!(0x0078D7),
 
         acrylic_in_app_fill_color_default_fallback: color!(0x2C2C2C),
 
         text_fill_color_primary: color!(0xFFFFFF),
         text_fill_color_secondary: from_argb!(0xC5FFFFFF),
-        // text_fill_color_tertiary: from_argb!(0x87FFFFFF),
+        text_fill_color_tertiary: from_argb!(0x87FFFFFF),
         text_fill_color_disabled: from_argb!(0x5DFFFFFF),
-        // text_fill_color_inverse: from_argb!(0xE4000000),
-        // accent_text_fill_color_disabled: from_argb!(0x5DFFFFFF),
-        // text_on_accent_fill_color_selected_text: color!(0xFFFFFF),
+        text_fill_color_inverse: from_argb!(0xE4000000),
+        accent_text_fill_color_primary: color!(0x99EBFF),
+        accent_text_fill_color_secondary: color!(0x99EBFF),
+        accent_text_fill_color_tertiary: color!(0x76B9ED),
+        accent_text_fill_color_disabled: from_argb!(0x5DFFFFFF),
+        text_on_accent_fill_color_selected_text: color!(0xFFFFFF),
         text_on_accent_fill_color_primary: color!(0x000000),
         text_on_accent_fill_color_secondary: from_argb!(0x80000000),
         text_on_accent_fill_color_disabled: from_argb!(0x87FFFFFF),
@@ -183,78 +295,83 @@ impl Palette {
         subtle_fill_color_secondary: from_argb!(0x0FFFFFFF),
         subtle_fill_color_tertiary: from_argb!(0x0AFFFFFF),
         subtle_fill_color_disabled: from_argb!(0x00FFFFFF),
-        // control_alt_fill_color_transparent: from_argb!(0x00FFFFFF),
+        control_alt_fill_color_transparent: from_argb!(0x00FFFFFF),
         control_alt_fill_color_secondary: from_argb!(0x19000000),
         control_alt_fill_color_tertiary: from_argb!(0x0BFFFFFF),
-        // control_alt_fill_color_quarternary: from_argb!(0x12FFFFFF),
+        control_alt_fill_color_quarternary: from_argb!(0x12FFFFFF),
         control_alt_fill_color_disabled: from_argb!(0x00FFFFFF),
-        // control_on_image_fill_color_default: from_argb!(0xB31C1C1C),
-        // control_on_image_fill_color_secondary: color!(0x1A1A1A),
-        // control_on_image_fill_color_tertiary: color!(0x131313),
-        // control_on_image_fill_color_disabled: color!(0x1E1E1E),
+        control_on_image_fill_color_default: from_argb!(0xB31C1C1C),
+        control_on_image_fill_color_secondary: color!(0x1A1A1A),
+        control_on_image_fill_color_tertiary: color!(0x131313),
+        control_on_image_fill_color_disabled: color!(0x1E1E1E),
         accent_fill_color_disabled: from_argb!(0x28FFFFFF),
         control_stroke_color_default: from_argb!(0x12FFFFFF),
         control_stroke_color_secondary: from_argb!(0x18FFFFFF),
-        // control_stroke_color_on_accent_default: from_argb!(0x14FFFFFF),
+        control_stroke_color_on_accent_default: from_argb!(0x14FFFFFF),
         control_stroke_color_on_accent_secondary: from_argb!(0x23000000),
-        // control_stroke_color_on_accent_tertiary: from_argb!(0x37000000),
-        // control_stroke_color_on_accent_disabled: from_argb!(0x33000000),
-        // control_stroke_color_for_strong_fill_when_on_image: from_argb!(0x6B000000),
+        control_stroke_color_on_accent_tertiary: from_argb!(0x37000000),
+        control_stroke_color_on_accent_disabled: from_argb!(0x33000000),
+        control_stroke_color_for_strong_fill_when_on_image: from_argb!(0x6B000000),
         card_stroke_color_default: from_argb!(0x19000000),
-        // card_stroke_color_default_solid: color!(0x1C1C1C),
+        card_stroke_color_default_solid: color!(0x1C1C1C),
         control_strong_stroke_color_default: from_argb!(0x8BFFFFFF),
         control_strong_stroke_color_disabled: from_argb!(0x28FFFFFF),
         surface_stroke_color_default: from_argb!(0x66757575),
         surface_stroke_color_flyout: from_argb!(0x33000000),
-        // surface_stroke_color_inverse: from_argb!(0x0F000000),
+        surface_stroke_color_inverse: from_argb!(0x0F000000),
         divider_stroke_color_default: from_argb!(0x15FFFFFF),
         focus_stroke_color_outer: color!(0xFFFFFF),
-        // focus_stroke_color_inner: from_argb!(0xB3000000),
+        focus_stroke_color_inner: from_argb!(0xB3000000),
         card_background_fill_color_default: from_argb!(0x0DFFFFFF),
-        // card_background_fill_color_secondary: from_argb!(0x08FFFFFF),
+        card_background_fill_color_secondary: from_argb!(0x08FFFFFF),
         smoke_fill_color_default: from_argb!(0x4D000000),
-        // layer_fill_color_default: from_argb!(0x4C3A3A3A),
+        layer_fill_color_default: from_argb!(0x4C3A3A3A),
         layer_fill_color_alt: from_argb!(0x0DFFFFFF),
-        // layer_on_acrylic_fill_color_default: from_argb!(0x09FFFFFF),
-        // layer_on_accent_acrylic_fill_color_default: from_argb!(0x09FFFFFF),
-        // layer_on_mica_base_alt_fill_color_default: from_argb!(0x733A3A3A),
-        // layer_on_mica_base_alt_fill_color_secondary: from_argb!(0x0FFFFFFF),
-        // layer_on_mica_base_alt_fill_color_tertiary: color!(0x2C2C2C),
-        // layer_on_mica_base_alt_fill_color_transparent: from_argb!(0x00FFFFFF),
+        layer_on_acrylic_fill_color_default: from_argb!(0x09FFFFFF),
+        layer_on_accent_acrylic_fill_color_default: from_argb!(0x09FFFFFF),
+        layer_on_mica_base_alt_fill_color_default: from_argb!(0x733A3A3A),
+        layer_on_mica_base_alt_fill_color_secondary: from_argb!(0x0FFFFFFF),
+        layer_on_mica_base_alt_fill_color_tertiary: color!(0x2C2C2C),
+        layer_on_mica_base_alt_fill_color_transparent: from_argb!(0x00FFFFFF),
         solid_background_fill_color_base: color!(0x202020),
-        // solid_background_fill_color_secondary: color!(0x1C1C1C),
+        solid_background_fill_color_secondary: color!(0x1C1C1C),
         solid_background_fill_color_tertiary: color!(0x282828),
         solid_background_fill_color_quarternary: color!(0x2C2C2C),
-        // solid_background_fill_color_transparent: from_argb!(0x00202020),
-        // solid_background_fill_color_base_alt: color!(0x0A0A0A),
-        // system_fill_color_success: color!(0x6CCB5F),
-        // system_fill_color_caution: color!(0xFCE100),
-        // system_fill_color_critical: color!(0xFF99A4),
-        // system_fill_color_neutral: from_argb!(0x8BFFFFFF),
-        // system_fill_color_solid_neutral: color!(0x9D9D9D),
-        // system_fill_color_attention_background: from_argb!(0x08FFFFFF),
-        // system_fill_color_success_background: color!(0x393D1B),
-        // system_fill_color_caution_background: color!(0x433519),
-        // system_fill_color_critical_background: color!(0x442726),
-        // system_fill_color_neutral_background: from_argb!(0x08FFFFFF),
-        // system_fill_color_solid_attention_background: color!(0x2E2E2E),
-        // system_fill_color_solid_neutral_background: color!(0x2E2E2E),
+        solid_background_fill_color_transparent: from_argb!(0x00202020),
+        solid_background_fill_color_base_alt: color!(0x0A0A0A),
+        system_fill_color_attention: color!(0x76B9ED),
+        system_fill_color_success: color!(0x6CCB5F),
+        system_fill_color_caution: color!(0xFCE100),
+        system_fill_color_critical: color!(0xFF99A4),
+        system_fill_color_neutral: from_argb!(0x8BFFFFFF),
+        system_fill_color_solid_neutral: color!(0x9D9D9D),
+        system_fill_color_attention_background: from_argb!(0x08FFFFFF),
+        system_fill_color_success_background: color!(0x393D1B),
+        system_fill_color_caution_background: color!(0x433519),
+        system_fill_color_critical_background: color!(0x442726),
+        system_fill_color_neutral_background: from_argb!(0x08FFFFFF),
+        system_fill_color_solid_attention_background: color!(0x2E2E2E),
+        system_fill_color_solid_neutral_background: color!(0x2E2E2E),
     };
 
     pub const LIGHT: Self = Self {
         accent_fill_color_default: from_argb!(0xFF005A9E),
         accent_fill_color_secondary: from_argb!(0xE6005A9E),
         accent_fill_color_tertiary: from_argb!(0xCC005A9E),
+        accent_fill_color_selected_text_background: color!(0x0078D7),
 
         acrylic_in_app_fill_color_default_fallback: color!(0xF9F9F9),
 
         text_fill_color_primary: from_argb!(0xE4000000),
         text_fill_color_secondary: from_argb!(0x9E000000),
-        // text_fill_color_tertiary: from_argb!(0x72000000),
+        text_fill_color_tertiary: from_argb!(0x72000000),
         text_fill_color_disabled: from_argb!(0x5C000000),
-        // text_fill_color_inverse: color!(0xFFFFFF),
-        // accent_text_fill_color_disabled: from_argb!(0x5C000000),
-        // text_on_accent_fill_color_selected_text: color!(0xFFFFFF),
+        text_fill_color_inverse: color!(0xFFFFFF),
+        accent_text_fill_color_primary: color!(0x004275),
+        accent_text_fill_color_secondary: color!(0x002642),
+        accent_text_fill_color_tertiary: color!(0x005A9E),
+        accent_text_fill_color_disabled: from_argb!(0x5C000000),
+        text_on_accent_fill_color_selected_text: color!(0xFFFFFF),
         text_on_accent_fill_color_primary: color!(0xFFFFFF),
         text_on_accent_fill_color_secondary: from_argb!(0xB3FFFFFF),
         text_on_accent_fill_color_disabled: color!(0xFFFFFF),
@@ -271,61 +388,145 @@ impl Palette {
         subtle_fill_color_secondary: from_argb!(0x09000000),
         subtle_fill_color_tertiary: from_argb!(0x06000000),
         subtle_fill_color_disabled: from_argb!(0x00FFFFFF),
-        // control_alt_fill_color_transparent: from_argb!(0x00FFFFFF),
+        control_alt_fill_color_transparent: from_argb!(0x00FFFFFF),
         control_alt_fill_color_secondary: from_argb!(0x06000000),
         control_alt_fill_color_tertiary: from_argb!(0x0F000000),
-        // control_alt_fill_color_quarternary: from_argb!(0x18000000),
+        control_alt_fill_color_quarternary: from_argb!(0x18000000),
         control_alt_fill_color_disabled: from_argb!(0x00FFFFFF),
-        // control_on_image_fill_color_default: from_argb!(0xC9FFFFFF),
-        // control_on_image_fill_color_secondary: color!(0xF3F3F3),
-        // control_on_image_fill_color_tertiary: color!(0xEBEBEB),
-        // control_on_image_fill_color_disabled: from_argb!(0x00FFFFFF),
+        control_on_image_fill_color_default: from_argb!(0xC9FFFFFF),
+        control_on_image_fill_color_secondary: color!(0xF3F3F3),
+        control_on_image_fill_color_tertiary: color!(0xEBEBEB),
+        control_on_image_fill_color_disabled: from_argb!(0x00FFFFFF),
         accent_fill_color_disabled: from_argb!(0x37000000),
         control_stroke_color_default: from_argb!(0x0F000000),
         control_stroke_color_secondary: from_argb!(0x29000000),
-        // control_stroke_color_on_accent_default: from_argb!(0x14FFFFFF),
+        control_stroke_color_on_accent_default: from_argb!(0x14FFFFFF),
         control_stroke_color_on_accent_secondary: from_argb!(0x66000000),
-        // control_stroke_color_on_accent_tertiary: from_argb!(0x37000000),
-        // control_stroke_color_on_accent_disabled: from_argb!(0x0F000000),
-        // control_stroke_color_for_strong_fill_when_on_image: from_argb!(0x59FFFFFF),
+        control_stroke_color_on_accent_tertiary: from_argb!(0x37000000),
+        control_stroke_color_on_accent_disabled: from_argb!(0x0F000000),
+        control_stroke_color_for_strong_fill_when_on_image: from_argb!(0x59FFFFFF),
         card_stroke_color_default: from_argb!(0x0F000000),
-        // card_stroke_color_default_solid: color!(0xEBEBEB),
+        card_stroke_color_default_solid: color!(0xEBEBEB),
         control_strong_stroke_color_default: from_argb!(0x72000000),
         control_strong_stroke_color_disabled: from_argb!(0x37000000),
         surface_stroke_color_default: from_argb!(0x66757575),
         surface_stroke_color_flyout: from_argb!(0x0F000000),
-        // surface_stroke_color_inverse: from_argb!(0x15FFFFFF),
+        surface_stroke_color_inverse: from_argb!(0x15FFFFFF),
         divider_stroke_color_default: from_argb!(0x0F000000),
         focus_stroke_color_outer: from_argb!(0xE4000000),
-        // focus_stroke_color_inner: from_argb!(0xB3FFFFFF),
+        focus_stroke_color_inner: from_argb!(0xB3FFFFFF),
         card_background_fill_color_default: from_argb!(0xB3FFFFFF),
-        // card_background_fill_color_secondary: from_argb!(0x80F6F6F6),
+        card_background_fill_color_secondary: from_argb!(0x80F6F6F6),
         smoke_fill_color_default: from_argb!(0x4D000000),
-        // layer_fill_color_default: from_argb!(0x80FFFFFF),
+        layer_fill_color_default: from_argb!(0x80FFFFFF),
         layer_fill_color_alt: color!(0xFFFFFF),
-        // layer_on_acrylic_fill_color_default: from_argb!(0x40FFFFFF),
-        // layer_on_accent_acrylic_fill_color_default: from_argb!(0x40FFFFFF),
-        // layer_on_mica_base_alt_fill_color_default: from_argb!(0xB3FFFFFF),
-        // layer_on_mica_base_alt_fill_color_secondary: from_argb!(0x0A000000),
-        // layer_on_mica_base_alt_fill_color_tertiary: color!(0xF9F9F9),
-        // layer_on_mica_base_alt_fill_color_transparent: from_argb!(0x00000000),
+        layer_on_acrylic_fill_color_default: from_argb!(0x40FFFFFF),
+        layer_on_accent_acrylic_fill_color_default: from_argb!(0x40FFFFFF),
+        layer_on_mica_base_alt_fill_color_default: from_argb!(0xB3FFFFFF),
+        layer_on_mica_base_alt_fill_color_secondary: from_argb!(0x0A000000),
+        layer_on_mica_base_alt_fill_color_tertiary: color!(0xF9F9F9),
+        layer_on_mica_base_alt_fill_color_transparent: from_argb!(0x00000000),
         solid_background_fill_color_base: color!(0xF3F3F3),
-        // solid_background_fill_color_secondary: color!(0xEEEEEE),
+        solid_background_fill_color_secondary: color!(0xEEEEEE),
         solid_background_fill_color_tertiary: color!(0xF9F9F9),
         solid_background_fill_color_quarternary: color!(0xFFFFFF),
-        // solid_background_fill_color_transparent: from_argb!(0x00F3F3F3),
-        // solid_background_fill_color_base_alt: color!(0xDADADA),
-        // system_fill_color_success: color!(0x0F7B0F),
-        // system_fill_color_caution: color!(0x9D5D00),
-        // system_fill_color_critical: color!(0xC42B1C),
-        // system_fill_color_neutral: from_argb!(0x72000000),
-        // system_fill_color_solid_neutral: color!(0x8A8A8A),
-        // system_fill_color_attention_background: from_argb!(0x80F6F6F6),
-        // system_fill_color_success_background: color!(0xDFF6DD),
-        // system_fill_color_caution_background: color!(0xFFF4CE),
-        // system_fill_color_critical_background: color!(0xFDE7E9),
-        // system_fill_color_neutral_background: from_argb!(0x06000000),
-        // system_fill_color_solid_attention_background: color!(0xF7F7F7),
-        // system_fill_color_solid_neutral_background: color!(0xF3F3F3),
+        solid_background_fill_color_transparent: from_argb!(0x00F3F3F3),
+        solid_background_fill_color_base_alt: color!(0xDADADA),
+        system_fill_color_attention: color!(0x005A9E),
+        system_fill_color_success: color!(0x0F7B0F),
+        system_fill_color_caution: color!(0x9D5D00),
+        system_fill_color_critical: color!(0xC42B1C),
+        system_fill_color_neutral: from_argb!(0x72000000),
+        system_fill_color_solid_neutral: color!(0x8A8A8A),
+        system_fill_color_attention_background: from_argb!(0x80F6F6F6),
+        system_fill_color_success_background: color!(0xDFF6DD),
+        system_fill_color_caution_background: color!(0xFFF4CE),
+        system_fill_color_critical_background: color!(0xFDE7E9),
+        system_fill_color_neutral_background: from_argb!(0x06000000),
+        system_fill_color_solid_attention_background: color!(0xF7F7F7),
+        system_fill_color_solid_neutral_background: color!(0xF3F3F3),
     };
+
+    pub const AQUATIC: Self = high_contrast::palette(&high_contrast::AQUATIC);
+    pub const DESERT: Self = high_contrast::palette(&high_contrast::DESERT);
+    pub const DUSK: Self = high_contrast::palette(&high_contrast::DUSK);
+    pub const NIGHT_SKY: Self = high_contrast::palette(&high_contrast::NIGHT_SKY);
+
+    // Dark when white text has more contrast on the background than black
+    pub fn is_dark(&self) -> bool {
+        accent::on(self.solid_background_fill_color_base) == Color::WHITE
+    }
+
+    // Blends every token towards `other`, used to cross-fade between themes
+    pub fn mix(&self, other: &Palette, t: f32) -> Palette {
+        let mut palette = self.clone();
+
+        for name in Self::TOKENS {
+            if let (Some(token), Some(colour)) = (palette.token_mut(name), other.token(name)) {
+                *token = token.interpolate(colour, t);
+            }
+        }
+
+        palette
+    }
+}
+
+// Builds a palette at runtime on top of a built-in or loaded base
+pub struct Builder {
+    palette: Palette,
+    is_dark: bool,
+}
+
+impl Builder {
+    pub fn new(palette: Palette) -> Self {
+        Self {
+            is_dark: palette.is_dark(),
+            palette,
+        }
+    }
+
+    // Derives the accent tokens from a single seed colour the same way WinUI maps
+    // the system accent palette onto its resources:
+    // https://github.com/microsoft/microsoft-ui-xaml/blob/winui3/release/1.5-stable/controls/dev/CommonStyles/Common_themeresources_any.xaml
+    pub fn accent(mut self, seed: Color) -> Self {
+        let ramp = accent::Ramp::new(seed);
+        let palette = &mut self.palette;
+
+        // Dark themes use SystemAccentColorLight2, light themes SystemAccentColorDark1
+        let fill = if self.is_dark {
+            ramp.light2
+        } else {
+            ramp.dark1
+        };
+        let text = accent::on(fill);
+
+        palette.accent_fill_color_default = fill;
+        palette.accent_fill_color_secondary = Color { a: 0.9, ..fill };
+        palette.accent_fill_color_tertiary = Color { a: 0.8, ..fill };
+        palette.accent_fill_color_selected_text_background = ramp.base;
+        palette.text_on_accent_fill_color_primary = text;
+        palette.text_on_accent_fill_color_secondary = Color {
+            a: if text == Color::BLACK { 0.5 } else { 0.7 },
+            ..text
+        };
+        palette.text_on_accent_fill_color_selected_text = accent::on(ramp.base);
+        palette.system_fill_color_attention = fill;
+
+        // Accent text follows the same pattern, one step further from the background
+        if self.is_dark {
+            palette.accent_text_fill_color_primary = ramp.light3;
+            palette.accent_text_fill_color_secondary = ramp.light3;
+            palette.accent_text_fill_color_tertiary = ramp.light2;
+        } else {
+            palette.accent_text_fill_color_primary = ramp.dark2;
+            palette.accent_text_fill_color_secondary = ramp.dark3;
+            palette.accent_text_fill_color_tertiary = ramp.dark1;
+        }
+
+        self
+    }
+
+    pub fn build(self) -> Palette {
+        self.palette
+    }
 }
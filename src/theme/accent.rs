@@ -0,0 +1,158 @@
+use super::contrast;
+
+use iced::{color, Color};
+
+// A selection of the Windows accent colour swatches
+pub const SWATCHES: [Color; 8] = [
+    color!(0xFFB900),
+    color!(0xF7630C),
+    color!(0xE81123),
+    color!(0xE3008C),
+    color!(0x8764B8),
+    color!(0x0063B1),
+    color!(0x00B294),
+    color!(0x107C10),
+];
+
+// Windows derives six shades from the user's accent colour which WinUI then maps
+// onto the AccentFillColor* resources:
+// https://learn.microsoft.com/en-us/windows/apps/design/style/color#accent-color-palette
+// The exact algorithm isn't public so the shades are approximated by moving the
+// lightness in HSL space, which matches the default blue closely.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct Ramp {
+    pub dark3: Color,
+    pub dark2: Color,
+    pub dark1: Color,
+    pub base: Color,
+    pub light1: Color,
+    pub light2: Color,
+    pub light3: Color,
+}
+
+impl Ramp {
+    pub fn new(seed: Color) -> Self {
+        let (hue, saturation, lightness) = to_hsl(seed);
+        let lighten =
+            |amount: f32| from_hsl(hue, saturation, lightness + (1.0 - lightness) * amount);
+        let darken = |amount: f32| from_hsl(hue, saturation, lightness * (1.0 - amount));
+
+        Self {
+            dark3: darken(0.6),
+            dark2: darken(0.4),
+            dark1: darken(0.2),
+            base: Color { a: 1.0, ..seed },
+            light1: lighten(0.25),
+            light2: lighten(0.5),
+            light3: lighten(0.7),
+        }
+    }
+}
+
+// Black or white, whichever reads better on top of `background`
+pub fn on(background: Color) -> Color {
+    if contrast::ratio(background, Color::BLACK) > contrast::ratio(background, Color::WHITE) {
+        Color::BLACK
+    } else {
+        Color::WHITE
+    }
+}
+
+fn to_hsl(colour: Color) -> (f32, f32, f32) {
+    let max = colour.r.max(colour.g).max(colour.b);
+    let min = colour.r.min(colour.g).min(colour.b);
+    let lightness = (max + min) / 2.0;
+    let delta = max - min;
+
+    if delta == 0.0 {
+        return (0.0, 0.0, lightness);
+    }
+
+    let saturation = delta / (1.0 - (2.0 * lightness - 1.0).abs());
+
+    let hue = if max == colour.r {
+        ((colour.g - colour.b) / delta).rem_euclid(6.0)
+    } else if max == colour.g {
+        (colour.b - colour.r) / delta + 2.0
+    } else {
+        (colour.r - colour.g) / delta + 4.0
+    };
+
+    (hue * 60.0, saturation, lightness)
+}
+
+fn from_hsl(hue: f32, saturation: f32, lightness: f32) -> Color {
+    let lightness = lightness.clamp(0.0, 1.0);
+    let chroma = (1.0 - (2.0 * lightness - 1.0).abs()) * saturation;
+    let x = chroma * (1.0 - ((hue / 60.0).rem_euclid(2.0) - 1.0).abs());
+    let m = lightness - chroma / 2.0;
+
+    let (r, g, b) = match hue.rem_euclid(360.0) as u32 / 60 {
+        0 => (chroma, x, 0.0),
+        1 => (x, chroma, 0.0),
+        2 => (0.0, chroma, x),
+        3 => (0.0, x, chroma),
+        4 => (x, 0.0, chroma),
+        _ => (chroma, 0.0, x),
+    };
+
+    Color::from_rgb(r + m, g + m, b + m)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{on, Ramp};
+
+    use crate::theme::{Builder, Palette};
+
+    use iced::{color, Color};
+
+    fn assert_close(actual: Color, expected: Color) {
+        let close = |a: f32, b: f32| (a - b).abs() <= 1.0 / 255.0;
+
+        assert!(
+            close(actual.r, expected.r)
+                && close(actual.g, expected.g)
+                && close(actual.b, expected.b),
+            "{actual:?} is not {expected:?}"
+        );
+    }
+
+    // Pure red sits at 50% lightness, so every shade is easy to work out by hand
+    #[test]
+    fn ramp_moves_the_lightness() {
+        let ramp = Ramp::new(color!(0xFF0000));
+
+        assert_close(ramp.dark3, color!(0x660000));
+        assert_close(ramp.dark2, color!(0x990000));
+        assert_close(ramp.dark1, color!(0xCC0000));
+        assert_close(ramp.base, color!(0xFF0000));
+        assert_close(ramp.light1, color!(0xFF4040));
+        assert_close(ramp.light2, color!(0xFF8080));
+        assert_close(ramp.light3, color!(0xFFB3B3));
+    }
+
+    #[test]
+    fn accent_maps_the_ramp_onto_the_tokens() {
+        let ramp = Ramp::new(color!(0xFF0000));
+        let light = Builder::new(Palette::LIGHT)
+            .accent(color!(0xFF0000))
+            .build();
+        let dark = Builder::new(Palette::DARK).accent(color!(0xFF0000)).build();
+
+        assert_eq!(light.accent_fill_color_default, ramp.dark1);
+        assert_eq!(light.accent_text_fill_color_primary, ramp.dark2);
+        assert_eq!(light.text_on_accent_fill_color_primary, Color::WHITE);
+        assert_eq!(dark.accent_fill_color_default, ramp.light2);
+        assert_eq!(dark.accent_text_fill_color_primary, ramp.light3);
+        assert_eq!(dark.text_on_accent_fill_color_primary, Color::BLACK);
+    }
+
+    // Mid-tones are where the luminance of the raw sRGB values goes wrong: this grey
+    // is 47% by that measure but black text still has more contrast on it
+    #[test]
+    fn text_on_mid_tones_uses_relative_luminance() {
+        assert_eq!(on(color!(0x777777)), Color::BLACK);
+        assert_eq!(on(color!(0x737373)), Color::WHITE);
+    }
+}
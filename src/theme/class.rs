@@ -0,0 +1,322 @@
+use super::Theme;
+
+use crate::style;
+
+use iced::widget::{
+    button, checkbox, container, overlay::menu, pick_list, radio, scrollable, slider, svg,
+    text_input, toggler,
+};
+
+use std::{
+    any::{self, Any, TypeId},
+    borrow::Cow,
+    collections::HashMap,
+    sync::{Arc, LazyLock},
+};
+
+// Named style classes carried by the theme, so an app can restyle all "card"
+// containers or "primary" buttons in one place. Classes are kept per widget kind,
+// told apart by the status and style types of their style function, and the
+// built-in ones are named after the functions in `style::*`. They can be
+// overridden with `register` on a `Registry` handed to `Theme::with_classes`.
+//
+// Widgets without a status, such as containers and menus, are registered with a
+// status of `()`.
+
+pub type Style<S, T> = Arc<dyn Fn(&Theme, S) -> T + Send + Sync>;
+
+// The class a widget is drawn with, either looked up by name when drawn or a
+// style function given at the call site with `.style()`
+pub enum Class<F> {
+    Named(Cow<'static, str>),
+    Custom(F),
+}
+
+impl<F> From<&'static str> for Class<F> {
+    fn from(name: &'static str) -> Self {
+        Class::Named(Cow::Borrowed(name))
+    }
+}
+
+impl<F> From<String> for Class<F> {
+    fn from(name: String) -> Self {
+        Class::Named(Cow::Owned(name))
+    }
+}
+
+impl<'a, S, T> From<Box<dyn Fn(&Theme, S) -> T + 'a>> for Class<Box<dyn Fn(&Theme, S) -> T + 'a>> {
+    fn from(style: Box<dyn Fn(&Theme, S) -> T + 'a>) -> Self {
+        Class::Custom(style)
+    }
+}
+
+impl<'a, T> From<Box<dyn Fn(&Theme) -> T + 'a>> for Class<Box<dyn Fn(&Theme) -> T + 'a>> {
+    fn from(style: Box<dyn Fn(&Theme) -> T + 'a>) -> Self {
+        Class::Custom(style)
+    }
+}
+
+impl<S: 'static, T: 'static> Class<Box<dyn Fn(&Theme, S) -> T + '_>> {
+    pub fn resolve(&self, theme: &Theme, status: S) -> T {
+        match self {
+            Class::Named(name) => theme.classes().get::<S, T>(name)(theme, status),
+            Class::Custom(style) => style(theme, status),
+        }
+    }
+}
+
+impl<T: 'static> Class<Box<dyn Fn(&Theme) -> T + '_>> {
+    pub fn resolve(&self, theme: &Theme) -> T {
+        match self {
+            Class::Named(name) => theme.classes().get::<(), T>(name)(theme, ()),
+            Class::Custom(style) => style(theme),
+        }
+    }
+}
+
+// The class widgets of each kind are drawn with unless told otherwise
+pub const DEFAULT: &str = "default";
+pub const DEFAULT_BUTTON: &str = "secondary";
+pub const DEFAULT_CHECKBOX: &str = "primary";
+pub const DEFAULT_CONTAINER: &str = "transparent";
+
+struct Kind {
+    // For `Debug`, the type ids don't say which widget they belong to
+    style: &'static str,
+    // Unknown names fall back to this rather than drawing nothing
+    default: Cow<'static, str>,
+    classes: HashMap<Cow<'static, str>, Box<dyn Any + Send + Sync>>,
+}
+
+pub struct Registry {
+    kinds: HashMap<TypeId, Kind>,
+}
+
+// Light, Dark and the contrast themes share these
+static BUILT_IN: LazyLock<Arc<Registry>> = LazyLock::new(|| Arc::new(Registry::default()));
+
+impl Registry {
+    pub fn built_in() -> &'static Arc<Self> {
+        &BUILT_IN
+    }
+
+    // Adds a class or replaces an existing one, including the built-in ones. The
+    // first class registered for a widget kind becomes its fallback.
+    pub fn register<S: 'static, T: 'static>(
+        &mut self,
+        name: impl Into<Cow<'static, str>>,
+        style: impl Fn(&Theme, S) -> T + Send + Sync + 'static,
+    ) {
+        let name = name.into();
+        let kind = self
+            .kinds
+            .entry(TypeId::of::<Style<S, T>>())
+            .or_insert_with(|| Kind {
+                style: any::type_name::<T>(),
+                default: name.clone(),
+                classes: HashMap::new(),
+            });
+
+        kind.classes
+            .insert(name, Box::new(Arc::new(style) as Style<S, T>));
+    }
+
+    pub fn register_button(
+        &mut self,
+        name: impl Into<Cow<'static, str>>,
+        style: impl Fn(&Theme, button::Status) -> button::Style + Send + Sync + 'static,
+    ) {
+        self.register(name, style);
+    }
+
+    pub fn register_container(
+        &mut self,
+        name: impl Into<Cow<'static, str>>,
+        style: impl Fn(&Theme) -> container::Style + Send + Sync + 'static,
+    ) {
+        self.register(name, move |theme, ()| style(theme));
+    }
+
+    // Every widget kind in the crate has built-in classes, so this only panics
+    // for a kind that was never registered
+    pub fn get<S: 'static, T: 'static>(&self, name: &str) -> Style<S, T> {
+        let kind = self
+            .kinds
+            .get(&TypeId::of::<Style<S, T>>())
+            .unwrap_or_else(|| panic!("no classes are registered for {}", any::type_name::<T>()));
+
+        kind.classes
+            .get(name)
+            .or_else(|| kind.classes.get(&kind.default))
+            .and_then(|style| style.downcast_ref::<Style<S, T>>())
+            .cloned()
+            .expect("classes are stored under their own type")
+    }
+
+    pub fn button(&self, name: &str) -> Style<button::Status, button::Style> {
+        self.get(name)
+    }
+
+    pub fn container(
+        &self,
+        name: &str,
+    ) -> impl Fn(&Theme) -> container::Style + Send + Sync + 'static {
+        let style = self.get::<(), container::Style>(name);
+
+        move |theme| style(theme, ())
+    }
+}
+
+impl Default for Registry {
+    fn default() -> Self {
+        let mut classes = Self {
+            kinds: HashMap::new(),
+        };
+
+        // Defaults first so unknown names fall back to them
+        classes.register_button(DEFAULT_BUTTON, style::button::secondary);
+        classes.register_button("primary", style::button::primary);
+        classes.register_button("transparent", style::button::transparent);
+        classes.register_button("hyperlink", style::button::hyperlink);
+        classes.register_button("flyout", style::button::flyout);
+        classes.register_button("menu_item", style::button::menu_item);
+        classes.register_button("nav_item", style::button::nav_item);
+        classes.register_button("split_content", style::button::split_content);
+        classes.register_button("split_indicator", style::button::split_indicator);
+
+        classes.register_container(DEFAULT_CONTAINER, style::container::transparent);
+        classes.register_container("card", style::container::card);
+        classes.register_container("overlay", style::container::overlay);
+        classes.register_container("dialog", style::container::dialog);
+
+        classes.register::<checkbox::Status, _>(DEFAULT_CHECKBOX, style::checkbox::primary);
+        classes.register::<(), menu::Style>(DEFAULT, |theme, ()| style::menu::default(theme));
+        classes.register::<pick_list::Status, _>(DEFAULT, style::pick_list::default);
+        classes.register::<radio::Status, _>(DEFAULT, style::radio::default);
+        classes.register::<scrollable::Status, _>(DEFAULT, style::scrollable::default);
+        classes.register::<slider::Status, _>(DEFAULT, style::slider::default);
+        classes.register::<svg::Status, _>(DEFAULT, style::svg::default);
+        classes.register::<svg::Status, _>("icon", style::svg::icon);
+        classes.register::<text_input::Status, _>(DEFAULT, style::text_input::default);
+        classes.register::<toggler::Status, _>(DEFAULT, style::toggler::default);
+
+        classes.register::<iced_aw::style::Status, _>(DEFAULT, style::menu_bar::default);
+        classes.register::<iced_aw::style::Status, _>(DEFAULT, style::number_input::default);
+
+        classes.register(DEFAULT, style::breadcrumb_bar::default);
+        classes.register(DEFAULT, style::calendar_view::default);
+        classes.register(DEFAULT, style::data_grid::default);
+        classes.register(DEFAULT, style::list_item::default);
+        classes.register(DEFAULT, style::progress::default);
+        classes.register(DEFAULT, style::quad::default);
+        classes.register("separator", style::quad::separator);
+        classes.register(DEFAULT, style::split_view::default);
+        classes.register("divider", style::split_view::divider);
+        classes.register(DEFAULT, style::tab_view::default);
+        classes.register(DEFAULT, style::time_picker::default);
+        classes.register(DEFAULT, style::underline::default);
+
+        classes
+    }
+}
+
+// Registries are compared by identity, style functions can't be compared
+impl PartialEq for Registry {
+    fn eq(&self, other: &Self) -> bool {
+        std::ptr::eq(self, other)
+    }
+}
+
+impl std::fmt::Debug for Registry {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let mut kinds: Vec<_> = self.kinds.values().collect();
+        kinds.sort_by_key(|kind| kind.style);
+
+        f.debug_map()
+            .entries(
+                kinds
+                    .into_iter()
+                    .map(|kind| (kind.style, kind.classes.keys().collect::<Vec<_>>())),
+            )
+            .finish()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    use crate::theme::{high_contrast::Contrast, Appearance};
+
+    use iced::{widget::button::Catalog as _, Color};
+
+    fn red(theme: &Theme, status: button::Status) -> button::Style {
+        style::button::secondary(theme, status).with_background(Color::from_rgb(1.0, 0.0, 0.0))
+    }
+
+    fn with_red_buttons(appearance: Appearance) -> Theme {
+        let mut classes = Registry::default();
+        classes.register_button(DEFAULT_BUTTON, red);
+
+        Theme::from(appearance).with_classes(Arc::new(classes))
+    }
+
+    fn default_button(theme: &Theme) -> button::Style {
+        theme.style(
+            &<Theme as button::Catalog>::default(),
+            button::Status::Active,
+        )
+    }
+
+    #[test]
+    fn widgets_default_to_the_registered_class() {
+        let theme = with_red_buttons(Appearance::Light);
+
+        assert_eq!(
+            default_button(&theme).background,
+            Some(Color::from_rgb(1.0, 0.0, 0.0).into())
+        );
+    }
+
+    #[test]
+    fn with_classes_keeps_the_appearance() {
+        for appearance in [
+            Appearance::Light,
+            Appearance::Dark,
+            Appearance::HighContrast(Contrast::Aquatic),
+        ] {
+            let theme = with_red_buttons(appearance.clone());
+
+            assert_eq!(theme.appearance(), &appearance);
+            assert_eq!(
+                default_button(&theme).background,
+                Some(Color::from_rgb(1.0, 0.0, 0.0).into())
+            );
+        }
+    }
+
+    #[test]
+    fn unknown_names_fall_back_to_the_default_class() {
+        let theme = Theme::from(Appearance::Light);
+        let style = theme.style(&"no_such_class".into(), button::Status::Active);
+
+        assert_eq!(
+            style.background,
+            style::button::secondary(&theme, button::Status::Active).background
+        );
+    }
+
+    #[test]
+    fn classes_are_kept_per_widget_kind() {
+        let mut classes = Registry::default();
+        classes.register_container("primary", style::container::card);
+
+        let theme = Theme::from(Appearance::Light).with_classes(Arc::new(classes));
+        let style = theme.style(&"primary".into(), button::Status::Active);
+
+        assert_eq!(
+            style.background,
+            style::button::primary(&theme, button::Status::Active).background
+        );
+    }
+}
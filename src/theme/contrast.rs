@@ -0,0 +1,41 @@
+// WCAG 2.x contrast: https://www.w3.org/TR/WCAG21/#dfn-contrast-ratio
+use iced::Color;
+
+// Body text, large text and UI components respectively
+pub const AA: f32 = 4.5;
+pub const AAA: f32 = 7.0;
+pub const AA_LARGE: f32 = 3.0;
+
+pub fn relative_luminance(colour: Color) -> f32 {
+    let [r, g, b, _] = colour.into_linear();
+
+    0.2126 * r + 0.7152 * g + 0.0722 * b
+}
+
+// Both colours are assumed opaque, use `over` first for translucent ones
+pub fn ratio(a: Color, b: Color) -> f32 {
+    let (a, b) = (relative_luminance(a), relative_luminance(b));
+    let (lighter, darker) = if a > b { (a, b) } else { (b, a) };
+
+    (lighter + 0.05) / (darker + 0.05)
+}
+
+// Source-over compositing of `foreground` onto `background` in sRGB space,
+// which is how the renderer blends layers
+pub fn over(foreground: Color, background: Color) -> Color {
+    let alpha = foreground.a + background.a * (1.0 - foreground.a);
+
+    if alpha == 0.0 {
+        return Color::TRANSPARENT;
+    }
+
+    let blend =
+        |f: f32, b: f32| (f * foreground.a + b * background.a * (1.0 - foreground.a)) / alpha;
+
+    Color {
+        r: blend(foreground.r, background.r),
+        g: blend(foreground.g, background.g),
+        b: blend(foreground.b, background.b),
+        a: alpha,
+    }
+}
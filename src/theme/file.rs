@@ -0,0 +1,258 @@
+// Reads and writes palettes as TOML or JSON token files, for example:
+//
+// base = "Dark"
+//
+// [tokens]
+// AccentFillColorDefault = "#FFFFB900"
+// TextFillColorPrimary = "#FFFFFFFF"
+//
+// With a base only the listed tokens are overridden, without one every token
+// has to be given.
+use crate::theme::{tokens, Palette};
+
+use serde::{Deserialize, Serialize};
+
+use std::{collections::BTreeMap, fmt, fs, io, path::Path};
+
+#[derive(Clone, Copy, Debug, Deserialize, PartialEq, Serialize)]
+pub enum Base {
+    Light,
+    Dark,
+}
+
+impl Base {
+    fn palette(self) -> Palette {
+        match self {
+            Base::Light => Palette::LIGHT,
+            Base::Dark => Palette::DARK,
+        }
+    }
+}
+
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum Format {
+    Toml,
+    Json,
+}
+
+impl Format {
+    pub fn from_path(path: &Path) -> Option<Self> {
+        match path.extension()?.to_str()?.to_ascii_lowercase().as_str() {
+            "toml" => Some(Format::Toml),
+            "json" => Some(Format::Json),
+            _ => None,
+        }
+    }
+}
+
+#[derive(Debug, Deserialize, Serialize)]
+#[serde(deny_unknown_fields)]
+struct File {
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    base: Option<Base>,
+    #[serde(default)]
+    tokens: BTreeMap<String, String>,
+}
+
+#[derive(Debug)]
+pub enum Error {
+    Io(io::Error),
+    UnsupportedFormat,
+    Parse(String),
+    Serialize(String),
+    UnknownTokens(Vec<String>),
+    MissingTokens(Vec<&'static str>),
+    InvalidColour { token: String, value: String },
+}
+
+impl fmt::Display for Error {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Error::Io(error) => write!(f, "{error}"),
+            Error::UnsupportedFormat => write!(f, "Theme files must be .toml or .json"),
+            Error::Parse(error) | Error::Serialize(error) => write!(f, "{error}"),
+            Error::UnknownTokens(tokens) => write!(f, "Unknown tokens: {}", tokens.join(", ")),
+            Error::MissingTokens(tokens) => write!(
+                f,
+                "Missing tokens (set a base to only override some): {}",
+                tokens.join(", ")
+            ),
+            Error::InvalidColour { token, value } => {
+                write!(
+                    f,
+                    "{token} has an invalid colour \"{value}\", expected #AARRGGBB"
+                )
+            }
+        }
+    }
+}
+
+impl std::error::Error for Error {}
+
+impl From<io::Error> for Error {
+    fn from(error: io::Error) -> Self {
+        Error::Io(error)
+    }
+}
+
+pub fn load(path: impl AsRef<Path>) -> Result<Palette, Error> {
+    let path = path.as_ref();
+    let format = Format::from_path(path).ok_or(Error::UnsupportedFormat)?;
+
+    parse(&fs::read_to_string(path)?, format)
+}
+
+pub fn save(palette: &Palette, path: impl AsRef<Path>) -> Result<(), Error> {
+    let path = path.as_ref();
+    let format = Format::from_path(path).ok_or(Error::UnsupportedFormat)?;
+
+    Ok(fs::write(path, serialize(palette, format)?)?)
+}
+
+pub fn parse(contents: &str, format: Format) -> Result<Palette, Error> {
+    let file: File = match format {
+        Format::Toml => {
+            toml::from_str(contents).map_err(|error| Error::Parse(error.to_string()))?
+        }
+        Format::Json => {
+            serde_json::from_str(contents).map_err(|error| Error::Parse(error.to_string()))?
+        }
+    };
+
+    let unknown: Vec<String> = file
+        .tokens
+        .keys()
+        .filter(|name| !Palette::TOKENS.contains(&name.as_str()))
+        .cloned()
+        .collect();
+
+    if !unknown.is_empty() {
+        return Err(Error::UnknownTokens(unknown));
+    }
+
+    if file.base.is_none() {
+        let missing: Vec<&'static str> = Palette::TOKENS
+            .iter()
+            .filter(|name| !file.tokens.contains_key(**name))
+            .copied()
+            .collect();
+
+        if !missing.is_empty() {
+            return Err(Error::MissingTokens(missing));
+        }
+    }
+
+    // Without a base every token is overwritten so the starting palette doesn't matter
+    let mut palette = file.base.unwrap_or(Base::Dark).palette();
+
+    for (token, value) in file.tokens {
+        let colour = tokens::from_hex(&value).ok_or_else(|| Error::InvalidColour {
+            token: token.clone(),
+            value: value.clone(),
+        })?;
+
+        if let Some(slot) = palette.token_mut(&token) {
+            *slot = colour;
+        }
+    }
+
+    Ok(palette)
+}
+
+// Writes every token so the file can be loaded without a base
+pub fn serialize(palette: &Palette, format: Format) -> Result<String, Error> {
+    let file = File {
+        base: None,
+        tokens: palette
+            .tokens()
+            .map(|(name, colour)| (name.to_string(), tokens::to_hex(colour)))
+            .collect(),
+    };
+
+    match format {
+        Format::Toml => toml::to_string(&file).map_err(|error| Error::Serialize(error.to_string())),
+        Format::Json => {
+            serde_json::to_string_pretty(&file).map_err(|error| Error::Serialize(error.to_string()))
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{load, parse, save, Error, Format};
+
+    use crate::theme::Palette;
+
+    use iced::color;
+
+    #[test]
+    fn saved_files_load_back() {
+        let directory = std::env::temp_dir().join(format!("theme-file-{}", std::process::id()));
+        std::fs::create_dir_all(&directory).unwrap();
+
+        for palette in [Palette::LIGHT, Palette::DARK] {
+            for extension in ["toml", "json"] {
+                let path = directory.join(format!("theme.{extension}"));
+
+                save(&palette, &path).unwrap();
+                assert_eq!(load(&path).unwrap(), palette, "{}", path.display());
+            }
+        }
+
+        std::fs::remove_dir_all(directory).unwrap();
+    }
+
+    #[test]
+    fn tokens_override_the_base() {
+        let palette = parse(
+            "base = \"Light\"\n[tokens]\nAccentFillColorDefault = \"#FF102030\"\n",
+            Format::Toml,
+        )
+        .unwrap();
+
+        assert_eq!(palette.accent_fill_color_default, color!(0x102030));
+        assert_eq!(
+            palette.text_fill_color_primary,
+            Palette::LIGHT.text_fill_color_primary
+        );
+    }
+
+    #[test]
+    fn bad_colours_are_rejected() {
+        let result = parse(
+            r##"{ "base": "Dark", "tokens": { "TextFillColorPrimary": "#+FFFFFF" } }"##,
+            Format::Json,
+        );
+
+        assert!(
+            matches!(result, Err(Error::InvalidColour { token, .. }) if token == "TextFillColorPrimary")
+        );
+    }
+
+    #[test]
+    fn unknown_keys_are_rejected() {
+        let result = parse(
+            "base = \"Dark\"\n[tokens]\nTextFillColourPrimary = \"#FFFFFFFF\"\n",
+            Format::Toml,
+        );
+        assert!(
+            matches!(result, Err(Error::UnknownTokens(tokens)) if tokens == ["TextFillColourPrimary"])
+        );
+
+        let result = parse(
+            r##"{ "base": "Dark", "accent": "#FFFFFFFF" }"##,
+            Format::Json,
+        );
+        assert!(matches!(result, Err(Error::Parse(_))));
+    }
+
+    #[test]
+    fn files_without_a_base_need_every_token() {
+        let result = parse(
+            "[tokens]\nTextFillColorPrimary = \"#FFFFFFFF\"\n",
+            Format::Toml,
+        );
+
+        assert!(matches!(result, Err(Error::MissingTokens(_))));
+    }
+}
@@ -0,0 +1,214 @@
+// Windows contrast themes only define a handful of system colours which WinUI maps
+// onto every other resource:
+// https://github.com/microsoft/microsoft-ui-xaml/blob/winui3/release/1.5-stable/controls/dev/CommonStyles/Common_themeresources_any.xaml
+// Colours are the Windows 11 defaults from Settings > Accessibility > Contrast themes.
+use crate::theme::Palette;
+
+use iced::{color, Color};
+
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct SystemColors {
+    pub window: Color,
+    pub window_text: Color,
+    pub hotlight: Color,
+    pub gray_text: Color,
+    pub highlight: Color,
+    pub highlight_text: Color,
+    pub button_face: Color,
+    pub button_text: Color,
+}
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum Contrast {
+    Aquatic,
+    Desert,
+    Dusk,
+    NightSky,
+}
+
+impl Contrast {
+    pub const ALL: [Contrast; 4] = [
+        Contrast::Aquatic,
+        Contrast::Desert,
+        Contrast::Dusk,
+        Contrast::NightSky,
+    ];
+
+    pub fn system_colors(&self) -> &'static SystemColors {
+        match self {
+            Contrast::Aquatic => &AQUATIC,
+            Contrast::Desert => &DESERT,
+            Contrast::Dusk => &DUSK,
+            Contrast::NightSky => &NIGHT_SKY,
+        }
+    }
+
+    pub fn palette(&self) -> &'static Palette {
+        match self {
+            Contrast::Aquatic => &Palette::AQUATIC,
+            Contrast::Desert => &Palette::DESERT,
+            Contrast::Dusk => &Palette::DUSK,
+            Contrast::NightSky => &Palette::NIGHT_SKY,
+        }
+    }
+}
+
+impl std::fmt::Display for Contrast {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Contrast::Aquatic => write!(f, "Aquatic"),
+            Contrast::Desert => write!(f, "Desert"),
+            Contrast::Dusk => write!(f, "Dusk"),
+            Contrast::NightSky => write!(f, "Night sky"),
+        }
+    }
+}
+
+pub const AQUATIC: SystemColors = SystemColors {
+    window: color!(0x202020),
+    window_text: color!(0xFFFFFF),
+    hotlight: color!(0x75E9FC),
+    gray_text: color!(0xA6A6A6),
+    highlight: color!(0x8EE3F0),
+    highlight_text: color!(0x263B50),
+    button_face: color!(0x202020),
+    button_text: color!(0xFFFFFF),
+};
+
+pub const DESERT: SystemColors = SystemColors {
+    window: color!(0xFFFAEF),
+    window_text: color!(0x3D3D3D),
+    hotlight: color!(0x1C5E75),
+    gray_text: color!(0x676767),
+    highlight: color!(0x903909),
+    highlight_text: color!(0xFFF5E3),
+    button_face: color!(0xFFFAEF),
+    button_text: color!(0x3D3D3D),
+};
+
+pub const DUSK: SystemColors = SystemColors {
+    window: color!(0x2D3236),
+    window_text: color!(0xFFFFFF),
+    hotlight: color!(0x70EBDE),
+    gray_text: color!(0xA6A6A6),
+    highlight: color!(0xA1BFDE),
+    highlight_text: color!(0x212D3B),
+    button_face: color!(0x2D3236),
+    button_text: color!(0xB6F6F0),
+};
+
+pub const NIGHT_SKY: SystemColors = SystemColors {
+    window: color!(0x000000),
+    window_text: color!(0xFFFFFF),
+    hotlight: color!(0x8080FF),
+    gray_text: color!(0xA6A6A6),
+    highlight: color!(0xD6B4FD),
+    highlight_text: color!(0x2B2B2B),
+    button_face: color!(0x000000),
+    button_text: color!(0xFFEE32),
+};
+
+// Translucent fills don't exist in high contrast so every token resolves to one of
+// the solid system colours
+pub const fn palette(colours: &SystemColors) -> Palette {
+    let transparent = Color {
+        a: 0.0,
+        ..colours.window
+    };
+
+    Palette {
+        accent_fill_color_default: colours.highlight,
+        accent_fill_color_secondary: colours.highlight,
+        accent_fill_color_tertiary: colours.highlight,
+        accent_fill_color_selected_text_background: colours.highlight,
+
+        acrylic_in_app_fill_color_default_fallback: colours.window,
+
+        text_fill_color_primary: colours.window_text,
+        text_fill_color_secondary: colours.window_text,
+        text_fill_color_tertiary: colours.window_text,
+        text_fill_color_disabled: colours.gray_text,
+        text_fill_color_inverse: colours.window,
+        accent_text_fill_color_primary: colours.hotlight,
+        accent_text_fill_color_secondary: colours.hotlight,
+        accent_text_fill_color_tertiary: colours.hotlight,
+        accent_text_fill_color_disabled: colours.gray_text,
+        text_on_accent_fill_color_selected_text: colours.highlight_text,
+        text_on_accent_fill_color_primary: colours.highlight_text,
+        text_on_accent_fill_color_secondary: colours.highlight_text,
+        text_on_accent_fill_color_disabled: colours.window,
+        control_fill_color_default: colours.button_face,
+        control_fill_color_secondary: colours.button_face,
+        control_fill_color_tertiary: colours.button_face,
+        control_fill_color_disabled: colours.window,
+        control_fill_color_transparent: transparent,
+        control_fill_color_input_active: colours.window,
+        control_strong_fill_color_default: colours.window_text,
+        control_strong_fill_color_disabled: colours.gray_text,
+        control_solid_fill_color_default: colours.window,
+        subtle_fill_color_transparent: transparent,
+        subtle_fill_color_secondary: colours.highlight,
+        subtle_fill_color_tertiary: colours.highlight,
+        subtle_fill_color_disabled: transparent,
+        control_alt_fill_color_transparent: transparent,
+        control_alt_fill_color_secondary: colours.window,
+        control_alt_fill_color_tertiary: colours.window,
+        control_alt_fill_color_quarternary: colours.window,
+        control_alt_fill_color_disabled: colours.window,
+        control_on_image_fill_color_default: colours.window,
+        control_on_image_fill_color_secondary: colours.window,
+        control_on_image_fill_color_tertiary: colours.window,
+        control_on_image_fill_color_disabled: colours.window,
+        accent_fill_color_disabled: colours.gray_text,
+        control_stroke_color_default: colours.button_text,
+        control_stroke_color_secondary: colours.button_text,
+        control_stroke_color_on_accent_default: colours.highlight_text,
+        control_stroke_color_on_accent_secondary: colours.highlight_text,
+        control_stroke_color_on_accent_tertiary: colours.highlight_text,
+        control_stroke_color_on_accent_disabled: colours.gray_text,
+        control_stroke_color_for_strong_fill_when_on_image: colours.window_text,
+        card_stroke_color_default: colours.window_text,
+        card_stroke_color_default_solid: colours.window_text,
+        control_strong_stroke_color_default: colours.window_text,
+        control_strong_stroke_color_disabled: colours.gray_text,
+        surface_stroke_color_default: colours.window_text,
+        surface_stroke_color_flyout: colours.window_text,
+        surface_stroke_color_inverse: colours.window,
+        divider_stroke_color_default: colours.window_text,
+        focus_stroke_color_outer: colours.window_text,
+        focus_stroke_color_inner: colours.window,
+        card_background_fill_color_default: colours.window,
+        card_background_fill_color_secondary: colours.window,
+        smoke_fill_color_default: Color {
+            a: 0.6,
+            ..colours.window
+        },
+        layer_fill_color_default: colours.window,
+        layer_fill_color_alt: colours.window,
+        layer_on_acrylic_fill_color_default: colours.window,
+        layer_on_accent_acrylic_fill_color_default: colours.window,
+        layer_on_mica_base_alt_fill_color_default: colours.window,
+        layer_on_mica_base_alt_fill_color_secondary: colours.window,
+        layer_on_mica_base_alt_fill_color_tertiary: colours.window,
+        layer_on_mica_base_alt_fill_color_transparent: transparent,
+        solid_background_fill_color_base: colours.window,
+        solid_background_fill_color_secondary: colours.window,
+        solid_background_fill_color_tertiary: colours.window,
+        solid_background_fill_color_quarternary: colours.window,
+        solid_background_fill_color_transparent: transparent,
+        solid_background_fill_color_base_alt: colours.window,
+        system_fill_color_attention: colours.window_text,
+        system_fill_color_success: colours.window_text,
+        system_fill_color_caution: colours.window_text,
+        system_fill_color_critical: colours.window_text,
+        system_fill_color_neutral: colours.window_text,
+        system_fill_color_solid_neutral: colours.window_text,
+        system_fill_color_attention_background: colours.window,
+        system_fill_color_success_background: colours.window,
+        system_fill_color_caution_background: colours.window,
+        system_fill_color_critical_background: colours.window,
+        system_fill_color_neutral_background: colours.window,
+        system_fill_color_solid_attention_background: colours.window,
+        system_fill_color_solid_neutral_background: colours.window,
+    }
+}
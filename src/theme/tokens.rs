@@ -0,0 +1,188 @@
+// Maps the Palette fields to their WinUI resource names (without the "Brush" suffix)
+// so palettes can be read from and written to token files.
+use crate::theme::Palette;
+
+use iced::Color;
+
+macro_rules! tokens {
+    ($($name:literal => $field:ident,)*) => {
+        impl Palette {
+            pub const TOKENS: &'static [&'static str] = &[$($name,)*];
+
+            pub fn token(&self, name: &str) -> Option<Color> {
+                match name {
+                    $($name => Some(self.$field),)*
+                    _ => None,
+                }
+            }
+
+            pub fn token_mut(&mut self, name: &str) -> Option<&mut Color> {
+                match name {
+                    $($name => Some(&mut self.$field),)*
+                    _ => None,
+                }
+            }
+
+            pub fn tokens(&self) -> impl Iterator<Item = (&'static str, Color)> + '_ {
+                Self::TOKENS
+                    .iter()
+                    .filter_map(|name| self.token(name).map(|colour| (*name, colour)))
+            }
+        }
+    };
+}
+
+tokens! {
+    "AccentFillColorDefault" => accent_fill_color_default,
+    "AccentFillColorSecondary" => accent_fill_color_secondary,
+    "AccentFillColorTertiary" => accent_fill_color_tertiary,
+    "AccentFillColorSelectedTextBackground" => accent_fill_color_selected_text_background,
+    "AcrylicInAppFillColorDefault" => acrylic_in_app_fill_color_default_fallback,
+    "TextFillColorPrimary" => text_fill_color_primary,
+    "TextFillColorSecondary" => text_fill_color_secondary,
+    "TextFillColorTertiary" => text_fill_color_tertiary,
+    "TextFillColorDisabled" => text_fill_color_disabled,
+    "TextFillColorInverse" => text_fill_color_inverse,
+    "AccentTextFillColorPrimary" => accent_text_fill_color_primary,
+    "AccentTextFillColorSecondary" => accent_text_fill_color_secondary,
+    "AccentTextFillColorTertiary" => accent_text_fill_color_tertiary,
+    "AccentTextFillColorDisabled" => accent_text_fill_color_disabled,
+    "TextOnAccentFillColorSelectedText" => text_on_accent_fill_color_selected_text,
+    "TextOnAccentFillColorPrimary" => text_on_accent_fill_color_primary,
+    "TextOnAccentFillColorSecondary" => text_on_accent_fill_color_secondary,
+    "TextOnAccentFillColorDisabled" => text_on_accent_fill_color_disabled,
+    "ControlFillColorDefault" => control_fill_color_default,
+    "ControlFillColorSecondary" => control_fill_color_secondary,
+    "ControlFillColorTertiary" => control_fill_color_tertiary,
+    "ControlFillColorDisabled" => control_fill_color_disabled,
+    "ControlFillColorTransparent" => control_fill_color_transparent,
+    "ControlFillColorInputActive" => control_fill_color_input_active,
+    "ControlStrongFillColorDefault" => control_strong_fill_color_default,
+    "ControlStrongFillColorDisabled" => control_strong_fill_color_disabled,
+    "ControlSolidFillColorDefault" => control_solid_fill_color_default,
+    "SubtleFillColorTransparent" => subtle_fill_color_transparent,
+    "SubtleFillColorSecondary" => subtle_fill_color_secondary,
+    "SubtleFillColorTertiary" => subtle_fill_color_tertiary,
+    "SubtleFillColorDisabled" => subtle_fill_color_disabled,
+    "ControlAltFillColorTransparent" => control_alt_fill_color_transparent,
+    "ControlAltFillColorSecondary" => control_alt_fill_color_secondary,
+    "ControlAltFillColorTertiary" => control_alt_fill_color_tertiary,
+    "ControlAltFillColorQuarternary" => control_alt_fill_color_quarternary,
+    "ControlAltFillColorDisabled" => control_alt_fill_color_disabled,
+    "ControlOnImageFillColorDefault" => control_on_image_fill_color_default,
+    "ControlOnImageFillColorSecondary" => control_on_image_fill_color_secondary,
+    "ControlOnImageFillColorTertiary" => control_on_image_fill_color_tertiary,
+    "ControlOnImageFillColorDisabled" => control_on_image_fill_color_disabled,
+    "AccentFillColorDisabled" => accent_fill_color_disabled,
+    "ControlStrokeColorDefault" => control_stroke_color_default,
+    "ControlStrokeColorSecondary" => control_stroke_color_secondary,
+    "ControlStrokeColorOnAccentDefault" => control_stroke_color_on_accent_default,
+    "ControlStrokeColorOnAccentSecondary" => control_stroke_color_on_accent_secondary,
+    "ControlStrokeColorOnAccentTertiary" => control_stroke_color_on_accent_tertiary,
+    "ControlStrokeColorOnAccentDisabled" => control_stroke_color_on_accent_disabled,
+    "ControlStrokeColorForStrongFillWhenOnImage" => control_stroke_color_for_strong_fill_when_on_image,
+    "CardStrokeColorDefault" => card_stroke_color_default,
+    "CardStrokeColorDefaultSolid" => card_stroke_color_default_solid,
+    "ControlStrongStrokeColorDefault" => control_strong_stroke_color_default,
+    "ControlStrongStrokeColorDisabled" => control_strong_stroke_color_disabled,
+    "SurfaceStrokeColorDefault" => surface_stroke_color_default,
+    "SurfaceStrokeColorFlyout" => surface_stroke_color_flyout,
+    "SurfaceStrokeColorInverse" => surface_stroke_color_inverse,
+    "DividerStrokeColorDefault" => divider_stroke_color_default,
+    "FocusStrokeColorOuter" => focus_stroke_color_outer,
+    "FocusStrokeColorInner" => focus_stroke_color_inner,
+    "CardBackgroundFillColorDefault" => card_background_fill_color_default,
+    "CardBackgroundFillColorSecondary" => card_background_fill_color_secondary,
+    "SmokeFillColorDefault" => smoke_fill_color_default,
+    "LayerFillColorDefault" => layer_fill_color_default,
+    "LayerFillColorAlt" => layer_fill_color_alt,
+    "LayerOnAcrylicFillColorDefault" => layer_on_acrylic_fill_color_default,
+    "LayerOnAccentAcrylicFillColorDefault" => layer_on_accent_acrylic_fill_color_default,
+    "LayerOnMicaBaseAltFillColorDefault" => layer_on_mica_base_alt_fill_color_default,
+    "LayerOnMicaBaseAltFillColorSecondary" => layer_on_mica_base_alt_fill_color_secondary,
+    "LayerOnMicaBaseAltFillColorTertiary" => layer_on_mica_base_alt_fill_color_tertiary,
+    "LayerOnMicaBaseAltFillColorTransparent" => layer_on_mica_base_alt_fill_color_transparent,
+    "SolidBackgroundFillColorBase" => solid_background_fill_color_base,
+    "SolidBackgroundFillColorSecondary" => solid_background_fill_color_secondary,
+    "SolidBackgroundFillColorTertiary" => solid_background_fill_color_tertiary,
+    "SolidBackgroundFillColorQuarternary" => solid_background_fill_color_quarternary,
+    "SolidBackgroundFillColorTransparent" => solid_background_fill_color_transparent,
+    "SolidBackgroundFillColorBaseAlt" => solid_background_fill_color_base_alt,
+    "SystemFillColorAttention" => system_fill_color_attention,
+    "SystemFillColorSuccess" => system_fill_color_success,
+    "SystemFillColorCaution" => system_fill_color_caution,
+    "SystemFillColorCritical" => system_fill_color_critical,
+    "SystemFillColorNeutral" => system_fill_color_neutral,
+    "SystemFillColorSolidNeutral" => system_fill_color_solid_neutral,
+    "SystemFillColorAttentionBackground" => system_fill_color_attention_background,
+    "SystemFillColorSuccessBackground" => system_fill_color_success_background,
+    "SystemFillColorCautionBackground" => system_fill_color_caution_background,
+    "SystemFillColorCriticalBackground" => system_fill_color_critical_background,
+    "SystemFillColorNeutralBackground" => system_fill_color_neutral_background,
+    "SystemFillColorSolidAttentionBackground" => system_fill_color_solid_attention_background,
+    "SystemFillColorSolidNeutralBackground" => system_fill_color_solid_neutral_background,
+}
+
+// Token files use XAML's #AARRGGBB notation, #RRGGBB is accepted as opaque
+pub fn to_hex(colour: Color) -> String {
+    let [r, g, b, a] = colour.into_rgba8();
+
+    format!("#{a:02X}{r:02X}{g:02X}{b:02X}")
+}
+
+pub fn from_hex(hex: &str) -> Option<Color> {
+    let digits = hex.strip_prefix('#')?;
+
+    // `from_str_radix` would also take a leading sign
+    if !digits.bytes().all(|digit| digit.is_ascii_hexdigit()) {
+        return None;
+    }
+
+    let value = u32::from_str_radix(digits, 16).ok()?;
+
+    let argb = match digits.len() {
+        6 => 0xFF000000 | value,
+        8 => value,
+        _ => return None,
+    };
+
+    let [a, r, g, b] = argb.to_be_bytes();
+
+    Some(Color::from_rgba8(r, g, b, a as f32 / 255.0))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{from_hex, to_hex};
+
+    use iced::Color;
+
+    #[test]
+    fn hex_round_trips() {
+        let colour = Color::from_rgba8(0x12, 0x34, 0x56, 0x78 as f32 / 255.0);
+
+        assert_eq!(to_hex(colour), "#78123456");
+        assert_eq!(from_hex("#78123456"), Some(colour));
+        assert_eq!(
+            from_hex("#123456"),
+            Some(Color::from_rgb8(0x12, 0x34, 0x56))
+        );
+    }
+
+    #[test]
+    fn bad_hex_is_rejected() {
+        for hex in [
+            "",
+            "#",
+            "FFFFFF",
+            "#FFFFF",
+            "#FFFFFFFFF",
+            "#+FFFFFF",
+            "#-FFFFFF",
+            "#GGGGGG",
+            "# FFFFFF",
+        ] {
+            assert_eq!(from_hex(hex), None, "{hex:?} was accepted");
+        }
+    }
+}
@@ -0,0 +1,394 @@
+// Generates complete palettes from an accent and an optional neutral seed, along
+// the lines of Aurora's tonal palettes (vendor/aurora/demo/tonal). Aurora works in
+// HCT, here tones are taken in OKLCH which is cheaper and close enough perceptually:
+// https://bottosson.github.io/posts/oklab/
+//
+// The built-in palette for the chosen mode is used as the template. Neutral tokens
+// keep their lightness and alpha but take on the neutral seed's hue, accent tokens
+// are taken from fixed tones of the accent seed and text tokens are then nudged
+// until they meet their contrast target.
+
+// The OKLab matrices are kept exactly as published
+#![allow(clippy::excessive_precision)]
+
+use crate::theme::{contrast, Palette};
+
+use iced::Color;
+
+// Neutrals are clamped so surfaces stay calm even with a saturated seed
+const MAX_NEUTRAL_CHROMA: f32 = 0.04;
+const DEFAULT_NEUTRAL_CHROMA: f32 = 0.012;
+
+// Anything below this is treated as grey in the template
+const ACHROMATIC: f32 = 0.02;
+
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct Oklch {
+    pub lightness: f32,
+    pub chroma: f32,
+    pub hue: f32,
+}
+
+impl Oklch {
+    pub fn from_color(colour: Color) -> Self {
+        let [r, g, b, _] = colour.into_linear();
+
+        let l = (0.4122214708 * r + 0.5363325363 * g + 0.0514459929 * b).cbrt();
+        let m = (0.2119034982 * r + 0.6806995451 * g + 0.1073969566 * b).cbrt();
+        let s = (0.0883024619 * r + 0.2817188376 * g + 0.6299787005 * b).cbrt();
+
+        let lightness = 0.2104542553 * l + 0.7936177850 * m - 0.0040720468 * s;
+        let a = 1.9779984951 * l - 2.4285922050 * m + 0.4505937099 * s;
+        let b = 0.0259040371 * l + 0.7827717662 * m - 0.8086757660 * s;
+
+        Self {
+            lightness,
+            chroma: a.hypot(b),
+            hue: b.atan2(a).to_degrees().rem_euclid(360.0),
+        }
+    }
+
+    // Chroma is reduced until the colour fits in sRGB, keeping lightness and hue
+    pub fn to_color(self, alpha: f32) -> Color {
+        if let Some(colour) = self.to_srgb(alpha) {
+            return colour;
+        }
+
+        let (mut low, mut high) = (0.0, self.chroma);
+
+        for _ in 0..16 {
+            let chroma = (low + high) / 2.0;
+
+            if self.with_chroma(chroma).to_srgb(alpha).is_some() {
+                low = chroma;
+            } else {
+                high = chroma;
+            }
+        }
+
+        let colour = self.with_chroma(low);
+
+        colour
+            .to_srgb(alpha)
+            .unwrap_or_else(|| gray(colour.lightness, alpha))
+    }
+
+    pub fn with_lightness(self, lightness: f32) -> Self {
+        Self {
+            lightness: lightness.clamp(0.0, 1.0),
+            ..self
+        }
+    }
+
+    pub fn with_chroma(self, chroma: f32) -> Self {
+        Self {
+            chroma: chroma.max(0.0),
+            ..self
+        }
+    }
+
+    fn to_srgb(self, alpha: f32) -> Option<Color> {
+        let (a, b) = (
+            self.chroma * self.hue.to_radians().cos(),
+            self.chroma * self.hue.to_radians().sin(),
+        );
+
+        let l = (self.lightness + 0.3963377774 * a + 0.2158037573 * b).powi(3);
+        let m = (self.lightness - 0.1055613458 * a - 0.0638541728 * b).powi(3);
+        let s = (self.lightness - 0.0894841775 * a - 1.2914855480 * b).powi(3);
+
+        let linear = [
+            4.0767416621 * l - 3.3077115913 * m + 0.2309699292 * s,
+            -1.2684380046 * l + 2.6097574011 * m - 0.3413193965 * s,
+            -0.0041960863 * l - 0.7034186147 * m + 1.7076147010 * s,
+        ];
+
+        // Allow for rounding error at the edges of the gamut
+        if linear.iter().any(|c| !(-1e-4..=1.0 + 1e-4).contains(c)) {
+            return None;
+        }
+
+        let [r, g, b] = linear.map(|c| c.clamp(0.0, 1.0));
+
+        Some(Color::from_linear_rgba(r, g, b, alpha))
+    }
+}
+
+fn gray(lightness: f32, alpha: f32) -> Color {
+    let value = lightness.clamp(0.0, 1.0).powi(3);
+
+    Color::from_linear_rgba(value, value, value, alpha)
+}
+
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct Tonal {
+    accent: Oklch,
+    neutral: Oklch,
+}
+
+impl Tonal {
+    pub fn new(accent: Color) -> Self {
+        let accent = Oklch::from_color(accent);
+
+        Self {
+            accent,
+            neutral: accent.with_chroma(accent.chroma.min(DEFAULT_NEUTRAL_CHROMA)),
+        }
+    }
+
+    // Tints the surfaces with a second seed instead of the accent's hue
+    pub fn neutral(mut self, seed: Color) -> Self {
+        let neutral = Oklch::from_color(seed);
+        self.neutral = neutral.with_chroma(neutral.chroma.min(MAX_NEUTRAL_CHROMA));
+        self
+    }
+
+    pub fn light(&self) -> Palette {
+        self.generate(Palette::LIGHT, false)
+    }
+
+    pub fn dark(&self) -> Palette {
+        self.generate(Palette::DARK, true)
+    }
+
+    fn generate(&self, template: Palette, is_dark: bool) -> Palette {
+        let mut palette = template.clone();
+
+        for (name, colour) in template.tokens() {
+            let tone = Oklch::from_color(colour);
+
+            if tone.chroma < ACHROMATIC {
+                if let Some(slot) = palette.token_mut(name) {
+                    *slot = self
+                        .neutral
+                        .with_lightness(tone.lightness)
+                        .to_color(colour.a);
+                }
+            }
+        }
+
+        // Tones roughly follow where WinUI places the default blue
+        let accent =
+            |lightness: f32, alpha: f32| self.accent.with_lightness(lightness).to_color(alpha);
+
+        let (fill, text_primary, text_secondary, text_tertiary) = if is_dark {
+            (0.78, 0.88, 0.88, 0.78)
+        } else {
+            (0.45, 0.37, 0.29, 0.45)
+        };
+
+        palette.accent_fill_color_default = accent(fill, 1.0);
+        palette.accent_fill_color_secondary = accent(fill, 0.9);
+        palette.accent_fill_color_tertiary = accent(fill, 0.8);
+        palette.accent_fill_color_selected_text_background = accent(0.55, 1.0);
+        palette.accent_text_fill_color_primary = accent(text_primary, 1.0);
+        palette.accent_text_fill_color_secondary = accent(text_secondary, 1.0);
+        palette.accent_text_fill_color_tertiary = accent(text_tertiary, 1.0);
+        palette.system_fill_color_attention = palette.accent_fill_color_default;
+
+        enforce_contrast(&mut palette, is_dark);
+
+        palette
+    }
+}
+
+// The surfaces text is most commonly drawn on, flattened onto the window background
+pub fn text_backgrounds(palette: &Palette) -> [Color; 5] {
+    let base = palette.solid_background_fill_color_base;
+    let page = palette.solid_background_fill_color_tertiary;
+
+    [
+        base,
+        page,
+        palette.solid_background_fill_color_quarternary,
+        contrast::over(palette.card_background_fill_color_default, page),
+        contrast::over(palette.control_fill_color_default, page),
+    ]
+}
+
+// The ratio of the weakest pairing of `foreground` with `backgrounds`
+pub fn min_contrast(foreground: Color, backgrounds: &[Color]) -> f32 {
+    backgrounds
+        .iter()
+        .map(|background| contrast::ratio(contrast::over(foreground, *background), *background))
+        .fold(f32::INFINITY, f32::min)
+}
+
+fn enforce_contrast(palette: &mut Palette, is_dark: bool) {
+    let backgrounds = text_backgrounds(palette);
+
+    for (text, target) in [
+        (&mut palette.text_fill_color_primary, contrast::AAA),
+        (&mut palette.text_fill_color_secondary, contrast::AA),
+        (&mut palette.text_fill_color_tertiary, contrast::AA),
+        (&mut palette.accent_text_fill_color_primary, contrast::AA),
+        (&mut palette.accent_text_fill_color_secondary, contrast::AA),
+        (&mut palette.accent_text_fill_color_tertiary, contrast::AA),
+    ] {
+        *text = towards_contrast(*text, &backgrounds, target, is_dark);
+    }
+
+    let accent = [palette.accent_fill_color_default];
+
+    palette.text_on_accent_fill_color_primary = black_or_white(&accent);
+    palette.text_on_accent_fill_color_secondary = towards_contrast(
+        palette.text_on_accent_fill_color_primary.scale_alpha(0.7),
+        &accent,
+        contrast::AA,
+        palette.text_on_accent_fill_color_primary == Color::WHITE,
+    );
+    palette.text_on_accent_fill_color_selected_text =
+        black_or_white(&[palette.accent_fill_color_selected_text_background]);
+}
+
+// Whichever of black and white contrasts best with all of `backgrounds`. One of them
+// is always at least 4.5:1 against a single opaque colour.
+fn black_or_white(backgrounds: &[Color]) -> Color {
+    if min_contrast(Color::BLACK, backgrounds) > min_contrast(Color::WHITE, backgrounds) {
+        Color::BLACK
+    } else {
+        Color::WHITE
+    }
+}
+
+// Moves `colour` towards white on dark surfaces (black on light ones) and then
+// makes it more opaque until `target` is met. If it's still short the colour is
+// dropped for black or white.
+fn towards_contrast(colour: Color, backgrounds: &[Color], target: f32, lighten: bool) -> Color {
+    let mut tone = Oklch::from_color(colour);
+    let mut alpha = colour.a;
+    let step = if lighten { 0.01 } else { -0.01 };
+
+    loop {
+        let candidate = tone.to_color(alpha);
+
+        if min_contrast(candidate, backgrounds) >= target {
+            return candidate;
+        }
+
+        let extreme = if lighten {
+            tone.lightness >= 1.0
+        } else {
+            tone.lightness <= 0.0
+        };
+
+        if !extreme {
+            tone = tone.with_lightness(tone.lightness + step);
+        } else if alpha < 1.0 {
+            alpha = (alpha + 0.05).min(1.0);
+        } else {
+            return black_or_white(backgrounds);
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    // Seeds around the hue circle at a spread of lightnesses, from near black to
+    // near white
+    fn seeds() -> impl Iterator<Item = Color> {
+        (0..12).flat_map(|hue| {
+            [0.15, 0.3, 0.45, 0.6, 0.75, 0.9].map(|lightness| {
+                Oklch {
+                    lightness,
+                    chroma: 0.2,
+                    hue: hue as f32 * 30.0,
+                }
+                .to_color(1.0)
+            })
+        })
+    }
+
+    fn palettes() -> impl Iterator<Item = Palette> {
+        seeds().flat_map(|seed| {
+            let tonal = Tonal::new(seed);
+            let tinted = tonal.neutral(Color::from_rgb(0.9, 0.3, 0.1));
+
+            [tonal.light(), tonal.dark(), tinted.light(), tinted.dark()]
+        })
+    }
+
+    #[test]
+    fn text_meets_its_target() {
+        for palette in palettes() {
+            let backgrounds = text_backgrounds(&palette);
+
+            for (name, text, target) in [
+                (
+                    "text primary",
+                    palette.text_fill_color_primary,
+                    contrast::AAA,
+                ),
+                (
+                    "text secondary",
+                    palette.text_fill_color_secondary,
+                    contrast::AA,
+                ),
+                (
+                    "text tertiary",
+                    palette.text_fill_color_tertiary,
+                    contrast::AA,
+                ),
+                (
+                    "accent text primary",
+                    palette.accent_text_fill_color_primary,
+                    contrast::AA,
+                ),
+                (
+                    "accent text secondary",
+                    palette.accent_text_fill_color_secondary,
+                    contrast::AA,
+                ),
+                (
+                    "accent text tertiary",
+                    palette.accent_text_fill_color_tertiary,
+                    contrast::AA,
+                ),
+            ] {
+                let ratio = min_contrast(text, &backgrounds);
+
+                assert!(ratio >= target, "{name} is {ratio}:1, {target}:1 needed");
+            }
+        }
+    }
+
+    #[test]
+    fn text_on_accent_meets_its_target() {
+        for palette in palettes() {
+            for (name, text, fill) in [
+                (
+                    "text on accent primary",
+                    palette.text_on_accent_fill_color_primary,
+                    palette.accent_fill_color_default,
+                ),
+                (
+                    "text on accent secondary",
+                    palette.text_on_accent_fill_color_secondary,
+                    palette.accent_fill_color_default,
+                ),
+                (
+                    "text on accent selected text",
+                    palette.text_on_accent_fill_color_selected_text,
+                    palette.accent_fill_color_selected_text_background,
+                ),
+            ] {
+                let ratio = min_contrast(text, &[fill]);
+
+                assert!(ratio >= contrast::AA, "{name} is {ratio}:1 on {fill:?}");
+            }
+        }
+    }
+
+    #[test]
+    fn falls_back_to_black_or_white() {
+        let grey = Color::from_rgb(0.5, 0.5, 0.5);
+
+        // Nothing reaches 7:1 on mid grey, so the best of black and white is used
+        assert_eq!(
+            towards_contrast(grey, &[grey], contrast::AAA, true),
+            Color::BLACK
+        );
+    }
+}
@@ -1,26 +1,40 @@
+pub mod breadcrumb_bar;
 pub mod button;
+pub mod calendar_date_picker;
+pub mod calendar_view;
 pub mod canvas;
 pub mod checkbox;
 pub mod combo_box;
+pub mod data_grid;
 pub mod dialog;
+pub mod flyout;
+pub mod info_bar;
+pub mod list_view;
 pub mod menu;
 pub mod number_input;
 pub mod pick_list;
+pub mod progress;
 pub mod quad;
 pub mod radio;
 pub mod ribbon;
 pub mod scrollable;
 pub mod side_nav;
 pub mod slider;
+pub mod split_view;
+pub mod tab_view;
+pub mod teaching_tip;
 pub mod text;
 pub mod text_input;
+pub mod time_picker;
 pub mod toggler;
+pub mod tree_view;
 pub mod underline;
+pub mod virtual_scroll;
 
 use crate::theme::Theme;
 
 type Renderer = iced::Renderer;
-pub type Button<'a, Message> = iced::widget::Button<'a, Message, Theme, Renderer>;
+pub type Button<'a, Message> = crate::widget::button::Button<'a, Message, Theme, Renderer>;
 pub type Canvas<P, Message> = iced::widget::Canvas<P, Message, Theme, Renderer>;
 pub type Checkbox<'a, Message> = iced::widget::Checkbox<'a, Message, Theme, Renderer>;
 pub type Column<'a, Message> = iced::widget::Column<'a, Message, Theme, Renderer>;
@@ -40,6 +54,5 @@ pub type Scrollable<'a, Message> = iced::widget::Scrollable<'a, Message, Theme,
 pub type Slider<'a, T, Message> = iced::widget::Slider<'a, T, Message, Theme>;
 pub type Text<'a> = iced::widget::Text<'a, Theme, Renderer>;
 pub type TextInput<'a, Message> = iced::widget::TextInput<'a, Message, Theme, Renderer>;
-pub type Toggler<'a, Message> = iced::widget::Toggler<'a, Message, Theme, Renderer>;
 pub type Wrap<'a, Message, Direction> =
     iced_aw::widget::Wrap<'a, Message, Direction, Theme, Renderer>;
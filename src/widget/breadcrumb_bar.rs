@@ -0,0 +1,945 @@
+use crate::{
+    density::{self, Metrics},
+    fluent_icon::FluentIcon,
+    font,
+    icon::Icon,
+    style::breadcrumb_bar::{Status, Style, StyleFn},
+    theme::{
+        class::{self, Class},
+        Theme,
+    },
+    widget::{text, Element, Row},
+};
+
+use iced::{
+    advanced::{
+        layout::{Limits, Node},
+        overlay,
+        renderer::{self, Renderer as _},
+        text::{LineHeight, Paragraph as _, Renderer as _, Shaping, Text, Wrapping},
+        widget::{tree, Tree},
+        Clipboard, Layout, Shell, Widget,
+    },
+    alignment::{Horizontal, Vertical},
+    event,
+    keyboard::{self, key::Named, Key},
+    mouse::{self, Cursor},
+    touch,
+    widget::{button, container},
+    Alignment, Background, Border, Color, Event, Length, Pixels, Point, Rectangle, Size, Vector,
+};
+
+type Renderer = iced::Renderer;
+
+// BreadcrumbBar sizes from
+// https://github.com/microsoft/microsoft-ui-xaml/blob/main/src/controls/dev/Breadcrumb/BreadcrumbBar_themeresources.xaml
+const ITEM_PADDING: f32 = 4.0;
+const SEPARATOR_WIDTH: f32 = 24.0;
+const ELLIPSIS_WIDTH: f32 = 32.0;
+const CHEVRON_SIZE: f32 = 12.0;
+const ELLIPSIS_SIZE: f32 = 16.0;
+// Menus follow MenuFlyout
+const MENU_PADDING: f32 = 4.0;
+const MENU_ITEM_PADDING: f32 = 12.0;
+const MENU_MIN_WIDTH: f32 = 120.0;
+// Closest a menu gets to the edges of the window
+const MARGIN: f32 = 8.0;
+const GAP: f32 = 4.0;
+
+pub struct Item {
+    label: String,
+    icon: Option<Icon>,
+    choices: Vec<String>,
+}
+
+impl Item {
+    pub fn new(label: impl Into<String>) -> Self {
+        Self {
+            label: label.into(),
+            icon: None,
+            choices: Vec::new(),
+        }
+    }
+
+    pub fn icon(mut self, icon: impl Into<Icon>) -> Self {
+        self.icon = Some(icon.into());
+        self
+    }
+
+    // What can follow this item in the path, listed in a menu opened from the
+    // separator after it. For all but the last item these are the siblings of the
+    // next item.
+    pub fn choices(mut self, choices: impl IntoIterator<Item = impl Into<String>>) -> Self {
+        self.choices = choices.into_iter().map(Into::into).collect();
+        self
+    }
+}
+
+// Based on https://learn.microsoft.com/en-us/windows/apps/design/controls/breadcrumbbar
+// and Aurora's AuroraBreadcrumbBar
+//
+// The host owns the path, the widget reports which item was clicked and which
+// choice was picked from a separator's menu. When the path doesn't fit the leading
+// items fold into an ellipsis menu, the last item is always shown.
+pub struct BreadcrumbBar<'a, Message> {
+    items: Vec<Item>,
+    on_press: Box<dyn Fn(usize) -> Message + 'a>,
+    on_choice: Option<Box<dyn Fn(usize, usize) -> Message + 'a>>,
+    size: Option<f32>,
+    width: Length,
+    class: Class<StyleFn>,
+}
+
+impl<'a, Message> BreadcrumbBar<'a, Message>
+where
+    Message: 'a + Clone,
+{
+    pub fn new(on_press: impl Fn(usize) -> Message + 'a) -> Self {
+        Self {
+            items: Vec::new(),
+            on_press: Box::new(on_press),
+            on_choice: None,
+            size: None,
+            width: Length::Fill,
+            class: class::DEFAULT.into(),
+        }
+    }
+
+    pub fn push(mut self, item: Item) -> Self {
+        self.items.push(item);
+        self
+    }
+
+    pub fn extend(mut self, items: impl IntoIterator<Item = Item>) -> Self {
+        self.items.extend(items);
+        self
+    }
+
+    // Called with the item's index and the choice's index, separators only open
+    // their menus when this is set
+    pub fn on_choice(mut self, on_choice: impl Fn(usize, usize) -> Message + 'a) -> Self {
+        self.on_choice = Some(Box::new(on_choice));
+        self
+    }
+
+    // The items' text size before text scaling, Body by default
+    pub fn size(mut self, size: f32) -> Self {
+        self.size = Some(size);
+        self
+    }
+
+    pub fn width(mut self, width: impl Into<Length>) -> Self {
+        self.width = width.into();
+        self
+    }
+
+    pub fn style(mut self, style: impl Fn(&Theme, Status) -> Style + 'static) -> Self {
+        self.class = Class::Custom(Box::new(style));
+        self
+    }
+
+    pub fn class(mut self, class: impl Into<Class<StyleFn>>) -> Self {
+        self.class = class.into();
+        self
+    }
+}
+
+impl<'a, Message> From<BreadcrumbBar<'a, Message>> for Element<'a, Message>
+where
+    Message: 'a + Clone,
+{
+    fn from(breadcrumb_bar: BreadcrumbBar<'a, Message>) -> Self {
+        let BreadcrumbBar {
+            items,
+            on_press,
+            on_choice,
+            size,
+            width,
+            class,
+        } = breadcrumb_bar;
+
+        let mut labels = Vec::with_capacity(items.len());
+        let mut names = Vec::with_capacity(items.len());
+        let mut choices = Vec::with_capacity(items.len());
+
+        for item in items {
+            let label = text::body1(item.label.clone()).wrapping(Wrapping::None);
+            let label = match size {
+                Some(size) => label
+                    .size(text::scaled(size))
+                    .line_height(LineHeight::default()),
+                None => label,
+            };
+
+            let icon_size = size.map_or(16.0, text::scaled);
+
+            labels.push(
+                Row::new()
+                    .push_maybe(item.icon.map(|icon| icon.view(icon_size)))
+                    .push(label)
+                    .spacing(8)
+                    .align_y(Alignment::Center)
+                    .into(),
+            );
+            names.push(item.label);
+            choices.push(item.choices);
+        }
+
+        Element::new(Bar {
+            labels,
+            names,
+            choices,
+            on_press,
+            on_choice,
+            width,
+            metrics: density::metrics(),
+            class,
+        })
+    }
+}
+
+struct Bar<'a, Message> {
+    labels: Vec<Element<'a, Message>>,
+    names: Vec<String>,
+    choices: Vec<Vec<String>>,
+    on_press: Box<dyn Fn(usize) -> Message + 'a>,
+    on_choice: Option<Box<dyn Fn(usize, usize) -> Message + 'a>>,
+    width: Length,
+    // Read when built, the density isn't known during layout
+    metrics: Metrics,
+    class: Class<StyleFn>,
+}
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+enum Target {
+    Item(usize),
+    Ellipsis,
+    // The separator after an item
+    Separator(usize),
+}
+
+#[derive(Default)]
+struct State {
+    // How many leading items are folded into the ellipsis, set on every layout
+    collapsed: usize,
+    pressed: Option<Target>,
+    menu: Option<Menu>,
+}
+
+struct Menu {
+    target: Target,
+    // The entry under the cursor or picked with the arrow keys
+    highlighted: Option<usize>,
+    offset: f32,
+}
+
+impl<'a, Message> Bar<'a, Message> {
+    fn has_menu(&self, index: usize) -> bool {
+        self.on_choice.is_some() && self.choices.get(index).is_some_and(|c| !c.is_empty())
+    }
+
+    // Separators are drawn between items and after the last one if it has choices
+    fn has_separator(&self, index: usize) -> bool {
+        index + 1 < self.labels.len() || self.has_menu(index)
+    }
+
+    fn item_bounds(&self, layout: Layout<'_>, index: usize) -> Rectangle {
+        let bounds = layout.bounds();
+        let label = layout.children().nth(index).unwrap().bounds();
+
+        Rectangle {
+            x: label.x - ITEM_PADDING,
+            y: bounds.y,
+            width: label.width + 2.0 * ITEM_PADDING,
+            height: bounds.height,
+        }
+    }
+
+    fn ellipsis_bounds(&self, layout: Layout<'_>) -> Rectangle {
+        Rectangle {
+            width: ELLIPSIS_WIDTH,
+            ..layout.bounds()
+        }
+    }
+
+    // The separator after the last folded item follows the ellipsis
+    fn separator_bounds(&self, state: &State, layout: Layout<'_>, index: usize) -> Rectangle {
+        let before = if index + 1 == state.collapsed {
+            self.ellipsis_bounds(layout)
+        } else {
+            self.item_bounds(layout, index)
+        };
+
+        Rectangle {
+            x: before.x + before.width,
+            width: SEPARATOR_WIDTH,
+            ..before
+        }
+    }
+
+    fn bounds_of(&self, state: &State, layout: Layout<'_>, target: Target) -> Rectangle {
+        match target {
+            Target::Item(index) => self.item_bounds(layout, index),
+            Target::Ellipsis => self.ellipsis_bounds(layout),
+            Target::Separator(index) => self.separator_bounds(state, layout, index),
+        }
+    }
+
+    fn separators(&self, state: &State) -> impl Iterator<Item = usize> + '_ {
+        (state.collapsed.saturating_sub(1)..self.labels.len())
+            .filter(|index| self.has_separator(*index))
+    }
+
+    fn target_at(&self, state: &State, layout: Layout<'_>, cursor: Cursor) -> Option<Target> {
+        if !cursor.is_over(layout.bounds()) {
+            return None;
+        }
+
+        if state.collapsed > 0 && cursor.is_over(self.ellipsis_bounds(layout)) {
+            return Some(Target::Ellipsis);
+        }
+
+        (state.collapsed..self.labels.len())
+            .map(Target::Item)
+            .chain(
+                self.separators(state)
+                    .filter(|index| self.has_menu(*index))
+                    .map(Target::Separator),
+            )
+            .find(|target| cursor.is_over(self.bounds_of(state, layout, *target)))
+    }
+
+    fn toggle_menu(&self, state: &mut State, target: Target) {
+        state.menu = match &state.menu {
+            Some(menu) if menu.target == target => None,
+            _ => Some(Menu {
+                target,
+                highlighted: None,
+                offset: 0.0,
+            }),
+        };
+    }
+}
+
+impl<'a, Message> Widget<Message, Theme, Renderer> for Bar<'a, Message>
+where
+    Message: 'a + Clone,
+{
+    fn tag(&self) -> tree::Tag {
+        tree::Tag::of::<State>()
+    }
+
+    fn state(&self) -> tree::State {
+        tree::State::new(State::default())
+    }
+
+    fn children(&self) -> Vec<Tree> {
+        self.labels.iter().map(Tree::new).collect()
+    }
+
+    fn diff(&self, tree: &mut Tree) {
+        tree.diff_children(&self.labels);
+
+        // A menu belongs to the path it was opened on, close it if that went away
+        let state = tree.state.downcast_mut::<State>();
+
+        if let Some(Target::Separator(index)) = state.menu.as_ref().map(|menu| menu.target) {
+            if !self.has_menu(index) {
+                state.menu = None;
+            }
+        }
+    }
+
+    fn size(&self) -> Size<Length> {
+        Size::new(self.width, Length::Shrink)
+    }
+
+    fn layout(&self, tree: &mut Tree, renderer: &Renderer, limits: &Limits) -> Node {
+        let limits = limits.width(self.width).height(Length::Shrink);
+        let label_limits = Limits::new(Size::ZERO, Size::new(f32::INFINITY, limits.max().height));
+
+        let labels: Vec<Node> = self
+            .labels
+            .iter()
+            .zip(&mut tree.children)
+            .map(|(label, tree)| label.as_widget().layout(tree, renderer, &label_limits))
+            .collect();
+
+        let count = labels.len();
+        let widths: Vec<f32> = labels
+            .iter()
+            .map(|label| label.size().width + 2.0 * ITEM_PADDING)
+            .collect();
+
+        let separator = |index| {
+            if self.has_separator(index) {
+                SEPARATOR_WIDTH
+            } else {
+                0.0
+            }
+        };
+
+        let width_from = |start: usize| {
+            let ellipsis = if start > 0 {
+                ELLIPSIS_WIDTH + SEPARATOR_WIDTH
+            } else {
+                0.0
+            };
+
+            ellipsis
+                + (start..count)
+                    .map(|index| widths[index] + separator(index))
+                    .sum::<f32>()
+        };
+
+        // Fold as few leading items as it takes to fit, down to only the last one
+        let available = limits.max().width;
+        let collapsed = (0..count)
+            .find(|start| width_from(*start) <= available)
+            .unwrap_or(count.saturating_sub(1));
+
+        let state = tree.state.downcast_mut::<State>();
+        state.collapsed = collapsed;
+
+        let height = labels
+            .iter()
+            .map(|label| label.size().height)
+            .fold(self.metrics.control_height - 2.0 * ITEM_PADDING, f32::max)
+            + 2.0 * ITEM_PADDING;
+
+        let mut x = if collapsed > 0 {
+            ELLIPSIS_WIDTH + SEPARATOR_WIDTH
+        } else {
+            0.0
+        };
+
+        let children = labels
+            .into_iter()
+            .enumerate()
+            .map(|(index, label)| {
+                if index < collapsed {
+                    return label;
+                }
+
+                let y = (height - label.size().height) / 2.0;
+                let label = label.move_to(Point::new(x + ITEM_PADDING, y));
+
+                x += widths[index] + separator(index);
+
+                label
+            })
+            .collect();
+
+        let size = limits.resolve(self.width, Length::Shrink, Size::new(x, height));
+
+        Node::with_children(size, children)
+    }
+
+    fn on_event(
+        &mut self,
+        tree: &mut Tree,
+        event: Event,
+        layout: Layout<'_>,
+        cursor: Cursor,
+        _renderer: &Renderer,
+        _clipboard: &mut dyn Clipboard,
+        shell: &mut Shell<'_, Message>,
+        _viewport: &Rectangle,
+    ) -> event::Status {
+        let state = tree.state.downcast_mut::<State>();
+
+        match event {
+            Event::Mouse(mouse::Event::ButtonPressed(mouse::Button::Left))
+            | Event::Touch(touch::Event::FingerPressed { .. }) => {
+                let Some(target) = self.target_at(state, layout, cursor) else {
+                    return event::Status::Ignored;
+                };
+
+                // Menus open as soon as they're pressed, items act once released
+                match target {
+                    Target::Item(_) => state.pressed = Some(target),
+                    Target::Ellipsis | Target::Separator(_) => self.toggle_menu(state, target),
+                }
+
+                event::Status::Captured
+            }
+            Event::Mouse(mouse::Event::ButtonReleased(mouse::Button::Left))
+            | Event::Touch(touch::Event::FingerLifted { .. }) => {
+                let Some(Target::Item(index)) = state.pressed.take() else {
+                    return event::Status::Ignored;
+                };
+
+                if self.target_at(state, layout, cursor) != Some(Target::Item(index)) {
+                    return event::Status::Ignored;
+                }
+
+                state.menu = None;
+                shell.publish((self.on_press)(index));
+
+                event::Status::Captured
+            }
+            Event::Touch(touch::Event::FingerLost { .. }) => {
+                state.pressed = None;
+
+                event::Status::Ignored
+            }
+            _ => event::Status::Ignored,
+        }
+    }
+
+    fn draw(
+        &self,
+        tree: &Tree,
+        renderer: &mut Renderer,
+        theme: &Theme,
+        _style: &renderer::Style,
+        layout: Layout<'_>,
+        cursor: Cursor,
+        viewport: &Rectangle,
+    ) {
+        let state = tree.state.downcast_ref::<State>();
+        let bounds = layout.bounds();
+        let hovered = self.target_at(state, layout, cursor);
+        let open = state.menu.as_ref().map(|menu| menu.target);
+        let count = self.labels.len();
+
+        let status = |target| {
+            if open == Some(target) || state.pressed == Some(target) && hovered == Some(target) {
+                Status::Pressed
+            } else if count.checked_sub(1).map(Target::Item) == Some(target) {
+                Status::Current
+            } else if hovered == Some(target) {
+                Status::Hovered
+            } else {
+                Status::Active
+            }
+        };
+
+        let glyph = |renderer: &mut Renderer, target, icon: FluentIcon, size, color| {
+            let bounds = self.bounds_of(state, layout, target);
+
+            renderer.fill_text(
+                Text {
+                    content: icon.codepoint().to_string(),
+                    bounds: bounds.size(),
+                    size: Pixels(size),
+                    line_height: LineHeight::default(),
+                    font: font::icons(),
+                    horizontal_alignment: Horizontal::Center,
+                    vertical_alignment: Vertical::Center,
+                    shaping: Shaping::Advanced,
+                    wrapping: Wrapping::default(),
+                },
+                bounds.center(),
+                color,
+                bounds,
+            );
+        };
+
+        let button = |renderer: &mut Renderer, target, icon, size| {
+            let style = self.class.resolve(theme, status(target));
+            let bounds = self.bounds_of(state, layout, target);
+
+            renderer.fill_quad(
+                renderer::Quad {
+                    bounds: bounds.shrink(2.0),
+                    border: Border {
+                        radius: 4.0.into(),
+                        ..Border::default()
+                    },
+                    ..renderer::Quad::default()
+                },
+                style.background,
+            );
+
+            glyph(renderer, target, icon, size, style.text_color);
+        };
+
+        renderer.with_layer(
+            bounds.intersection(viewport).unwrap_or(bounds),
+            |renderer| {
+                if state.collapsed > 0 {
+                    button(renderer, Target::Ellipsis, FluentIcon::More, ELLIPSIS_SIZE);
+                }
+
+                for index in self.separators(state) {
+                    let target = Target::Separator(index);
+
+                    if self.has_menu(index) {
+                        let icon = if open == Some(target) {
+                            FluentIcon::ChevronDown
+                        } else {
+                            FluentIcon::ChevronRight
+                        };
+
+                        button(renderer, target, icon, CHEVRON_SIZE);
+                    } else {
+                        let style = self.class.resolve(theme, Status::Active);
+
+                        glyph(
+                            renderer,
+                            target,
+                            FluentIcon::ChevronRight,
+                            CHEVRON_SIZE,
+                            style.text_color,
+                        );
+                    }
+                }
+
+                for (index, (label, (tree, layout))) in self
+                    .labels
+                    .iter()
+                    .zip(tree.children.iter().zip(layout.children()))
+                    .enumerate()
+                    .skip(state.collapsed)
+                {
+                    let style = self.class.resolve(theme, status(Target::Item(index)));
+
+                    label.as_widget().draw(
+                        tree,
+                        renderer,
+                        theme,
+                        &renderer::Style {
+                            text_color: style.text_color,
+                        },
+                        layout,
+                        cursor,
+                        &bounds,
+                    );
+                }
+            },
+        );
+    }
+
+    fn mouse_interaction(
+        &self,
+        tree: &Tree,
+        layout: Layout<'_>,
+        cursor: Cursor,
+        _viewport: &Rectangle,
+        _renderer: &Renderer,
+    ) -> mouse::Interaction {
+        let state = tree.state.downcast_ref::<State>();
+
+        if self.target_at(state, layout, cursor).is_some() {
+            mouse::Interaction::Pointer
+        } else {
+            mouse::Interaction::default()
+        }
+    }
+
+    fn overlay<'b>(
+        &'b mut self,
+        tree: &'b mut Tree,
+        layout: Layout<'_>,
+        _renderer: &Renderer,
+        translation: Vector,
+    ) -> Option<overlay::Element<'b, Message, Theme, Renderer>> {
+        let state = tree.state.downcast_mut::<State>();
+        let target = state.menu.as_ref()?.target;
+        let anchor = self.bounds_of(state, layout, target) + translation;
+
+        let entries = match target {
+            Target::Ellipsis => &self.names[..state.collapsed],
+            Target::Separator(index) => &self.choices[index][..],
+            Target::Item(_) => return None,
+        };
+
+        Some(overlay::Element::new(Box::new(MenuOverlay {
+            state,
+            entries,
+            anchor,
+            on_press: self.on_press.as_ref(),
+            on_choice: &self.on_choice,
+            row_height: self.metrics.menu_item_height,
+        })))
+    }
+}
+
+// The folded items or an item's choices, listed under the ellipsis or separator
+// that opened them
+struct MenuOverlay<'a, 'b, Message> {
+    state: &'b mut State,
+    entries: &'b [String],
+    anchor: Rectangle,
+    on_press: &'b (dyn Fn(usize) -> Message + 'a),
+    on_choice: &'b Option<Box<dyn Fn(usize, usize) -> Message + 'a>>,
+    row_height: f32,
+}
+
+impl<Message> MenuOverlay<'_, '_, Message> {
+    fn entry_at(&self, bounds: Rectangle, cursor: Cursor) -> Option<usize> {
+        let position = cursor.position_over(bounds.shrink(MENU_PADDING))?;
+        let offset = self.state.menu.as_ref().map_or(0.0, |menu| menu.offset);
+        let index = ((position.y - bounds.y - MENU_PADDING + offset) / self.row_height) as usize;
+
+        (index < self.entries.len()).then_some(index)
+    }
+
+    fn max_offset(&self, bounds: Rectangle) -> f32 {
+        (self.entries.len() as f32 * self.row_height - (bounds.height - 2.0 * MENU_PADDING))
+            .max(0.0)
+    }
+
+    fn message(&self, index: usize) -> Option<Message> {
+        match self.state.menu.as_ref()?.target {
+            Target::Ellipsis => Some((self.on_press)(index)),
+            Target::Separator(item) => self
+                .on_choice
+                .as_ref()
+                .map(|on_choice| on_choice(item, index)),
+            Target::Item(_) => None,
+        }
+    }
+
+    fn text<T>(content: T, bounds: Size) -> Text<T> {
+        Text {
+            content,
+            bounds,
+            size: Pixels(text::scaled(14.0)),
+            line_height: LineHeight::Absolute(Pixels(text::scaled(20.0))),
+            font: font::text(),
+            horizontal_alignment: Horizontal::Left,
+            vertical_alignment: Vertical::Center,
+            shaping: Shaping::Advanced,
+            wrapping: Wrapping::None,
+        }
+    }
+}
+
+impl<Message> overlay::Overlay<Message, Theme, Renderer> for MenuOverlay<'_, '_, Message>
+where
+    Message: Clone,
+{
+    fn layout(&mut self, _renderer: &Renderer, bounds: Size) -> Node {
+        let widest = self
+            .entries
+            .iter()
+            .map(|entry| {
+                <Renderer as iced::advanced::text::Renderer>::Paragraph::with_text(Self::text(
+                    entry.as_str(),
+                    Size::INFINITY,
+                ))
+                .min_width()
+            })
+            .fold(0.0, f32::max);
+
+        let width = (widest + 2.0 * (MENU_ITEM_PADDING + MENU_PADDING))
+            .max(MENU_MIN_WIDTH)
+            .min(bounds.width - 2.0 * MARGIN)
+            .max(0.0);
+
+        let below = bounds.height - (self.anchor.y + self.anchor.height + GAP) - MARGIN;
+        let above = self.anchor.y - GAP - MARGIN;
+        let wanted = self.entries.len() as f32 * self.row_height + 2.0 * MENU_PADDING;
+
+        // Open downwards unless there's more room above for a menu that doesn't fit
+        let (y, height) = if wanted <= below || below >= above {
+            (self.anchor.y + self.anchor.height + GAP, wanted.min(below))
+        } else {
+            let height = wanted.min(above);
+            (self.anchor.y - GAP - height, height)
+        };
+
+        let x = self.anchor.x.min(bounds.width - width - MARGIN).max(MARGIN);
+
+        Node::new(Size::new(width, height.max(0.0))).move_to(Point::new(x, y))
+    }
+
+    fn draw(
+        &self,
+        renderer: &mut Renderer,
+        theme: &Theme,
+        _style: &renderer::Style,
+        layout: Layout<'_>,
+        cursor: Cursor,
+    ) {
+        let bounds = layout.bounds();
+        let appearance = <Theme as container::Catalog>::style(theme, &"overlay".into());
+        let background = match appearance.background {
+            Some(Background::Color(colour)) => colour,
+            _ => Color::TRANSPARENT,
+        };
+
+        renderer.fill_quad(
+            renderer::Quad {
+                bounds,
+                border: appearance.border,
+                shadow: appearance.shadow,
+            },
+            background,
+        );
+
+        let highlighted = self
+            .entry_at(bounds, cursor)
+            .or_else(|| self.state.menu.as_ref()?.highlighted);
+        let offset = self.state.menu.as_ref().map_or(0.0, |menu| menu.offset);
+        let clip = bounds.shrink(MENU_PADDING);
+        let row_height = self.row_height;
+
+        renderer.with_layer(clip, |renderer| {
+            for (index, entry) in self.entries.iter().enumerate() {
+                let row = Rectangle {
+                    x: clip.x,
+                    y: clip.y + index as f32 * row_height - offset,
+                    width: clip.width,
+                    height: row_height,
+                };
+
+                if !row.intersects(&clip) {
+                    continue;
+                }
+
+                let status = if highlighted == Some(index) {
+                    button::Status::Hovered
+                } else {
+                    button::Status::Active
+                };
+                let style = button::Catalog::style(theme, &"menu_item".into(), status);
+
+                if let Some(background) = style.background {
+                    renderer.fill_quad(
+                        renderer::Quad {
+                            bounds: row,
+                            border: style.border,
+                            ..renderer::Quad::default()
+                        },
+                        background,
+                    );
+                }
+
+                let text_bounds = row.shrink([0.0, MENU_ITEM_PADDING]);
+
+                renderer.fill_text(
+                    Self::text(entry.clone(), text_bounds.size()),
+                    Point::new(text_bounds.x, row.center_y()),
+                    style.text_color,
+                    text_bounds,
+                );
+            }
+        });
+    }
+
+    fn on_event(
+        &mut self,
+        event: Event,
+        layout: Layout<'_>,
+        cursor: Cursor,
+        _renderer: &Renderer,
+        _clipboard: &mut dyn Clipboard,
+        shell: &mut Shell<'_, Message>,
+    ) -> event::Status {
+        let bounds = layout.bounds();
+        let count = self.entries.len();
+
+        match event {
+            Event::Mouse(mouse::Event::CursorMoved { .. }) => {
+                let entry = self.entry_at(bounds, cursor);
+
+                if let (Some(menu), Some(_)) = (&mut self.state.menu, entry) {
+                    menu.highlighted = entry;
+                }
+
+                event::Status::Ignored
+            }
+            Event::Mouse(mouse::Event::WheelScrolled { delta }) if cursor.is_over(bounds) => {
+                let max_offset = self.max_offset(bounds);
+                let y = match delta {
+                    mouse::ScrollDelta::Lines { y, .. } => y * self.row_height,
+                    mouse::ScrollDelta::Pixels { y, .. } => y,
+                };
+
+                if let Some(menu) = &mut self.state.menu {
+                    menu.offset = (menu.offset - y).clamp(0.0, max_offset);
+                }
+
+                event::Status::Captured
+            }
+            Event::Mouse(mouse::Event::ButtonPressed(mouse::Button::Left))
+            | Event::Touch(touch::Event::FingerPressed { .. }) => {
+                if cursor.is_over(self.anchor) {
+                    // Left to the bar so the press toggles the menu closed
+                    return event::Status::Ignored;
+                }
+
+                if !cursor.is_over(bounds) {
+                    self.state.menu = None;
+
+                    return event::Status::Ignored;
+                }
+
+                if let Some(message) = self
+                    .entry_at(bounds, cursor)
+                    .and_then(|index| self.message(index))
+                {
+                    shell.publish(message);
+                    self.state.menu = None;
+                }
+
+                event::Status::Captured
+            }
+            Event::Keyboard(keyboard::Event::KeyPressed { key, .. }) => {
+                let visible = ((bounds.height - 2.0 * MENU_PADDING) / self.row_height).floor();
+                let max_offset = self.max_offset(bounds);
+                let row_height = self.row_height;
+
+                let Some(menu) = &mut self.state.menu else {
+                    return event::Status::Ignored;
+                };
+
+                let highlighted = match key.as_ref() {
+                    Key::Named(Named::Escape) => {
+                        self.state.menu = None;
+
+                        return event::Status::Captured;
+                    }
+                    Key::Named(Named::Enter) => {
+                        if let Some(message) =
+                            menu.highlighted.and_then(|index| self.message(index))
+                        {
+                            shell.publish(message);
+                            self.state.menu = None;
+                        }
+
+                        return event::Status::Captured;
+                    }
+                    Key::Named(Named::ArrowDown) => menu
+                        .highlighted
+                        .map_or(0, |index| (index + 1).min(count.saturating_sub(1))),
+                    Key::Named(Named::ArrowUp) => menu
+                        .highlighted
+                        .map_or(count.saturating_sub(1), |index| index.saturating_sub(1)),
+                    Key::Named(Named::Home) => 0,
+                    Key::Named(Named::End) => count.saturating_sub(1),
+                    _ => return event::Status::Ignored,
+                };
+
+                // Keep the highlighted entry in view
+                let top = highlighted as f32 * row_height;
+                menu.offset = menu
+                    .offset
+                    .max(top - (visible - 1.0).max(0.0) * row_height)
+                    .min(top)
+                    .clamp(0.0, max_offset);
+                menu.highlighted = Some(highlighted);
+
+                event::Status::Captured
+            }
+            _ => event::Status::Ignored,
+        }
+    }
+
+    fn mouse_interaction(
+        &self,
+        layout: Layout<'_>,
+        cursor: Cursor,
+        _viewport: &Rectangle,
+        _renderer: &Renderer,
+    ) -> mouse::Interaction {
+        if self.entry_at(layout.bounds(), cursor).is_some() {
+            mouse::Interaction::Pointer
+        } else {
+            mouse::Interaction::default()
+        }
+    }
+}
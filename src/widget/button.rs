@@ -1,19 +1,44 @@
 use crate::{
+    density,
     fluent_icon::FluentIcon,
-    style,
-    widget::{text, Button, Column, DropDown, Element, Row},
+    icon::{self, Icon},
+    motion::{self, Animated, Interpolate},
+    theme,
+    widget::{flyout::Flyout, text, Column, Element, Row},
 };
 
 use iced::{
+    advanced::{
+        self,
+        layout::{self, Limits, Node},
+        overlay, renderer,
+        widget::{tree, Operation, Tree},
+        Clipboard, Layout, Shell, Widget,
+    },
     alignment::{Horizontal, Vertical},
-    widget::{center, row},
-    Length,
+    event,
+    mouse::{self, Cursor},
+    touch,
+    widget::{
+        button::{Catalog, Status, Style, StyleFn},
+        center, row,
+        text::IntoFragment,
+    },
+    window, Background, Border, Color, Event, Length, Padding, Rectangle, Size, Vector,
 };
 
+use std::time::Instant;
+
 pub fn standard<'a, Message>(content: impl Into<Element<'a, Message>>) -> Button<'a, Message> {
     Button::new(content)
-        .height(32)
-        .style(style::button::secondary)
+        .height(density::metrics().control_height)
+        .class("secondary")
+}
+
+pub fn hyperlink<'a, Message>(label: impl IntoFragment<'a>) -> Button<'a, Message> {
+    Button::new(text::body1(label))
+        .height(density::metrics().control_height)
+        .class("hyperlink")
 }
 
 pub fn dropdown<'a, Message>(content: impl Into<Element<'a, Message>>) -> Button<'a, Message>
@@ -23,12 +48,12 @@ where
     Button::new(
         Row::new()
             .push(content.into())
-            .push(text::icon(FluentIcon::ChevronDown.codepoint()).size(8))
+            .push(text::icon(FluentIcon::ChevronDown.codepoint(), 8.0))
             .height(Length::Fill)
             .spacing(8)
             .align_y(Vertical::Center),
     )
-    .height(32.0)
+    .height(density::metrics().control_height)
 }
 
 pub fn split<'a, Message>(
@@ -42,18 +67,16 @@ where
     Message: 'a + Clone,
 {
     let indicator = Button::new(
-        text::icon(FluentIcon::ChevronDown.codepoint())
-            .size(10)
-            .align_y(Vertical::Center),
+        text::icon(FluentIcon::ChevronDown.codepoint(), 10.0).align_y(Vertical::Center),
     )
-    .height(32)
+    .height(density::metrics().control_height)
     .on_press(on_open)
-    .style(style::button::split_indicator);
+    .class("split_indicator");
     let underlay = Row::new().push(content).push(indicator);
 
-    DropDown::new(underlay, overlay, expanded)
-        .width(Length::Shrink)
+    Flyout::new(underlay, overlay, expanded)
         .on_dismiss(on_dismiss)
+        .padding(0)
         .into()
 }
 
@@ -63,9 +86,9 @@ where
 {
     Button::new(content)
         .width(Length::Fill)
-        .height(28.0)
+        .height(density::metrics().menu_item_height)
         .padding([0, 8])
-        .style(style::button::flyout)
+        .class("flyout")
 }
 
 pub fn menu_labelled<'a, Message>(label: &'a str) -> Button<'a, Message>
@@ -79,15 +102,15 @@ where
     )
 }
 
-pub fn menu_icon<'a, Message>(label: &'a str, icon: FluentIcon) -> Button<'a, Message>
+pub fn menu_icon<'a, Message>(label: &'a str, icon: impl Into<Icon>) -> Button<'a, Message>
 where
     Message: 'a + Clone,
 {
     menu_base(
-        row![text::icon(icon.codepoint()), text::body1(label)]
+        row![icon.into().view(icon::DEFAULT_SIZE), text::body1(label)]
             .height(Length::Fill)
             .align_y(Vertical::Center)
-            .spacing(12),
+            .spacing(density::metrics().spacing),
     )
 }
 
@@ -98,7 +121,7 @@ where
     Button::new(
         center(
             Column::new()
-                .push(text::icon(icon.codepoint()))
+                .push(text::icon(icon.codepoint(), 16.0))
                 .push(text::body1(label))
                 .spacing(-4.0)
                 .align_x(Horizontal::Center),
@@ -107,6 +130,429 @@ where
         .height(Length::Fill),
     )
     .width(64)
-    .height(52)
-    .style(style::button::transparent)
+    .height(density::metrics().app_bar_height)
+    .class("transparent")
+}
+
+// iced's button with the style cross-faded between statuses
+pub struct Button<'a, Message, Theme = theme::Theme, Renderer = iced::Renderer>
+where
+    Theme: Catalog,
+{
+    content: iced::Element<'a, Message, Theme, Renderer>,
+    on_press: Option<OnPress<'a, Message>>,
+    width: Length,
+    height: Length,
+    padding: Padding,
+    clip: bool,
+    class: Theme::Class<'a>,
+}
+
+enum OnPress<'a, Message> {
+    Direct(Message),
+    Closure(Box<dyn Fn() -> Message + 'a>),
+}
+
+impl<Message: Clone> OnPress<'_, Message> {
+    fn get(&self) -> Message {
+        match self {
+            OnPress::Direct(message) => message.clone(),
+            OnPress::Closure(f) => f(),
+        }
+    }
+}
+
+impl<'a, Message, Theme, Renderer> Button<'a, Message, Theme, Renderer>
+where
+    Theme: Catalog,
+    Renderer: advanced::Renderer,
+{
+    pub fn new(content: impl Into<iced::Element<'a, Message, Theme, Renderer>>) -> Self {
+        let content = content.into();
+        let size = content.as_widget().size_hint();
+
+        Self {
+            content,
+            on_press: None,
+            width: size.width.fluid(),
+            height: size.height.fluid(),
+            padding: Padding {
+                top: 5.0,
+                bottom: 5.0,
+                right: 10.0,
+                left: 10.0,
+            },
+            clip: false,
+            class: Theme::default(),
+        }
+    }
+
+    pub fn width(mut self, width: impl Into<Length>) -> Self {
+        self.width = width.into();
+        self
+    }
+
+    pub fn height(mut self, height: impl Into<Length>) -> Self {
+        self.height = height.into();
+        self
+    }
+
+    pub fn padding(mut self, padding: impl Into<Padding>) -> Self {
+        self.padding = padding.into();
+        self
+    }
+
+    pub fn on_press(mut self, on_press: Message) -> Self {
+        self.on_press = Some(OnPress::Direct(on_press));
+        self
+    }
+
+    pub fn on_press_with(mut self, on_press: impl Fn() -> Message + 'a) -> Self {
+        self.on_press = Some(OnPress::Closure(Box::new(on_press)));
+        self
+    }
+
+    pub fn on_press_maybe(mut self, on_press: Option<Message>) -> Self {
+        self.on_press = on_press.map(OnPress::Direct);
+        self
+    }
+
+    pub fn clip(mut self, clip: bool) -> Self {
+        self.clip = clip;
+        self
+    }
+
+    pub fn style(mut self, style: impl Fn(&Theme, Status) -> Style + 'a) -> Self
+    where
+        Theme::Class<'a>: From<StyleFn<'a, Theme>>,
+    {
+        self.class = (Box::new(style) as StyleFn<'a, Theme>).into();
+        self
+    }
+
+    pub fn class(mut self, class: impl Into<Theme::Class<'a>>) -> Self {
+        self.class = class.into();
+        self
+    }
+
+    fn status(&self, state: &State, layout: Layout<'_>, cursor: Cursor) -> Status {
+        if self.on_press.is_none() {
+            Status::Disabled
+        } else if cursor.is_over(layout.bounds()) {
+            if state.is_pressed {
+                Status::Pressed
+            } else {
+                Status::Hovered
+            }
+        } else {
+            Status::Active
+        }
+    }
+}
+
+struct State {
+    is_pressed: bool,
+    // The status being faded from and to, `progress` runs from 0 to 1 between them
+    from: Option<Status>,
+    to: Option<Status>,
+    progress: Animated<f32>,
+    now: Instant,
+}
+
+impl Default for State {
+    fn default() -> Self {
+        Self {
+            is_pressed: false,
+            from: None,
+            to: None,
+            progress: Animated::new(1.0).duration(motion::FAST),
+            now: Instant::now(),
+        }
+    }
+}
+
+impl<'a, Message, Theme, Renderer> Widget<Message, Theme, Renderer>
+    for Button<'a, Message, Theme, Renderer>
+where
+    Message: 'a + Clone,
+    Theme: Catalog,
+    Renderer: 'a + advanced::Renderer,
+{
+    fn tag(&self) -> tree::Tag {
+        tree::Tag::of::<State>()
+    }
+
+    fn state(&self) -> tree::State {
+        tree::State::new(State::default())
+    }
+
+    fn children(&self) -> Vec<Tree> {
+        vec![Tree::new(&self.content)]
+    }
+
+    fn diff(&self, tree: &mut Tree) {
+        tree.diff_children(std::slice::from_ref(&self.content));
+    }
+
+    fn size(&self) -> Size<Length> {
+        Size {
+            width: self.width,
+            height: self.height,
+        }
+    }
+
+    fn layout(&self, tree: &mut Tree, renderer: &Renderer, limits: &Limits) -> Node {
+        layout::padded(limits, self.width, self.height, self.padding, |limits| {
+            self.content
+                .as_widget()
+                .layout(&mut tree.children[0], renderer, limits)
+        })
+    }
+
+    fn operate(
+        &self,
+        tree: &mut Tree,
+        layout: Layout<'_>,
+        renderer: &Renderer,
+        operation: &mut dyn Operation,
+    ) {
+        operation.container(None, layout.bounds(), &mut |operation| {
+            self.content.as_widget().operate(
+                &mut tree.children[0],
+                content_layout(layout),
+                renderer,
+                operation,
+            );
+        });
+    }
+
+    fn on_event(
+        &mut self,
+        tree: &mut Tree,
+        event: Event,
+        layout: Layout<'_>,
+        cursor: Cursor,
+        renderer: &Renderer,
+        clipboard: &mut dyn Clipboard,
+        shell: &mut Shell<'_, Message>,
+        viewport: &Rectangle,
+    ) -> event::Status {
+        if let event::Status::Captured = self.content.as_widget_mut().on_event(
+            &mut tree.children[0],
+            event.clone(),
+            content_layout(layout),
+            cursor,
+            renderer,
+            clipboard,
+            shell,
+            viewport,
+        ) {
+            return event::Status::Captured;
+        }
+
+        let mut status = event::Status::Ignored;
+
+        match event {
+            Event::Mouse(mouse::Event::ButtonPressed(mouse::Button::Left))
+            | Event::Touch(touch::Event::FingerPressed { .. })
+                if self.on_press.is_some() && cursor.is_over(layout.bounds()) =>
+            {
+                tree.state.downcast_mut::<State>().is_pressed = true;
+                status = event::Status::Captured;
+            }
+            Event::Mouse(mouse::Event::ButtonReleased(mouse::Button::Left))
+            | Event::Touch(touch::Event::FingerLifted { .. }) => {
+                let state = tree.state.downcast_mut::<State>();
+
+                if let Some(on_press) = self.on_press.as_ref().map(OnPress::get) {
+                    if state.is_pressed {
+                        state.is_pressed = false;
+
+                        if cursor.is_over(layout.bounds()) {
+                            shell.publish(on_press);
+                        }
+
+                        status = event::Status::Captured;
+                    }
+                }
+            }
+            Event::Touch(touch::Event::FingerLost { .. }) => {
+                tree.state.downcast_mut::<State>().is_pressed = false;
+            }
+            Event::Window(window::Event::RedrawRequested(now)) => {
+                let state = tree.state.downcast_mut::<State>();
+                state.now = now;
+
+                if state.progress.is_animating(now) {
+                    shell.request_redraw(window::RedrawRequest::NextFrame);
+                }
+            }
+            _ => {}
+        }
+
+        let current = self.status(tree.state.downcast_ref::<State>(), layout, cursor);
+        let state = tree.state.downcast_mut::<State>();
+
+        match state.to {
+            Some(to) if to != current => {
+                // Enabling and disabling happen on rebuild so they don't fade
+                let fade = to != Status::Disabled && current != Status::Disabled;
+                let now = Instant::now();
+
+                state.from = Some(to);
+                state.to = Some(current);
+                state.progress.set(0.0);
+
+                if fade {
+                    state.progress.go_to(1.0, now);
+                    state.now = now;
+                    shell.request_redraw(window::RedrawRequest::NextFrame);
+                } else {
+                    state.progress.set(1.0);
+                }
+            }
+            None => state.to = Some(current),
+            _ => {}
+        }
+
+        status
+    }
+
+    fn draw(
+        &self,
+        tree: &Tree,
+        renderer: &mut Renderer,
+        theme: &Theme,
+        _style: &renderer::Style,
+        layout: Layout<'_>,
+        cursor: Cursor,
+        viewport: &Rectangle,
+    ) {
+        let bounds = layout.bounds();
+        let state = tree.state.downcast_ref::<State>();
+        let status = self.status(state, layout, cursor);
+
+        // The tracked status lags behind when the button was rebuilt without an event
+        let style = match (state.from, state.to) {
+            (Some(from), Some(to)) if to == status => blend(
+                theme.style(&self.class, from),
+                theme.style(&self.class, to),
+                state.progress.value(state.now),
+            ),
+            _ => theme.style(&self.class, status),
+        };
+
+        if style.background.is_some() || style.border.width > 0.0 || style.shadow.color.a > 0.0 {
+            renderer.fill_quad(
+                renderer::Quad {
+                    bounds,
+                    border: style.border,
+                    shadow: style.shadow,
+                },
+                style
+                    .background
+                    .unwrap_or(Background::Color(Color::TRANSPARENT)),
+            );
+        }
+
+        let viewport = if self.clip {
+            bounds.intersection(viewport).unwrap_or(*viewport)
+        } else {
+            *viewport
+        };
+
+        self.content.as_widget().draw(
+            &tree.children[0],
+            renderer,
+            theme,
+            &renderer::Style {
+                text_color: style.text_color,
+            },
+            content_layout(layout),
+            cursor,
+            &viewport,
+        );
+    }
+
+    fn mouse_interaction(
+        &self,
+        _tree: &Tree,
+        layout: Layout<'_>,
+        cursor: Cursor,
+        _viewport: &Rectangle,
+        _renderer: &Renderer,
+    ) -> mouse::Interaction {
+        if cursor.is_over(layout.bounds()) && self.on_press.is_some() {
+            mouse::Interaction::Pointer
+        } else {
+            mouse::Interaction::default()
+        }
+    }
+
+    fn overlay<'b>(
+        &'b mut self,
+        tree: &'b mut Tree,
+        layout: Layout<'_>,
+        renderer: &Renderer,
+        translation: Vector,
+    ) -> Option<overlay::Element<'b, Message, Theme, Renderer>> {
+        self.content.as_widget_mut().overlay(
+            &mut tree.children[0],
+            content_layout(layout),
+            renderer,
+            translation,
+        )
+    }
+}
+
+impl<'a, Message, Theme, Renderer> From<Button<'a, Message, Theme, Renderer>>
+    for iced::Element<'a, Message, Theme, Renderer>
+where
+    Message: 'a + Clone,
+    Theme: 'a + Catalog,
+    Renderer: 'a + advanced::Renderer,
+{
+    fn from(button: Button<'a, Message, Theme, Renderer>) -> Self {
+        Self::new(button)
+    }
+}
+
+fn content_layout(layout: Layout) -> Layout {
+    layout
+        .children()
+        .next()
+        .expect("failed to get content layout")
+}
+
+fn blend(from: Style, to: Style, t: f32) -> Style {
+    if t >= 1.0 {
+        return to;
+    }
+
+    // A missing background fades from/to a transparent version of the other
+    let colour =
+        |background: Option<Background>, other: Option<Background>| match (background, other) {
+            (Some(Background::Color(colour)), _) => Some(colour),
+            (None, Some(Background::Color(other))) => Some(Color { a: 0.0, ..other }),
+            _ => None,
+        };
+
+    let background = match (
+        colour(from.background, to.background),
+        colour(to.background, from.background),
+    ) {
+        (Some(a), Some(b)) => Some(Background::Color(a.interpolate(b, t))),
+        _ => to.background,
+    };
+
+    Style {
+        background,
+        text_color: from.text_color.interpolate(to.text_color, t),
+        border: Border {
+            color: from.border.color.interpolate(to.border.color, t),
+            width: from.border.width.interpolate(to.border.width, t),
+            ..to.border
+        },
+        shadow: to.shadow,
+    }
 }
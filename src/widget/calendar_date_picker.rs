@@ -0,0 +1,601 @@
+use crate::{
+    date_time::{Date, English, Formatter, Weekday},
+    fluent_icon::FluentIcon,
+    font,
+    theme::Theme,
+    widget::{self, calendar_view::CalendarView, Element},
+};
+
+use iced::{
+    advanced::{
+        layout::{Limits, Node},
+        overlay,
+        renderer::{self, Renderer as _},
+        widget::{
+            operation::{self, focusable, Operation},
+            tree, Tree,
+        },
+        Clipboard, Layout, Shell, Widget,
+    },
+    event,
+    keyboard::{self, key::Named, Key},
+    mouse::{self, Cursor},
+    touch,
+    widget::{
+        container,
+        text_input::{self, Icon, Side},
+    },
+    Background, Color, Event, Length, Point, Rectangle, Size, Vector,
+};
+
+use std::cell::RefCell;
+
+type Renderer = iced::Renderer;
+type Paragraph = <Renderer as iced::advanced::text::Renderer>::Paragraph;
+
+// The calendar glyph at the end of the field, which opens the calendar
+const BUTTON_WIDTH: f32 = 36.0;
+// Closest the calendar gets to the edges of the window
+const MARGIN: f32 = 8.0;
+const GAP: f32 = 4.0;
+
+// What the field sends the picker
+#[derive(Clone, Debug)]
+enum Field {
+    Edited(String),
+    Submitted,
+}
+
+// Based on https://learn.microsoft.com/en-us/windows/apps/design/controls/calendar-date-picker
+//
+// A text field for a date that opens a CalendarView in a flyout. A typed date is
+// read with the formatter when Enter is pressed or the field is clicked away from,
+// and put back as it was if it can't be read or can't be picked.
+pub struct CalendarDatePicker<'a, Message> {
+    date: Option<Date>,
+    on_select: Box<dyn Fn(Date) -> Message + 'a>,
+    placeholder: Option<String>,
+    min_date: Option<Date>,
+    max_date: Option<Date>,
+    is_blackout: Option<Box<dyn Fn(Date) -> bool + 'a>>,
+    first_day_of_week: Weekday,
+    formatter: &'a dyn Formatter,
+    width: Length,
+    // Built on layout from the date or what's been typed
+    field: RefCell<Option<Element<'a, Field>>>,
+}
+
+impl<'a, Message> CalendarDatePicker<'a, Message> {
+    pub fn new(date: Option<Date>, on_select: impl Fn(Date) -> Message + 'a) -> Self {
+        Self {
+            date,
+            on_select: Box::new(on_select),
+            placeholder: None,
+            min_date: None,
+            max_date: None,
+            is_blackout: None,
+            first_day_of_week: Weekday::Sunday,
+            formatter: &English,
+            width: Length::Fixed(180.0),
+            field: RefCell::new(None),
+        }
+    }
+
+    // In place of the formatter's placeholder
+    pub fn placeholder(mut self, placeholder: impl Into<String>) -> Self {
+        self.placeholder = Some(placeholder.into());
+        self
+    }
+
+    pub fn min_date(mut self, min_date: Date) -> Self {
+        self.min_date = Some(min_date);
+        self
+    }
+
+    pub fn max_date(mut self, max_date: Date) -> Self {
+        self.max_date = Some(max_date);
+        self
+    }
+
+    // Dates for which this returns true can't be picked or typed
+    pub fn blackout(mut self, is_blackout: impl Fn(Date) -> bool + 'a) -> Self {
+        self.is_blackout = Some(Box::new(is_blackout));
+        self
+    }
+
+    pub fn first_day_of_week(mut self, first_day_of_week: Weekday) -> Self {
+        self.first_day_of_week = first_day_of_week;
+        self
+    }
+
+    pub fn formatter(mut self, formatter: &'a dyn Formatter) -> Self {
+        self.formatter = formatter;
+        self
+    }
+
+    pub fn width(mut self, width: impl Into<Length>) -> Self {
+        self.width = width.into();
+        self
+    }
+
+    fn can_pick(&self, date: Date) -> bool {
+        self.min_date.is_none_or(|min| date >= min)
+            && self.max_date.is_none_or(|max| date <= max)
+            && !self
+                .is_blackout
+                .as_ref()
+                .is_some_and(|is_blackout| is_blackout(date))
+    }
+
+    fn button_bounds(bounds: Rectangle) -> Rectangle {
+        Rectangle {
+            x: bounds.x + bounds.width - BUTTON_WIDTH,
+            width: BUTTON_WIDTH,
+            ..bounds
+        }
+    }
+
+    // Reads back what's been typed, if anything
+    fn commit(&self, state: &mut State, shell: &mut Shell<'_, Message>) {
+        let Some(draft) = state.draft.take() else {
+            return;
+        };
+
+        if let Some(date) = self
+            .formatter
+            .parse_date(&draft)
+            .filter(|date| self.can_pick(*date))
+        {
+            if Some(date) != self.date {
+                shell.publish((self.on_select)(date));
+            }
+        }
+
+        shell.invalidate_layout();
+    }
+
+    fn calendar<'b>(&'b self) -> CalendarView<'b, Date> {
+        let calendar = CalendarView::new(self.date, |date| date)
+            .min_date_maybe(self.min_date)
+            .max_date_maybe(self.max_date)
+            .first_day_of_week(self.first_day_of_week)
+            .formatter(self.formatter);
+
+        match &self.is_blackout {
+            Some(is_blackout) => calendar.blackout(is_blackout),
+            None => calendar,
+        }
+    }
+}
+
+struct State {
+    // What's been typed since the field was last read, shown instead of the date
+    draft: Option<String>,
+    field: Tree,
+    calendar: Tree,
+    is_open: bool,
+    // Set when the calendar opens, so it takes the keyboard
+    needs_focus: bool,
+}
+
+// Whether the field has the keyboard
+fn is_focused(state: &State) -> bool {
+    state
+        .field
+        .state
+        .downcast_ref::<text_input::State<Paragraph>>()
+        .is_focused()
+}
+
+impl<'a, Message> Widget<Message, Theme, Renderer> for CalendarDatePicker<'a, Message> {
+    fn tag(&self) -> tree::Tag {
+        tree::Tag::of::<State>()
+    }
+
+    fn state(&self) -> tree::State {
+        tree::State::new(State {
+            draft: None,
+            field: Tree::empty(),
+            calendar: Tree::empty(),
+            is_open: false,
+            needs_focus: false,
+        })
+    }
+
+    fn size(&self) -> Size<Length> {
+        Size::new(self.width, Length::Shrink)
+    }
+
+    fn layout(&self, tree: &mut Tree, renderer: &Renderer, limits: &Limits) -> Node {
+        let state = tree.state.downcast_mut::<State>();
+
+        let value = match &state.draft {
+            Some(draft) => draft.clone(),
+            None => self
+                .date
+                .map(|date| self.formatter.date(date))
+                .unwrap_or_default(),
+        };
+
+        let placeholder = self
+            .placeholder
+            .clone()
+            .unwrap_or_else(|| self.formatter.date_placeholder());
+
+        let field: Element<'a, Field> = widget::text_input::standard(&placeholder, &value)
+            .on_input(Field::Edited)
+            .on_submit(Field::Submitted)
+            .icon(Icon {
+                font: font::icons(),
+                code_point: FluentIcon::Calendar.codepoint(),
+                size: Some(14.into()),
+                spacing: 8.0,
+                side: Side::Right,
+            })
+            .width(Length::Fill)
+            .into();
+
+        state.field.diff(field.as_widget());
+
+        let node = field
+            .as_widget()
+            .layout(&mut state.field, renderer, &limits.width(self.width));
+
+        *self.field.borrow_mut() = Some(field);
+
+        Node::with_children(node.size(), vec![node])
+    }
+
+    fn on_event(
+        &mut self,
+        tree: &mut Tree,
+        event: Event,
+        layout: Layout<'_>,
+        cursor: Cursor,
+        renderer: &Renderer,
+        clipboard: &mut dyn Clipboard,
+        shell: &mut Shell<'_, Message>,
+        viewport: &Rectangle,
+    ) -> event::Status {
+        let state = tree.state.downcast_mut::<State>();
+        let bounds = layout.bounds();
+
+        let is_press = matches!(
+            event,
+            Event::Mouse(mouse::Event::ButtonPressed(mouse::Button::Left))
+                | Event::Touch(touch::Event::FingerPressed { .. })
+        );
+
+        if is_press && cursor.is_over(Self::button_bounds(bounds)) {
+            self.commit(state, shell);
+
+            state.is_open = !state.is_open;
+            state.calendar = Tree::empty();
+            state.needs_focus = state.is_open;
+
+            return event::Status::Captured;
+        }
+
+        // Alt+Down opens the calendar from the keyboard
+        if let Event::Keyboard(keyboard::Event::KeyPressed { key, modifiers, .. }) = &event {
+            if key.as_ref() == Key::Named(Named::ArrowDown) && modifiers.alt() && is_focused(state)
+            {
+                self.commit(state, shell);
+
+                state.is_open = true;
+                state.calendar = Tree::empty();
+                state.needs_focus = true;
+
+                return event::Status::Captured;
+            }
+        }
+
+        // The calendar closes itself on presses outside of it and the field
+        if is_press {
+            if cursor.is_over(bounds) {
+                state.is_open = false;
+            } else {
+                self.commit(state, shell);
+            }
+        }
+
+        let Some(field) = self.field.get_mut().as_mut() else {
+            return event::Status::Ignored;
+        };
+
+        let mut messages = Vec::new();
+        let mut local = Shell::new(&mut messages);
+
+        let status = field.as_widget_mut().on_event(
+            &mut state.field,
+            event,
+            layout.children().next().expect("Field layout"),
+            cursor,
+            renderer,
+            clipboard,
+            &mut local,
+            viewport,
+        );
+
+        if let Some(request) = local.redraw_request() {
+            shell.request_redraw(request);
+        }
+
+        if local.is_layout_invalid() {
+            shell.invalidate_layout();
+        }
+
+        if local.are_widgets_invalid() {
+            shell.invalidate_widgets();
+        }
+
+        for message in messages {
+            match message {
+                Field::Edited(draft) => {
+                    state.draft = Some(draft);
+                    shell.invalidate_layout();
+                }
+                Field::Submitted => self.commit(state, shell),
+            }
+        }
+
+        status
+    }
+
+    fn draw(
+        &self,
+        tree: &Tree,
+        renderer: &mut Renderer,
+        theme: &Theme,
+        style: &renderer::Style,
+        layout: Layout<'_>,
+        cursor: Cursor,
+        viewport: &Rectangle,
+    ) {
+        let state = tree.state.downcast_ref::<State>();
+
+        if let Some(field) = self.field.borrow().as_ref() {
+            field.as_widget().draw(
+                &state.field,
+                renderer,
+                theme,
+                style,
+                layout.children().next().expect("Field layout"),
+                cursor,
+                viewport,
+            );
+        }
+    }
+
+    fn mouse_interaction(
+        &self,
+        tree: &Tree,
+        layout: Layout<'_>,
+        cursor: Cursor,
+        viewport: &Rectangle,
+        renderer: &Renderer,
+    ) -> mouse::Interaction {
+        let state = tree.state.downcast_ref::<State>();
+
+        if cursor.is_over(Self::button_bounds(layout.bounds())) {
+            return mouse::Interaction::Pointer;
+        }
+
+        self.field
+            .borrow()
+            .as_ref()
+            .map_or(mouse::Interaction::default(), |field| {
+                field.as_widget().mouse_interaction(
+                    &state.field,
+                    layout.children().next().expect("Field layout"),
+                    cursor,
+                    viewport,
+                    renderer,
+                )
+            })
+    }
+
+    fn overlay<'b>(
+        &'b mut self,
+        tree: &'b mut Tree,
+        layout: Layout<'_>,
+        _renderer: &Renderer,
+        translation: Vector,
+    ) -> Option<overlay::Element<'b, Message, Theme, Renderer>> {
+        let state = tree.state.downcast_mut::<State>();
+
+        if !state.is_open {
+            return None;
+        }
+
+        let calendar = Element::from(self.calendar());
+        state.calendar.diff(calendar.as_widget());
+
+        Some(overlay::Element::new(Box::new(CalendarOverlay {
+            calendar,
+            state,
+            anchor: layout.bounds() + translation,
+            date: self.date,
+            on_select: self.on_select.as_ref(),
+        })))
+    }
+}
+
+impl<'a, Message> From<CalendarDatePicker<'a, Message>> for Element<'a, Message>
+where
+    Message: 'a,
+{
+    fn from(calendar_date_picker: CalendarDatePicker<'a, Message>) -> Self {
+        Self::new(calendar_date_picker)
+    }
+}
+
+// The calendar, under the field or above it when there's no room below
+struct CalendarOverlay<'a, 'b, Message> {
+    calendar: Element<'b, Date>,
+    state: &'b mut State,
+    anchor: Rectangle,
+    date: Option<Date>,
+    on_select: &'b (dyn Fn(Date) -> Message + 'a),
+}
+
+impl<Message> overlay::Overlay<Message, Theme, Renderer> for CalendarOverlay<'_, '_, Message> {
+    fn layout(&mut self, renderer: &Renderer, bounds: Size) -> Node {
+        let node = self.calendar.as_widget().layout(
+            &mut self.state.calendar,
+            renderer,
+            &Limits::new(Size::ZERO, bounds),
+        );
+        let size = node.size();
+
+        if self.state.needs_focus {
+            self.state.needs_focus = false;
+
+            let mut operation: Box<dyn Operation> = Box::new(focusable::focus_next());
+
+            loop {
+                self.calendar.as_widget().operate(
+                    &mut self.state.calendar,
+                    Layout::new(&node),
+                    renderer,
+                    operation.as_mut(),
+                );
+
+                match operation.finish() {
+                    operation::Outcome::Chain(next) => operation = next,
+                    _ => break,
+                }
+            }
+        }
+
+        let below = self.anchor.y + self.anchor.height + GAP;
+        let y = if below + size.height <= bounds.height - MARGIN
+            || self.anchor.y - GAP - size.height < MARGIN
+        {
+            below
+        } else {
+            self.anchor.y - GAP - size.height
+        };
+        let x = self
+            .anchor
+            .x
+            .min(bounds.width - size.width - MARGIN)
+            .max(MARGIN);
+
+        Node::with_children(size, vec![node]).move_to(Point::new(x, y))
+    }
+
+    fn draw(
+        &self,
+        renderer: &mut Renderer,
+        theme: &Theme,
+        style: &renderer::Style,
+        layout: Layout<'_>,
+        cursor: Cursor,
+    ) {
+        let bounds = layout.bounds();
+        let appearance = <Theme as container::Catalog>::style(theme, &"overlay".into());
+
+        // The flyout's shadow, the calendar draws its own background and border
+        renderer.fill_quad(
+            renderer::Quad {
+                bounds,
+                border: appearance.border,
+                shadow: appearance.shadow,
+            },
+            appearance
+                .background
+                .unwrap_or(Background::Color(Color::TRANSPARENT)),
+        );
+
+        self.calendar.as_widget().draw(
+            &self.state.calendar,
+            renderer,
+            theme,
+            style,
+            layout.children().next().expect("Calendar layout"),
+            cursor,
+            &bounds,
+        );
+    }
+
+    fn on_event(
+        &mut self,
+        event: Event,
+        layout: Layout<'_>,
+        cursor: Cursor,
+        renderer: &Renderer,
+        clipboard: &mut dyn Clipboard,
+        shell: &mut Shell<'_, Message>,
+    ) -> event::Status {
+        let bounds = layout.bounds();
+
+        match &event {
+            Event::Keyboard(keyboard::Event::KeyPressed {
+                key: Key::Named(Named::Escape),
+                ..
+            }) => {
+                self.state.is_open = false;
+
+                return event::Status::Captured;
+            }
+            // Presses on the field are left to it so the glyph can close the calendar
+            Event::Mouse(mouse::Event::ButtonPressed(_))
+            | Event::Touch(touch::Event::FingerPressed { .. })
+                if !cursor.is_over(bounds) && !cursor.is_over(self.anchor) =>
+            {
+                self.state.is_open = false;
+
+                return event::Status::Ignored;
+            }
+            _ => {}
+        }
+
+        let mut messages = Vec::new();
+        let mut local = Shell::new(&mut messages);
+
+        let status = self.calendar.as_widget_mut().on_event(
+            &mut self.state.calendar,
+            event,
+            layout.children().next().expect("Calendar layout"),
+            cursor,
+            renderer,
+            clipboard,
+            &mut local,
+            &bounds,
+        );
+
+        if let Some(request) = local.redraw_request() {
+            shell.request_redraw(request);
+        }
+
+        if let Some(date) = messages.pop() {
+            self.state.draft = None;
+            self.state.is_open = false;
+
+            if Some(date) != self.date {
+                shell.publish((self.on_select)(date));
+            }
+
+            shell.invalidate_layout();
+        }
+
+        status
+    }
+
+    fn mouse_interaction(
+        &self,
+        layout: Layout<'_>,
+        cursor: Cursor,
+        viewport: &Rectangle,
+        renderer: &Renderer,
+    ) -> mouse::Interaction {
+        self.calendar.as_widget().mouse_interaction(
+            &self.state.calendar,
+            layout.children().next().expect("Calendar layout"),
+            cursor,
+            viewport,
+            renderer,
+        )
+    }
+}
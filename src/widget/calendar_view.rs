@@ -0,0 +1,896 @@
+use crate::{
+    date_time::{Date, English, Formatter, Weekday},
+    fluent_icon::FluentIcon,
+    font,
+    icon::Icon,
+    style::calendar_view::{Status, Style, StyleFn},
+    theme::{
+        class::{self, Class},
+        Theme,
+    },
+    widget::{text, Element},
+};
+
+use iced::{
+    advanced::{
+        layout::{Limits, Node},
+        renderer::{self, Renderer as _},
+        text::{LineHeight, Renderer as _, Shaping, Text, Wrapping},
+        widget::{
+            operation::{focusable::Focusable, Operation},
+            tree, Tree,
+        },
+        Clipboard, Layout, Shell, Widget,
+    },
+    alignment::{Horizontal, Vertical},
+    border::Radius,
+    event,
+    keyboard::{self, key::Named, Key},
+    mouse::{self, Cursor},
+    touch, Border, Color, Event, Font, Length, Pixels, Point, Rectangle, Size,
+};
+
+type Renderer = iced::Renderer;
+
+// CalendarView sizes from
+// https://github.com/microsoft/microsoft-ui-xaml/blob/main/src/controls/dev/CommonStyles/CalendarView_themeresources.xaml
+const WIDTH: f32 = 300.0;
+const PADDING: f32 = 10.0;
+const HEADER_HEIGHT: f32 = 48.0;
+const BUTTON_HEIGHT: f32 = 40.0;
+const NAVIGATION_WIDTH: f32 = 40.0;
+const ICON_SIZE: f32 = 12.0;
+// The weekday names and six weeks, or four rows of months or years
+const ROW_HEIGHT: f32 = 40.0;
+const BODY_HEIGHT: f32 = 7.0 * ROW_HEIGHT;
+const DAYS: usize = 42;
+// The year and decade views show 16 months or years, a few from the next year or
+// decade after the current one
+const CELLS: usize = 16;
+const LARGE_ITEM_SIZE: f32 = 56.0;
+
+// What the calendar is showing, from zoomed in to zoomed out. The header zooms out
+// and picking a month or year zooms back in.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub enum DisplayMode {
+    #[default]
+    Month,
+    Year,
+    Decade,
+}
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+enum Target {
+    Header,
+    Previous,
+    Next,
+    Item(usize),
+}
+
+// Based on https://learn.microsoft.com/en-us/windows/apps/design/controls/calendar-view
+//
+// A single date can be picked, which the application keeps. Dates before the
+// minimum, after the maximum and blackout dates are shown but can't be picked.
+pub struct CalendarView<'a, Message> {
+    selected: Option<Date>,
+    on_select: Box<dyn Fn(Date) -> Message + 'a>,
+    today: Date,
+    min_date: Option<Date>,
+    max_date: Option<Date>,
+    is_blackout: Option<Box<dyn Fn(Date) -> bool + 'a>>,
+    first_day_of_week: Weekday,
+    formatter: &'a dyn Formatter,
+    class: Class<StyleFn>,
+}
+
+impl<'a, Message> CalendarView<'a, Message> {
+    pub fn new(selected: Option<Date>, on_select: impl Fn(Date) -> Message + 'a) -> Self {
+        Self {
+            selected,
+            on_select: Box::new(on_select),
+            today: Date::today(),
+            min_date: None,
+            max_date: None,
+            is_blackout: None,
+            first_day_of_week: Weekday::Sunday,
+            formatter: &English,
+            class: class::DEFAULT.into(),
+        }
+    }
+
+    pub fn min_date(mut self, min_date: Date) -> Self {
+        self.min_date = Some(min_date);
+        self
+    }
+
+    pub fn max_date(mut self, max_date: Date) -> Self {
+        self.max_date = Some(max_date);
+        self
+    }
+
+    pub fn min_date_maybe(mut self, min_date: Option<Date>) -> Self {
+        self.min_date = min_date;
+        self
+    }
+
+    pub fn max_date_maybe(mut self, max_date: Option<Date>) -> Self {
+        self.max_date = max_date;
+        self
+    }
+
+    // Dates for which this returns true are struck through and can't be picked
+    pub fn blackout(mut self, is_blackout: impl Fn(Date) -> bool + 'a) -> Self {
+        self.is_blackout = Some(Box::new(is_blackout));
+        self
+    }
+
+    pub fn first_day_of_week(mut self, first_day_of_week: Weekday) -> Self {
+        self.first_day_of_week = first_day_of_week;
+        self
+    }
+
+    pub fn formatter(mut self, formatter: &'a dyn Formatter) -> Self {
+        self.formatter = formatter;
+        self
+    }
+
+    pub fn style(mut self, style: impl Fn(&Theme, Status) -> Style + 'static) -> Self {
+        self.class = Class::Custom(Box::new(style));
+        self
+    }
+
+    pub fn class(mut self, class: impl Into<Class<StyleFn>>) -> Self {
+        self.class = class.into();
+        self
+    }
+
+    fn clamp(&self, date: Date) -> Date {
+        let date = self.min_date.map_or(date, |min| date.max(min));
+
+        self.max_date.map_or(date, |max| date.min(max))
+    }
+
+    // The first of the month shown, which for the year and decade views only
+    // decides the year. It starts at the selected date or today.
+    fn page(&self, state: &State) -> Date {
+        state.page.unwrap_or_else(|| {
+            self.clamp(self.selected.unwrap_or(self.today))
+                .first_of_month()
+        })
+    }
+
+    fn is_blackout(&self, date: Date) -> bool {
+        self.is_blackout
+            .as_ref()
+            .is_some_and(|is_blackout| is_blackout(date))
+    }
+
+    fn item(&self, mode: DisplayMode, page: Date, index: usize) -> Date {
+        match mode {
+            DisplayMode::Month => {
+                let lead = (page.weekday().index() + 7 - self.first_day_of_week.index()) % 7;
+
+                page.add_days(index as i64 - lead as i64)
+            }
+            DisplayMode::Year => Date::new(page.year(), 1, 1)
+                .expect("January 1st")
+                .add_months(index as i32),
+            DisplayMode::Decade => {
+                Date::new(decade(page) + index as i32, 1, 1).expect("January 1st")
+            }
+        }
+    }
+
+    fn items(mode: DisplayMode) -> usize {
+        match mode {
+            DisplayMode::Month => DAYS,
+            DisplayMode::Year | DisplayMode::Decade => CELLS,
+        }
+    }
+
+    fn is_enabled(&self, mode: DisplayMode, date: Date) -> bool {
+        let (first, last) = span(mode, date);
+
+        self.min_date.is_none_or(|min| last >= min)
+            && self.max_date.is_none_or(|max| first <= max)
+            && !(mode == DisplayMode::Month && self.is_blackout(date))
+    }
+
+    // Whether there's anything that can be picked on the page before or after
+    fn can_turn(&self, mode: DisplayMode, page: Date, forwards: bool) -> bool {
+        let (first, last) = scope(mode, turn(mode, page, if forwards { 1 } else { -1 }));
+
+        if forwards {
+            self.max_date.is_none_or(|max| first <= max)
+        } else {
+            self.min_date.is_none_or(|min| last >= min)
+        }
+    }
+
+    fn target_at(&self, state: &State, bounds: Rectangle, cursor: Cursor) -> Option<Target> {
+        let position = cursor.position_over(bounds)?;
+
+        if let Some(target) = [Target::Header, Target::Previous, Target::Next]
+            .into_iter()
+            .find(|target| target_bounds(bounds, *target).contains(position))
+        {
+            return Some(target);
+        }
+
+        (0..Self::items(state.mode))
+            .find(|index| cell_bounds(state.mode, bounds, *index).contains(position))
+            .map(Target::Item)
+    }
+
+    fn is_target_enabled(&self, state: &State, target: Target) -> bool {
+        let page = self.page(state);
+
+        match target {
+            Target::Header => state.mode != DisplayMode::Decade,
+            Target::Previous => self.can_turn(state.mode, page, false),
+            Target::Next => self.can_turn(state.mode, page, true),
+            Target::Item(index) => self.is_enabled(state.mode, self.item(state.mode, page, index)),
+        }
+    }
+
+    fn activate(&self, state: &mut State, target: Target, shell: &mut Shell<'_, Message>) {
+        if !self.is_target_enabled(state, target) {
+            return;
+        }
+
+        let page = self.page(state);
+
+        match target {
+            Target::Header => {
+                state.mode = match state.mode {
+                    DisplayMode::Month => DisplayMode::Year,
+                    DisplayMode::Year | DisplayMode::Decade => DisplayMode::Decade,
+                };
+                state.page = Some(page);
+            }
+            Target::Previous | Target::Next => {
+                let direction = if target == Target::Next { 1 } else { -1 };
+
+                state.page = Some(turn(state.mode, page, direction));
+            }
+            Target::Item(index) => {
+                let date = self.item(state.mode, page, index);
+
+                match state.mode {
+                    DisplayMode::Month => shell.publish((self.on_select)(date)),
+                    DisplayMode::Year => state.mode = DisplayMode::Month,
+                    DisplayMode::Decade => state.mode = DisplayMode::Year,
+                }
+
+                state.page = Some(date.first_of_month());
+                state.focused = Some(date);
+            }
+        }
+    }
+
+    // The date with keyboard focus, kept on the page shown
+    fn focused(&self, state: &State) -> Date {
+        let page = self.page(state);
+        let mode = state.mode;
+        let on_page = |date: &Date| in_scope(mode, page, *date);
+
+        state
+            .focused
+            .filter(on_page)
+            .or(self.selected.filter(on_page))
+            .or(Some(self.today).filter(on_page))
+            .unwrap_or(scope(mode, page).0)
+    }
+
+    fn on_key(
+        &self,
+        state: &mut State,
+        key: &Key,
+        shell: &mut Shell<'_, Message>,
+    ) -> event::Status {
+        let mode = state.mode;
+        let focused = self.focused(state);
+        let (first, last) = scope(mode, self.page(state));
+        // Steps for one item across and one row down
+        let (across, down) = match mode {
+            DisplayMode::Month => (1, 7),
+            DisplayMode::Year | DisplayMode::Decade => (1, 4),
+        };
+
+        let date = match key.as_ref() {
+            Key::Named(Named::ArrowLeft) => step(mode, focused, -across),
+            Key::Named(Named::ArrowRight) => step(mode, focused, across),
+            Key::Named(Named::ArrowUp) => step(mode, focused, -down),
+            Key::Named(Named::ArrowDown) => step(mode, focused, down),
+            Key::Named(Named::PageUp) => turn(mode, focused, -1),
+            Key::Named(Named::PageDown) => turn(mode, focused, 1),
+            Key::Named(Named::Home) => first,
+            Key::Named(Named::End) => match mode {
+                DisplayMode::Month => last,
+                DisplayMode::Year => last.first_of_month(),
+                DisplayMode::Decade => Date::new(last.year(), 1, 1).expect("January 1st"),
+            },
+            Key::Named(Named::Enter | Named::Space) => {
+                let page = self.page(state);
+
+                if let Some(index) = (0..Self::items(mode))
+                    .find(|index| same(mode, self.item(mode, page, *index), focused))
+                {
+                    self.activate(state, Target::Item(index), shell);
+                }
+
+                state.show_focus = true;
+
+                return event::Status::Captured;
+            }
+            _ => return event::Status::Ignored,
+        };
+
+        let date = self.clamp(date);
+
+        state.focused = Some(date);
+        state.show_focus = true;
+
+        if !in_scope(mode, self.page(state), date) {
+            state.page = Some(date.first_of_month());
+        }
+
+        event::Status::Captured
+    }
+
+    fn draw_item(
+        &self,
+        renderer: &mut Renderer,
+        theme: &Theme,
+        state: &State,
+        bounds: Rectangle,
+        index: usize,
+        hovered: Option<Target>,
+    ) {
+        let mode = state.mode;
+        let page = self.page(state);
+        let date = self.item(mode, page, index);
+        let cell = cell_bounds(mode, bounds, index);
+        let size = match mode {
+            DisplayMode::Month => cell.width.min(cell.height) - 4.0,
+            DisplayMode::Year | DisplayMode::Decade => LARGE_ITEM_SIZE,
+        };
+        let shape = Rectangle {
+            x: cell.center_x() - size / 2.0,
+            y: cell.center_y() - size / 2.0,
+            width: size,
+            height: size,
+        };
+
+        let is_enabled = self.is_enabled(mode, date);
+        let status = item_status(state, Target::Item(index), hovered, is_enabled);
+        let style = self.class.resolve(theme, status);
+        let is_today = same(mode, date, self.today);
+        let is_selected = self
+            .selected
+            .is_some_and(|selected| same(mode, date, selected));
+
+        let (background, mut colour) = if is_today && is_enabled {
+            (style.today_background, style.today_text_color)
+        } else if in_scope(mode, page, date) {
+            (style.item_background, style.text_color)
+        } else {
+            (style.item_background, style.out_of_scope_text_color)
+        };
+
+        renderer.fill_quad(
+            renderer::Quad {
+                bounds: shape,
+                border: Border {
+                    color: style.selected_border_color,
+                    width: if is_selected && !is_today { 2.0 } else { 0.0 },
+                    radius: Radius::new(size / 2.0),
+                },
+                ..renderer::Quad::default()
+            },
+            background,
+        );
+
+        // Selecting today leaves a ring of the background inside its fill
+        if is_selected && is_today {
+            renderer.fill_quad(
+                renderer::Quad {
+                    bounds: shape.shrink(2.0),
+                    border: Border {
+                        color: style.background,
+                        width: 2.0,
+                        radius: Radius::new(size / 2.0 - 2.0),
+                    },
+                    ..renderer::Quad::default()
+                },
+                Color::TRANSPARENT,
+            );
+        }
+
+        if !is_enabled {
+            colour = style.text_color;
+        }
+
+        let label = match mode {
+            DisplayMode::Month => date.day().to_string(),
+            DisplayMode::Year => self.formatter.month_abbreviation(date.month()),
+            DisplayMode::Decade => date.year().to_string(),
+        };
+
+        draw_text(
+            renderer,
+            label,
+            cell,
+            font::text(),
+            14.0,
+            Horizontal::Center,
+            colour,
+        );
+
+        if mode == DisplayMode::Month && self.is_blackout(date) {
+            renderer.fill_quad(
+                renderer::Quad {
+                    bounds: Rectangle {
+                        x: cell.center_x() - 9.0,
+                        y: cell.center_y(),
+                        width: 18.0,
+                        height: 1.0,
+                    },
+                    ..renderer::Quad::default()
+                },
+                colour,
+            );
+        }
+
+        if state.is_focused && state.show_focus && same(mode, date, self.focused(state)) {
+            renderer.fill_quad(
+                renderer::Quad {
+                    bounds: shape.expand(2.0),
+                    border: Border {
+                        color: style.focus,
+                        width: 2.0,
+                        radius: Radius::new(size / 2.0 + 2.0),
+                    },
+                    ..renderer::Quad::default()
+                },
+                Color::TRANSPARENT,
+            );
+        }
+    }
+}
+
+#[derive(Default)]
+struct State {
+    mode: DisplayMode,
+    // None until the calendar is paged, see `CalendarView::page`
+    page: Option<Date>,
+    focused: Option<Date>,
+    is_focused: bool,
+    // The focus ring only shows once the keyboard is used, as in WinUI
+    show_focus: bool,
+    pressed: Option<Target>,
+}
+
+impl Focusable for State {
+    fn is_focused(&self) -> bool {
+        self.is_focused
+    }
+
+    fn focus(&mut self) {
+        self.is_focused = true;
+        self.show_focus = true;
+    }
+
+    fn unfocus(&mut self) {
+        self.is_focused = false;
+        self.show_focus = false;
+    }
+}
+
+impl<'a, Message> Widget<Message, Theme, Renderer> for CalendarView<'a, Message> {
+    fn tag(&self) -> tree::Tag {
+        tree::Tag::of::<State>()
+    }
+
+    fn state(&self) -> tree::State {
+        tree::State::new(State::default())
+    }
+
+    fn size(&self) -> Size<Length> {
+        Size::new(Length::Shrink, Length::Shrink)
+    }
+
+    fn layout(&self, _tree: &mut Tree, _renderer: &Renderer, limits: &Limits) -> Node {
+        Node::new(limits.resolve(
+            Length::Shrink,
+            Length::Shrink,
+            Size::new(WIDTH, HEADER_HEIGHT + BODY_HEIGHT + PADDING),
+        ))
+    }
+
+    fn operate(
+        &self,
+        tree: &mut Tree,
+        _layout: Layout<'_>,
+        _renderer: &Renderer,
+        operation: &mut dyn Operation,
+    ) {
+        operation.focusable(tree.state.downcast_mut::<State>(), None);
+    }
+
+    fn on_event(
+        &mut self,
+        tree: &mut Tree,
+        event: Event,
+        layout: Layout<'_>,
+        cursor: Cursor,
+        _renderer: &Renderer,
+        _clipboard: &mut dyn Clipboard,
+        shell: &mut Shell<'_, Message>,
+        _viewport: &Rectangle,
+    ) -> event::Status {
+        let state = tree.state.downcast_mut::<State>();
+        let bounds = layout.bounds();
+
+        match event {
+            Event::Mouse(mouse::Event::ButtonPressed(mouse::Button::Left))
+            | Event::Touch(touch::Event::FingerPressed { .. }) => {
+                if !cursor.is_over(bounds) {
+                    state.is_focused = false;
+
+                    return event::Status::Ignored;
+                }
+
+                state.is_focused = true;
+                state.show_focus = false;
+                state.pressed = self.target_at(state, bounds, cursor);
+
+                event::Status::Captured
+            }
+            Event::Mouse(mouse::Event::ButtonReleased(mouse::Button::Left))
+            | Event::Touch(touch::Event::FingerLifted { .. }) => {
+                let Some(pressed) = state.pressed.take() else {
+                    return event::Status::Ignored;
+                };
+
+                if self.target_at(state, bounds, cursor) == Some(pressed) {
+                    self.activate(state, pressed, shell);
+                }
+
+                event::Status::Captured
+            }
+            Event::Touch(touch::Event::FingerLost { .. }) => {
+                state.pressed = None;
+
+                event::Status::Ignored
+            }
+            // The wheel turns the pages
+            Event::Mouse(mouse::Event::WheelScrolled { delta }) if cursor.is_over(bounds) => {
+                let y = match delta {
+                    mouse::ScrollDelta::Lines { y, .. } | mouse::ScrollDelta::Pixels { y, .. } => y,
+                };
+
+                let target = if y > 0.0 {
+                    Target::Previous
+                } else if y < 0.0 {
+                    Target::Next
+                } else {
+                    return event::Status::Ignored;
+                };
+
+                self.activate(state, target, shell);
+
+                event::Status::Captured
+            }
+            Event::Keyboard(keyboard::Event::KeyPressed { key, .. }) if state.is_focused => {
+                self.on_key(state, &key, shell)
+            }
+            _ => event::Status::Ignored,
+        }
+    }
+
+    fn draw(
+        &self,
+        tree: &Tree,
+        renderer: &mut Renderer,
+        theme: &Theme,
+        _style: &renderer::Style,
+        layout: Layout<'_>,
+        cursor: Cursor,
+        _viewport: &Rectangle,
+    ) {
+        let state = tree.state.downcast_ref::<State>();
+        let bounds = layout.bounds();
+        let style = self.class.resolve(theme, Status::Active);
+        let hovered = self.target_at(state, bounds, cursor);
+        let page = self.page(state);
+
+        renderer.fill_quad(
+            renderer::Quad {
+                bounds,
+                border: Border {
+                    color: style.border_color,
+                    width: 1.0,
+                    radius: Radius::new(8.0),
+                },
+                ..renderer::Quad::default()
+            },
+            style.background,
+        );
+
+        for target in [Target::Header, Target::Previous, Target::Next] {
+            let is_enabled = self.is_target_enabled(state, target);
+            let status = item_status(state, target, hovered, is_enabled);
+            let button_style = self.class.resolve(theme, status);
+            let target_bounds = target_bounds(bounds, target);
+
+            renderer.fill_quad(
+                renderer::Quad {
+                    bounds: target_bounds,
+                    border: Border {
+                        radius: Radius::new(4.0),
+                        ..Border::default()
+                    },
+                    ..renderer::Quad::default()
+                },
+                button_style.item_background,
+            );
+
+            let colour = if is_enabled || target == Target::Header {
+                style.header_text_color
+            } else {
+                button_style.text_color
+            };
+
+            match target {
+                Target::Header => {
+                    let heading = match state.mode {
+                        DisplayMode::Month => self.formatter.month_year(page),
+                        DisplayMode::Year => page.year().to_string(),
+                        DisplayMode::Decade => format!("{} - {}", decade(page), decade(page) + 9),
+                    };
+
+                    draw_text(
+                        renderer,
+                        heading,
+                        target_bounds.shrink([0.0, 12.0]),
+                        font::semibold(),
+                        14.0,
+                        Horizontal::Left,
+                        colour,
+                    );
+                }
+                Target::Previous | Target::Next => {
+                    let glyph = if target == Target::Previous {
+                        FluentIcon::ChevronUp
+                    } else {
+                        FluentIcon::ChevronDown
+                    };
+
+                    Icon::from(glyph).draw(
+                        renderer,
+                        Rectangle {
+                            x: target_bounds.center_x() - ICON_SIZE / 2.0,
+                            y: target_bounds.center_y() - ICON_SIZE / 2.0,
+                            width: ICON_SIZE,
+                            height: ICON_SIZE,
+                        },
+                        colour,
+                    );
+                }
+                Target::Item(_) => {}
+            }
+        }
+
+        if state.mode == DisplayMode::Month {
+            let body = body_bounds(bounds);
+
+            for column in 0..7 {
+                let weekday = self.first_day_of_week.add(column);
+
+                draw_text(
+                    renderer,
+                    self.formatter.weekday_abbreviation(weekday),
+                    Rectangle {
+                        x: body.x + column as f32 * body.width / 7.0,
+                        y: body.y,
+                        width: body.width / 7.0,
+                        height: ROW_HEIGHT,
+                    },
+                    font::semibold(),
+                    12.0,
+                    Horizontal::Center,
+                    style.weekday_text_color,
+                );
+            }
+        }
+
+        for index in 0..Self::items(state.mode) {
+            self.draw_item(renderer, theme, state, bounds, index, hovered);
+        }
+    }
+
+    fn mouse_interaction(
+        &self,
+        tree: &Tree,
+        layout: Layout<'_>,
+        cursor: Cursor,
+        _viewport: &Rectangle,
+        _renderer: &Renderer,
+    ) -> mouse::Interaction {
+        let state = tree.state.downcast_ref::<State>();
+
+        match self.target_at(state, layout.bounds(), cursor) {
+            Some(target) if self.is_target_enabled(state, target) => mouse::Interaction::Pointer,
+            _ => mouse::Interaction::default(),
+        }
+    }
+}
+
+impl<'a, Message> From<CalendarView<'a, Message>> for Element<'a, Message>
+where
+    Message: 'a,
+{
+    fn from(calendar_view: CalendarView<'a, Message>) -> Self {
+        Self::new(calendar_view)
+    }
+}
+
+fn decade(date: Date) -> i32 {
+    date.year().div_euclid(10) * 10
+}
+
+// The first and last dates in a day, month or year item
+fn span(mode: DisplayMode, date: Date) -> (Date, Date) {
+    match mode {
+        DisplayMode::Month => (date, date),
+        DisplayMode::Year => (
+            date.first_of_month(),
+            date.first_of_month().add_months(1).add_days(-1),
+        ),
+        DisplayMode::Decade => (
+            Date::new(date.year(), 1, 1).expect("January 1st"),
+            Date::new(date.year(), 12, 31).expect("December 31st"),
+        ),
+    }
+}
+
+// The first and last dates of the month, year or decade a page is about
+fn scope(mode: DisplayMode, page: Date) -> (Date, Date) {
+    match mode {
+        DisplayMode::Month => span(DisplayMode::Year, page),
+        DisplayMode::Year => span(DisplayMode::Decade, page),
+        DisplayMode::Decade => (
+            Date::new(decade(page), 1, 1).expect("January 1st"),
+            Date::new(decade(page) + 9, 12, 31).expect("December 31st"),
+        ),
+    }
+}
+
+fn in_scope(mode: DisplayMode, page: Date, date: Date) -> bool {
+    let (first, last) = scope(mode, page);
+
+    first <= date && date <= last
+}
+
+// Whether two dates fall in the same item
+fn same(mode: DisplayMode, a: Date, b: Date) -> bool {
+    span(mode, a) == span(mode, b)
+}
+
+fn step(mode: DisplayMode, date: Date, items: i32) -> Date {
+    match mode {
+        DisplayMode::Month => date.add_days(i64::from(items)),
+        DisplayMode::Year => date.add_months(items),
+        DisplayMode::Decade => date.add_years(items),
+    }
+}
+
+fn turn(mode: DisplayMode, date: Date, pages: i32) -> Date {
+    match mode {
+        DisplayMode::Month => date.add_months(pages),
+        DisplayMode::Year => date.add_years(pages),
+        DisplayMode::Decade => date.add_years(10 * pages),
+    }
+}
+
+fn item_status(state: &State, target: Target, hovered: Option<Target>, is_enabled: bool) -> Status {
+    if !is_enabled {
+        Status::Disabled
+    } else if hovered == Some(target) && state.pressed == Some(target) {
+        Status::Pressed
+    } else if hovered == Some(target) {
+        Status::Hovered
+    } else {
+        Status::Active
+    }
+}
+
+fn target_bounds(bounds: Rectangle, target: Target) -> Rectangle {
+    let y = bounds.y + (HEADER_HEIGHT - BUTTON_HEIGHT) / 2.0;
+    let right = bounds.x + bounds.width - PADDING;
+
+    match target {
+        Target::Header => Rectangle {
+            x: bounds.x + PADDING,
+            y,
+            width: bounds.width - 2.0 * PADDING - 2.0 * NAVIGATION_WIDTH,
+            height: BUTTON_HEIGHT,
+        },
+        Target::Previous => Rectangle {
+            x: right - 2.0 * NAVIGATION_WIDTH,
+            y,
+            width: NAVIGATION_WIDTH,
+            height: BUTTON_HEIGHT,
+        },
+        Target::Next => Rectangle {
+            x: right - NAVIGATION_WIDTH,
+            y,
+            width: NAVIGATION_WIDTH,
+            height: BUTTON_HEIGHT,
+        },
+        Target::Item(_) => Rectangle::default(),
+    }
+}
+
+fn body_bounds(bounds: Rectangle) -> Rectangle {
+    Rectangle {
+        x: bounds.x + PADDING,
+        y: bounds.y + HEADER_HEIGHT,
+        width: bounds.width - 2.0 * PADDING,
+        height: BODY_HEIGHT,
+    }
+}
+
+fn cell_bounds(mode: DisplayMode, bounds: Rectangle, index: usize) -> Rectangle {
+    let body = body_bounds(bounds);
+
+    match mode {
+        // Under the weekday names
+        DisplayMode::Month => Rectangle {
+            x: body.x + (index % 7) as f32 * body.width / 7.0,
+            y: body.y + (index / 7 + 1) as f32 * ROW_HEIGHT,
+            width: body.width / 7.0,
+            height: ROW_HEIGHT,
+        },
+        DisplayMode::Year | DisplayMode::Decade => Rectangle {
+            x: body.x + (index % 4) as f32 * body.width / 4.0,
+            y: body.y + (index / 4) as f32 * body.height / 4.0,
+            width: body.width / 4.0,
+            height: body.height / 4.0,
+        },
+    }
+}
+
+fn draw_text(
+    renderer: &mut Renderer,
+    content: String,
+    bounds: Rectangle,
+    font: Font,
+    size: f32,
+    align: Horizontal,
+    colour: Color,
+) {
+    let x = match align {
+        Horizontal::Left => bounds.x,
+        Horizontal::Center => bounds.center_x(),
+        Horizontal::Right => bounds.x + bounds.width,
+    };
+
+    renderer.fill_text(
+        Text {
+            content,
+            bounds: bounds.size(),
+            size: Pixels(text::scaled(size)),
+            line_height: LineHeight::Absolute(Pixels(text::scaled(size + 6.0))),
+            font,
+            horizontal_alignment: align,
+            vertical_alignment: Vertical::Center,
+            shaping: Shaping::Advanced,
+            wrapping: Wrapping::None,
+        },
+        Point::new(x, bounds.center_y()),
+        colour,
+        bounds,
+    );
+}
@@ -1,11 +1,18 @@
-use crate::{fluent_icon::FluentIcon, font, widget::Checkbox};
+use crate::{
+    fluent_icon::FluentIcon,
+    font, icon,
+    theme::Theme,
+    widget::{text, Checkbox},
+};
 
 use iced::{
+    advanced::renderer::{self, Renderer as _},
+    border::Radius,
     widget::{
-        checkbox::Icon,
+        checkbox::{self, Icon, Status},
         text::{LineHeight, Shaping},
     },
-    Pixels,
+    Background, Border, Color, Pixels, Rectangle,
 };
 
 #[derive(Clone, Copy, Debug, PartialEq)]
@@ -31,7 +38,7 @@ fn base<'a, Message>(
     code_point: char,
 ) -> Checkbox<'a, Message> {
     let check_mark = Icon {
-        font: font::SEGOE_FLUENT_ICONS,
+        font: font::icons(),
         code_point,
         size: Some(Pixels(14.0)),
         line_height: LineHeight::default(),
@@ -40,9 +47,9 @@ fn base<'a, Message>(
 
     Checkbox::new(label, is_checked)
         .size(20)
-        .font(font::SEGOE)
-        .text_size(14)
-        .text_line_height(LineHeight::Absolute(Pixels(20.0)))
+        .font(font::text())
+        .text_size(text::scaled(14.0))
+        .text_line_height(LineHeight::Absolute(Pixels(text::scaled(20.0))))
         .icon(check_mark)
 }
 
@@ -60,3 +67,46 @@ pub fn three_state<'a, Message>(
         ThreeState::Indeterminate => base(label, true, FluentIcon::DashKey.codepoint()),
     }
 }
+
+// For widgets that draw checkboxes in their rows themselves, so they match the
+// ones above
+pub fn draw(
+    renderer: &mut iced::Renderer,
+    theme: &Theme,
+    bounds: Rectangle,
+    check_state: ThreeState,
+    is_hovered: bool,
+) {
+    let is_checked = check_state != ThreeState::Unchecked;
+    let status = if is_hovered {
+        Status::Hovered { is_checked }
+    } else {
+        Status::Active { is_checked }
+    };
+
+    let style = checkbox::Catalog::style(theme, &<Theme as checkbox::Catalog>::default(), status);
+    let background = match style.background {
+        Background::Color(colour) => colour,
+        Background::Gradient(_) => Color::TRANSPARENT,
+    };
+
+    renderer.fill_quad(
+        renderer::Quad {
+            bounds,
+            border: Border {
+                radius: Radius::new(4),
+                ..style.border
+            },
+            ..renderer::Quad::default()
+        },
+        background,
+    );
+
+    let glyph = match check_state {
+        ThreeState::Checked => FluentIcon::CheckMark,
+        ThreeState::Indeterminate => FluentIcon::DashKey,
+        ThreeState::Unchecked => return,
+    };
+
+    icon::Icon::from(glyph).draw(renderer, bounds.shrink(3.0), style.icon_color);
+}
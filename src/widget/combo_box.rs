@@ -1,4 +1,9 @@
-use crate::{fluent_icon::FluentIcon, font, widget::ComboBox};
+use crate::{
+    density,
+    fluent_icon::FluentIcon,
+    font,
+    widget::{text, ComboBox},
+};
 
 use iced::{
     widget::{
@@ -19,10 +24,12 @@ where
     T: std::fmt::Display + Clone,
 {
     ComboBox::new(state, placeholder, selection, on_selected)
-        .font(font::SEGOE)
-        .line_height(LineHeight::Absolute(Pixels(20.0)))
+        .font(font::text())
+        .size(text::scaled(14.0))
+        .line_height(LineHeight::Absolute(Pixels(text::scaled(20.0))))
+        .padding(density::metrics().control_padding)
         .icon(Icon {
-            font: font::SEGOE_FLUENT_ICONS,
+            font: font::icons(),
             code_point: FluentIcon::ChevronDown.codepoint(),
             size: Some(10.into()),
             spacing: 4.0,
@@ -0,0 +1,1981 @@
+use crate::{
+    density,
+    fluent_icon::FluentIcon,
+    font,
+    icon::Icon,
+    style::data_grid::{Status, Style, StyleFn},
+    theme::{
+        class::{self, Class},
+        Theme,
+    },
+    widget::{
+        checkbox::{self, ThreeState},
+        combo_box,
+        list_view::{Action, Selection, SelectionMode},
+        number_input::NumberInput,
+        text, text_input,
+        virtual_scroll::Scroll,
+        Element,
+    },
+};
+
+use iced::{
+    advanced::{
+        layout::{Limits, Node as LayoutNode},
+        mouse::{click, Click},
+        overlay,
+        renderer::{self, Renderer as _},
+        text::{LineHeight, Paragraph as _, Renderer as _, Shaping, Text, Wrapping},
+        widget::{
+            operation::{self, focusable, Operation},
+            tree, Tree,
+        },
+        Clipboard, Layout, Overlay, Shell, Widget,
+    },
+    alignment::{Horizontal, Vertical},
+    border::{self, Radius},
+    event,
+    keyboard::{self, key::Named, Key},
+    mouse::{self, Cursor},
+    touch,
+    widget::combo_box::State as Choices,
+    Border, Color, Event, Length, Pixels, Point, Rectangle, Size, Vector,
+};
+
+use std::{cell::RefCell, ops::RangeInclusive};
+
+type Renderer = iced::Renderer;
+type OnEdit<'a, Message> = Box<dyn Fn(usize, usize, Value) -> Message + 'a>;
+
+// Sizes from the Windows Community Toolkit DataGrid
+// https://github.com/CommunityToolkit/Windows/blob/main/components/DataGrid/src/DataGrid/DataGrid.xaml
+const CELL_PADDING: f32 = 12.0;
+const MIN_COLUMN_WIDTH: f32 = 40.0;
+const SORT_ICON_SIZE: f32 = 12.0;
+const CHECKBOX_SIZE: f32 = 20.0;
+// How far either side of a header's edge it can be grabbed to resize the column
+const RESIZE_GRIP: f32 = 4.0;
+
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum Width {
+    Fixed(f32),
+    // As wide as the header or the widest cell that has been in view
+    Auto,
+    // A share of the width the other columns leave
+    Fill(u16),
+}
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum SortDirection {
+    Ascending,
+    Descending,
+}
+
+// What a cell holds. Numbers are shown with the column's decimals and booleans
+// as checkboxes.
+#[derive(Clone, Debug, PartialEq)]
+pub enum Value {
+    Text(String),
+    Number(f64),
+    Bool(bool),
+}
+
+// How a column's cells are edited, each with the crate's own input
+pub enum Editor<'a> {
+    Text,
+    Number(RangeInclusive<f64>),
+    // Toggled in place by clicking the checkbox or pressing Space
+    Check,
+    Choice(&'a Choices<String>),
+}
+
+pub struct Column<'a> {
+    header: String,
+    width: Width,
+    min_width: f32,
+    align: Horizontal,
+    decimals: usize,
+    sortable: bool,
+    editor: Option<Editor<'a>>,
+}
+
+impl<'a> Column<'a> {
+    pub fn new(header: impl Into<String>) -> Self {
+        Self {
+            header: header.into(),
+            width: Width::Auto,
+            min_width: MIN_COLUMN_WIDTH,
+            align: Horizontal::Left,
+            decimals: 0,
+            sortable: true,
+            editor: None,
+        }
+    }
+
+    pub fn width(mut self, width: Width) -> Self {
+        self.width = width;
+        self
+    }
+
+    pub fn min_width(mut self, min_width: f32) -> Self {
+        self.min_width = min_width;
+        self
+    }
+
+    pub fn align(mut self, align: Horizontal) -> Self {
+        self.align = align;
+        self
+    }
+
+    // Digits shown after the decimal point in number cells
+    pub fn decimals(mut self, decimals: usize) -> Self {
+        self.decimals = decimals;
+        self
+    }
+
+    pub fn sortable(mut self, sortable: bool) -> Self {
+        self.sortable = sortable;
+        self
+    }
+
+    pub fn editor(mut self, editor: Editor<'a>) -> Self {
+        self.editor = Some(editor);
+        self
+    }
+
+    fn format(&self, value: &Value) -> String {
+        match value {
+            Value::Text(text) => text.clone(),
+            Value::Number(number) => format!("{number:.*}", self.decimals),
+            Value::Bool(value) => value.to_string(),
+        }
+    }
+
+    fn is_check(&self) -> bool {
+        matches!(self.editor, Some(Editor::Check))
+    }
+
+    // Whether the cell opens an editor, rather than being read only or toggled in
+    // place
+    fn opens_editor(&self) -> bool {
+        matches!(
+            self.editor,
+            Some(Editor::Text | Editor::Number(_) | Editor::Choice(_))
+        )
+    }
+
+    fn is_choice(&self) -> bool {
+        matches!(self.editor, Some(Editor::Choice(_)))
+    }
+
+    fn checkbox_bounds(&self, cell: Rectangle) -> Rectangle {
+        let x = match self.align {
+            Horizontal::Left => cell.x + CELL_PADDING,
+            Horizontal::Center => cell.center_x() - CHECKBOX_SIZE / 2.0,
+            Horizontal::Right => cell.x + cell.width - CELL_PADDING - CHECKBOX_SIZE,
+        };
+
+        Rectangle {
+            x,
+            y: cell.center_y() - CHECKBOX_SIZE / 2.0,
+            width: CHECKBOX_SIZE,
+            height: CHECKBOX_SIZE,
+        }
+    }
+}
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+enum Hit {
+    Header(usize),
+    // The grip on a header's trailing edge
+    Resize(usize),
+    Cell { row: usize, column: usize },
+}
+
+// Based on https://learn.microsoft.com/en-us/windows/communitytoolkit/controls/datagrid
+//
+// The application keeps the rows and hands cells over through `cell` as they're
+// drawn, so only the rows in view are ever read. Sorting and edits come back as
+// messages for the application to apply to its rows.
+pub struct DataGrid<'a, Message> {
+    columns: Vec<Column<'a>>,
+    rows: usize,
+    cell: Box<dyn Fn(usize, usize) -> Value + 'a>,
+    selection: &'a Selection,
+    selection_mode: SelectionMode,
+    on_action: Option<Box<dyn Fn(Action) -> Message + 'a>>,
+    on_sort: Option<Box<dyn Fn(usize, SortDirection) -> Message + 'a>>,
+    on_edit: Option<OnEdit<'a, Message>>,
+    sort: Option<(usize, SortDirection)>,
+    frozen_columns: usize,
+    alternate_rows: bool,
+    width: Length,
+    height: Length,
+    // Read when built, the density isn't known during layout
+    row_height: f32,
+    class: Class<StyleFn>,
+    // The open cell editor, built on layout from the value being edited
+    editor: RefCell<Option<Element<'a, Value>>>,
+}
+
+impl<'a, Message> DataGrid<'a, Message> {
+    pub fn new(
+        columns: impl IntoIterator<Item = Column<'a>>,
+        rows: usize,
+        selection: &'a Selection,
+        cell: impl Fn(usize, usize) -> Value + 'a,
+    ) -> Self {
+        Self {
+            columns: columns.into_iter().collect(),
+            rows,
+            cell: Box::new(cell),
+            selection,
+            selection_mode: SelectionMode::Extended,
+            on_action: None,
+            on_sort: None,
+            on_edit: None,
+            sort: None,
+            frozen_columns: 0,
+            alternate_rows: true,
+            width: Length::Fill,
+            height: Length::Fill,
+            row_height: density::metrics().control_height,
+            class: class::DEFAULT.into(),
+            editor: RefCell::new(None),
+        }
+    }
+
+    pub fn on_action(mut self, on_action: impl Fn(Action) -> Message + 'a) -> Self {
+        self.on_action = Some(Box::new(on_action));
+        self
+    }
+
+    // Sent when a sortable header is clicked, with the direction it should now be
+    // sorted in. The grid doesn't reorder anything itself.
+    pub fn on_sort(mut self, on_sort: impl Fn(usize, SortDirection) -> Message + 'a) -> Self {
+        self.on_sort = Some(Box::new(on_sort));
+        self
+    }
+
+    // Sent with the row, column and new value when an edit is committed
+    pub fn on_edit(mut self, on_edit: impl Fn(usize, usize, Value) -> Message + 'a) -> Self {
+        self.on_edit = Some(Box::new(on_edit));
+        self
+    }
+
+    // The column the rows are sorted by, which gets the sort indicator
+    pub fn sort(mut self, column: usize, direction: SortDirection) -> Self {
+        self.sort = Some((column, direction));
+        self
+    }
+
+    pub fn selection_mode(mut self, selection_mode: SelectionMode) -> Self {
+        self.selection_mode = selection_mode;
+        self
+    }
+
+    // Leading columns that stay put while the rest scroll sideways
+    pub fn frozen_columns(mut self, frozen_columns: usize) -> Self {
+        self.frozen_columns = frozen_columns;
+        self
+    }
+
+    pub fn alternate_rows(mut self, alternate_rows: bool) -> Self {
+        self.alternate_rows = alternate_rows;
+        self
+    }
+
+    pub fn width(mut self, width: impl Into<Length>) -> Self {
+        self.width = width.into();
+        self
+    }
+
+    pub fn height(mut self, height: impl Into<Length>) -> Self {
+        self.height = height.into();
+        self
+    }
+
+    pub fn style(mut self, style: impl Fn(&Theme, Status) -> Style + 'static) -> Self {
+        self.class = Class::Custom(Box::new(style));
+        self
+    }
+
+    pub fn class(mut self, class: impl Into<Class<StyleFn>>) -> Self {
+        self.class = class.into();
+        self
+    }
+
+    fn frozen(&self) -> usize {
+        self.frozen_columns.min(self.columns.len())
+    }
+
+    fn frozen_width(&self, state: &State) -> f32 {
+        state.widths[..self.frozen()].iter().sum()
+    }
+
+    // Where a column starts before any scrolling, from the grid's leading edge
+    fn column_left(state: &State, column: usize) -> f32 {
+        state.widths[..column].iter().sum()
+    }
+
+    fn column_x(&self, state: &State, bounds: Rectangle, column: usize) -> f32 {
+        let scrolled = if column < self.frozen() {
+            0.0
+        } else {
+            state.columns.offset()
+        };
+
+        bounds.x + Self::column_left(state, column) - scrolled
+    }
+
+    fn header_bounds(&self, bounds: Rectangle) -> Rectangle {
+        Rectangle {
+            height: self.row_height.min(bounds.height),
+            ..bounds
+        }
+    }
+
+    fn body_bounds(&self, bounds: Rectangle) -> Rectangle {
+        let header = self.row_height.min(bounds.height);
+
+        Rectangle {
+            y: bounds.y + header,
+            height: bounds.height - header,
+            ..bounds
+        }
+    }
+
+    // The frozen columns' area and the area right of it the others scroll in,
+    // both the full height of the grid
+    fn regions(&self, state: &State, bounds: Rectangle) -> (Rectangle, Rectangle) {
+        let frozen_width = self.frozen_width(state).min(bounds.width);
+
+        (
+            Rectangle {
+                width: frozen_width,
+                ..bounds
+            },
+            Rectangle {
+                x: bounds.x + frozen_width,
+                width: bounds.width - frozen_width,
+                ..bounds
+            },
+        )
+    }
+
+    // Where each scrollbar's rail goes, the vertical one beside the rows and the
+    // horizontal one under the scrolling columns
+    fn scrollbar_bounds(&self, state: &State, bounds: Rectangle) -> (Rectangle, Rectangle) {
+        let body = self.body_bounds(bounds);
+        let (_, scrolling) = self.regions(state, body);
+
+        (
+            Rectangle {
+                height: body.height - state.columns.gutter(),
+                ..body
+            },
+            Rectangle {
+                width: scrolling.width - state.rows.gutter(),
+                ..scrolling
+            },
+        )
+    }
+
+    fn cell_bounds(
+        &self,
+        state: &State,
+        bounds: Rectangle,
+        row: usize,
+        column: usize,
+    ) -> Rectangle {
+        let body = self.body_bounds(bounds);
+
+        Rectangle {
+            x: self.column_x(state, bounds, column),
+            y: body.y + row as f32 * self.row_height - state.rows.offset(),
+            width: state.widths[column],
+            height: self.row_height,
+        }
+    }
+
+    fn column_at(&self, state: &State, bounds: Rectangle, x: f32) -> Option<usize> {
+        let (frozen, _) = self.regions(state, bounds);
+        let range = if x < frozen.x + frozen.width {
+            0..self.frozen()
+        } else {
+            self.frozen()..self.columns.len()
+        };
+
+        range.into_iter().find(|column| {
+            let left = self.column_x(state, bounds, *column);
+
+            left <= x && x < left + state.widths[*column]
+        })
+    }
+
+    fn hit(&self, state: &State, bounds: Rectangle, cursor: Cursor) -> Option<Hit> {
+        let position = cursor.position_over(bounds)?;
+        let (vertical, horizontal) = self.scrollbar_bounds(state, bounds);
+
+        if state.rows.is_over_scrollbar(vertical, cursor)
+            || state.columns.is_over_scrollbar(horizontal, cursor)
+        {
+            return None;
+        }
+
+        if self.header_bounds(bounds).contains(position) {
+            let (frozen, scrolling) = self.regions(state, bounds);
+
+            let grip = (0..self.columns.len()).find(|column| {
+                let right = self.column_x(state, bounds, *column) + state.widths[*column];
+                let region = if *column < self.frozen() {
+                    frozen
+                } else {
+                    scrolling
+                };
+
+                (right - position.x).abs() <= RESIZE_GRIP
+                    && right > region.x
+                    && right <= region.x + region.width + RESIZE_GRIP
+            });
+
+            return match grip {
+                Some(column) => Some(Hit::Resize(column)),
+                None => self.column_at(state, bounds, position.x).map(Hit::Header),
+            };
+        }
+
+        let body = self.body_bounds(bounds);
+        let row = ((position.y - body.y + state.rows.offset()) / self.row_height).floor() as usize;
+
+        if row >= self.rows {
+            return None;
+        }
+
+        self.column_at(state, bounds, position.x)
+            .map(|column| Hit::Cell { row, column })
+    }
+
+    // How the editor is moved from where it's laid out to where its cell is shown
+    fn editor_offset(&self, state: &State, column: usize) -> Vector {
+        let x = if column < self.frozen() {
+            0.0
+        } else {
+            state.columns.offset()
+        };
+
+        Vector::new(-x, -state.rows.offset())
+    }
+
+    // The part of the body the editor for `column` is drawn in
+    fn editor_clip(&self, state: &State, bounds: Rectangle, column: usize) -> Rectangle {
+        let (frozen, scrolling) = self.regions(state, self.body_bounds(bounds));
+
+        if column < self.frozen() {
+            frozen
+        } else {
+            scrolling
+        }
+    }
+
+    fn editor_cursor(
+        &self,
+        state: &State,
+        bounds: Rectangle,
+        column: usize,
+        cursor: Cursor,
+    ) -> Cursor {
+        match cursor.position_over(self.editor_clip(state, bounds, column)) {
+            Some(position) => Cursor::Available(position - self.editor_offset(state, column)),
+            None => Cursor::Unavailable,
+        }
+    }
+
+    fn editor_element(&self, column: &Column<'a>, draft: &Value) -> Option<Element<'a, Value>> {
+        match (column.editor.as_ref()?, draft) {
+            (Editor::Text, Value::Text(value)) => Some(
+                text_input::standard("", value)
+                    .on_input(Value::Text)
+                    .width(Length::Fill)
+                    .into(),
+            ),
+            (Editor::Number(range), Value::Number(value)) => Some(
+                NumberInput::new(*value, range.clone(), Value::Number)
+                    .width(Length::Fill)
+                    .into(),
+            ),
+            (Editor::Choice(choices), Value::Text(value)) => Some(
+                combo_box::standard(choices, "", Some(value), Value::Text)
+                    .width(Length::Fill)
+                    .into(),
+            ),
+            _ => None,
+        }
+    }
+
+    fn perform(&self, action: Action, shell: &mut Shell<'_, Message>) {
+        if let Some(on_action) = &self.on_action {
+            shell.publish(on_action(action));
+        }
+    }
+
+    // Opens the cell's editor, starting from `draft` when typing began the edit
+    fn begin_edit(
+        &self,
+        state: &mut State,
+        row: usize,
+        column: usize,
+        draft: Option<Value>,
+        shell: &mut Shell<'_, Message>,
+    ) -> bool {
+        if self.on_edit.is_none() || !self.columns[column].opens_editor() {
+            return false;
+        }
+
+        let original = (self.cell)(row, column);
+
+        if self.columns[column].is_choice() && !matches!(original, Value::Text(_)) {
+            return false;
+        }
+
+        state.editing = Some(Editing {
+            row,
+            column,
+            draft: draft.unwrap_or_else(|| original.clone()),
+            original,
+            tree: Tree::empty(),
+            needs_focus: true,
+            is_done: false,
+        });
+        state.current = Some((row, column));
+
+        shell.invalidate_layout();
+
+        true
+    }
+
+    fn commit_edit(&self, state: &mut State, shell: &mut Shell<'_, Message>) {
+        if let Some(editing) = state.editing.take() {
+            if !editing.is_done {
+                editing.commit(self.on_edit.as_deref(), shell);
+            }
+
+            shell.invalidate_layout();
+        }
+    }
+
+    // Closes the editor without sending the draft
+    fn cancel_edit(&self, state: &mut State, shell: &mut Shell<'_, Message>) {
+        if state.editing.take().is_some() {
+            shell.invalidate_layout();
+        }
+    }
+
+    // Tab moves the edit on to the next cell with an editor, wrapping onto the next
+    // row, and Shift+Tab back
+    fn edit_next(
+        &self,
+        state: &mut State,
+        row: usize,
+        column: usize,
+        forwards: bool,
+        shell: &mut Shell<'_, Message>,
+    ) {
+        let columns = self.columns.len();
+        let start = row * columns + column;
+        let cells = self.rows * columns;
+
+        let next = if forwards {
+            (start + 1..cells).find(|cell| self.columns[cell % columns].opens_editor())
+        } else {
+            (0..start)
+                .rev()
+                .find(|cell| self.columns[cell % columns].opens_editor())
+        };
+
+        if let Some(cell) = next {
+            self.move_to(
+                state,
+                cell / columns,
+                cell % columns,
+                keyboard::Modifiers::default(),
+                shell,
+            );
+            self.begin_edit(state, cell / columns, cell % columns, None, shell);
+        }
+    }
+
+    // The direction clicking a column's header asks for, which reverses the sort
+    // if the rows are already sorted ascending by it
+    fn sort_direction(&self, column: usize) -> SortDirection {
+        match self.sort {
+            Some((sorted, SortDirection::Ascending)) if sorted == column => {
+                SortDirection::Descending
+            }
+            _ => SortDirection::Ascending,
+        }
+    }
+
+    fn toggle(&self, row: usize, column: usize, shell: &mut Shell<'_, Message>) {
+        if let (Some(on_edit), Value::Bool(value)) = (&self.on_edit, (self.cell)(row, column)) {
+            shell.publish(on_edit(row, column, Value::Bool(!value)));
+        }
+    }
+
+    // Moves the current cell and brings it into view. The row selection follows as
+    // in a ListView.
+    fn move_to(
+        &self,
+        state: &mut State,
+        row: usize,
+        column: usize,
+        modifiers: keyboard::Modifiers,
+        shell: &mut Shell<'_, Message>,
+    ) {
+        let previous = state.current.map(|(row, _)| row);
+        state.current = Some((row, column));
+
+        let row_height = self.row_height;
+        let top = row as f32 * row_height;
+        state.rows.show(top, top + row_height, shell);
+
+        if column >= self.frozen() {
+            let left = Self::column_left(state, column) - self.frozen_width(state);
+            state.columns.show(left, left + state.widths[column], shell);
+        }
+
+        if previous != Some(row) {
+            if let Some(action) = self.selection_mode.on_focus(self.selection, row, modifiers) {
+                self.perform(action, shell);
+            }
+        }
+    }
+
+    fn on_key(
+        &self,
+        state: &mut State,
+        key: &Key,
+        text: Option<&str>,
+        modifiers: keyboard::Modifiers,
+        shell: &mut Shell<'_, Message>,
+    ) -> event::Status {
+        if self.rows == 0 || self.columns.is_empty() {
+            return event::Status::Ignored;
+        }
+
+        let last_row = self.rows - 1;
+        let last_column = self.columns.len() - 1;
+        let (row, column) = state.current.map_or((0, 0), |(row, column)| {
+            (row.min(last_row), column.min(last_column))
+        });
+
+        let (row, column) = match key.as_ref() {
+            Key::Named(Named::ArrowDown) => ((row + 1).min(last_row), column),
+            Key::Named(Named::ArrowUp) => (row.saturating_sub(1), column),
+            Key::Named(Named::ArrowRight) => (row, (column + 1).min(last_column)),
+            Key::Named(Named::ArrowLeft) => (row, column.saturating_sub(1)),
+            Key::Named(Named::PageDown) => ((row + state.page).min(last_row), column),
+            Key::Named(Named::PageUp) => (row.saturating_sub(state.page), column),
+            Key::Named(Named::Home) if modifiers.command() => (0, column),
+            Key::Named(Named::Home) => (row, 0),
+            Key::Named(Named::End) if modifiers.command() => (last_row, column),
+            Key::Named(Named::End) => (row, last_column),
+            Key::Named(Named::F2 | Named::Enter) => {
+                self.begin_edit(state, row, column, None, shell);
+
+                return event::Status::Captured;
+            }
+            Key::Named(Named::Space) => {
+                if self.columns[column].is_check() {
+                    self.toggle(row, column, shell);
+                } else if let Some(action) =
+                    self.selection_mode.on_click(self.selection, row, modifiers)
+                {
+                    self.perform(action, shell);
+                }
+
+                return event::Status::Captured;
+            }
+            Key::Character("a")
+                if modifiers.command()
+                    && matches!(
+                        self.selection_mode,
+                        SelectionMode::Multiple | SelectionMode::Extended
+                    ) =>
+            {
+                self.perform(Action::SelectAll(self.rows), shell);
+
+                return event::Status::Captured;
+            }
+            _ => {
+                // Typing into a text cell starts editing it afresh, as in a
+                // spreadsheet
+                let Some(text) = text
+                    .filter(|text| !modifiers.command() && text.chars().all(|c| !c.is_control()))
+                else {
+                    return event::Status::Ignored;
+                };
+
+                if !matches!(self.columns[column].editor, Some(Editor::Text)) {
+                    return event::Status::Ignored;
+                }
+
+                self.begin_edit(
+                    state,
+                    row,
+                    column,
+                    Some(Value::Text(text.to_owned())),
+                    shell,
+                );
+
+                return event::Status::Captured;
+            }
+        };
+
+        self.move_to(state, row, column, modifiers, shell);
+
+        event::Status::Captured
+    }
+
+    #[allow(clippy::too_many_arguments)]
+    fn draw_cell(
+        &self,
+        renderer: &mut Renderer,
+        theme: &Theme,
+        column: usize,
+        value: &Value,
+        bounds: Rectangle,
+        colour: Color,
+        is_hovered: bool,
+    ) {
+        let column = &self.columns[column];
+
+        match value {
+            Value::Bool(value) => {
+                let check_state = if *value {
+                    ThreeState::Checked
+                } else {
+                    ThreeState::Unchecked
+                };
+
+                checkbox::draw(
+                    renderer,
+                    theme,
+                    column.checkbox_bounds(bounds),
+                    check_state,
+                    is_hovered && column.is_check(),
+                );
+            }
+            _ => draw_text(
+                renderer,
+                &column.format(value),
+                bounds.shrink([0.0, CELL_PADDING]),
+                font::text(),
+                column.align,
+                colour,
+            ),
+        }
+    }
+}
+
+struct Editing {
+    row: usize,
+    column: usize,
+    original: Value,
+    draft: Value,
+    tree: Tree,
+    // Set until the editor has been laid out and given focus
+    needs_focus: bool,
+    // Set when a choice was picked from the editor's menu, which commits it. The
+    // editor closes on the next layout.
+    is_done: bool,
+}
+
+impl Editing {
+    fn commit<Message>(
+        &self,
+        on_edit: Option<&dyn Fn(usize, usize, Value) -> Message>,
+        shell: &mut Shell<'_, Message>,
+    ) {
+        if let Some(on_edit) = on_edit {
+            if self.draft != self.original {
+                shell.publish(on_edit(self.row, self.column, self.draft.clone()));
+            }
+        }
+    }
+}
+
+struct Resize {
+    column: usize,
+    origin: f32,
+    width: f32,
+}
+
+struct State {
+    rows: Scroll,
+    columns: Scroll,
+    // Each column's width from the last layout, and widths dragged to by hand
+    widths: Vec<f32>,
+    resized: Vec<Option<f32>>,
+    // The widest content seen in each auto column, so they don't shrink back as
+    // rows scroll past
+    measured: Vec<f32>,
+    // Rows in a page, for Page Up and Page Down
+    page: usize,
+    resizing: Option<Resize>,
+    pressed_header: Option<usize>,
+    // The cell with keyboard focus, and whether the grid has it at all. The grid
+    // takes focus when it's clicked and loses it to a click anywhere else
+    current: Option<(usize, usize)>,
+    is_focused: bool,
+    pressed_row: Option<usize>,
+    modifiers: keyboard::Modifiers,
+    last_click: Option<Click>,
+    editing: Option<Editing>,
+}
+
+impl Default for State {
+    fn default() -> Self {
+        Self {
+            rows: Scroll::default(),
+            columns: Scroll::horizontal(),
+            widths: Vec::new(),
+            resized: Vec::new(),
+            measured: Vec::new(),
+            page: 1,
+            resizing: None,
+            pressed_header: None,
+            current: None,
+            is_focused: false,
+            pressed_row: None,
+            modifiers: keyboard::Modifiers::default(),
+            last_click: None,
+            editing: None,
+        }
+    }
+}
+
+impl<'a, Message> Widget<Message, Theme, Renderer> for DataGrid<'a, Message> {
+    fn tag(&self) -> tree::Tag {
+        tree::Tag::of::<State>()
+    }
+
+    fn state(&self) -> tree::State {
+        tree::State::new(State::default())
+    }
+
+    fn size(&self) -> Size<Length> {
+        Size::new(self.width, self.height)
+    }
+
+    fn layout(&self, tree: &mut Tree, renderer: &Renderer, limits: &Limits) -> LayoutNode {
+        let state = tree.state.downcast_mut::<State>();
+        let count = self.columns.len();
+        let row_height = self.row_height;
+
+        if state.widths.len() != count {
+            state.widths = vec![0.0; count];
+            state.resized = vec![None; count];
+            state.measured = vec![0.0; count];
+        }
+
+        let size = limits.resolve(
+            self.width,
+            self.height,
+            Size::new(
+                limits.max().width,
+                (self.rows + 1) as f32 * row_height + state.columns.gutter(),
+            ),
+        );
+        let body_height = (size.height - row_height).max(0.0);
+
+        state.rows.resize(
+            self.rows as f32 * row_height,
+            body_height - state.columns.gutter(),
+        );
+
+        // Auto columns fit the header and whatever is in view, and only grow
+        let visible = state.rows.visible(row_height, self.rows, 0);
+
+        for (index, column) in self.columns.iter().enumerate() {
+            if column.width != Width::Auto || state.resized[index].is_some() {
+                continue;
+            }
+
+            let header = measure(&column.header, font::semibold()) + SORT_ICON_SIZE + CELL_PADDING;
+            let widest = visible
+                .clone()
+                .map(|row| match (self.cell)(row, index) {
+                    Value::Bool(_) => CHECKBOX_SIZE,
+                    value => measure(&column.format(&value), font::text()),
+                })
+                .fold(header, f32::max);
+
+            state.measured[index] = state.measured[index].max(widest);
+        }
+
+        let available = size.width - state.rows.gutter();
+        let mut fill_portions = 0;
+        let mut used = 0.0;
+
+        for (index, column) in self.columns.iter().enumerate() {
+            let width = match (state.resized[index], column.width) {
+                (Some(width), _) | (None, Width::Fixed(width)) => width,
+                (None, Width::Auto) => state.measured[index] + 2.0 * CELL_PADDING,
+                (None, Width::Fill(portion)) => {
+                    fill_portions += portion;
+                    continue;
+                }
+            };
+
+            state.widths[index] = width.max(column.min_width);
+            used += state.widths[index];
+        }
+
+        let remaining = (available - used).max(0.0);
+
+        for (index, column) in self.columns.iter().enumerate() {
+            if let (None, Width::Fill(portion)) = (state.resized[index], column.width) {
+                let share = remaining * f32::from(portion) / f32::from(fill_portions.max(1));
+                state.widths[index] = share.max(column.min_width);
+            }
+        }
+
+        let frozen_width = self.frozen_width(state);
+        let total: f32 = state.widths.iter().sum();
+
+        state
+            .columns
+            .resize(total - frozen_width, (available - frozen_width).max(0.0));
+        state.rows.resize(
+            self.rows as f32 * row_height,
+            body_height - state.columns.gutter(),
+        );
+        state.page =
+            (((body_height - state.columns.gutter()) / row_height).floor() as usize).max(1);
+
+        let mut editor = self.editor.borrow_mut();
+        *editor = None;
+
+        if state.editing.as_ref().is_some_and(|editing| {
+            editing.is_done || editing.row >= self.rows || editing.column >= count
+        }) {
+            state.editing = None;
+        }
+
+        let Some(column) = state.editing.as_ref().map(|editing| editing.column) else {
+            return LayoutNode::new(size);
+        };
+
+        let left = Self::column_left(state, column);
+        let width = state.widths[column];
+        let editing = state.editing.as_mut().expect("Editing a cell");
+
+        let Some(element) = self.editor_element(&self.columns[editing.column], &editing.draft)
+        else {
+            state.editing = None;
+
+            return LayoutNode::new(size);
+        };
+
+        editing.tree.diff(element.as_widget());
+
+        let node = element.as_widget().layout(
+            &mut editing.tree,
+            renderer,
+            &Limits::new(Size::ZERO, Size::new(width, row_height)),
+        );
+        let top = (row_height - node.size().height).max(0.0) / 2.0;
+        let node = node.move_to(Point::new(
+            left,
+            row_height + editing.row as f32 * row_height + top,
+        ));
+
+        if editing.needs_focus {
+            editing.needs_focus = false;
+
+            let mut operation: Box<dyn Operation> = Box::new(focusable::focus_next());
+
+            loop {
+                element.as_widget().operate(
+                    &mut editing.tree,
+                    Layout::new(&node),
+                    renderer,
+                    operation.as_mut(),
+                );
+
+                match operation.finish() {
+                    operation::Outcome::Chain(next) => operation = next,
+                    _ => break,
+                }
+            }
+        }
+
+        *editor = Some(element);
+
+        LayoutNode::with_children(size, vec![node])
+    }
+
+    fn on_event(
+        &mut self,
+        tree: &mut Tree,
+        event: Event,
+        layout: Layout<'_>,
+        cursor: Cursor,
+        renderer: &Renderer,
+        clipboard: &mut dyn Clipboard,
+        shell: &mut Shell<'_, Message>,
+        _viewport: &Rectangle,
+    ) -> event::Status {
+        let state = tree.state.downcast_mut::<State>();
+        let bounds = layout.bounds();
+
+        if let Event::Keyboard(keyboard::Event::ModifiersChanged(modifiers)) = event {
+            state.modifiers = modifiers;
+        }
+
+        if state
+            .editing
+            .as_ref()
+            .is_some_and(|editing| editing.is_done)
+        {
+            state.editing = None;
+        }
+
+        // Esc, Tab and Enter are the grid's while editing, before the editor can
+        // take them
+        if let (
+            Some(editing),
+            Event::Keyboard(keyboard::Event::KeyPressed { key, modifiers, .. }),
+        ) = (&state.editing, &event)
+        {
+            let (row, column) = (editing.row, editing.column);
+
+            match key.as_ref() {
+                Key::Named(Named::Escape) => {
+                    self.cancel_edit(state, shell);
+
+                    return event::Status::Captured;
+                }
+                Key::Named(Named::Tab) => {
+                    self.commit_edit(state, shell);
+                    self.edit_next(state, row, column, !modifiers.shift(), shell);
+
+                    return event::Status::Captured;
+                }
+                Key::Named(Named::Enter) if !self.columns[column].is_choice() => {
+                    self.commit_edit(state, shell);
+
+                    return event::Status::Captured;
+                }
+                _ => {}
+            }
+        }
+
+        let (vertical, horizontal) = self.scrollbar_bounds(state, bounds);
+
+        // Shift turns the wheel sideways
+        let sideways = match event {
+            Event::Mouse(mouse::Event::WheelScrolled { delta }) if state.modifiers.shift() => {
+                Some(Event::Mouse(mouse::Event::WheelScrolled {
+                    delta: match delta {
+                        mouse::ScrollDelta::Lines { x, y } => {
+                            mouse::ScrollDelta::Lines { x: y, y: x }
+                        }
+                        mouse::ScrollDelta::Pixels { x, y } => {
+                            mouse::ScrollDelta::Pixels { x: y, y: x }
+                        }
+                    },
+                }))
+            }
+            _ => None,
+        };
+        let scroll_event = sideways.as_ref().unwrap_or(&event);
+
+        if state.rows.on_event(scroll_event, vertical, cursor, shell) == event::Status::Captured
+            || state
+                .columns
+                .on_event(scroll_event, horizontal, cursor, shell)
+                == event::Status::Captured
+        {
+            return event::Status::Captured;
+        }
+
+        if let (Some(column), Some(editor_layout)) = (
+            state.editing.as_ref().map(|editing| editing.column),
+            layout.children().next(),
+        ) {
+            let editor_cursor = self.editor_cursor(state, bounds, column, cursor);
+            let clip = self.editor_clip(state, bounds, column);
+            let editor_viewport = clip - self.editor_offset(state, column);
+            let Some(editor) = self.editor.get_mut().as_mut() else {
+                return event::Status::Ignored;
+            };
+            let editing = state.editing.as_mut().expect("Editing a cell");
+
+            let mut messages = Vec::new();
+            let mut local = Shell::new(&mut messages);
+
+            let status = editor.as_widget_mut().on_event(
+                &mut editing.tree,
+                event.clone(),
+                editor_layout,
+                editor_cursor,
+                renderer,
+                clipboard,
+                &mut local,
+                &editor_viewport,
+            );
+
+            if let Some(request) = local.redraw_request() {
+                shell.request_redraw(request);
+            }
+
+            let is_layout_invalid = local.is_layout_invalid();
+            let are_widgets_invalid = local.are_widgets_invalid();
+
+            if is_layout_invalid || !messages.is_empty() {
+                shell.invalidate_layout();
+            }
+
+            if are_widgets_invalid {
+                shell.invalidate_widgets();
+            }
+
+            let is_choice = self.columns[column].is_choice();
+            let picked = is_choice && !messages.is_empty();
+
+            if let Some(value) = messages.pop() {
+                editing.draft = value;
+            }
+
+            // A choice commits as soon as it's picked, or with Enter on what's
+            // been typed
+            let entered = matches!(
+                &event,
+                Event::Keyboard(keyboard::Event::KeyPressed {
+                    key: Key::Named(Named::Enter),
+                    ..
+                })
+            );
+
+            if picked || (is_choice && entered) {
+                self.commit_edit(state, shell);
+
+                return event::Status::Captured;
+            }
+
+            if status == event::Status::Captured {
+                return status;
+            }
+
+            // Clicking anywhere else commits the edit
+            let is_press = matches!(
+                event,
+                Event::Mouse(mouse::Event::ButtonPressed(_))
+                    | Event::Touch(touch::Event::FingerPressed { .. })
+            );
+
+            if is_press && !editor_cursor.is_over(editor_layout.bounds()) {
+                self.commit_edit(state, shell);
+            }
+        }
+
+        match event {
+            Event::Mouse(mouse::Event::ButtonPressed(mouse::Button::Left))
+            | Event::Touch(touch::Event::FingerPressed { .. }) => {
+                let Some(position) = cursor.position_over(bounds) else {
+                    state.is_focused = false;
+
+                    return event::Status::Ignored;
+                };
+
+                state.is_focused = true;
+
+                match self.hit(state, bounds, cursor) {
+                    Some(Hit::Resize(column)) => {
+                        state.resizing = Some(Resize {
+                            column,
+                            origin: position.x,
+                            width: state.widths[column],
+                        });
+                    }
+                    Some(Hit::Header(column)) => state.pressed_header = Some(column),
+                    Some(Hit::Cell { row, column }) => {
+                        let click = Click::new(position, mouse::Button::Left, state.last_click);
+                        state.last_click = Some(click);
+                        state.pressed_row = Some(row);
+                        state.current = Some((row, column));
+
+                        let cell = self.cell_bounds(state, bounds, row, column);
+
+                        if self.columns[column].is_check()
+                            && self.columns[column]
+                                .checkbox_bounds(cell)
+                                .contains(position)
+                        {
+                            self.toggle(row, column, shell);
+                        } else if let Some(action) =
+                            self.selection_mode
+                                .on_click(self.selection, row, state.modifiers)
+                        {
+                            self.perform(action, shell);
+                        }
+
+                        if matches!(click.kind(), click::Kind::Double) {
+                            self.begin_edit(state, row, column, None, shell);
+                        }
+                    }
+                    None => {}
+                }
+
+                event::Status::Captured
+            }
+            Event::Mouse(mouse::Event::CursorMoved { position })
+            | Event::Touch(touch::Event::FingerMoved { position, .. }) => {
+                let Some(resize) = &state.resizing else {
+                    return event::Status::Ignored;
+                };
+
+                let width = (resize.width + position.x - resize.origin)
+                    .max(self.columns[resize.column].min_width);
+
+                state.resized[resize.column] = Some(width);
+                shell.invalidate_layout();
+
+                event::Status::Captured
+            }
+            Event::Mouse(mouse::Event::ButtonReleased(mouse::Button::Left))
+            | Event::Touch(touch::Event::FingerLifted { .. })
+            | Event::Touch(touch::Event::FingerLost { .. }) => {
+                state.pressed_row = None;
+
+                if state.resizing.take().is_some() {
+                    return event::Status::Captured;
+                }
+
+                let Some(pressed) = state.pressed_header.take() else {
+                    return event::Status::Ignored;
+                };
+
+                if self.hit(state, bounds, cursor) != Some(Hit::Header(pressed))
+                    || !self.columns[pressed].sortable
+                {
+                    return event::Status::Ignored;
+                }
+
+                if let Some(on_sort) = &self.on_sort {
+                    shell.publish(on_sort(pressed, self.sort_direction(pressed)));
+                }
+
+                event::Status::Captured
+            }
+            Event::Keyboard(keyboard::Event::KeyPressed {
+                key,
+                text,
+                modifiers,
+                ..
+            }) if state.is_focused && state.editing.is_none() => {
+                self.on_key(state, &key, text.as_deref(), modifiers, shell)
+            }
+            _ => event::Status::Ignored,
+        }
+    }
+
+    fn draw(
+        &self,
+        tree: &Tree,
+        renderer: &mut Renderer,
+        theme: &Theme,
+        _style: &renderer::Style,
+        layout: Layout<'_>,
+        cursor: Cursor,
+        viewport: &Rectangle,
+    ) {
+        let state = tree.state.downcast_ref::<State>();
+        let bounds = layout.bounds();
+        let Some(clip) = bounds.intersection(viewport) else {
+            return;
+        };
+
+        let style = self.class.resolve(theme, Status::Active);
+        let header = self.header_bounds(bounds);
+        let body = self.body_bounds(bounds);
+        let hovered = self.hit(state, bounds, cursor);
+        let row_height = self.row_height;
+        let visible = state.rows.visible(row_height, self.rows, 0);
+        let editing = state.editing.as_ref().filter(|editing| !editing.is_done);
+
+        renderer.with_layer(clip, |renderer| {
+            renderer.fill_quad(
+                renderer::Quad {
+                    bounds,
+                    border: Border {
+                        color: style.border_color,
+                        width: 1.0,
+                        radius: 4.0.into(),
+                    },
+                    ..renderer::Quad::default()
+                },
+                style.background,
+            );
+
+            let body_clip = body.intersection(&clip).unwrap_or(body);
+            let (frozen, scrolling) = self.regions(state, body);
+
+            renderer.with_layer(body_clip, |renderer| {
+                for row in visible.clone() {
+                    let row_bounds = Rectangle {
+                        y: body.y + row as f32 * row_height - state.rows.offset(),
+                        height: row_height,
+                        ..body
+                    };
+
+                    if self.alternate_rows && row % 2 == 1 {
+                        renderer.fill_quad(
+                            renderer::Quad {
+                                bounds: row_bounds,
+                                ..renderer::Quad::default()
+                            },
+                            style.alternate_background,
+                        );
+                    }
+
+                    let is_selected =
+                        self.selection_mode != SelectionMode::None && self.selection.contains(row);
+                    let is_hovered =
+                        matches!(hovered, Some(Hit::Cell { row: hovered, .. }) if hovered == row);
+
+                    let status = match (is_selected, is_hovered) {
+                        (true, true) => Status::SelectedHovered,
+                        (true, false) => Status::Selected,
+                        (false, true) => Status::Hovered,
+                        (false, false) => Status::Active,
+                    };
+
+                    renderer.fill_quad(
+                        renderer::Quad {
+                            bounds: row_bounds,
+                            ..renderer::Quad::default()
+                        },
+                        self.class.resolve(theme, status).row_background,
+                    );
+                }
+
+                // The scrolling columns first, then the frozen ones in their own
+                // area so nothing scrolls underneath them
+                for (region, columns) in [
+                    (scrolling, self.frozen()..self.columns.len()),
+                    (frozen, 0..self.frozen()),
+                ] {
+                    let Some(region) = region.intersection(&body_clip) else {
+                        continue;
+                    };
+
+                    renderer.with_layer(region, |renderer| {
+                        for row in visible.clone() {
+                            for column in columns.clone() {
+                                let cell = self.cell_bounds(state, bounds, row, column);
+
+                                if !cell.intersects(&region)
+                                    || editing.is_some_and(|editing| {
+                                        (editing.row, editing.column) == (row, column)
+                                    })
+                                {
+                                    continue;
+                                }
+
+                                let is_hovered = hovered == Some(Hit::Cell { row, column });
+
+                                self.draw_cell(
+                                    renderer,
+                                    theme,
+                                    column,
+                                    &(self.cell)(row, column),
+                                    cell,
+                                    style.text_color,
+                                    is_hovered,
+                                );
+                            }
+                        }
+                    });
+                }
+
+                if self.frozen() > 0 {
+                    renderer.fill_quad(
+                        renderer::Quad {
+                            bounds: Rectangle {
+                                x: frozen.x + frozen.width - 1.0,
+                                width: 1.0,
+                                ..body
+                            },
+                            ..renderer::Quad::default()
+                        },
+                        style.divider,
+                    );
+                }
+
+                if let Some((row, column)) = state
+                    .current
+                    .filter(|_| state.is_focused && editing.is_none())
+                    .filter(|(row, column)| *row < self.rows && *column < self.columns.len())
+                {
+                    let cell = self.cell_bounds(state, bounds, row, column);
+                    let region = if column < self.frozen() {
+                        frozen
+                    } else {
+                        scrolling
+                    };
+
+                    if let Some(region) = region.intersection(&body_clip) {
+                        renderer.with_layer(region, |renderer| {
+                            renderer.fill_quad(
+                                renderer::Quad {
+                                    bounds: cell,
+                                    border: Border {
+                                        color: style.focus,
+                                        width: 2.0,
+                                        radius: 4.0.into(),
+                                    },
+                                    ..renderer::Quad::default()
+                                },
+                                Color::TRANSPARENT,
+                            );
+                        });
+                    }
+                }
+            });
+
+            renderer.fill_quad(
+                renderer::Quad {
+                    bounds: header.shrink(1.0),
+                    border: Border {
+                        radius: border::top(3.0),
+                        ..Border::default()
+                    },
+                    ..renderer::Quad::default()
+                },
+                style.header_background,
+            );
+
+            let (frozen, scrolling) = self.regions(state, header);
+
+            for (region, columns) in [
+                (scrolling, self.frozen()..self.columns.len()),
+                (frozen, 0..self.frozen()),
+            ] {
+                let Some(region) = region.intersection(&clip) else {
+                    continue;
+                };
+
+                renderer.with_layer(region, |renderer| {
+                    for column in columns {
+                        let definition = &self.columns[column];
+                        let cell = Rectangle {
+                            x: self.column_x(state, bounds, column),
+                            width: state.widths[column],
+                            ..header
+                        };
+
+                        if !cell.intersects(&region) {
+                            continue;
+                        }
+
+                        let is_hovered = definition.sortable
+                            && self.on_sort.is_some()
+                            && hovered == Some(Hit::Header(column));
+                        let text_color = if is_hovered {
+                            style.header_hovered_text_color
+                        } else {
+                            style.header_text_color
+                        };
+
+                        if is_hovered {
+                            renderer.fill_quad(
+                                renderer::Quad {
+                                    bounds: cell.shrink(1.0),
+                                    border: Border {
+                                        radius: Radius::new(3.0),
+                                        ..Border::default()
+                                    },
+                                    ..renderer::Quad::default()
+                                },
+                                style.header_hovered_background,
+                            );
+                        }
+
+                        let mut label = cell.shrink([0.0, CELL_PADDING]);
+
+                        if let Some((_, direction)) =
+                            self.sort.filter(|(sorted, _)| *sorted == column)
+                        {
+                            let glyph = match direction {
+                                SortDirection::Ascending => FluentIcon::ChevronUp,
+                                SortDirection::Descending => FluentIcon::ChevronDown,
+                            };
+
+                            label.width -= SORT_ICON_SIZE + CELL_PADDING / 2.0;
+
+                            Icon::from(glyph).draw(
+                                renderer,
+                                Rectangle {
+                                    x: cell.x + cell.width - CELL_PADDING - SORT_ICON_SIZE,
+                                    y: cell.center_y() - SORT_ICON_SIZE / 2.0,
+                                    width: SORT_ICON_SIZE,
+                                    height: SORT_ICON_SIZE,
+                                },
+                                text_color,
+                            );
+                        }
+
+                        draw_text(
+                            renderer,
+                            &definition.header,
+                            label,
+                            font::semibold(),
+                            definition.align,
+                            text_color,
+                        );
+
+                        renderer.fill_quad(
+                            renderer::Quad {
+                                bounds: Rectangle {
+                                    x: cell.x + cell.width - 1.0,
+                                    y: cell.y + 8.0,
+                                    width: 1.0,
+                                    height: (cell.height - 16.0).max(0.0),
+                                },
+                                ..renderer::Quad::default()
+                            },
+                            style.divider,
+                        );
+                    }
+                });
+            }
+
+            renderer.fill_quad(
+                renderer::Quad {
+                    bounds: Rectangle {
+                        y: header.y + header.height - 1.0,
+                        height: 1.0,
+                        ..header
+                    },
+                    ..renderer::Quad::default()
+                },
+                style.divider,
+            );
+
+            if let (Some(editing), Some(editor), Some(editor_layout)) = (
+                editing,
+                self.editor.borrow().as_ref(),
+                layout.children().next(),
+            ) {
+                let offset = self.editor_offset(state, editing.column);
+                let region = self.editor_clip(state, bounds, editing.column);
+                let editor_cursor = self.editor_cursor(state, bounds, editing.column, cursor);
+
+                if let Some(region) = region.intersection(&clip) {
+                    renderer.with_layer(region, |renderer| {
+                        renderer.with_translation(offset, |renderer| {
+                            editor.as_widget().draw(
+                                &editing.tree,
+                                renderer,
+                                theme,
+                                &renderer::Style {
+                                    text_color: style.text_color,
+                                },
+                                editor_layout,
+                                editor_cursor,
+                                &(region - offset),
+                            );
+                        });
+                    });
+                }
+            }
+
+            let (vertical, horizontal) = self.scrollbar_bounds(state, bounds);
+
+            state.rows.draw(renderer, theme, vertical, cursor);
+            state.columns.draw(renderer, theme, horizontal, cursor);
+        });
+    }
+
+    fn mouse_interaction(
+        &self,
+        tree: &Tree,
+        layout: Layout<'_>,
+        cursor: Cursor,
+        viewport: &Rectangle,
+        renderer: &Renderer,
+    ) -> mouse::Interaction {
+        let state = tree.state.downcast_ref::<State>();
+        let bounds = layout.bounds();
+
+        if state.resizing.is_some() {
+            return mouse::Interaction::ResizingHorizontally;
+        }
+
+        if let (Some(editing), Some(editor), Some(editor_layout)) = (
+            state.editing.as_ref(),
+            self.editor.borrow().as_ref(),
+            layout.children().next(),
+        ) {
+            let editor_cursor = self.editor_cursor(state, bounds, editing.column, cursor);
+
+            if editor_cursor.is_over(editor_layout.bounds()) {
+                return editor.as_widget().mouse_interaction(
+                    &editing.tree,
+                    editor_layout,
+                    editor_cursor,
+                    viewport,
+                    renderer,
+                );
+            }
+        }
+
+        match self.hit(state, bounds, cursor) {
+            Some(Hit::Resize(_)) => mouse::Interaction::ResizingHorizontally,
+            Some(Hit::Header(column))
+                if self.columns[column].sortable && self.on_sort.is_some() =>
+            {
+                mouse::Interaction::Pointer
+            }
+            Some(Hit::Cell { row, column }) if self.columns[column].is_check() => {
+                let cell = self.cell_bounds(state, bounds, row, column);
+
+                if cursor.is_over(self.columns[column].checkbox_bounds(cell)) {
+                    mouse::Interaction::Pointer
+                } else {
+                    mouse::Interaction::default()
+                }
+            }
+            _ => mouse::Interaction::default(),
+        }
+    }
+
+    fn overlay<'b>(
+        &'b mut self,
+        tree: &'b mut Tree,
+        layout: Layout<'_>,
+        renderer: &Renderer,
+        translation: Vector,
+    ) -> Option<overlay::Element<'b, Message, Theme, Renderer>> {
+        let state = tree.state.downcast_mut::<State>();
+        let offset = self.editor_offset(state, state.editing.as_ref()?.column);
+
+        let Self {
+            editor, on_edit, ..
+        } = self;
+
+        let Editing {
+            row,
+            column,
+            original,
+            draft,
+            tree,
+            is_done,
+            ..
+        } = state.editing.as_mut().filter(|editing| !editing.is_done)?;
+
+        let content = editor.get_mut().as_mut()?.as_widget_mut().overlay(
+            tree,
+            layout.children().next()?,
+            renderer,
+            translation + offset,
+        )?;
+
+        Some(overlay::Element::new(Box::new(EditorOverlay {
+            content,
+            row: *row,
+            column: *column,
+            original,
+            draft,
+            is_done,
+            on_edit: on_edit.as_deref(),
+        })))
+    }
+}
+
+impl<'a, Message> From<DataGrid<'a, Message>> for Element<'a, Message>
+where
+    Message: 'a,
+{
+    fn from(data_grid: DataGrid<'a, Message>) -> Self {
+        Self::new(data_grid)
+    }
+}
+
+// The editor's own overlay, the menu of a choice editor. Picking from it commits
+// the edit straight away.
+struct EditorOverlay<'a, 'b, Message> {
+    content: overlay::Element<'b, Value, Theme, Renderer>,
+    row: usize,
+    column: usize,
+    original: &'b Value,
+    draft: &'b mut Value,
+    is_done: &'b mut bool,
+    on_edit: Option<&'b (dyn Fn(usize, usize, Value) -> Message + 'a)>,
+}
+
+impl<Message> Overlay<Message, Theme, Renderer> for EditorOverlay<'_, '_, Message> {
+    fn layout(&mut self, renderer: &Renderer, bounds: Size) -> LayoutNode {
+        self.content.layout(renderer, bounds)
+    }
+
+    fn draw(
+        &self,
+        renderer: &mut Renderer,
+        theme: &Theme,
+        style: &renderer::Style,
+        layout: Layout<'_>,
+        cursor: Cursor,
+    ) {
+        self.content.draw(renderer, theme, style, layout, cursor);
+    }
+
+    fn on_event(
+        &mut self,
+        event: Event,
+        layout: Layout<'_>,
+        cursor: Cursor,
+        renderer: &Renderer,
+        clipboard: &mut dyn Clipboard,
+        shell: &mut Shell<'_, Message>,
+    ) -> event::Status {
+        let mut messages = Vec::new();
+        let mut local = Shell::new(&mut messages);
+
+        let status = self
+            .content
+            .on_event(event, layout, cursor, renderer, clipboard, &mut local);
+
+        if let Some(request) = local.redraw_request() {
+            shell.request_redraw(request);
+        }
+
+        if local.is_layout_invalid() {
+            shell.invalidate_layout();
+        }
+
+        if local.are_widgets_invalid() {
+            shell.invalidate_widgets();
+        }
+
+        if let Some(value) = messages.pop() {
+            *self.draft = value;
+            *self.is_done = true;
+
+            if let Some(on_edit) = self.on_edit {
+                if self.draft != self.original {
+                    shell.publish(on_edit(self.row, self.column, self.draft.clone()));
+                }
+            }
+
+            shell.invalidate_layout();
+        }
+
+        status
+    }
+
+    fn mouse_interaction(
+        &self,
+        layout: Layout<'_>,
+        cursor: Cursor,
+        viewport: &Rectangle,
+        renderer: &Renderer,
+    ) -> mouse::Interaction {
+        self.content
+            .mouse_interaction(layout, cursor, viewport, renderer)
+    }
+
+    fn is_over(&self, layout: Layout<'_>, renderer: &Renderer, cursor_position: Point) -> bool {
+        self.content.is_over(layout, renderer, cursor_position)
+    }
+}
+
+fn text_size() -> Pixels {
+    Pixels(text::scaled(14.0))
+}
+
+fn line_height() -> LineHeight {
+    LineHeight::Absolute(Pixels(text::scaled(20.0)))
+}
+
+fn measure(content: &str, font: iced::Font) -> f32 {
+    <Renderer as iced::advanced::text::Renderer>::Paragraph::with_text(Text {
+        content,
+        bounds: Size::INFINITY,
+        size: text_size(),
+        line_height: line_height(),
+        font,
+        horizontal_alignment: Horizontal::Left,
+        vertical_alignment: Vertical::Center,
+        shaping: Shaping::Advanced,
+        wrapping: Wrapping::None,
+    })
+    .min_width()
+}
+
+fn draw_text(
+    renderer: &mut Renderer,
+    content: &str,
+    bounds: Rectangle,
+    font: iced::Font,
+    align: Horizontal,
+    colour: Color,
+) {
+    let x = match align {
+        Horizontal::Left => bounds.x,
+        Horizontal::Center => bounds.center_x(),
+        Horizontal::Right => bounds.x + bounds.width,
+    };
+
+    renderer.fill_text(
+        Text {
+            content: content.to_owned(),
+            bounds: bounds.size(),
+            size: text_size(),
+            line_height: line_height(),
+            font,
+            horizontal_alignment: align,
+            vertical_alignment: Vertical::Center,
+            shaping: Shaping::Advanced,
+            wrapping: Wrapping::None,
+        },
+        Point::new(x, bounds.center_y()),
+        colour,
+        bounds,
+    );
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    type Edit = (usize, usize, Value);
+
+    // A name column to type into, a read only id and a number column
+    fn grid(selection: &Selection) -> DataGrid<'_, Edit> {
+        let columns = [
+            Column::new("Name").editor(Editor::Text),
+            Column::new("Id"),
+            Column::new("Price").editor(Editor::Number(0.0..=100.0)),
+        ];
+
+        DataGrid::new(columns, 3, selection, |row, column| match column {
+            0 => Value::Text(format!("Item {row}")),
+            1 => Value::Number(row as f64),
+            _ => Value::Number(10.0),
+        })
+        .on_edit(|row, column, value| (row, column, value))
+    }
+
+    fn state() -> State {
+        State {
+            widths: vec![100.0; 3],
+            ..State::default()
+        }
+    }
+
+    #[test]
+    fn clicking_the_sorted_header_reverses_the_sort() {
+        let selection = Selection::new();
+
+        assert_eq!(grid(&selection).sort_direction(0), SortDirection::Ascending);
+
+        let sorted = grid(&selection).sort(0, SortDirection::Ascending);
+
+        assert_eq!(sorted.sort_direction(0), SortDirection::Descending);
+        assert_eq!(sorted.sort_direction(2), SortDirection::Ascending);
+
+        let reversed = grid(&selection).sort(0, SortDirection::Descending);
+
+        assert_eq!(reversed.sort_direction(0), SortDirection::Ascending);
+    }
+
+    #[test]
+    fn committing_sends_the_changed_draft() {
+        let selection = Selection::new();
+        let grid = grid(&selection);
+        let mut state = state();
+        let mut edits = Vec::new();
+        let mut shell = Shell::new(&mut edits);
+
+        assert!(grid.begin_edit(&mut state, 1, 0, None, &mut shell));
+
+        // Committing what was already there sends nothing
+        grid.commit_edit(&mut state, &mut shell);
+
+        assert!(grid.begin_edit(
+            &mut state,
+            1,
+            0,
+            Some(Value::Text("Pen".into())),
+            &mut shell
+        ));
+        grid.commit_edit(&mut state, &mut shell);
+
+        assert!(state.editing.is_none());
+        assert_eq!(edits, [(1, 0, Value::Text("Pen".into()))]);
+    }
+
+    #[test]
+    fn cancelling_drops_the_draft() {
+        let selection = Selection::new();
+        let grid = grid(&selection);
+        let mut state = state();
+        let mut edits = Vec::new();
+        let mut shell = Shell::new(&mut edits);
+
+        grid.begin_edit(&mut state, 1, 2, Some(Value::Number(5.0)), &mut shell);
+        grid.cancel_edit(&mut state, &mut shell);
+
+        assert!(state.editing.is_none());
+        assert!(edits.is_empty());
+    }
+
+    #[test]
+    fn read_only_cells_have_no_editor() {
+        let selection = Selection::new();
+        let mut state = state();
+        let mut edits = Vec::new();
+        let mut shell = Shell::new(&mut edits);
+
+        assert!(!grid(&selection).begin_edit(&mut state, 0, 1, None, &mut shell));
+
+        let without_on_edit = DataGrid::<Edit>::new(
+            [Column::new("Name").editor(Editor::Text)],
+            1,
+            &selection,
+            |_, _| Value::Text(String::new()),
+        );
+
+        assert!(!without_on_edit.begin_edit(&mut state, 0, 0, None, &mut shell));
+        assert!(state.editing.is_none());
+    }
+
+    #[test]
+    fn tab_commits_and_edits_the_next_editable_cell() {
+        let selection = Selection::new();
+        let grid = grid(&selection);
+        let mut state = state();
+        let mut edits = Vec::new();
+        let mut shell = Shell::new(&mut edits);
+
+        grid.begin_edit(&mut state, 0, 2, Some(Value::Number(5.0)), &mut shell);
+        grid.commit_edit(&mut state, &mut shell);
+        // Wraps onto the next row, past the read only id
+        grid.edit_next(&mut state, 0, 2, true, &mut shell);
+
+        let editing = state.editing.as_ref().expect("Editing a cell");
+
+        assert_eq!((editing.row, editing.column), (1, 0));
+
+        grid.edit_next(&mut state, 1, 0, false, &mut shell);
+
+        let editing = state.editing.as_ref().expect("Editing a cell");
+
+        assert_eq!((editing.row, editing.column), (0, 2));
+        assert_eq!(edits, [(0, 2, Value::Number(5.0))]);
+    }
+}
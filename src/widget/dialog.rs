@@ -1,83 +1,551 @@
 use crate::{
-    style,
-    widget::{self, button, text, Column, Container, Element, Row},
+    density,
+    theme::Theme,
+    widget::{button, scrollable, text, Column, Container, Element, Row},
 };
 
 use iced::{
-    alignment::Horizontal,
+    advanced::{
+        layout::{Limits, Node},
+        overlay,
+        renderer::{self, Renderer as _},
+        widget::{
+            operation::{focusable, Outcome},
+            Operation, Tree,
+        },
+        Clipboard, Layout, Shell, Widget,
+    },
+    alignment::{Horizontal, Vertical},
     border::Radius,
-    widget::{container, vertical_space},
-    Border, Length,
+    event,
+    keyboard::{self, key::Named},
+    mouse::{self, Cursor},
+    widget::{container, horizontal_space, stack},
+    Border, Event, Length, Padding, Point, Rectangle, Size, Vector,
 };
 
-pub fn content<'a, Message>(
-    title: Option<&'a str>,
-    content: impl Into<Element<'a, Message>>,
-    primary_button: Option<Button<Message>>,
-    secondary_button: Option<Button<Message>>,
-    close_button: Button<Message>,
-) -> Container<'a, Message>
+type Renderer = iced::Renderer;
+
+// ContentDialog sizes from
+// https://github.com/microsoft/microsoft-ui-xaml/blob/main/src/controls/dev/CommonStyles/ContentDialog_themeresources.xaml
+const MIN_WIDTH: f32 = 320.0;
+const MAX_WIDTH: f32 = 548.0;
+// Closest a dialog gets to the edges of the window
+const MARGIN: f32 = 24.0;
+
+// The button that gets the accent colour and is pressed by Enter
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub enum DefaultButton {
+    #[default]
+    None,
+    Primary,
+    Secondary,
+    Close,
+}
+
+pub struct ContentDialog<'a, Message> {
+    title: Option<String>,
+    content: Element<'a, Message>,
+    primary_button: Option<(String, Message)>,
+    secondary_button: Option<(String, Message)>,
+    close_button: Option<(String, Message)>,
+    default_button: DefaultButton,
+}
+
+impl<'a, Message> ContentDialog<'a, Message>
 where
     Message: 'a + Clone,
 {
-    let contents = Container::new(
-        Column::new()
-            .push_maybe(title.map(text::subtitle1))
-            .push(vertical_space().height(12))
-            .push(content),
-    )
-    .style(|theme| container::Style {
-        background: Some(theme.palette().layer_fill_color_alt.into()),
-        border: Border {
-            radius: Radius::new(12).bottom(0),
-            ..Border::default()
-        },
-        ..container::Style::default()
-    })
-    .width(Length::Fill)
-    .padding(24);
-
-    let buttons = Container::new(
-        Row::new()
-            .push_maybe(
-                primary_button.map(move |button| button.view().style(style::button::primary)),
+    pub fn new(content: impl Into<Element<'a, Message>>) -> Self {
+        Self {
+            title: None,
+            content: content.into(),
+            primary_button: None,
+            secondary_button: None,
+            close_button: None,
+            default_button: DefaultButton::default(),
+        }
+    }
+
+    pub fn title(mut self, title: impl Into<String>) -> Self {
+        self.title = Some(title.into());
+        self
+    }
+
+    pub fn primary_button(mut self, label: impl Into<String>, on_press: Message) -> Self {
+        self.primary_button = Some((label.into(), on_press));
+        self
+    }
+
+    pub fn secondary_button(mut self, label: impl Into<String>, on_press: Message) -> Self {
+        self.secondary_button = Some((label.into(), on_press));
+        self
+    }
+
+    // Also sent when Esc is pressed
+    pub fn close_button(mut self, label: impl Into<String>, on_press: Message) -> Self {
+        self.close_button = Some((label.into(), on_press));
+        self
+    }
+
+    pub fn default_button(mut self, default_button: DefaultButton) -> Self {
+        self.default_button = default_button;
+        self
+    }
+
+    pub fn map<B>(self, f: impl Fn(Message) -> B + Clone + 'a) -> ContentDialog<'a, B>
+    where
+        B: 'a,
+    {
+        let map_button = |button: Option<(String, Message)>| {
+            button.map(|(label, on_press)| (label, f(on_press)))
+        };
+
+        ContentDialog {
+            title: self.title,
+            content: self.content.map(f.clone()),
+            primary_button: map_button(self.primary_button),
+            secondary_button: map_button(self.secondary_button),
+            close_button: map_button(self.close_button),
+            default_button: self.default_button,
+        }
+    }
+
+    fn on_enter(&self) -> Option<Message> {
+        let button = match self.default_button {
+            DefaultButton::None => None,
+            DefaultButton::Primary => self.primary_button.as_ref(),
+            DefaultButton::Secondary => self.secondary_button.as_ref(),
+            DefaultButton::Close => self.close_button.as_ref(),
+        };
+
+        button.map(|(_, on_press)| on_press.clone())
+    }
+
+    fn on_escape(&self) -> Option<Message> {
+        self.close_button
+            .as_ref()
+            .map(|(_, on_press)| on_press.clone())
+    }
+
+    fn view(self) -> Element<'a, Message> {
+        let metrics = density::metrics();
+        let padding = metrics.dialog_padding;
+        let buttons_height = metrics.control_height + 2.0 * padding;
+
+        let button = |button: Option<(String, Message)>, is_default: bool| {
+            button.map(|(label, on_press)| {
+                button::standard(text::body1(label).align_x(Horizontal::Center))
+                    .on_press(on_press)
+                    .width(Length::Fill)
+                    .class(if is_default { "primary" } else { "secondary" })
+            })
+        };
+
+        let buttons = Row::new()
+            .push_maybe(button(
+                self.primary_button,
+                self.default_button == DefaultButton::Primary,
+            ))
+            .push_maybe(button(
+                self.secondary_button,
+                self.default_button == DefaultButton::Secondary,
+            ))
+            .push_maybe(button(
+                self.close_button,
+                self.default_button == DefaultButton::Close,
+            ))
+            .spacing(8);
+
+        // The title stays put while long content scrolls beneath it
+        let body = Container::new(
+            Column::new()
+                .push_maybe(self.title.map(text::subtitle1))
+                .push(scrollable::standard(self.content))
+                // Holds the dialog at its minimum width when the content is narrow
+                .push(horizontal_space().width(MIN_WIDTH - 2.0 * padding))
+                .spacing(12),
+        )
+        .style(|theme| container::Style {
+            background: Some(theme.palette().layer_fill_color_alt.into()),
+            border: Border {
+                radius: Radius::new(8).bottom(0),
+                ..Border::default()
+            },
+            ..container::Style::default()
+        })
+        .padding(padding);
+
+        // The buttons are laid over space kept free below the body, so the body
+        // can only grow into what's left of the window after them
+        stack![
+            Container::new(body)
+                .padding(Padding::ZERO.bottom(buttons_height))
+                .max_width(MAX_WIDTH)
+                .class("dialog"),
+            Container::new(
+                Container::new(buttons)
+                    .height(buttons_height)
+                    .padding(padding)
+                    .align_y(Vertical::Center),
             )
-            .push_maybe(secondary_button.map(Button::view))
-            .push(close_button.view())
-            .spacing(8),
-    )
-    .height(80)
-    .padding(24);
-
-    Container::new(
-        Column::new()
-            .push(contents)
-            .push(vertical_space())
-            .push(buttons),
-    )
-    .width(328)
-    .height(220)
-    .style(style::container::dialog)
-    // Clipping not working
-    // https://github.com/iced-rs/iced/issues/2093
-    .clip(true)
+            .height(Length::Fill)
+            .align_bottom(Length::Fill),
+        ]
+        .into()
+    }
 }
 
-pub struct Button<Message>
+struct Layer<'a, Message> {
+    element: Element<'a, Message>,
+    on_enter: Option<Message>,
+    on_escape: Option<Message>,
+}
+
+// Shows dialogs over `base` on a smoke layer. Only the top dialog takes input:
+// Enter presses its default button, Esc its close button and Tab moves focus
+// without leaving it.
+pub struct Modal<'a, Message> {
+    base: Element<'a, Message>,
+    dialogs: Vec<Layer<'a, Message>>,
+}
+
+impl<'a, Message> Modal<'a, Message>
 where
-    Message: Clone,
+    Message: 'a + Clone,
 {
-    pub text: String,
-    pub on_press: Message,
+    pub fn new(base: impl Into<Element<'a, Message>>) -> Self {
+        Self {
+            base: base.into(),
+            dialogs: Vec::new(),
+        }
+    }
+
+    // Each dialog is stacked on top of the ones before it
+    pub fn push(mut self, dialog: ContentDialog<'a, Message>) -> Self {
+        self.dialogs.push(Layer {
+            on_enter: dialog.on_enter(),
+            on_escape: dialog.on_escape(),
+            element: dialog.view(),
+        });
+        self
+    }
+
+    pub fn extend(self, dialogs: impl IntoIterator<Item = ContentDialog<'a, Message>>) -> Self {
+        dialogs.into_iter().fold(self, Self::push)
+    }
+
+    fn elements(&self) -> impl Iterator<Item = &Element<'a, Message>> {
+        std::iter::once(&self.base).chain(self.dialogs.iter().map(|dialog| &dialog.element))
+    }
 }
 
-impl<'a, Message> Button<Message>
+impl<'a, Message> Widget<Message, Theme, Renderer> for Modal<'a, Message>
 where
-    Message: Clone,
+    Message: 'a + Clone,
 {
-    fn view(self) -> widget::Button<'a, Message> {
-        button::standard(text::body1(self.text).align_x(Horizontal::Center))
-            .on_press(self.on_press.clone())
-            .width(Length::Fill)
+    fn size(&self) -> Size<Length> {
+        self.base.as_widget().size()
+    }
+
+    fn children(&self) -> Vec<Tree> {
+        self.elements().map(Tree::new).collect()
+    }
+
+    fn diff(&self, tree: &mut Tree) {
+        tree.diff_children(&self.elements().collect::<Vec<_>>());
+    }
+
+    fn layout(&self, tree: &mut Tree, renderer: &Renderer, limits: &Limits) -> Node {
+        let base = self
+            .base
+            .as_widget()
+            .layout(&mut tree.children[0], renderer, limits);
+        let size = base.size();
+
+        let limits = Limits::new(
+            Size::ZERO,
+            Size::new(
+                (size.width - 2.0 * MARGIN).max(0.0),
+                (size.height - 2.0 * MARGIN).max(0.0),
+            ),
+        );
+
+        let dialogs = self
+            .dialogs
+            .iter()
+            .zip(&mut tree.children[1..])
+            .map(|(dialog, tree)| {
+                let node = dialog.element.as_widget().layout(tree, renderer, &limits);
+                let dialog_size = node.size();
+
+                node.move_to(Point::new(
+                    (size.width - dialog_size.width) / 2.0,
+                    (size.height - dialog_size.height) / 2.0,
+                ))
+            });
+
+        Node::with_children(size, std::iter::once(base).chain(dialogs).collect())
+    }
+
+    fn draw(
+        &self,
+        tree: &Tree,
+        renderer: &mut Renderer,
+        theme: &Theme,
+        style: &renderer::Style,
+        layout: Layout<'_>,
+        cursor: Cursor,
+        viewport: &Rectangle,
+    ) {
+        let bounds = layout.bounds();
+        let top = self.dialogs.len();
+
+        // Nothing under a dialog reacts to the mouse
+        let cursor_at = |index| {
+            if index == top {
+                cursor
+            } else {
+                Cursor::Unavailable
+            }
+        };
+
+        for (index, ((element, tree), layout)) in self
+            .elements()
+            .zip(&tree.children)
+            .zip(layout.children())
+            .enumerate()
+        {
+            if index == 0 {
+                element.as_widget().draw(
+                    tree,
+                    renderer,
+                    theme,
+                    style,
+                    layout,
+                    cursor_at(index),
+                    viewport,
+                );
+
+                continue;
+            }
+
+            renderer.with_layer(bounds, |renderer| {
+                renderer.fill_quad(
+                    renderer::Quad {
+                        bounds,
+                        ..renderer::Quad::default()
+                    },
+                    theme.palette().smoke_fill_color_default,
+                );
+
+                element.as_widget().draw(
+                    tree,
+                    renderer,
+                    theme,
+                    style,
+                    layout,
+                    cursor_at(index),
+                    viewport,
+                );
+            });
+        }
+    }
+
+    fn operate(
+        &self,
+        tree: &mut Tree,
+        layout: Layout<'_>,
+        renderer: &Renderer,
+        operation: &mut dyn Operation,
+    ) {
+        // Operations like focusing only reach the top dialog while one is open
+        let index = self.dialogs.len();
+
+        self.elements().last().unwrap().as_widget().operate(
+            &mut tree.children[index],
+            layout.children().nth(index).unwrap(),
+            renderer,
+            operation,
+        );
+    }
+
+    fn on_event(
+        &mut self,
+        tree: &mut Tree,
+        event: Event,
+        layout: Layout<'_>,
+        cursor: Cursor,
+        renderer: &Renderer,
+        clipboard: &mut dyn Clipboard,
+        shell: &mut Shell<'_, Message>,
+        viewport: &Rectangle,
+    ) -> event::Status {
+        let Some((dialog, below)) = self.dialogs.split_last_mut() else {
+            return self.base.as_widget_mut().on_event(
+                &mut tree.children[0],
+                event,
+                layout,
+                cursor,
+                renderer,
+                clipboard,
+                shell,
+                viewport,
+            );
+        };
+
+        let mut layouts = layout.children();
+        let top = tree.children.len() - 1;
+        let (trees, dialog_tree) = tree.children.split_at_mut(top);
+
+        // Everything keeps animating and redrawing underneath
+        if let Event::Window(_) = event {
+            for ((element, tree), layout) in std::iter::once(&mut self.base)
+                .chain(below.iter_mut().map(|dialog| &mut dialog.element))
+                .zip(trees.iter_mut())
+                .zip(&mut layouts)
+            {
+                let _ = element.as_widget_mut().on_event(
+                    tree,
+                    event.clone(),
+                    layout,
+                    Cursor::Unavailable,
+                    renderer,
+                    clipboard,
+                    shell,
+                    viewport,
+                );
+            }
+        }
+
+        let dialog_layout = layout.children().last().unwrap();
+
+        let status = dialog.element.as_widget_mut().on_event(
+            &mut dialog_tree[0],
+            event.clone(),
+            dialog_layout,
+            cursor,
+            renderer,
+            clipboard,
+            shell,
+            viewport,
+        );
+
+        if status == event::Status::Captured {
+            return status;
+        }
+
+        match event {
+            Event::Keyboard(keyboard::Event::KeyPressed {
+                key: keyboard::Key::Named(key),
+                modifiers,
+                ..
+            }) => {
+                let message = match key {
+                    Named::Enter => dialog.on_enter.clone(),
+                    Named::Escape => dialog.on_escape.clone(),
+                    Named::Tab => {
+                        focus(
+                            &dialog.element,
+                            &mut dialog_tree[0],
+                            dialog_layout,
+                            renderer,
+                            modifiers.shift(),
+                        );
+
+                        return event::Status::Captured;
+                    }
+                    _ => None,
+                };
+
+                match message {
+                    Some(message) => {
+                        shell.publish(message);
+                        event::Status::Captured
+                    }
+                    None => event::Status::Ignored,
+                }
+            }
+            // The smoke layer swallows clicks meant for whatever is under it
+            Event::Mouse(_) | Event::Touch(_) => event::Status::Captured,
+            _ => event::Status::Ignored,
+        }
+    }
+
+    fn mouse_interaction(
+        &self,
+        tree: &Tree,
+        layout: Layout<'_>,
+        cursor: Cursor,
+        viewport: &Rectangle,
+        renderer: &Renderer,
+    ) -> mouse::Interaction {
+        let index = self.dialogs.len();
+        let element = self.elements().last().unwrap();
+
+        element.as_widget().mouse_interaction(
+            &tree.children[index],
+            layout.children().nth(index).unwrap(),
+            cursor,
+            viewport,
+            renderer,
+        )
+    }
+
+    fn overlay<'b>(
+        &'b mut self,
+        tree: &'b mut Tree,
+        layout: Layout<'_>,
+        renderer: &Renderer,
+        translation: Vector,
+    ) -> Option<overlay::Element<'b, Message, Theme, Renderer>> {
+        let index = self.dialogs.len();
+        let element = match self.dialogs.last_mut() {
+            Some(dialog) => &mut dialog.element,
+            None => &mut self.base,
+        };
+
+        element.as_widget_mut().overlay(
+            &mut tree.children[index],
+            layout.children().nth(index).unwrap(),
+            renderer,
+            translation,
+        )
+    }
+}
+
+impl<'a, Message> From<Modal<'a, Message>> for Element<'a, Message>
+where
+    Message: 'a + Clone,
+{
+    fn from(modal: Modal<'a, Message>) -> Self {
+        Self::new(modal)
+    }
+}
+
+// Runs a focus operation to completion on one element only, so focus can't move
+// out of it
+fn focus<Message>(
+    element: &Element<'_, Message>,
+    tree: &mut Tree,
+    layout: Layout<'_>,
+    renderer: &Renderer,
+    backwards: bool,
+) {
+    let mut operation: Box<dyn Operation> = if backwards {
+        Box::new(focusable::focus_previous())
+    } else {
+        Box::new(focusable::focus_next())
+    };
+
+    loop {
+        element
+            .as_widget()
+            .operate(tree, layout, renderer, operation.as_mut());
+
+        match operation.finish() {
+            Outcome::Chain(next) => operation = next,
+            _ => break,
+        }
     }
 }
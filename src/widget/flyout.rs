@@ -0,0 +1,688 @@
+use crate::{theme::Theme, widget::Element};
+
+use iced::{
+    advanced::{
+        graphics::geometry::Renderer as _,
+        layout::{self, Limits, Node},
+        overlay,
+        renderer::{self, Renderer as _},
+        widget::{Operation, Tree},
+        Clipboard, Layout, Shell, Widget,
+    },
+    event,
+    keyboard::{self, key::Named},
+    mouse::{self, Cursor},
+    touch,
+    widget::{
+        canvas::{Frame, Path, Stroke},
+        container::{self, Catalog},
+    },
+    Background, Color, Event, Length, Padding, Point, Rectangle, Size, Vector,
+};
+
+use std::fmt;
+
+type Renderer = iced::Renderer;
+
+// Closest a flyout gets to the edges of the window
+const MARGIN: f32 = 8.0;
+// Space between the target and the flyout, the tail sits in it when drawn
+const GAP: f32 = 4.0;
+const TAIL_WIDTH: f32 = 16.0;
+const TAIL_HEIGHT: f32 = 8.0;
+
+// Where the flyout opens relative to its target. The side is flipped when the
+// flyout wouldn't fit in the window and `Auto` takes the first side with room.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub enum Placement {
+    Top,
+    #[default]
+    Bottom,
+    Left,
+    Right,
+    Auto,
+}
+
+impl Placement {
+    pub const ALL: [Placement; 5] = [
+        Placement::Top,
+        Placement::Bottom,
+        Placement::Left,
+        Placement::Right,
+        Placement::Auto,
+    ];
+
+    fn opposite(self) -> Self {
+        match self {
+            Placement::Top => Placement::Bottom,
+            Placement::Bottom => Placement::Top,
+            Placement::Left => Placement::Right,
+            Placement::Right => Placement::Left,
+            Placement::Auto => Placement::Auto,
+        }
+    }
+}
+
+impl fmt::Display for Placement {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(match self {
+            Placement::Top => "Top",
+            Placement::Bottom => "Bottom",
+            Placement::Left => "Left",
+            Placement::Right => "Right",
+            Placement::Auto => "Auto",
+        })
+    }
+}
+
+// Shows `content` in a popup anchored to `target` while open. With `on_dismiss`
+// set the popup light-dismisses, clicking outside of it or pressing Esc sends the
+// message.
+pub struct Flyout<'a, Message> {
+    target: Element<'a, Message>,
+    content: Element<'a, Message>,
+    is_open: bool,
+    on_dismiss: Option<Message>,
+    placement: Placement,
+    has_tail: bool,
+    width: Length,
+    padding: Padding,
+    class: <Theme as Catalog>::Class<'a>,
+}
+
+impl<'a, Message> Flyout<'a, Message> {
+    pub fn new(
+        target: impl Into<Element<'a, Message>>,
+        content: impl Into<Element<'a, Message>>,
+        is_open: bool,
+    ) -> Self {
+        Self {
+            target: target.into(),
+            content: content.into(),
+            is_open,
+            on_dismiss: None,
+            placement: Placement::default(),
+            has_tail: false,
+            width: Length::Shrink,
+            padding: Padding::new(16.0),
+            class: "overlay".into(),
+        }
+    }
+
+    pub fn on_dismiss(mut self, on_dismiss: Message) -> Self {
+        self.on_dismiss = Some(on_dismiss);
+        self
+    }
+
+    pub fn on_dismiss_maybe(mut self, on_dismiss: Option<Message>) -> Self {
+        self.on_dismiss = on_dismiss;
+        self
+    }
+
+    pub fn placement(mut self, placement: Placement) -> Self {
+        self.placement = placement;
+        self
+    }
+
+    // Draws a tail pointing from the flyout at the middle of the target
+    pub fn tail(mut self, has_tail: bool) -> Self {
+        self.has_tail = has_tail;
+        self
+    }
+
+    pub fn width(mut self, width: impl Into<Length>) -> Self {
+        self.width = width.into();
+        self
+    }
+
+    pub fn padding(mut self, padding: impl Into<Padding>) -> Self {
+        self.padding = padding.into();
+        self
+    }
+
+    pub fn class(mut self, class: impl Into<<Theme as Catalog>::Class<'a>>) -> Self {
+        self.class = class.into();
+        self
+    }
+}
+
+impl<'a, Message> Widget<Message, Theme, Renderer> for Flyout<'a, Message>
+where
+    Message: Clone,
+{
+    fn size(&self) -> Size<Length> {
+        self.target.as_widget().size()
+    }
+
+    fn layout(&self, tree: &mut Tree, renderer: &Renderer, limits: &Limits) -> Node {
+        self.target
+            .as_widget()
+            .layout(&mut tree.children[0], renderer, limits)
+    }
+
+    fn draw(
+        &self,
+        tree: &Tree,
+        renderer: &mut Renderer,
+        theme: &Theme,
+        style: &renderer::Style,
+        layout: Layout<'_>,
+        cursor: Cursor,
+        viewport: &Rectangle,
+    ) {
+        self.target.as_widget().draw(
+            &tree.children[0],
+            renderer,
+            theme,
+            style,
+            layout,
+            cursor,
+            viewport,
+        );
+    }
+
+    fn children(&self) -> Vec<Tree> {
+        vec![Tree::new(&self.target), Tree::new(&self.content)]
+    }
+
+    fn diff(&self, tree: &mut Tree) {
+        tree.diff_children(&[&self.target, &self.content]);
+    }
+
+    fn operate(
+        &self,
+        tree: &mut Tree,
+        layout: Layout<'_>,
+        renderer: &Renderer,
+        operation: &mut dyn Operation,
+    ) {
+        self.target
+            .as_widget()
+            .operate(&mut tree.children[0], layout, renderer, operation);
+    }
+
+    fn on_event(
+        &mut self,
+        tree: &mut Tree,
+        event: Event,
+        layout: Layout<'_>,
+        cursor: Cursor,
+        renderer: &Renderer,
+        clipboard: &mut dyn Clipboard,
+        shell: &mut Shell<'_, Message>,
+        viewport: &Rectangle,
+    ) -> event::Status {
+        self.target.as_widget_mut().on_event(
+            &mut tree.children[0],
+            event,
+            layout,
+            cursor,
+            renderer,
+            clipboard,
+            shell,
+            viewport,
+        )
+    }
+
+    fn mouse_interaction(
+        &self,
+        tree: &Tree,
+        layout: Layout<'_>,
+        cursor: Cursor,
+        viewport: &Rectangle,
+        renderer: &Renderer,
+    ) -> mouse::Interaction {
+        self.target.as_widget().mouse_interaction(
+            &tree.children[0],
+            layout,
+            cursor,
+            viewport,
+            renderer,
+        )
+    }
+
+    fn overlay<'b>(
+        &'b mut self,
+        tree: &'b mut Tree,
+        layout: Layout<'_>,
+        renderer: &Renderer,
+        translation: Vector,
+    ) -> Option<overlay::Element<'b, Message, Theme, Renderer>> {
+        let [target, content] = &mut tree.children[..] else {
+            return None;
+        };
+
+        if !self.is_open {
+            return self
+                .target
+                .as_widget_mut()
+                .overlay(target, layout, renderer, translation);
+        }
+
+        Some(overlay::Element::new(Box::new(Overlay {
+            tree: content,
+            content: &mut self.content,
+            on_dismiss: &self.on_dismiss,
+            placement: self.placement,
+            has_tail: self.has_tail,
+            width: self.width,
+            padding: self.padding,
+            class: &self.class,
+            target: layout.bounds() + translation,
+        })))
+    }
+}
+
+impl<'a, Message> From<Flyout<'a, Message>> for Element<'a, Message>
+where
+    Message: 'a + Clone,
+{
+    fn from(flyout: Flyout<'a, Message>) -> Self {
+        Self::new(flyout)
+    }
+}
+
+struct Overlay<'a, 'b, Message> {
+    tree: &'b mut Tree,
+    content: &'b mut Element<'a, Message>,
+    on_dismiss: &'b Option<Message>,
+    placement: Placement,
+    has_tail: bool,
+    width: Length,
+    padding: Padding,
+    class: &'b <Theme as Catalog>::Class<'a>,
+    target: Rectangle,
+}
+
+impl<Message> Overlay<'_, '_, Message> {
+    fn gap(&self) -> f32 {
+        if self.has_tail {
+            GAP + TAIL_HEIGHT
+        } else {
+            GAP
+        }
+    }
+
+    fn fits(&self, placement: Placement, size: Size, window: Size) -> bool {
+        let target = self.target;
+        let gap = self.gap();
+
+        match placement {
+            Placement::Top => target.y - gap - size.height >= MARGIN,
+            Placement::Bottom => {
+                target.y + target.height + gap + size.height <= window.height - MARGIN
+            }
+            Placement::Left => target.x - gap - size.width >= MARGIN,
+            Placement::Right => target.x + target.width + gap + size.width <= window.width - MARGIN,
+            Placement::Auto => false,
+        }
+    }
+
+    // The side the flyout ends up on after avoiding the edges of the window
+    fn resolve(&self, size: Size, window: Size) -> Placement {
+        let candidates = match self.placement {
+            Placement::Auto => [
+                Placement::Bottom,
+                Placement::Top,
+                Placement::Right,
+                Placement::Left,
+            ],
+            placement => [placement, placement.opposite(), placement, placement],
+        };
+
+        candidates
+            .into_iter()
+            .find(|&placement| self.fits(placement, size, window))
+            .unwrap_or(candidates[0])
+    }
+}
+
+impl<Message> overlay::Overlay<Message, Theme, Renderer> for Overlay<'_, '_, Message>
+where
+    Message: Clone,
+{
+    fn layout(&mut self, renderer: &Renderer, bounds: Size) -> Node {
+        let limits = Limits::new(
+            Size::ZERO,
+            Size::new(
+                (bounds.width - 2.0 * MARGIN).max(0.0),
+                (bounds.height - 2.0 * MARGIN).max(0.0),
+            ),
+        );
+
+        let node = layout::padded(
+            &limits,
+            self.width,
+            Length::Shrink,
+            self.padding,
+            |limits| self.content.as_widget().layout(self.tree, renderer, limits),
+        );
+
+        let size = node.size();
+        let target = self.target;
+        let gap = self.gap();
+
+        let centred_x = target.center_x() - size.width / 2.0;
+        let centred_y = target.center_y() - size.height / 2.0;
+
+        let position = match self.resolve(size, bounds) {
+            Placement::Top => Point::new(centred_x, target.y - gap - size.height),
+            Placement::Left => Point::new(target.x - gap - size.width, centred_y),
+            Placement::Right => Point::new(target.x + target.width + gap, centred_y),
+            Placement::Bottom | Placement::Auto => {
+                Point::new(centred_x, target.y + target.height + gap)
+            }
+        };
+
+        // Slide along the window's edges rather than going past them
+        node.move_to(Point::new(
+            position
+                .x
+                .min(bounds.width - size.width - MARGIN)
+                .max(MARGIN),
+            position
+                .y
+                .min(bounds.height - size.height - MARGIN)
+                .max(MARGIN),
+        ))
+    }
+
+    fn draw(
+        &self,
+        renderer: &mut Renderer,
+        theme: &Theme,
+        style: &renderer::Style,
+        layout: Layout<'_>,
+        cursor: Cursor,
+    ) {
+        let bounds = layout.bounds();
+        let appearance = theme.style(self.class);
+        let background = match appearance.background {
+            Some(Background::Color(colour)) => colour,
+            _ => Color::TRANSPARENT,
+        };
+
+        renderer.fill_quad(
+            renderer::Quad {
+                bounds,
+                border: appearance.border,
+                shadow: appearance.shadow,
+            },
+            background,
+        );
+
+        if self.has_tail {
+            draw_tail(renderer, bounds, self.target, &appearance, background);
+        }
+
+        self.content.as_widget().draw(
+            self.tree,
+            renderer,
+            theme,
+            &renderer::Style {
+                text_color: appearance.text_color.unwrap_or(style.text_color),
+            },
+            layout.children().next().unwrap(),
+            cursor,
+            &bounds,
+        );
+    }
+
+    fn on_event(
+        &mut self,
+        event: Event,
+        layout: Layout<'_>,
+        cursor: Cursor,
+        renderer: &Renderer,
+        clipboard: &mut dyn Clipboard,
+        shell: &mut Shell<'_, Message>,
+    ) -> event::Status {
+        if let Some(on_dismiss) = self.on_dismiss {
+            match &event {
+                Event::Keyboard(keyboard::Event::KeyPressed {
+                    key: keyboard::Key::Named(Named::Escape),
+                    ..
+                }) => {
+                    shell.publish(on_dismiss.clone());
+
+                    return event::Status::Captured;
+                }
+                // Presses on the target are left to it so that it can toggle the flyout
+                Event::Mouse(mouse::Event::ButtonPressed(
+                    mouse::Button::Left | mouse::Button::Right,
+                ))
+                | Event::Touch(touch::Event::FingerPressed { .. })
+                    if !cursor.is_over(layout.bounds()) && !cursor.is_over(self.target) =>
+                {
+                    shell.publish(on_dismiss.clone());
+                }
+                _ => {}
+            }
+        }
+
+        self.content.as_widget_mut().on_event(
+            self.tree,
+            event,
+            layout.children().next().unwrap(),
+            cursor,
+            renderer,
+            clipboard,
+            shell,
+            &layout.bounds(),
+        )
+    }
+
+    fn mouse_interaction(
+        &self,
+        layout: Layout<'_>,
+        cursor: Cursor,
+        viewport: &Rectangle,
+        renderer: &Renderer,
+    ) -> mouse::Interaction {
+        self.content.as_widget().mouse_interaction(
+            self.tree,
+            layout.children().next().unwrap(),
+            cursor,
+            viewport,
+            renderer,
+        )
+    }
+
+    fn overlay<'c>(
+        &'c mut self,
+        layout: Layout<'_>,
+        renderer: &Renderer,
+    ) -> Option<overlay::Element<'c, Message, Theme, Renderer>> {
+        self.content.as_widget_mut().overlay(
+            self.tree,
+            layout.children().next().unwrap(),
+            renderer,
+            Vector::ZERO,
+        )
+    }
+}
+
+// A triangle on the side of the flyout facing the target, lined up with the middle
+// of the target but kept clear of the flyout's rounded corners
+fn draw_tail(
+    renderer: &mut Renderer,
+    bounds: Rectangle,
+    target: Rectangle,
+    appearance: &container::Style,
+    background: Color,
+) {
+    let radius = appearance.border.radius.top_left;
+    let inset = radius + TAIL_WIDTH / 2.0;
+    let half = TAIL_WIDTH / 2.0;
+    // The base overlaps the flyout's border so the tail looks joined to it
+    let overlap = appearance.border.width;
+
+    let along_x = target.center_x().clamp(
+        bounds.x + inset,
+        (bounds.x + bounds.width - inset).max(bounds.x + inset),
+    );
+    let along_y = target.center_y().clamp(
+        bounds.y + inset,
+        (bounds.y + bounds.height - inset).max(bounds.y + inset),
+    );
+
+    let (apex, start, end) = if bounds.y >= target.y + target.height {
+        let y = bounds.y + overlap;
+        (
+            Point::new(along_x, bounds.y - TAIL_HEIGHT),
+            Point::new(along_x - half, y),
+            Point::new(along_x + half, y),
+        )
+    } else if bounds.y + bounds.height <= target.y {
+        let y = bounds.y + bounds.height - overlap;
+        (
+            Point::new(along_x, y + overlap + TAIL_HEIGHT),
+            Point::new(along_x - half, y),
+            Point::new(along_x + half, y),
+        )
+    } else if bounds.x + bounds.width <= target.x {
+        let x = bounds.x + bounds.width - overlap;
+        (
+            Point::new(x + overlap + TAIL_HEIGHT, along_y),
+            Point::new(x, along_y - half),
+            Point::new(x, along_y + half),
+        )
+    } else if bounds.x >= target.x + target.width {
+        let x = bounds.x + overlap;
+        (
+            Point::new(bounds.x - TAIL_HEIGHT, along_y),
+            Point::new(x, along_y - half),
+            Point::new(x, along_y + half),
+        )
+    } else {
+        // Squeezed over the target by the window's edges, there's nowhere to point
+        return;
+    };
+
+    let mut frame = Frame::new(
+        renderer,
+        Size::new(
+            bounds.x + bounds.width + TAIL_HEIGHT,
+            bounds.y + bounds.height + TAIL_HEIGHT,
+        ),
+    );
+
+    frame.fill(
+        &Path::new(|builder| {
+            builder.move_to(start);
+            builder.line_to(apex);
+            builder.line_to(end);
+            builder.close();
+        }),
+        background,
+    );
+
+    if appearance.border.width > 0.0 {
+        frame.stroke(
+            &Path::new(|builder| {
+                builder.move_to(start);
+                builder.line_to(apex);
+                builder.line_to(end);
+            }),
+            Stroke::default()
+                .with_color(appearance.border.color)
+                .with_width(appearance.border.width),
+        );
+    }
+
+    renderer.draw_geometry(frame.into_geometry());
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    use iced::widget::Space;
+
+    const WINDOW: Size = Size::new(800.0, 600.0);
+
+    // The side a 200 by 100 flyout opens on for a 100 by 32 target at `position`
+    fn resolve(placement: Placement, position: Point, window: Size) -> Placement {
+        let mut tree = Tree::empty();
+        let mut content = Element::<()>::from(Space::new(0, 0));
+        let class = <Theme as Catalog>::default();
+
+        let overlay = Overlay {
+            tree: &mut tree,
+            content: &mut content,
+            on_dismiss: &None,
+            placement,
+            has_tail: true,
+            width: Length::Shrink,
+            padding: Padding::ZERO,
+            class: &class,
+            target: Rectangle::new(position, Size::new(100.0, 32.0)),
+        };
+
+        overlay.resolve(Size::new(200.0, 100.0), window)
+    }
+
+    #[test]
+    fn placements_with_room_are_kept() {
+        let centre = Point::new(350.0, 284.0);
+
+        for placement in [
+            Placement::Top,
+            Placement::Bottom,
+            Placement::Left,
+            Placement::Right,
+        ] {
+            assert_eq!(resolve(placement, centre, WINDOW), placement);
+        }
+
+        assert_eq!(resolve(Placement::Auto, centre, WINDOW), Placement::Bottom);
+    }
+
+    #[test]
+    fn placements_flip_away_from_the_window_edges() {
+        let cases = [
+            (Placement::Top, Point::new(350.0, 10.0), Placement::Bottom),
+            (Placement::Bottom, Point::new(350.0, 550.0), Placement::Top),
+            (Placement::Left, Point::new(10.0, 284.0), Placement::Right),
+            (Placement::Right, Point::new(690.0, 284.0), Placement::Left),
+        ];
+
+        for (placement, position, flipped) in cases {
+            assert_eq!(resolve(placement, position, WINDOW), flipped);
+        }
+    }
+
+    #[test]
+    fn auto_takes_the_first_side_with_room() {
+        let short = Size::new(800.0, 200.0);
+
+        assert_eq!(
+            resolve(Placement::Auto, Point::new(350.0, 550.0), WINDOW),
+            Placement::Top
+        );
+        // Too close to both the top and the bottom of a short window
+        assert_eq!(
+            resolve(Placement::Auto, Point::new(10.0, 84.0), short),
+            Placement::Right
+        );
+    }
+
+    #[test]
+    fn the_requested_side_is_kept_when_neither_fits() {
+        let short = Size::new(800.0, 150.0);
+
+        assert_eq!(
+            resolve(Placement::Top, Point::new(350.0, 59.0), short),
+            Placement::Top
+        );
+        // Auto falls back to below, its first choice
+        assert_eq!(
+            resolve(
+                Placement::Auto,
+                Point::new(100.0, 59.0),
+                Size::new(300.0, 150.0)
+            ),
+            Placement::Bottom
+        );
+    }
+}
@@ -0,0 +1,153 @@
+use crate::{
+    fluent_icon::FluentIcon,
+    style,
+    widget::{text, Button, Container, Element, Row},
+};
+
+use iced::{
+    alignment::Vertical,
+    widget::{stack, Space},
+    Length, Padding,
+};
+
+// Based on https://learn.microsoft.com/en-us/windows/apps/design/controls/infobar
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub enum Severity {
+    #[default]
+    Informational,
+    Success,
+    Warning,
+    Error,
+}
+
+impl Severity {
+    pub const ALL: [Severity; 4] = [
+        Severity::Informational,
+        Severity::Success,
+        Severity::Warning,
+        Severity::Error,
+    ];
+
+    fn glyph(self) -> FluentIcon {
+        match self {
+            Severity::Informational => FluentIcon::StatusCircleInfo,
+            Severity::Success => FluentIcon::StatusCircleCheckmark,
+            Severity::Warning => FluentIcon::StatusCircleExclamation,
+            Severity::Error => FluentIcon::StatusCircleErrorX,
+        }
+    }
+}
+
+impl std::fmt::Display for Severity {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{:?}", self)
+    }
+}
+
+pub struct InfoBar<'a, Message>
+where
+    Message: 'a + Clone,
+{
+    severity: Severity,
+    title: String,
+    message: String,
+    action: Option<Element<'a, Message>>,
+    on_close: Option<Message>,
+    is_open: bool,
+}
+
+impl<'a, Message> InfoBar<'a, Message>
+where
+    Message: 'a + Clone,
+{
+    pub fn new(severity: Severity, title: impl Into<String>, message: impl Into<String>) -> Self {
+        Self {
+            severity,
+            title: title.into(),
+            message: message.into(),
+            action: None,
+            on_close: None,
+            is_open: true,
+        }
+    }
+
+    // Usually a `button::standard` or `button::hyperlink`
+    pub fn action(mut self, action: impl Into<Element<'a, Message>>) -> Self {
+        self.action = Some(action.into());
+        self
+    }
+
+    // Without this there is no close button, like WinUI's IsClosable="False"
+    pub fn on_close(mut self, message: Message) -> Self {
+        self.on_close = Some(message);
+        self
+    }
+
+    pub fn open(mut self, is_open: bool) -> Self {
+        self.is_open = is_open;
+        self
+    }
+}
+
+impl<'a, Message> From<InfoBar<'a, Message>> for Element<'a, Message>
+where
+    Message: 'a + Clone,
+{
+    fn from(info_bar: InfoBar<'a, Message>) -> Self {
+        if !info_bar.is_open {
+            return Space::new(Length::Fill, 0).into();
+        }
+
+        let severity = info_bar.severity;
+
+        // The coloured circle with the symbol knocked out of it
+        let icon = stack![
+            text::icon(FluentIcon::StatusCircleOuter.codepoint(), 16.0).style(move |theme| {
+                iced::widget::text::Style {
+                    color: Some(style::info_bar::icon(theme, severity)),
+                }
+            }),
+            text::icon(severity.glyph().codepoint(), 16.0).style(|theme| {
+                iced::widget::text::Style {
+                    color: Some(style::info_bar::icon_glyph(theme)),
+                }
+            }),
+        ];
+
+        let right_padding = if info_bar.on_close.is_some() { 4 } else { 16 };
+
+        let close = info_bar.on_close.map(|message| {
+            Button::new(text::icon(FluentIcon::Cancel.codepoint(), 12.0))
+                .width(38)
+                .height(38)
+                .padding(13)
+                .class("transparent")
+                .on_press(message)
+        });
+
+        let content = Row::new()
+            .push(Container::new(icon).padding(Padding::ZERO.top(16)))
+            .push(
+                Row::new()
+                    .push(text::body1_strong(info_bar.title))
+                    .push(text::body1(info_bar.message).width(Length::Fill))
+                    .spacing(12)
+                    .padding(Padding::ZERO.top(14).bottom(14))
+                    .width(Length::Fill),
+            )
+            .push_maybe(
+                info_bar
+                    .action
+                    .map(|action| Container::new(action).padding(Padding::ZERO.top(8))),
+            )
+            .push_maybe(close.map(|close| Container::new(close).padding(Padding::ZERO.top(5))))
+            .spacing(12)
+            .align_y(Vertical::Top);
+
+        Container::new(content)
+            .padding(Padding::ZERO.left(16).right(right_padding))
+            .width(Length::Fill)
+            .style(move |theme| style::info_bar::container(theme, severity))
+            .into()
+    }
+}
@@ -0,0 +1,1138 @@
+use crate::{
+    density,
+    style::list_item::{Status, Style, StyleFn},
+    theme::{
+        class::{self, Class},
+        Theme,
+    },
+    widget::{virtual_scroll::Scroll, Element},
+};
+
+use iced::{
+    advanced::{
+        layout::{Limits, Node as LayoutNode},
+        mouse::{click, Click},
+        overlay,
+        renderer::{self, Renderer as _},
+        widget::{tree, Operation, Tree},
+        Clipboard, Layout, Shell, Widget,
+    },
+    event,
+    keyboard::{self, key::Named, Key},
+    mouse::{self, Cursor},
+    touch, Border, Color, Event, Length, Point, Rectangle, Size, Vector,
+};
+
+use std::{
+    cell::RefCell,
+    collections::{btree_map::Entry, BTreeMap, BTreeSet},
+    fmt,
+    ops::Range,
+};
+
+type Renderer = iced::Renderer;
+
+// ListViewItem and GridViewItem sizes from
+// https://github.com/microsoft/microsoft-ui-xaml/blob/main/src/controls/dev/CommonStyles/ListViewItem_themeresources.xaml
+// and GridViewItem_themeresources.xaml in the same directory
+const INSET: f32 = 4.0;
+const LEADING: f32 = 16.0;
+const TRAILING: f32 = 12.0;
+// Space between rows of a list and around the cells of a grid
+const GAP: f32 = 2.0;
+const GRID_GAP: f32 = 4.0;
+// Rows built beyond either edge of the view, so short scrolls don't have to wait
+// for a new layout
+const BUFFER: usize = 4;
+
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum ItemHeight {
+    // Every row is this tall and nothing is measured
+    Fixed(f32),
+    // Rows are measured as they come into view and taken to be this tall until
+    // then
+    Estimated(f32),
+}
+
+impl ItemHeight {
+    fn value(self) -> f32 {
+        match self {
+            ItemHeight::Fixed(height) | ItemHeight::Estimated(height) => height,
+        }
+    }
+}
+
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub enum SelectionMode {
+    None,
+    #[default]
+    Single,
+    // Clicking an item adds it to or removes it from the selection
+    Multiple,
+    // Clicking selects just that item, Ctrl adds to the selection and Shift
+    // selects a range
+    Extended,
+}
+
+impl SelectionMode {
+    pub const ALL: [SelectionMode; 4] = [
+        SelectionMode::None,
+        SelectionMode::Single,
+        SelectionMode::Multiple,
+        SelectionMode::Extended,
+    ];
+
+    // What clicking an item or pressing Space on it asks for
+    pub(crate) fn on_click(
+        self,
+        selection: &Selection,
+        index: usize,
+        modifiers: keyboard::Modifiers,
+    ) -> Option<Action> {
+        let is_selected = selection.contains(index);
+
+        match self {
+            SelectionMode::None => None,
+            SelectionMode::Single if modifiers.command() && is_selected => {
+                Some(Action::Toggle(index))
+            }
+            SelectionMode::Single => (!is_selected).then_some(Action::Select(index)),
+            SelectionMode::Multiple => Some(Action::Toggle(index)),
+            SelectionMode::Extended if modifiers.shift() => Some(Action::Extend(index)),
+            SelectionMode::Extended if modifiers.command() => Some(Action::Toggle(index)),
+            SelectionMode::Extended => Some(Action::Select(index)),
+        }
+    }
+
+    // What moving keyboard focus to an item asks for, the selection follows focus
+    // in single and extended modes unless Ctrl is held
+    pub(crate) fn on_focus(
+        self,
+        selection: &Selection,
+        index: usize,
+        modifiers: keyboard::Modifiers,
+    ) -> Option<Action> {
+        match self {
+            SelectionMode::Single if !modifiers.command() && !selection.contains(index) => {
+                Some(Action::Select(index))
+            }
+            SelectionMode::Extended if modifiers.shift() => Some(Action::Extend(index)),
+            SelectionMode::Extended if !modifiers.command() => Some(Action::Select(index)),
+            _ => None,
+        }
+    }
+}
+
+impl fmt::Display for SelectionMode {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(match self {
+            SelectionMode::None => "None",
+            SelectionMode::Single => "Single",
+            SelectionMode::Multiple => "Multiple",
+            SelectionMode::Extended => "Extended",
+        })
+    }
+}
+
+// Changes the list asks for, applied with `Selection::perform`
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum Action {
+    // Selects just this item
+    Select(usize),
+    // Adds the item to the selection or takes it out
+    Toggle(usize),
+    // Selects the range from the anchor to this item
+    Extend(usize),
+    // Selects every item, given the number of them
+    SelectAll(usize),
+}
+
+// Indices of the selected items, kept by the application alongside the items
+// themselves
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct Selection {
+    selected: BTreeSet<usize>,
+    // Where Shift selects from, the last item selected without it
+    anchor: Option<usize>,
+}
+
+impl Selection {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    pub fn perform(&mut self, action: Action) {
+        match action {
+            Action::Select(index) => {
+                self.selected.clear();
+                self.selected.insert(index);
+                self.anchor = Some(index);
+            }
+            Action::Toggle(index) => {
+                if !self.selected.remove(&index) {
+                    self.selected.insert(index);
+                }
+
+                self.anchor = Some(index);
+            }
+            Action::Extend(index) => {
+                let anchor = self.anchor.unwrap_or(index);
+
+                self.selected = (anchor.min(index)..=anchor.max(index)).collect();
+                self.anchor = Some(anchor);
+            }
+            Action::SelectAll(count) => self.selected = (0..count).collect(),
+        }
+    }
+
+    pub fn clear(&mut self) {
+        self.selected.clear();
+        self.anchor = None;
+    }
+
+    pub fn contains(&self, index: usize) -> bool {
+        self.selected.contains(&index)
+    }
+
+    pub fn iter(&self) -> impl Iterator<Item = usize> + '_ {
+        self.selected.iter().copied()
+    }
+
+    pub fn len(&self) -> usize {
+        self.selected.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.selected.is_empty()
+    }
+}
+
+// Based on https://learn.microsoft.com/en-us/windows/apps/design/controls/listview-and-gridview
+//
+// Items are built by `view` as they come into view rather than all up front, so
+// only the rows on screen and a few either side are ever laid out. Rows are a
+// single item in a list and as many as fit across in a grid.
+pub struct ListView<'a, Message> {
+    count: usize,
+    selection: &'a Selection,
+    view: Box<dyn Fn(usize) -> Element<'a, Message> + 'a>,
+    on_action: Option<Box<dyn Fn(Action) -> Message + 'a>>,
+    on_activate: Option<Box<dyn Fn(usize) -> Message + 'a>>,
+    selection_mode: SelectionMode,
+    item_height: ItemHeight,
+    // How narrow the cells of a grid can get, a list when there's none
+    min_item_width: Option<f32>,
+    width: Length,
+    height: Length,
+    // Read when built, the density isn't known during layout
+    min_height: f32,
+    class: Class<StyleFn>,
+    // The items in and around the view, built on layout along with their indices
+    items: RefCell<Vec<(usize, Element<'a, Message>)>>,
+}
+
+impl<'a, Message> ListView<'a, Message> {
+    pub fn new(
+        count: usize,
+        selection: &'a Selection,
+        view: impl Fn(usize) -> Element<'a, Message> + 'a,
+    ) -> Self {
+        Self {
+            count,
+            selection,
+            view: Box::new(view),
+            on_action: None,
+            on_activate: None,
+            selection_mode: SelectionMode::default(),
+            item_height: ItemHeight::Estimated(density::metrics().control_height),
+            min_item_width: None,
+            width: Length::Fill,
+            height: Length::Fill,
+            min_height: density::metrics().control_height,
+            class: class::DEFAULT.into(),
+            items: RefCell::new(Vec::new()),
+        }
+    }
+
+    // Lays the items out in as many columns of at least `min_item_width` as fit,
+    // which share out whatever width is left over
+    pub fn grid(mut self, min_item_width: impl Into<f32>) -> Self {
+        self.min_item_width = Some(min_item_width.into());
+        self
+    }
+
+    pub fn on_action(mut self, on_action: impl Fn(Action) -> Message + 'a) -> Self {
+        self.on_action = Some(Box::new(on_action));
+        self
+    }
+
+    // Sent for a double click or Enter on an item
+    pub fn on_activate(mut self, on_activate: impl Fn(usize) -> Message + 'a) -> Self {
+        self.on_activate = Some(Box::new(on_activate));
+        self
+    }
+
+    pub fn selection_mode(mut self, selection_mode: SelectionMode) -> Self {
+        self.selection_mode = selection_mode;
+        self
+    }
+
+    pub fn item_height(mut self, item_height: ItemHeight) -> Self {
+        self.item_height = item_height;
+        self
+    }
+
+    pub fn width(mut self, width: impl Into<Length>) -> Self {
+        self.width = width.into();
+        self
+    }
+
+    pub fn height(mut self, height: impl Into<Length>) -> Self {
+        self.height = height.into();
+        self
+    }
+
+    pub fn style(mut self, style: impl Fn(&Theme, Status) -> Style + 'static) -> Self {
+        self.class = Class::Custom(Box::new(style));
+        self
+    }
+
+    pub fn class(mut self, class: impl Into<Class<StyleFn>>) -> Self {
+        self.class = class.into();
+        self
+    }
+
+    fn is_grid(&self) -> bool {
+        self.min_item_width.is_some()
+    }
+
+    fn gap(&self) -> f32 {
+        if self.is_grid() {
+            GRID_GAP
+        } else {
+            GAP
+        }
+    }
+
+    // Where an item's background goes, relative to the top left of the unscrolled
+    // content
+    fn item_bounds(&self, state: &State, index: usize) -> Rectangle {
+        let row = index / state.columns;
+        let column = index % state.columns;
+        let top = state.tops[row];
+        let height = state.heights[row];
+
+        if self.is_grid() {
+            Rectangle {
+                x: INSET + column as f32 * state.cell_width + GRID_GAP / 2.0,
+                y: top + GRID_GAP / 2.0,
+                width: (state.cell_width - GRID_GAP).max(0.0),
+                height: (height - GRID_GAP).max(0.0),
+            }
+        } else {
+            Rectangle {
+                x: INSET,
+                y: top + GAP / 2.0,
+                width: state.cell_width,
+                height: (height - GAP).max(0.0),
+            }
+        }
+    }
+
+    fn hit(&self, state: &State, bounds: Rectangle, cursor: Cursor) -> Option<usize> {
+        let position = cursor.position_over(bounds)?;
+
+        if state.scroll.is_over_scrollbar(bounds, cursor) || state.cell_width <= 0.0 {
+            return None;
+        }
+
+        let point = Point::new(
+            position.x - bounds.x,
+            state.scroll.content_y(bounds, position),
+        );
+        let row = state.row_at(point.y);
+        let column = ((point.x - INSET) / state.cell_width).floor();
+
+        if row >= state.heights.len() || column < 0.0 || column as usize >= state.columns {
+            return None;
+        }
+
+        let index = row * state.columns + column as usize;
+
+        (index < self.count && self.item_bounds(state, index).contains(point)).then_some(index)
+    }
+
+    // The cursor as the items see it, which are laid out as if nothing had been
+    // scrolled
+    fn content_cursor(state: &State, bounds: Rectangle, cursor: Cursor) -> Cursor {
+        match cursor.position_over(bounds) {
+            Some(position) if !state.scroll.is_over_scrollbar(bounds, cursor) => {
+                Cursor::Available(position + Vector::new(0.0, state.scroll.offset()))
+            }
+            _ => Cursor::Unavailable,
+        }
+    }
+
+    fn perform(&self, action: Action, shell: &mut Shell<'_, Message>) {
+        if let Some(on_action) = &self.on_action {
+            shell.publish(on_action(action));
+        }
+    }
+
+    fn activate(&self, index: usize, shell: &mut Shell<'_, Message>) {
+        if let Some(on_activate) = &self.on_activate {
+            shell.publish(on_activate(index));
+        }
+    }
+
+    // What clicking an item or pressing Space on it does in each selection mode
+    fn invoke(&self, index: usize, modifiers: keyboard::Modifiers, shell: &mut Shell<'_, Message>) {
+        if let Some(action) = self
+            .selection_mode
+            .on_click(self.selection, index, modifiers)
+        {
+            self.perform(action, shell);
+        }
+    }
+
+    fn focus(
+        &self,
+        state: &mut State,
+        index: usize,
+        modifiers: keyboard::Modifiers,
+        shell: &mut Shell<'_, Message>,
+    ) {
+        state.focused = Some(index);
+
+        let row = index / state.columns;
+        state
+            .scroll
+            .show(state.tops[row], state.tops[row + 1], shell);
+
+        if let Some(action) = self
+            .selection_mode
+            .on_focus(self.selection, index, modifiers)
+        {
+            self.perform(action, shell);
+        }
+    }
+
+    fn on_key(
+        &self,
+        state: &mut State,
+        key: &Key,
+        modifiers: keyboard::Modifiers,
+        shell: &mut Shell<'_, Message>,
+    ) -> event::Status {
+        if self.count == 0 {
+            return event::Status::Ignored;
+        }
+
+        let last = self.count - 1;
+        let columns = state.columns;
+        let focused = state.focused.filter(|index| *index <= last);
+        let current = focused.unwrap_or(0);
+        let row = current / columns;
+        let column = current % columns;
+
+        // Moves to the same column of another row, or the last item if that row
+        // stops short of it
+        let in_row = |row: usize| (row * columns + column).min(last);
+
+        let target = match key.as_ref() {
+            _ if focused.is_none()
+                && matches!(
+                    key.as_ref(),
+                    Key::Named(
+                        Named::ArrowDown
+                            | Named::ArrowUp
+                            | Named::ArrowLeft
+                            | Named::ArrowRight
+                            | Named::PageDown
+                            | Named::PageUp
+                    )
+                ) =>
+            {
+                0
+            }
+            Key::Named(Named::ArrowDown) => {
+                if row + 1 < state.heights.len() {
+                    in_row(row + 1)
+                } else {
+                    current
+                }
+            }
+            Key::Named(Named::ArrowUp) => in_row(row.saturating_sub(1)),
+            Key::Named(Named::ArrowRight) if self.is_grid() => (current + 1).min(last),
+            Key::Named(Named::ArrowLeft) if self.is_grid() => current.saturating_sub(1),
+            Key::Named(Named::PageDown) => {
+                let end = state.tops[row] + state.viewport;
+                in_row(state.row_at(end).min(state.heights.len() - 1).max(row))
+            }
+            Key::Named(Named::PageUp) => {
+                let start = (state.tops[row + 1] - state.viewport).max(0.0);
+                in_row(state.row_at(start).min(row))
+            }
+            Key::Named(Named::Home) => 0,
+            Key::Named(Named::End) => last,
+            Key::Named(Named::Space) => {
+                if let Some(index) = focused {
+                    self.invoke(index, modifiers, shell);
+                }
+
+                return event::Status::Captured;
+            }
+            Key::Named(Named::Enter) => {
+                if let Some(index) = focused {
+                    self.activate(index, shell);
+                }
+
+                return event::Status::Captured;
+            }
+            Key::Character("a")
+                if modifiers.command()
+                    && matches!(
+                        self.selection_mode,
+                        SelectionMode::Multiple | SelectionMode::Extended
+                    ) =>
+            {
+                self.perform(Action::SelectAll(self.count), shell);
+
+                return event::Status::Captured;
+            }
+            _ => return event::Status::Ignored,
+        };
+
+        self.focus(state, target, modifiers, shell);
+
+        event::Status::Captured
+    }
+}
+
+struct State {
+    scroll: Scroll,
+    viewport: f32,
+    columns: usize,
+    cell_width: f32,
+    // How tall each row is, measured or estimated, and where each one starts with
+    // the bottom of the last one at the end
+    heights: Vec<f32>,
+    tops: Vec<f32>,
+    // The rows whose items were built on the last layout, and the items' state
+    built: Range<usize>,
+    trees: BTreeMap<usize, Tree>,
+    // The item with keyboard focus, and whether the list has it at all. The list
+    // takes focus when it's clicked and loses it to a click anywhere else
+    focused: Option<usize>,
+    is_focused: bool,
+    pressed: Option<usize>,
+    modifiers: keyboard::Modifiers,
+    last_click: Option<Click>,
+}
+
+impl Default for State {
+    fn default() -> Self {
+        Self {
+            scroll: Scroll::default(),
+            viewport: 0.0,
+            columns: 1,
+            cell_width: 0.0,
+            heights: Vec::new(),
+            tops: vec![0.0],
+            built: 0..0,
+            trees: BTreeMap::new(),
+            focused: None,
+            is_focused: false,
+            pressed: None,
+            modifiers: keyboard::Modifiers::default(),
+            last_click: None,
+        }
+    }
+}
+
+impl State {
+    fn update_tops(&mut self) {
+        self.tops.clear();
+        self.tops.push(0.0);
+
+        let mut top = 0.0;
+
+        for height in &self.heights {
+            top += height;
+            self.tops.push(top);
+        }
+    }
+
+    fn extent(&self) -> f32 {
+        self.tops.last().copied().unwrap_or_default()
+    }
+
+    // The row at `y` in the content, or the number of rows when it's past the end
+    fn row_at(&self, y: f32) -> usize {
+        self.tops[1..].partition_point(|bottom| *bottom <= y)
+    }
+
+    // The rows at least partly in view
+    fn in_view(&self) -> Range<usize> {
+        let offset = self.scroll.offset();
+        let first = self.row_at(offset);
+        let last =
+            self.tops[..self.heights.len()].partition_point(|top| *top < offset + self.viewport);
+
+        first.min(last)..last
+    }
+}
+
+impl<'a, Message> Widget<Message, Theme, Renderer> for ListView<'a, Message> {
+    fn tag(&self) -> tree::Tag {
+        tree::Tag::of::<State>()
+    }
+
+    fn state(&self) -> tree::State {
+        tree::State::new(State::default())
+    }
+
+    fn size(&self) -> Size<Length> {
+        Size::new(self.width, self.height)
+    }
+
+    fn layout(&self, tree: &mut Tree, renderer: &Renderer, limits: &Limits) -> LayoutNode {
+        let state = tree.state.downcast_mut::<State>();
+        let width = limits
+            .resolve(
+                self.width,
+                Length::Shrink,
+                Size::new(limits.max().width, 0.0),
+            )
+            .width;
+        let available = (width - 2.0 * INSET - state.scroll.gutter()).max(0.0);
+        let columns = self.min_item_width.map_or(1, |min_item_width| {
+            ((available / min_item_width.max(1.0)).floor() as usize).max(1)
+        });
+
+        // Rows are made of different items once the column count changes, so
+        // what was measured no longer applies
+        if columns != state.columns {
+            state.columns = columns;
+            state.heights.clear();
+        }
+
+        state
+            .heights
+            .resize(self.count.div_ceil(columns), self.item_height.value());
+
+        if let ItemHeight::Fixed(height) = self.item_height {
+            state.heights.fill(height);
+        }
+
+        state.cell_width = available / columns as f32;
+        state.update_tops();
+
+        let size = limits.resolve(self.width, self.height, Size::new(width, state.extent()));
+        state.viewport = size.height;
+        state.scroll.resize(state.extent(), size.height);
+
+        let in_view = state.in_view();
+        let rows = state.heights.len();
+        let built = in_view.start.saturating_sub(BUFFER)..(in_view.end + BUFFER).min(rows);
+        let indices = built.start * columns..(built.end * columns).min(self.count);
+
+        let gap = self.gap();
+        let item_width = if self.is_grid() {
+            state.cell_width - GRID_GAP
+        } else {
+            state.cell_width - LEADING - TRAILING
+        }
+        .max(0.0);
+        let max_height = match self.item_height {
+            ItemHeight::Fixed(height) => (height - gap).max(0.0),
+            ItemHeight::Estimated(_) => f32::INFINITY,
+        };
+        let item_limits = Limits::new(
+            Size::new(item_width, 0.0),
+            Size::new(item_width, max_height),
+        );
+
+        let mut items = self.items.borrow_mut();
+        items.clear();
+        state.trees.retain(|index, _| indices.contains(index));
+
+        let nodes: Vec<_> = indices
+            .clone()
+            .map(|index| {
+                let item = (self.view)(index);
+                let tree = match state.trees.entry(index) {
+                    Entry::Occupied(entry) => {
+                        let tree = entry.into_mut();
+                        tree.diff(item.as_widget());
+                        tree
+                    }
+                    Entry::Vacant(entry) => entry.insert(Tree::new(item.as_widget())),
+                };
+
+                let node = item.as_widget().layout(tree, renderer, &item_limits);
+                items.push((index, item));
+
+                node
+            })
+            .collect();
+
+        let mut size = size;
+
+        if let ItemHeight::Estimated(_) = self.item_height {
+            let min_height = self.min_height;
+            // Rows above the view that turn out a different height than estimated
+            // would otherwise push what's in view up or down
+            let mut shift = 0.0;
+
+            for (row, nodes) in built.clone().zip(nodes.chunks(columns)) {
+                let height = nodes
+                    .iter()
+                    .map(|node| node.size().height + gap)
+                    .fold(min_height, f32::max);
+
+                if row < in_view.start {
+                    shift += height - state.heights[row];
+                }
+
+                state.heights[row] = height;
+            }
+
+            state.update_tops();
+            size = limits.resolve(self.width, self.height, Size::new(width, state.extent()));
+            state.viewport = size.height;
+            state.scroll.resize(state.extent(), size.height);
+
+            if shift != 0.0 {
+                state.scroll.shift(shift);
+            }
+        }
+
+        let nodes = nodes
+            .into_iter()
+            .zip(indices)
+            .map(|(node, index)| {
+                let bounds = self.item_bounds(state, index);
+                let x = if self.is_grid() {
+                    bounds.x
+                } else {
+                    bounds.x + LEADING
+                };
+
+                let y = bounds.y + (bounds.height - node.size().height).max(0.0) / 2.0;
+
+                node.move_to(Point::new(x, y))
+            })
+            .collect();
+
+        state.built = built;
+
+        LayoutNode::with_children(size, nodes)
+    }
+
+    fn operate(
+        &self,
+        tree: &mut Tree,
+        layout: Layout<'_>,
+        renderer: &Renderer,
+        operation: &mut dyn Operation,
+    ) {
+        let state = tree.state.downcast_mut::<State>();
+
+        operation.container(None, layout.bounds(), &mut |operation| {
+            self.items
+                .borrow()
+                .iter()
+                .zip(state.trees.values_mut())
+                .zip(layout.children())
+                .for_each(|(((_, item), tree), layout)| {
+                    item.as_widget().operate(tree, layout, renderer, operation);
+                });
+        });
+    }
+
+    fn on_event(
+        &mut self,
+        tree: &mut Tree,
+        event: Event,
+        layout: Layout<'_>,
+        cursor: Cursor,
+        renderer: &Renderer,
+        clipboard: &mut dyn Clipboard,
+        shell: &mut Shell<'_, Message>,
+        viewport: &Rectangle,
+    ) -> event::Status {
+        let state = tree.state.downcast_mut::<State>();
+        let bounds = layout.bounds();
+
+        if let Event::Keyboard(keyboard::Event::ModifiersChanged(modifiers)) = event {
+            state.modifiers = modifiers;
+        }
+
+        let mut status = state.scroll.on_event(&event, bounds, cursor, shell);
+
+        if status == event::Status::Ignored {
+            let offset = Vector::new(0.0, state.scroll.offset());
+            let item_cursor = Self::content_cursor(state, bounds, cursor);
+            let item_viewport = bounds.intersection(viewport).unwrap_or(bounds) + offset;
+
+            for (((_, item), tree), layout) in self
+                .items
+                .get_mut()
+                .iter_mut()
+                .zip(state.trees.values_mut())
+                .zip(layout.children())
+            {
+                status = status.merge(item.as_widget_mut().on_event(
+                    tree,
+                    event.clone(),
+                    layout,
+                    item_cursor,
+                    renderer,
+                    clipboard,
+                    shell,
+                    &item_viewport,
+                ));
+            }
+        }
+
+        if status == event::Status::Ignored {
+            status = match event {
+                Event::Mouse(mouse::Event::ButtonPressed(mouse::Button::Left))
+                | Event::Touch(touch::Event::FingerPressed { .. }) => {
+                    if let Some(position) = cursor.position_over(bounds) {
+                        state.is_focused = true;
+
+                        if let Some(index) = self.hit(state, bounds, cursor) {
+                            let click = Click::new(position, mouse::Button::Left, state.last_click);
+                            state.last_click = Some(click);
+                            state.focused = Some(index);
+                            state.pressed = Some(index);
+
+                            self.invoke(index, state.modifiers, shell);
+
+                            if matches!(click.kind(), click::Kind::Double) {
+                                self.activate(index, shell);
+                            }
+                        }
+
+                        event::Status::Captured
+                    } else {
+                        state.is_focused = false;
+
+                        event::Status::Ignored
+                    }
+                }
+                Event::Mouse(mouse::Event::ButtonReleased(mouse::Button::Left))
+                | Event::Touch(touch::Event::FingerLifted { .. })
+                | Event::Touch(touch::Event::FingerLost { .. }) => {
+                    state.pressed = None;
+
+                    event::Status::Ignored
+                }
+                Event::Keyboard(keyboard::Event::KeyPressed { key, modifiers, .. })
+                    if state.is_focused =>
+                {
+                    self.on_key(state, &key, modifiers, shell)
+                }
+                _ => event::Status::Ignored,
+            };
+        }
+
+        // Scrolling past the rows that were built needs them built first
+        let in_view = state.in_view();
+
+        if in_view.start < state.built.start || in_view.end > state.built.end {
+            shell.invalidate_layout();
+        }
+
+        status
+    }
+
+    fn draw(
+        &self,
+        tree: &Tree,
+        renderer: &mut Renderer,
+        theme: &Theme,
+        _style: &renderer::Style,
+        layout: Layout<'_>,
+        cursor: Cursor,
+        viewport: &Rectangle,
+    ) {
+        let state = tree.state.downcast_ref::<State>();
+        let bounds = layout.bounds();
+        let hovered = self.hit(state, bounds, cursor);
+        let clip = bounds.intersection(viewport).unwrap_or(bounds);
+        let offset = Vector::new(0.0, state.scroll.offset());
+        let item_cursor = Self::content_cursor(state, bounds, cursor);
+        let item_viewport = clip + offset;
+        let origin = Vector::new(bounds.x, bounds.y) - offset;
+
+        renderer.with_layer(clip, |renderer| {
+            for (((index, item), tree), layout) in self
+                .items
+                .borrow()
+                .iter()
+                .zip(state.trees.values())
+                .zip(layout.children())
+            {
+                let index = *index;
+                let item_bounds = self.item_bounds(state, index) + origin;
+
+                if !item_bounds.intersects(&clip) {
+                    continue;
+                }
+
+                let is_selected =
+                    self.selection_mode != SelectionMode::None && self.selection.contains(index);
+                let is_hovered = hovered == Some(index);
+
+                let status = match (is_selected, is_hovered) {
+                    (true, true) => Status::SelectedHovered,
+                    (true, false) => Status::Selected,
+                    (false, true) if state.pressed == Some(index) => Status::Pressed,
+                    (false, true) => Status::Hovered,
+                    (false, false) => Status::Active,
+                };
+
+                let style = self.class.resolve(theme, status);
+
+                renderer.fill_quad(
+                    renderer::Quad {
+                        bounds: item_bounds,
+                        border: Border {
+                            radius: 4.0.into(),
+                            ..Border::default()
+                        },
+                        ..renderer::Quad::default()
+                    },
+                    style.background,
+                );
+
+                // Grid cells are outlined when selected where list rows get a pill
+                if is_selected && self.is_grid() {
+                    renderer.fill_quad(
+                        renderer::Quad {
+                            bounds: item_bounds,
+                            border: Border {
+                                color: style.indicator,
+                                width: 2.0,
+                                radius: 4.0.into(),
+                            },
+                            ..renderer::Quad::default()
+                        },
+                        Color::TRANSPARENT,
+                    );
+                } else if is_selected {
+                    renderer.fill_quad(
+                        renderer::Quad {
+                            bounds: Rectangle {
+                                x: item_bounds.x,
+                                y: item_bounds.center_y() - 8.0,
+                                width: 3.0,
+                                height: 16.0,
+                            },
+                            border: Border {
+                                radius: 1.5.into(),
+                                ..Border::default()
+                            },
+                            ..renderer::Quad::default()
+                        },
+                        style.indicator,
+                    );
+                }
+
+                if state.is_focused && state.focused == Some(index) {
+                    let ring = if self.is_grid() {
+                        item_bounds.expand(GRID_GAP / 2.0)
+                    } else {
+                        item_bounds
+                    };
+
+                    renderer.fill_quad(
+                        renderer::Quad {
+                            bounds: ring,
+                            border: Border {
+                                color: style.focus,
+                                width: 2.0,
+                                radius: 4.0.into(),
+                            },
+                            ..renderer::Quad::default()
+                        },
+                        Color::TRANSPARENT,
+                    );
+                }
+
+                renderer.with_translation(-offset, |renderer| {
+                    item.as_widget().draw(
+                        tree,
+                        renderer,
+                        theme,
+                        &renderer::Style {
+                            text_color: style.text_color,
+                        },
+                        layout,
+                        item_cursor,
+                        &item_viewport,
+                    );
+                });
+            }
+        });
+
+        state.scroll.draw(renderer, theme, bounds, cursor);
+    }
+
+    fn mouse_interaction(
+        &self,
+        tree: &Tree,
+        layout: Layout<'_>,
+        cursor: Cursor,
+        viewport: &Rectangle,
+        renderer: &Renderer,
+    ) -> mouse::Interaction {
+        let state = tree.state.downcast_ref::<State>();
+        let bounds = layout.bounds();
+        let item_cursor = Self::content_cursor(state, bounds, cursor);
+        let item_viewport = bounds.intersection(viewport).unwrap_or(bounds)
+            + Vector::new(0.0, state.scroll.offset());
+
+        self.items
+            .borrow()
+            .iter()
+            .zip(state.trees.values())
+            .zip(layout.children())
+            .map(|(((_, item), tree), layout)| {
+                item.as_widget().mouse_interaction(
+                    tree,
+                    layout,
+                    item_cursor,
+                    &item_viewport,
+                    renderer,
+                )
+            })
+            .max()
+            .unwrap_or_default()
+    }
+
+    fn overlay<'b>(
+        &'b mut self,
+        tree: &'b mut Tree,
+        layout: Layout<'_>,
+        renderer: &Renderer,
+        translation: Vector,
+    ) -> Option<overlay::Element<'b, Message, Theme, Renderer>> {
+        let state = tree.state.downcast_mut::<State>();
+        let translation = translation - Vector::new(0.0, state.scroll.offset());
+
+        let overlays: Vec<_> = self
+            .items
+            .get_mut()
+            .iter_mut()
+            .zip(state.trees.values_mut())
+            .zip(layout.children())
+            .filter_map(|(((_, item), tree), layout)| {
+                item.as_widget_mut()
+                    .overlay(tree, layout, renderer, translation)
+            })
+            .collect();
+
+        (!overlays.is_empty()).then(|| overlay::Group::with_children(overlays).overlay())
+    }
+}
+
+impl<'a, Message> From<ListView<'a, Message>> for Element<'a, Message>
+where
+    Message: 'a,
+{
+    fn from(list_view: ListView<'a, Message>) -> Self {
+        Self::new(list_view)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    use keyboard::Modifiers;
+
+    fn click(selection: &mut Selection, index: usize, modifiers: Modifiers) {
+        if let Some(action) = SelectionMode::Extended.on_click(selection, index, modifiers) {
+            selection.perform(action);
+        }
+    }
+
+    fn selected(selection: &Selection) -> Vec<usize> {
+        selection.iter().collect()
+    }
+
+    #[test]
+    fn clicking_selects_just_that_item() {
+        let mut selection = Selection::new();
+
+        click(&mut selection, 2, Modifiers::empty());
+        click(&mut selection, 4, Modifiers::COMMAND);
+        click(&mut selection, 6, Modifiers::empty());
+
+        assert_eq!(selected(&selection), [6]);
+    }
+
+    #[test]
+    fn shift_selects_the_range_from_the_anchor() {
+        let mut selection = Selection::new();
+
+        click(&mut selection, 2, Modifiers::empty());
+        click(&mut selection, 5, Modifiers::SHIFT);
+
+        assert_eq!(selected(&selection), [2, 3, 4, 5]);
+
+        // The anchor stays put, so the range can be moved to the other side of it
+        click(&mut selection, 0, Modifiers::SHIFT);
+
+        assert_eq!(selected(&selection), [0, 1, 2]);
+    }
+
+    #[test]
+    fn ctrl_toggles_an_item_and_moves_the_anchor() {
+        let mut selection = Selection::new();
+
+        click(&mut selection, 2, Modifiers::empty());
+        click(&mut selection, 5, Modifiers::COMMAND);
+
+        assert_eq!(selected(&selection), [2, 5]);
+
+        click(&mut selection, 2, Modifiers::COMMAND);
+
+        assert_eq!(selected(&selection), [5]);
+
+        click(&mut selection, 7, Modifiers::SHIFT);
+
+        assert_eq!(selected(&selection), [2, 3, 4, 5, 6, 7]);
+    }
+
+    #[test]
+    fn shift_without_an_anchor_selects_the_item() {
+        let mut selection = Selection::new();
+
+        click(&mut selection, 3, Modifiers::SHIFT);
+
+        assert_eq!(selected(&selection), [3]);
+    }
+
+    #[test]
+    fn focus_moves_the_selection_unless_ctrl_is_held() {
+        let mut selection = Selection::new();
+        selection.perform(Action::Select(1));
+
+        let mode = SelectionMode::Extended;
+
+        assert_eq!(
+            mode.on_focus(&selection, 2, Modifiers::empty()),
+            Some(Action::Select(2))
+        );
+        assert_eq!(
+            mode.on_focus(&selection, 2, Modifiers::SHIFT),
+            Some(Action::Extend(2))
+        );
+        assert_eq!(mode.on_focus(&selection, 2, Modifiers::COMMAND), None);
+    }
+}
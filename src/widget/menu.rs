@@ -9,20 +9,17 @@ pub fn standard<Message>(items: Vec<MenuItem<Message>>) -> Menu<Message> {
 }
 
 pub mod bar {
-    use crate::{
-        style,
-        widget::{MenuBar, MenuItem},
-    };
+    use crate::widget::{MenuBar, MenuItem};
 
     pub fn standard<Message>(roots: Vec<MenuItem<Message>>) -> MenuBar<Message> {
-        MenuBar::new(roots).style(style::menu_bar::default)
+        MenuBar::new(roots)
     }
 }
 
 pub mod item {
     use crate::{
+        density,
         fluent_icon::FluentIcon,
-        style,
         widget::{text, Button, Element, Menu, MenuItem, Quad, Row},
     };
 
@@ -43,14 +40,14 @@ pub mod item {
         center(
             Button::new(content)
                 .width(Length::Fill)
-                .height(28)
+                .height(density::metrics().menu_item_height)
                 .padding([3, 10])
-                .style(style::button::menu_item)
+                .class("menu_item")
                 .on_press_maybe(on_press),
         )
         .width(Length::Fill)
         .height(Length::Shrink)
-        .padding(5)
+        .padding(density::metrics().menu_item_padding)
         .into()
     }
 
@@ -91,7 +88,7 @@ pub mod item {
 
         let content = Row::new()
             .push(
-                text::icon(bullet)
+                text::icon(bullet, 16.0)
                     .width(14)
                     .height(Length::Fill)
                     .align_x(Horizontal::Center)
@@ -121,8 +118,7 @@ pub mod item {
             .push(text::body1(label))
             .push(horizontal_space())
             .push(
-                text::icon(FluentIcon::ChevronRightMed.codepoint())
-                    .size(8)
+                text::icon(FluentIcon::ChevronRightMed.codepoint(), 8.0)
                     .height(Length::Fill)
                     .align_y(Vertical::Center),
             );
@@ -138,7 +134,7 @@ pub mod item {
             Quad::new(None::<Element<Message>>)
                 .width(Length::Fill)
                 .height(1)
-                .style(style::quad::separator),
+                .class("separator"),
         )
     }
 }
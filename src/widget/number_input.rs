@@ -1,4 +1,5 @@
 use crate::{
+    density::{self, Metrics},
     fluent_icon::FluentIcon,
     font, style, theme,
     widget::underline::{ElementType, Underline},
@@ -43,8 +44,6 @@ use iced_aw::{
 
 use num_traits::{Bounded, Num, NumAssignOps};
 
-const DEFAULT_PADDING: Padding = Padding::new(5.0);
-
 pub struct NumberInput<'a, T, Message, Theme = theme::Theme, Renderer = iced::Renderer>
 where
     Theme: Catalog + ExtendedCatalog,
@@ -77,7 +76,7 @@ where
         F: 'static + Fn(T) -> Message + Copy,
         T: 'static,
     {
-        let padding = DEFAULT_PADDING;
+        let padding = Padding::new(density::metrics().number_input_padding);
 
         Self {
             value,
@@ -289,7 +288,8 @@ where
                 .center_x(Length::Shrink)
         };
 
-        let default_padding = DEFAULT_PADDING;
+        // The stacked arrows only fit beside an input as tall as a standard one
+        let default_padding = Padding::new(Metrics::STANDARD.number_input_padding);
 
         let element = if self.padding.top < default_padding.top
             || self.padding.bottom < default_padding.bottom
@@ -688,7 +688,7 @@ where
                 content: FluentIcon::ChevronDown.codepoint().to_string(),
                 bounds: Size::new(dec_bounds.width, dec_bounds.height),
                 size: icon_size,
-                font: font::SEGOE_FLUENT_ICONS,
+                font: font::icons(),
                 horizontal_alignment: Horizontal::Center,
                 vertical_alignment: Vertical::Center,
                 line_height: LineHeight::Relative(1.3),
@@ -723,7 +723,7 @@ where
                 content: FluentIcon::ChevronUp.codepoint().to_string(),
                 bounds: Size::new(inc_bounds.width, inc_bounds.height),
                 size: icon_size,
-                font: font::SEGOE_FLUENT_ICONS,
+                font: font::icons(),
                 horizontal_alignment: Horizontal::Center,
                 vertical_alignment: Vertical::Center,
                 line_height: LineHeight::Relative(1.3),
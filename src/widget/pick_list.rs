@@ -1,4 +1,9 @@
-use crate::{fluent_icon::FluentIcon, font, widget::PickList};
+use crate::{
+    density,
+    fluent_icon::FluentIcon,
+    font,
+    widget::{text, PickList},
+};
 
 use iced::{
     widget::{
@@ -22,11 +27,12 @@ where
     Message: Clone,
 {
     PickList::new(options, selected, on_select)
-        .font(font::SEGOE)
-        .text_size(14)
-        .text_line_height(LineHeight::Absolute(Pixels(20.0)))
+        .font(font::text())
+        .text_size(text::scaled(14.0))
+        .text_line_height(LineHeight::Absolute(Pixels(text::scaled(20.0))))
+        .padding(density::metrics().control_padding)
         .handle(Handle::Static(Icon {
-            font: font::SEGOE_FLUENT_ICONS,
+            font: font::icons(),
             code_point: FluentIcon::ChevronDown.codepoint(),
             size: Some(10.into()),
             line_height: LineHeight::default(),
@@ -0,0 +1,495 @@
+use crate::{
+    motion::{self, Animated, Easing},
+    style::progress::{Style, StyleFn},
+    theme::{
+        class::{self, Class},
+        Theme,
+    },
+};
+
+use iced::{
+    advanced::{
+        graphics::geometry::Renderer as _,
+        layout::{self, Limits, Node},
+        renderer::{self, Renderer as _},
+        widget::{tree, Tree},
+        Clipboard, Layout, Shell, Widget,
+    },
+    event,
+    mouse::Cursor,
+    widget::canvas::{path::Arc, Frame, LineCap, Path, Stroke},
+    window, Border, Element, Event, Length, Point, Radians, Rectangle, Size, Vector,
+};
+
+use std::{
+    f32::consts::{PI, TAU},
+    fmt,
+    ops::RangeInclusive,
+    time::{Duration, Instant},
+};
+
+type Renderer = iced::Renderer;
+
+// One pass of the indeterminate animations
+const CYCLE: Duration = Duration::from_secs(2);
+
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub enum Status {
+    #[default]
+    Running,
+    Paused,
+    Error,
+}
+
+impl Status {
+    pub const ALL: [Status; 3] = [Status::Running, Status::Paused, Status::Error];
+}
+
+impl fmt::Display for Status {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(match self {
+            Status::Running => "Running",
+            Status::Paused => "Paused",
+            Status::Error => "Error",
+        })
+    }
+}
+
+// Either how far along the work is from 0 to 1 or `None` when that isn't known
+#[derive(Clone, Copy, Debug, PartialEq)]
+struct Progress(Option<f32>);
+
+impl Progress {
+    fn new(range: RangeInclusive<f32>, value: f32) -> Self {
+        let (start, end) = range.into_inner();
+        let length = end - start;
+
+        Self(Some(if length > 0.0 {
+            ((value - start) / length).clamp(0.0, 1.0)
+        } else {
+            0.0
+        }))
+    }
+}
+
+struct State {
+    // The determinate fill, which eases between values
+    value: Animated<f32>,
+    // When the indeterminate animation started
+    start: Instant,
+    now: Instant,
+}
+
+impl State {
+    fn new(progress: Progress) -> Self {
+        let now = Instant::now();
+
+        Self {
+            value: Animated::new(progress.0.unwrap_or_default())
+                .duration(motion::NORMAL)
+                .easing(Easing::FAST_OUT_SLOW_IN),
+            start: now,
+            now,
+        }
+    }
+
+    fn diff(&mut self, progress: Progress) {
+        if let Some(value) = progress.0 {
+            let now = Instant::now();
+
+            self.value.go_to(value, now);
+            self.now = now;
+        }
+    }
+
+    // How far through the current indeterminate cycle the animation is, from 0 to 1.
+    // With reduced motion the indeterminate indicators are drawn standing still
+    fn phase(&self) -> f32 {
+        if motion::reduce_motion() {
+            return 0.25;
+        }
+
+        let elapsed = self.now.saturating_duration_since(self.start).as_secs_f32();
+
+        (elapsed / CYCLE.as_secs_f32()).fract()
+    }
+
+    // Frames are only requested while something is moving and the widget can be
+    // seen, scrolling it back into view redraws it which picks the animation up again
+    fn on_redraw(
+        &mut self,
+        now: Instant,
+        progress: Progress,
+        status: Status,
+        bounds: Rectangle,
+        viewport: &Rectangle,
+        shell: &mut Shell<'_, impl Sized>,
+    ) {
+        self.now = now;
+
+        let is_animating = match progress.0 {
+            Some(_) => self.value.is_animating(now),
+            None => status == Status::Running && !motion::reduce_motion(),
+        };
+
+        if is_animating && bounds.intersects(viewport) {
+            shell.request_redraw(window::RedrawRequest::NextFrame);
+        }
+    }
+}
+
+// A horizontal bar filled from the left for determinate progress, with segments
+// sweeping across it when the progress is indeterminate
+pub struct ProgressBar {
+    progress: Progress,
+    status: Status,
+    width: Length,
+    height: f32,
+    class: Class<StyleFn>,
+}
+
+impl ProgressBar {
+    pub fn new(range: RangeInclusive<f32>, value: f32) -> Self {
+        Self::with_progress(Progress::new(range, value))
+    }
+
+    pub fn indeterminate() -> Self {
+        Self::with_progress(Progress(None))
+    }
+
+    fn with_progress(progress: Progress) -> Self {
+        Self {
+            progress,
+            status: Status::default(),
+            width: Length::Fill,
+            height: 3.0,
+            class: class::DEFAULT.into(),
+        }
+    }
+
+    pub fn status(mut self, status: Status) -> Self {
+        self.status = status;
+        self
+    }
+
+    pub fn width(mut self, width: impl Into<Length>) -> Self {
+        self.width = width.into();
+        self
+    }
+
+    pub fn height(mut self, height: f32) -> Self {
+        self.height = height;
+        self
+    }
+
+    pub fn style(mut self, style: impl Fn(&Theme, Status) -> Style + 'static) -> Self {
+        self.class = Class::Custom(Box::new(style));
+        self
+    }
+
+    pub fn class(mut self, class: impl Into<Class<StyleFn>>) -> Self {
+        self.class = class.into();
+        self
+    }
+}
+
+impl<Message> Widget<Message, Theme, Renderer> for ProgressBar {
+    fn tag(&self) -> tree::Tag {
+        tree::Tag::of::<State>()
+    }
+
+    fn state(&self) -> tree::State {
+        tree::State::new(State::new(self.progress))
+    }
+
+    fn diff(&self, tree: &mut Tree) {
+        tree.state.downcast_mut::<State>().diff(self.progress);
+    }
+
+    fn size(&self) -> Size<Length> {
+        Size {
+            width: self.width,
+            height: Length::Fixed(self.height),
+        }
+    }
+
+    fn layout(&self, _tree: &mut Tree, _renderer: &Renderer, limits: &Limits) -> Node {
+        layout::atomic(limits, self.width, self.height)
+    }
+
+    fn on_event(
+        &mut self,
+        tree: &mut Tree,
+        event: Event,
+        layout: Layout<'_>,
+        _cursor: Cursor,
+        _renderer: &Renderer,
+        _clipboard: &mut dyn Clipboard,
+        shell: &mut Shell<'_, Message>,
+        viewport: &Rectangle,
+    ) -> event::Status {
+        if let Event::Window(window::Event::RedrawRequested(now)) = event {
+            tree.state.downcast_mut::<State>().on_redraw(
+                now,
+                self.progress,
+                self.status,
+                layout.bounds(),
+                viewport,
+                shell,
+            );
+        }
+
+        event::Status::Ignored
+    }
+
+    fn draw(
+        &self,
+        tree: &Tree,
+        renderer: &mut Renderer,
+        theme: &Theme,
+        _style: &renderer::Style,
+        layout: Layout<'_>,
+        _cursor: Cursor,
+        _viewport: &Rectangle,
+    ) {
+        let state = tree.state.downcast_ref::<State>();
+        let style = self.class.resolve(theme, self.status);
+        let bounds = layout.bounds();
+
+        // A hairline track through the middle of the bar
+        let track = Rectangle {
+            y: bounds.center_y() - 0.5,
+            height: 1.0,
+            ..bounds
+        };
+
+        renderer.fill_quad(
+            renderer::Quad {
+                bounds: track,
+                border: Border::default().rounded(0.5),
+                ..renderer::Quad::default()
+            },
+            style.track,
+        );
+
+        let mut segment = |from: f32, to: f32| {
+            let from = from.clamp(0.0, 1.0);
+            let to = to.clamp(0.0, 1.0);
+
+            if to > from {
+                renderer.fill_quad(
+                    renderer::Quad {
+                        bounds: Rectangle {
+                            x: bounds.x + from * bounds.width,
+                            width: (to - from) * bounds.width,
+                            ..bounds
+                        },
+                        border: Border::default().rounded(bounds.height / 2.0),
+                        ..renderer::Quad::default()
+                    },
+                    style.indicator,
+                );
+            }
+        };
+
+        match self.progress.0 {
+            Some(_) => segment(0.0, state.value.value(state.now)),
+            // Paused or failed work without a known value fills the whole bar
+            None if self.status != Status::Running => segment(0.0, 1.0),
+            None => {
+                // A long segment followed by a short one, as in WinUI's storyboard
+                let phase = state.phase();
+
+                let long = Easing::POINT_TO_POINT.apply(phase / 0.75);
+                let long = -0.4 + 1.4 * long;
+                segment(long, long + 0.4);
+
+                if phase > 0.5 {
+                    let short = Easing::FAST_OUT_SLOW_IN.apply((phase - 0.5) / 0.5);
+                    let short = -0.15 + 1.15 * short;
+                    segment(short, short + 0.15);
+                }
+            }
+        }
+    }
+}
+
+impl<'a, Message: 'a> From<ProgressBar> for Element<'a, Message, Theme, Renderer> {
+    fn from(progress_bar: ProgressBar) -> Self {
+        Self::new(progress_bar)
+    }
+}
+
+// A circular indicator drawn with canvas geometry. The arc sweeps clockwise from
+// the top for determinate progress and spins while the progress is indeterminate.
+pub struct ProgressRing {
+    progress: Progress,
+    status: Status,
+    size: f32,
+    thickness: f32,
+    class: Class<StyleFn>,
+}
+
+impl ProgressRing {
+    pub fn new(range: RangeInclusive<f32>, value: f32) -> Self {
+        Self::with_progress(Progress::new(range, value))
+    }
+
+    pub fn indeterminate() -> Self {
+        Self::with_progress(Progress(None))
+    }
+
+    fn with_progress(progress: Progress) -> Self {
+        Self {
+            progress,
+            status: Status::default(),
+            size: 32.0,
+            thickness: 4.0,
+            class: class::DEFAULT.into(),
+        }
+    }
+
+    pub fn status(mut self, status: Status) -> Self {
+        self.status = status;
+        self
+    }
+
+    pub fn size(mut self, size: f32) -> Self {
+        self.size = size;
+        self
+    }
+
+    pub fn thickness(mut self, thickness: f32) -> Self {
+        self.thickness = thickness;
+        self
+    }
+
+    pub fn style(mut self, style: impl Fn(&Theme, Status) -> Style + 'static) -> Self {
+        self.class = Class::Custom(Box::new(style));
+        self
+    }
+
+    pub fn class(mut self, class: impl Into<Class<StyleFn>>) -> Self {
+        self.class = class.into();
+        self
+    }
+}
+
+impl<Message> Widget<Message, Theme, Renderer> for ProgressRing {
+    fn tag(&self) -> tree::Tag {
+        tree::Tag::of::<State>()
+    }
+
+    fn state(&self) -> tree::State {
+        tree::State::new(State::new(self.progress))
+    }
+
+    fn diff(&self, tree: &mut Tree) {
+        tree.state.downcast_mut::<State>().diff(self.progress);
+    }
+
+    fn size(&self) -> Size<Length> {
+        Size::new(Length::Fixed(self.size), Length::Fixed(self.size))
+    }
+
+    fn layout(&self, _tree: &mut Tree, _renderer: &Renderer, limits: &Limits) -> Node {
+        layout::atomic(limits, self.size, self.size)
+    }
+
+    fn on_event(
+        &mut self,
+        tree: &mut Tree,
+        event: Event,
+        layout: Layout<'_>,
+        _cursor: Cursor,
+        _renderer: &Renderer,
+        _clipboard: &mut dyn Clipboard,
+        shell: &mut Shell<'_, Message>,
+        viewport: &Rectangle,
+    ) -> event::Status {
+        if let Event::Window(window::Event::RedrawRequested(now)) = event {
+            tree.state.downcast_mut::<State>().on_redraw(
+                now,
+                self.progress,
+                self.status,
+                layout.bounds(),
+                viewport,
+                shell,
+            );
+        }
+
+        event::Status::Ignored
+    }
+
+    fn draw(
+        &self,
+        tree: &Tree,
+        renderer: &mut Renderer,
+        theme: &Theme,
+        _style: &renderer::Style,
+        layout: Layout<'_>,
+        _cursor: Cursor,
+        _viewport: &Rectangle,
+    ) {
+        // Angles are clockwise from the positive x axis, so the top is -90°
+        const TOP: f32 = -PI / 2.0;
+
+        let state = tree.state.downcast_ref::<State>();
+        let style = self.class.resolve(theme, self.status);
+        let bounds = layout.bounds();
+
+        let center = Point::new(bounds.width / 2.0, bounds.height / 2.0);
+        let radius = (bounds.width.min(bounds.height) - self.thickness) / 2.0;
+
+        let arc = |start: f32, sweep: f32| {
+            Path::new(|builder| {
+                builder.arc(Arc {
+                    center,
+                    radius,
+                    start_angle: Radians(start),
+                    end_angle: Radians(start + sweep),
+                });
+            })
+        };
+
+        let path = match self.progress.0 {
+            Some(_) => {
+                let value = state.value.value(state.now);
+
+                (value > 0.0).then(|| arc(TOP, value * TAU))
+            }
+            None if self.status != Status::Running => Some(Path::circle(center, radius)),
+            None => {
+                // The arc grows and shrinks as it turns twice each cycle
+                let phase = state.phase();
+                let sweep = 0.1 + 0.6 * (1.0 - (TAU * phase).cos()) / 2.0;
+
+                Some(arc(TOP + 2.0 * TAU * phase, sweep * TAU))
+            }
+        };
+
+        let Some(path) = path else {
+            return;
+        };
+
+        let mut frame = Frame::new(renderer, bounds.size());
+
+        frame.stroke(
+            &path,
+            Stroke::default()
+                .with_color(style.indicator)
+                .with_width(self.thickness)
+                .with_line_cap(LineCap::Round),
+        );
+
+        renderer.with_translation(Vector::new(bounds.x, bounds.y), |renderer| {
+            renderer.draw_geometry(frame.into_geometry());
+        });
+    }
+}
+
+impl<'a, Message: 'a> From<ProgressRing> for Element<'a, Message, Theme, Renderer> {
+    fn from(progress_ring: ProgressRing) -> Self {
+        Self::new(progress_ring)
+    }
+}
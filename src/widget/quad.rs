@@ -61,6 +61,11 @@ where
         self.class = (Box::new(style) as StyleFn<'a, Theme>).into();
         self
     }
+
+    pub fn class(mut self, class: impl Into<Theme::Class<'a>>) -> Self {
+        self.class = class.into();
+        self
+    }
 }
 
 impl<'a, Message, Theme, Renderer> Default for Quad<'a, Message, Theme, Renderer>
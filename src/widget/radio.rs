@@ -1,4 +1,7 @@
-use crate::{font, style, widget::Radio};
+use crate::{
+    font,
+    widget::{text, Radio},
+};
 
 use iced::{widget::text::LineHeight, Pixels};
 
@@ -15,8 +18,7 @@ where
 {
     iced::widget::Radio::new(label, value, selected, f)
         .size(20)
-        .font(font::SEGOE)
-        .text_size(14)
-        .text_line_height(LineHeight::Absolute(Pixels(20.0)))
-        .style(style::radio::default)
+        .font(font::text())
+        .text_size(text::scaled(14.0))
+        .text_line_height(LineHeight::Absolute(Pixels(text::scaled(20.0))))
 }
@@ -55,8 +55,8 @@ pub fn horizontal_separator<'a, Message: 'a>() -> Element<'a, Message> {
 pub mod button {
 
     use crate::{
-        fluent_icon::FluentIcon,
-        style,
+        density,
+        icon::{self, Icon},
         widget::{text, Button, Column, Row},
     };
 
@@ -68,64 +68,60 @@ pub mod button {
 
     /// Large ribbon button: icon (28px) stacked above text label. 60x72 pixels.
     /// Corresponds to PresentationPriority::Top in the Aurora ribbon.
-    pub fn large<'a, Message>(label: &'a str, icon: FluentIcon) -> Button<'a, Message>
+    pub fn large<'a, Message>(label: &'a str, icon: impl Into<Icon>) -> Button<'a, Message>
     where
         Message: 'a + Clone,
     {
         Button::new(center(
             Column::new()
-                .push(
-                    text::icon(icon.codepoint())
-                        .height(Length::FillPortion(1))
-                        .size(28),
-                )
+                .push(center(icon.into().view(28.0)).height(Length::FillPortion(1)))
                 .push(text::caption1(label).height(Length::FillPortion(1)))
                 .align_x(Horizontal::Center),
         ))
         .width(60)
-        .height(72)
+        .height(density::metrics().ribbon_large_height)
         .padding(0)
-        .style(style::button::transparent)
+        .class("transparent")
     }
 
     /// Medium ribbon button: icon and text side-by-side. 24px height.
     /// Corresponds to PresentationPriority::Medium in the Aurora ribbon.
-    pub fn medium<'a, Message>(label: &'a str, icon: FluentIcon) -> Button<'a, Message>
+    pub fn medium<'a, Message>(label: &'a str, icon: impl Into<Icon>) -> Button<'a, Message>
     where
         Message: 'a + Clone,
     {
         Button::new(
             Row::new()
-                .push(text::icon(icon.codepoint()))
+                .push(icon.into().view(icon::DEFAULT_SIZE))
                 .push(text::caption1(label))
                 .height(Length::Fill)
                 .align_y(Vertical::Center)
                 .spacing(4),
         )
         .width(Length::Shrink)
-        .height(24)
+        .height(density::metrics().ribbon_item_height)
         .padding(Padding::new(0.0).left(4).right(8))
-        .style(style::button::transparent)
+        .class("transparent")
     }
 
     /// Small ribbon button: icon only. 24x24 pixels.
     /// Corresponds to PresentationPriority::Low in the Aurora ribbon.
     #[allow(dead_code)]
-    pub fn small<'a, Message>(icon: FluentIcon) -> Button<'a, Message>
+    pub fn small<'a, Message>(icon: impl Into<Icon>) -> Button<'a, Message>
     where
         Message: 'a + Clone,
     {
-        Button::new(center(text::icon(icon.codepoint())))
-            .width(24)
-            .height(24)
+        Button::new(center(icon.into().view(icon::DEFAULT_SIZE)))
+            .width(density::metrics().ribbon_item_height)
+            .height(density::metrics().ribbon_item_height)
             .padding(4)
-            .style(style::button::transparent)
+            .class("transparent")
     }
 
     /// Toggle variant of large button - shows selected state via accent styling.
     pub fn toggle_large<'a, Message>(
         label: &'a str,
-        icon: FluentIcon,
+        icon: impl Into<Icon>,
         selected: bool,
     ) -> Button<'a, Message>
     where
@@ -133,40 +129,36 @@ pub mod button {
     {
         let btn = Button::new(center(
             Column::new()
-                .push(
-                    text::icon(icon.codepoint())
-                        .height(Length::FillPortion(1))
-                        .size(28),
-                )
+                .push(center(icon.into().view(28.0)).height(Length::FillPortion(1)))
                 .push(text::caption1(label).height(Length::FillPortion(1)))
                 .align_x(Horizontal::Center),
         ))
         .width(60)
-        .height(72)
+        .height(density::metrics().ribbon_large_height)
         .padding(0);
 
         if selected {
-            btn.style(style::button::secondary)
+            btn.class("secondary")
         } else {
-            btn.style(style::button::transparent)
+            btn.class("transparent")
         }
     }
 
     /// Toggle variant of small button - shows selected state via accent styling.
     #[allow(dead_code)]
-    pub fn toggle_small<'a, Message>(icon: FluentIcon, selected: bool) -> Button<'a, Message>
+    pub fn toggle_small<'a, Message>(icon: impl Into<Icon>, selected: bool) -> Button<'a, Message>
     where
         Message: 'a + Clone,
     {
-        let btn = Button::new(center(text::icon(icon.codepoint())))
-            .width(24)
-            .height(24)
+        let btn = Button::new(center(icon.into().view(icon::DEFAULT_SIZE)))
+            .width(density::metrics().ribbon_item_height)
+            .height(density::metrics().ribbon_item_height)
             .padding(4);
 
         if selected {
-            btn.style(style::button::secondary)
+            btn.class("secondary")
         } else {
-            btn.style(style::button::transparent)
+            btn.class("transparent")
         }
     }
 }
@@ -174,7 +166,9 @@ pub mod button {
 pub mod split_button {
 
     use crate::{
+        density,
         fluent_icon::FluentIcon,
+        icon::Icon,
         theme,
         widget::{text, Button, Column, DropDown, Element, Quad},
     };
@@ -188,7 +182,7 @@ pub mod split_button {
 
     pub fn large<'a, Message>(
         label: &'a str,
-        icon: FluentIcon,
+        icon: impl Into<Icon>,
         overlay: impl Into<Element<'a, Message>>,
         on_press: Message,
         on_open: Message,
@@ -282,7 +276,7 @@ pub mod split_button {
             }
         }
 
-        let icon_button = Button::new(center(text::icon(icon.codepoint()).size(28)))
+        let icon_button = Button::new(center(icon.into().view(28.0)))
             .width(Length::Fill)
             .height(Length::FillPortion(1))
             .padding(0)
@@ -293,9 +287,7 @@ pub mod split_button {
             Column::new()
                 .push(text::caption1(label))
                 .push(
-                    text::icon(FluentIcon::ChevronDown.codepoint())
-                        .size(10)
-                        .align_y(Vertical::Center),
+                    text::icon(FluentIcon::ChevronDown.codepoint(), 10.0).align_y(Vertical::Center),
                 )
                 .width(Length::Fill)
                 .align_x(Horizontal::Center),
@@ -314,7 +306,7 @@ pub mod split_button {
                 .height(Length::Fill),
         ))
         .width(40)
-        .height(72)
+        .height(density::metrics().ribbon_large_height)
         .style(quad_style);
 
         DropDown::new(underlay, overlay, expanded).on_dismiss(on_dismiss)
@@ -325,15 +317,12 @@ pub mod split_button {
 /// alignment controls). Mirrors Aurora's CommandButtonStrip.
 pub mod button_strip {
     use crate::{
-        fluent_icon::FluentIcon,
-        widget::{text, Button, Element, Row},
+        density,
+        icon::{self, Icon},
+        widget::{Button, Element, Row},
     };
 
-    use iced::{
-        border::Radius,
-        widget::center,
-        Border, Shadow,
-    };
+    use iced::{border::Radius, widget::center, Border, Shadow};
 
     use crate::theme;
 
@@ -412,13 +401,13 @@ pub mod button_strip {
     }
 
     /// A single strip button (icon-only, no border radius) for use within a strip.
-    pub fn icon_button<'a, Message>(icon: FluentIcon, selected: bool) -> Button<'a, Message>
+    pub fn icon_button<'a, Message>(icon: impl Into<Icon>, selected: bool) -> Button<'a, Message>
     where
         Message: 'a + Clone,
     {
-        let btn = Button::new(center(text::icon(icon.codepoint())))
-            .width(24)
-            .height(24)
+        let btn = Button::new(center(icon.into().view(icon::DEFAULT_SIZE)))
+            .width(density::metrics().ribbon_item_height)
+            .height(density::metrics().ribbon_item_height)
             .padding(0);
 
         if selected {
@@ -430,15 +419,13 @@ pub mod button_strip {
 
     /// Creates a horizontal strip of icon buttons from a slice of (icon, selected) pairs.
     /// Returns the Row so callers can attach .on_press to each button individually.
-    pub fn horizontal<'a, Message>(
-        buttons: Vec<Element<'a, Message>>,
-    ) -> Element<'a, Message>
+    pub fn horizontal<'a, Message>(buttons: Vec<Element<'a, Message>>) -> Element<'a, Message>
     where
         Message: 'a + Clone,
     {
         Row::from_vec(buttons)
             .spacing(0)
-            .height(24)
+            .height(density::metrics().ribbon_item_height)
             .into()
     }
 }
@@ -446,16 +433,9 @@ pub mod button_strip {
 /// A RibbonBand is a labeled group of controls within a task.
 /// It wraps its content with a title label at the bottom, matching the Aurora pattern.
 pub mod band {
-    use crate::{
-        widget::{text, Container, Element, Row},
-    };
+    use crate::widget::{text, Container, Element, Row};
 
-    use iced::{
-        alignment::Horizontal,
-        border::Radius,
-        widget::column,
-        Border, Length,
-    };
+    use iced::{alignment::Horizontal, border::Radius, widget::column, Border, Length};
 
     /// Creates a ribbon band with a title label at the bottom.
     /// The `content` is the main body of the band (buttons, strips, etc.).
@@ -518,15 +498,11 @@ pub mod band {
 /// Mirrors Aurora's RibbonTask tabs.
 pub mod tab_bar {
     use crate::{
-        theme,
+        density, theme,
         widget::{text, Button, Container, Element, Row},
     };
 
-    use iced::{
-        alignment::Vertical,
-        border::Radius,
-        Border, Length, Padding, Shadow,
-    };
+    use iced::{alignment::Vertical, border::Radius, Border, Length, Padding, Shadow};
 
     type ButtonStatus = iced::widget::button::Status;
     type ButtonStyle = iced::widget::button::Style;
@@ -595,10 +571,7 @@ pub mod tab_bar {
     ) -> impl Fn(&theme::Theme, ButtonStatus) -> ButtonStyle {
         move |theme: &theme::Theme, status: ButtonStatus| {
             let palette = theme.palette();
-            let tinted = iced::Color {
-                a: 0.15,
-                ..hue
-            };
+            let tinted = iced::Color { a: 0.15, ..hue };
             let base = ButtonStyle {
                 background: Some(tinted.into()),
                 text_color: palette.text_fill_color_primary,
@@ -633,10 +606,7 @@ pub mod tab_bar {
     ) -> impl Fn(&theme::Theme, ButtonStatus) -> ButtonStyle {
         move |theme: &theme::Theme, status: ButtonStatus| {
             let palette = theme.palette();
-            let tinted = iced::Color {
-                a: 0.3,
-                ..hue
-            };
+            let tinted = iced::Color { a: 0.3, ..hue };
             let base = ButtonStyle {
                 background: Some(tinted.into()),
                 text_color: palette.text_fill_color_primary,
@@ -674,7 +644,7 @@ pub mod tab_bar {
                 .align_y(Vertical::Center)
                 .height(Length::Fill),
         )
-        .height(28)
+        .height(density::metrics().ribbon_tab_height)
         .padding(Padding::new(0.0).left(12).right(12))
         .on_press(on_press);
 
@@ -697,7 +667,7 @@ pub mod tab_bar {
                 .align_y(Vertical::Center)
                 .height(Length::Fill),
         )
-        .height(28)
+        .height(density::metrics().ribbon_tab_height)
         .padding(Padding::new(0.0).left(12).right(12))
         .on_press(on_press);
 
@@ -718,7 +688,7 @@ pub mod tab_bar {
                 .height(Length::Fill)
                 .align_y(Vertical::Bottom),
         )
-        .height(32)
+        .height(density::metrics().ribbon_tab_height + 4.0)
         .padding(Padding::new(0.0).left(4).top(4))
         .into()
     }
@@ -729,11 +699,7 @@ pub mod tab_bar {
 pub mod ribbon_bar {
     use crate::widget::{Container, Element};
 
-    use iced::{
-        border::Radius,
-        widget::column,
-        Border, Length,
-    };
+    use iced::{border::Radius, widget::column, Border, Length};
 
     /// Assembles a full ribbon: tab bar on top, band content below.
     pub fn ribbon<'a, Message: 'a + Clone>(
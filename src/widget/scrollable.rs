@@ -1,7 +1,4 @@
-use crate::{
-    style,
-    widget::{Element, Scrollable},
-};
+use crate::widget::{Element, Scrollable};
 
 use iced::widget::scrollable::Direction;
 
@@ -9,13 +6,13 @@ use iced::widget::scrollable::Direction;
 // like the WinUI ScrollView so this feature is ignored for the moment
 pub fn standard<'a, Message>(content: impl Into<Element<'a, Message>>) -> Scrollable<'a, Message> {
     Scrollable::with_direction(content, Direction::Vertical(scrollbar::standard()))
-        .style(style::scrollable::default)
 }
 
 /// Horizontal-only scrollable with a thin scrollbar, used for ribbon band overflow.
-pub fn horizontal<'a, Message>(content: impl Into<Element<'a, Message>>) -> Scrollable<'a, Message> {
+pub fn horizontal<'a, Message>(
+    content: impl Into<Element<'a, Message>>,
+) -> Scrollable<'a, Message> {
     Scrollable::with_direction(content, Direction::Horizontal(scrollbar::horizontal()))
-        .style(style::scrollable::default)
 }
 
 pub mod scrollbar {
@@ -1,7 +1,10 @@
 use crate::{
+    density,
     fluent_icon::FluentIcon,
-    style,
-    widget::{scrollable, text, Button, Column, Container, DropDown, Element, Row, Text},
+    icon::{self, Icon},
+    widget::{
+        scrollable, split_view::SplitView, text, Button, Column, Container, DropDown, Element, Row,
+    },
 };
 
 use iced::{
@@ -27,29 +30,57 @@ pub struct SideNav<'a, Message>
 where
     Message: 'a + Clone,
 {
-    full_width: Length,
+    full_width: f32,
+    min_width: f32,
+    max_width: f32,
     height: Length,
     display_mode: DisplayMode,
     groups: Vec<Group<'a, Message>>,
     footer_groups: Vec<Group<'a, Message>>,
     on_nav_button_pressed: Message,
+    on_resize: Option<Box<dyn Fn(f32) -> Message + 'a>>,
 }
 
 impl<'a, Message> SideNav<'a, Message>
 where
     Message: 'a + Clone,
 {
+    pub const DEFAULT_FULL_WIDTH: f32 = 300.0;
+    pub const MIN_FULL_WIDTH: f32 = 200.0;
+    pub const MAX_FULL_WIDTH: f32 = 500.0;
+
     pub fn new(display_mode: DisplayMode, on_nav_button_pressed: Message) -> Self {
         Self {
-            full_width: Length::Fixed(300.0),
+            full_width: Self::DEFAULT_FULL_WIDTH,
+            min_width: Self::MIN_FULL_WIDTH,
+            max_width: Self::MAX_FULL_WIDTH,
             height: Length::Fill,
             display_mode,
             groups: Vec::new(),
             footer_groups: Vec::new(),
             on_nav_button_pressed,
+            on_resize: None,
         }
     }
 
+    pub fn full_width(mut self, width: f32) -> Self {
+        self.full_width = width;
+        self
+    }
+
+    pub fn width_limits(mut self, min: f32, max: f32) -> Self {
+        self.min_width = min;
+        self.max_width = max.max(min);
+        self
+    }
+
+    // Shows a splitter on the pane's edge in Full mode. The new width is emitted
+    // rather than stored so the host can persist it.
+    pub fn on_resize(mut self, on_resize: impl Fn(f32) -> Message + 'a) -> Self {
+        self.on_resize = Some(Box::new(on_resize));
+        self
+    }
+
     pub fn with_groups(mut self, items: impl IntoIterator<Item = Group<'a, Message>>) -> Self {
         self.groups.extend(items);
         self
@@ -64,10 +95,8 @@ where
     }
 
     fn nav_button(&self) -> Button<'a, Message> {
-        button_base(compact_view_contents(
-            FluentIcon::GlobalNavButton.codepoint(),
-        ))
-        .on_press(self.on_nav_button_pressed.clone())
+        button_base(compact_view_contents(FluentIcon::GlobalNavButton.into()))
+            .on_press(self.on_nav_button_pressed.clone())
     }
 
     fn compact_view(self) -> Element<'a, Message> {
@@ -107,10 +136,18 @@ where
         contents.push(group_items);
         contents.extend(footer_group_items);
 
-        Column::with_children(contents)
-            .width(self.full_width)
-            .height(self.height)
-            .into()
+        let full_width = self.full_width.clamp(self.min_width, self.max_width);
+        let column = Column::with_children(contents).height(self.height);
+
+        match self.on_resize {
+            Some(on_resize) => SplitView::pane(column, full_width)
+                .limits(self.min_width, self.max_width)
+                .default_position(Self::DEFAULT_FULL_WIDTH)
+                .height(self.height)
+                .on_resize(on_resize)
+                .into(),
+            None => column.width(full_width).into(),
+        }
     }
 }
 
@@ -130,7 +167,7 @@ pub struct Group<'a, Message>
 where
     Message: 'a + Clone,
 {
-    icon: char,
+    icon: Icon,
     label: Fragment<'a>,
     overlay_width: Length,
     items: Vec<Item<'a, Message>>,
@@ -143,9 +180,9 @@ impl<'a, Message> Group<'a, Message>
 where
     Message: 'a + Clone,
 {
-    pub fn new(icon: char, label: impl IntoFragment<'a>, on_press: Message) -> Self {
+    pub fn new(icon: impl Into<Icon>, label: impl IntoFragment<'a>, on_press: Message) -> Self {
         Self {
-            icon,
+            icon: icon.into(),
             label: label.into_fragment(),
             overlay_width: 300.into(),
             items: Vec::new(),
@@ -186,7 +223,7 @@ where
 
         let header = button_base(
             full_view_contents(Some(self.icon), self.label)
-                .push_maybe(indicator.map(|chevron| text::icon(chevron.codepoint()).size(8))),
+                .push_maybe(indicator.map(|chevron| text::icon(chevron.codepoint(), 8.0))),
         )
         .on_press(self.on_press);
 
@@ -219,7 +256,7 @@ where
             .into()
         })))
         .width(self.overlay_width)
-        .style(style::container::overlay);
+        .class("overlay");
 
         let button = DropDown::new(underlay, overlay, self.expanded)
             .width(Length::Shrink)
@@ -237,7 +274,7 @@ pub struct Item<'a, Message>
 where
     Message: 'a + Clone,
 {
-    icon: Option<char>,
+    icon: Option<Icon>,
     label: Fragment<'a>,
     on_press: Message,
 }
@@ -246,7 +283,7 @@ impl<'a, Message> Item<'a, Message>
 where
     Message: 'a + Clone,
 {
-    pub fn new(icon: Option<char>, label: impl IntoFragment<'a>, on_press: Message) -> Self {
+    pub fn new(icon: Option<Icon>, label: impl IntoFragment<'a>, on_press: Message) -> Self {
         Self {
             icon,
             label: label.into_fragment(),
@@ -258,19 +295,19 @@ where
 fn button_base<'a, Message>(content: impl Into<Element<'a, Message>>) -> Button<'a, Message> {
     Button::new(content)
         .width(Length::Fill)
-        .height(36.0)
+        .height(density::metrics().nav_item_height)
         .padding([0.0, 12.0])
-        .style(style::button::nav_item)
+        .class("nav_item")
 }
 
 fn full_view_contents<'a, Message: 'a>(
-    icon: Option<char>,
+    icon: Option<Icon>,
     label: impl IntoFragment<'a>,
 ) -> Row<'a, Message> {
-    let icon: Element<'a, Message> = icon
-        .map_or(horizontal_space().width(16).into(), |codepoint| {
-            text::icon(codepoint).into()
-        });
+    let icon: Element<'a, Message> = icon.map_or(
+        horizontal_space().width(icon::DEFAULT_SIZE).into(),
+        |icon| icon.view(icon::DEFAULT_SIZE),
+    );
 
     row![icon, text::body1(label), horizontal_space(),]
         .height(Length::Fill)
@@ -278,8 +315,8 @@ fn full_view_contents<'a, Message: 'a>(
         .align_y(Vertical::Center)
 }
 
-fn compact_view_contents<'a>(icon: char) -> Text<'a> {
-    text::icon(icon)
+fn compact_view_contents<'a, Message: 'a>(icon: Icon) -> Container<'a, Message> {
+    Container::new(icon.view(icon::DEFAULT_SIZE))
         .height(Length::Fill)
         .align_y(Vertical::Center)
 }
@@ -1,4 +1,4 @@
-use crate::{style, widget::Slider};
+use crate::widget::Slider;
 
 use std::ops::RangeInclusive;
 
@@ -12,5 +12,5 @@ where
     F: 'a + Fn(T) -> Message,
     Message: Clone,
 {
-    Slider::new(range, value, on_change).style(style::slider::default)
+    Slider::new(range, value, on_change)
 }
@@ -0,0 +1,429 @@
+// A horizontal splitter with a draggable handle between two panes. With only a
+// first pane it acts as a resize handle on that pane's trailing edge, which is
+// how the SideNav uses it.
+use crate::{
+    style::{
+        self,
+        split_view::{Catalog, Status, Style, StyleFn},
+    },
+    theme,
+};
+
+use iced::{
+    advanced::{
+        self,
+        layout::{Limits, Node},
+        mouse::{click::Kind, Click},
+        overlay, renderer,
+        widget::{tree, Tree},
+        Clipboard, Layout, Shell, Widget,
+    },
+    event,
+    mouse::{self, Cursor},
+    Border, Element, Event, Length, Point, Rectangle, Shadow, Size, Vector,
+};
+
+pub struct SplitView<'a, Message, Theme = theme::Theme, Renderer = iced::Renderer>
+where
+    Theme: Catalog,
+{
+    panes: Vec<Element<'a, Message, Theme, Renderer>>,
+    position: f32,
+    min: f32,
+    max: f32,
+    default_position: f32,
+    handle_width: f32,
+    width: Length,
+    height: Length,
+    on_resize: Option<Box<dyn Fn(f32) -> Message + 'a>>,
+    class: Theme::Class<'a>,
+}
+
+impl<'a, Message, Theme, Renderer> SplitView<'a, Message, Theme, Renderer>
+where
+    Theme: Catalog,
+{
+    pub const DEFAULT_HANDLE_WIDTH: f32 = 6.0;
+
+    // `position` is the width of the first pane
+    pub fn new(
+        first: impl Into<Element<'a, Message, Theme, Renderer>>,
+        second: impl Into<Element<'a, Message, Theme, Renderer>>,
+        position: f32,
+    ) -> Self {
+        Self::with_panes(vec![first.into(), second.into()], position)
+    }
+
+    // A single resizable pane with the handle on its trailing edge
+    pub fn pane(content: impl Into<Element<'a, Message, Theme, Renderer>>, position: f32) -> Self {
+        Self::with_panes(vec![content.into()], position).width(Length::Shrink)
+    }
+
+    fn with_panes(panes: Vec<Element<'a, Message, Theme, Renderer>>, position: f32) -> Self {
+        Self {
+            panes,
+            position,
+            min: 0.0,
+            max: f32::INFINITY,
+            default_position: position,
+            handle_width: Self::DEFAULT_HANDLE_WIDTH,
+            width: Length::Fill,
+            height: Length::Fill,
+            on_resize: None,
+            class: Theme::default(),
+        }
+    }
+
+    pub fn limits(mut self, min: f32, max: f32) -> Self {
+        self.min = min;
+        self.max = max.max(min);
+        self
+    }
+
+    // Position restored when the handle is double-clicked
+    pub fn default_position(mut self, position: f32) -> Self {
+        self.default_position = position;
+        self
+    }
+
+    pub fn handle_width(mut self, width: f32) -> Self {
+        self.handle_width = width;
+        self
+    }
+
+    pub fn width(mut self, width: impl Into<Length>) -> Self {
+        self.width = width.into();
+        self
+    }
+
+    pub fn height(mut self, height: impl Into<Length>) -> Self {
+        self.height = height.into();
+        self
+    }
+
+    pub fn on_resize(mut self, on_resize: impl Fn(f32) -> Message + 'a) -> Self {
+        self.on_resize = Some(Box::new(on_resize));
+        self
+    }
+
+    pub fn style(mut self, style: impl Fn(&Theme, Status) -> Style + 'a) -> Self
+    where
+        Theme::Class<'a>: From<StyleFn<'a, Theme>>,
+    {
+        self.class = (Box::new(style) as StyleFn<'a, Theme>).into();
+        self
+    }
+
+    pub fn class(mut self, class: impl Into<Theme::Class<'a>>) -> Self {
+        self.class = class.into();
+        self
+    }
+
+    fn clamped(&self, position: f32) -> f32 {
+        position.clamp(self.min, self.max)
+    }
+}
+
+#[derive(Default)]
+struct State {
+    // Offset between the cursor and the handle's leading edge while dragging
+    drag_offset: Option<f32>,
+    last_click: Option<Click>,
+}
+
+fn handle_bounds(layout: Layout<'_>) -> Rectangle {
+    layout
+        .children()
+        .nth(1)
+        .expect("failed to get handle layout")
+        .bounds()
+}
+
+fn pane_layouts(layout: Layout<'_>) -> impl Iterator<Item = Layout<'_>> {
+    // The handle sits between the first and second pane
+    layout
+        .children()
+        .enumerate()
+        .filter_map(|(index, child)| (index != 1).then_some(child))
+}
+
+impl<'a, Message, Theme, Renderer> Widget<Message, Theme, Renderer>
+    for SplitView<'a, Message, Theme, Renderer>
+where
+    Renderer: advanced::Renderer,
+    Theme: 'a + Catalog,
+{
+    fn size(&self) -> Size<Length> {
+        Size::new(self.width, self.height)
+    }
+
+    fn tag(&self) -> tree::Tag {
+        tree::Tag::of::<State>()
+    }
+
+    fn state(&self) -> tree::State {
+        tree::State::new(State::default())
+    }
+
+    fn children(&self) -> Vec<Tree> {
+        self.panes.iter().map(Tree::new).collect()
+    }
+
+    fn diff(&self, tree: &mut Tree) {
+        tree.diff_children(&self.panes);
+    }
+
+    fn layout(&self, tree: &mut Tree, renderer: &Renderer, limits: &Limits) -> Node {
+        let limits = limits.width(self.width).height(self.height);
+        let max = limits.max();
+        let position = self
+            .clamped(self.position)
+            .min(max.width - self.handle_width);
+        let position = position.max(0.0);
+
+        let first = self.panes[0].as_widget().layout(
+            &mut tree.children[0],
+            renderer,
+            &Limits::new(Size::ZERO, Size::new(position, max.height))
+                .width(position)
+                .height(max.height),
+        );
+
+        let handle =
+            Node::new(Size::new(self.handle_width, max.height)).move_to(Point::new(position, 0.0));
+
+        let mut children = vec![first, handle];
+
+        if let Some(second) = self.panes.get(1) {
+            let x = position + self.handle_width;
+            let width = (max.width - x).max(0.0);
+
+            children.push(
+                second
+                    .as_widget()
+                    .layout(
+                        &mut tree.children[1],
+                        renderer,
+                        &Limits::new(Size::ZERO, Size::new(width, max.height))
+                            .width(width)
+                            .height(max.height),
+                    )
+                    .move_to(Point::new(x, 0.0)),
+            );
+        }
+
+        let width = if self.panes.len() == 1 {
+            position + self.handle_width
+        } else {
+            max.width
+        };
+
+        Node::with_children(
+            limits.resolve(self.width, self.height, Size::new(width, max.height)),
+            children,
+        )
+    }
+
+    fn operate(
+        &self,
+        tree: &mut Tree,
+        layout: Layout<'_>,
+        renderer: &Renderer,
+        operation: &mut dyn advanced::widget::Operation,
+    ) {
+        operation.container(None, layout.bounds(), &mut |operation| {
+            self.panes
+                .iter()
+                .zip(&mut tree.children)
+                .zip(pane_layouts(layout))
+                .for_each(|((pane, state), layout)| {
+                    pane.as_widget().operate(state, layout, renderer, operation);
+                });
+        });
+    }
+
+    fn on_event(
+        &mut self,
+        tree: &mut Tree,
+        event: Event,
+        layout: Layout<'_>,
+        cursor: Cursor,
+        renderer: &Renderer,
+        clipboard: &mut dyn Clipboard,
+        shell: &mut Shell<'_, Message>,
+        viewport: &Rectangle,
+    ) -> event::Status {
+        let handle = handle_bounds(layout);
+
+        if self.on_resize.is_some() {
+            let state = tree.state.downcast_mut::<State>();
+
+            match event {
+                Event::Mouse(mouse::Event::ButtonPressed(mouse::Button::Left)) => {
+                    if let Some(position) = cursor.position_over(handle) {
+                        let click = Click::new(position, mouse::Button::Left, state.last_click);
+                        state.last_click = Some(click);
+
+                        if matches!(click.kind(), Kind::Double) {
+                            state.drag_offset = None;
+
+                            if let Some(on_resize) = &self.on_resize {
+                                shell.publish(on_resize(self.clamped(self.default_position)));
+                            }
+                        } else {
+                            state.drag_offset = Some(position.x - handle.x);
+                        }
+
+                        return event::Status::Captured;
+                    }
+                }
+                Event::Mouse(mouse::Event::CursorMoved { position }) => {
+                    if let Some(offset) = state.drag_offset {
+                        let new_position = self.clamped(position.x - offset - layout.bounds().x);
+
+                        if new_position != self.position {
+                            if let Some(on_resize) = &self.on_resize {
+                                shell.publish(on_resize(new_position));
+                            }
+                        }
+
+                        return event::Status::Captured;
+                    }
+                }
+                Event::Mouse(mouse::Event::ButtonReleased(mouse::Button::Left))
+                    if state.drag_offset.is_some() =>
+                {
+                    state.drag_offset = None;
+                    return event::Status::Captured;
+                }
+                _ => {}
+            }
+        }
+
+        self.panes
+            .iter_mut()
+            .zip(&mut tree.children)
+            .zip(pane_layouts(layout))
+            .map(|((pane, state), layout)| {
+                pane.as_widget_mut().on_event(
+                    state,
+                    event.clone(),
+                    layout,
+                    cursor,
+                    renderer,
+                    clipboard,
+                    shell,
+                    viewport,
+                )
+            })
+            .fold(event::Status::Ignored, event::Status::merge)
+    }
+
+    fn draw(
+        &self,
+        tree: &Tree,
+        renderer: &mut Renderer,
+        theme: &Theme,
+        style: &renderer::Style,
+        layout: Layout<'_>,
+        cursor: Cursor,
+        viewport: &Rectangle,
+    ) {
+        self.panes
+            .iter()
+            .zip(&tree.children)
+            .zip(pane_layouts(layout))
+            .for_each(|((pane, state), layout)| {
+                pane.as_widget()
+                    .draw(state, renderer, theme, style, layout, cursor, viewport);
+            });
+
+        let handle = handle_bounds(layout);
+        let state = tree.state.downcast_ref::<State>();
+
+        let status = if state.drag_offset.is_some() {
+            Status::Dragged
+        } else if self.on_resize.is_some() && cursor.is_over(handle) {
+            Status::Hovered
+        } else {
+            Status::Active
+        };
+
+        let handle_style = style::split_view::Catalog::style(theme, &self.class, status);
+        let width = handle_style.handle_width.min(handle.width);
+
+        renderer.fill_quad(
+            renderer::Quad {
+                bounds: Rectangle {
+                    x: handle.center_x() - width / 2.0,
+                    width,
+                    ..handle
+                },
+                border: Border::default().rounded(width / 2.0),
+                shadow: Shadow::default(),
+            },
+            handle_style.handle_colour,
+        );
+    }
+
+    fn mouse_interaction(
+        &self,
+        tree: &Tree,
+        layout: Layout<'_>,
+        cursor: Cursor,
+        viewport: &Rectangle,
+        renderer: &Renderer,
+    ) -> mouse::Interaction {
+        let state = tree.state.downcast_ref::<State>();
+
+        if self.on_resize.is_some()
+            && (state.drag_offset.is_some() || cursor.is_over(handle_bounds(layout)))
+        {
+            return mouse::Interaction::ResizingHorizontally;
+        }
+
+        self.panes
+            .iter()
+            .zip(&tree.children)
+            .zip(pane_layouts(layout))
+            .map(|((pane, state), layout)| {
+                pane.as_widget()
+                    .mouse_interaction(state, layout, cursor, viewport, renderer)
+            })
+            .max()
+            .unwrap_or_default()
+    }
+
+    fn overlay<'b>(
+        &'b mut self,
+        tree: &'b mut Tree,
+        layout: Layout<'_>,
+        renderer: &Renderer,
+        translation: Vector,
+    ) -> Option<overlay::Element<'b, Message, Theme, Renderer>> {
+        let children = self
+            .panes
+            .iter_mut()
+            .zip(&mut tree.children)
+            .zip(pane_layouts(layout))
+            .filter_map(|((pane, state), layout)| {
+                pane.as_widget_mut()
+                    .overlay(state, layout, renderer, translation)
+            })
+            .collect::<Vec<_>>();
+
+        (!children.is_empty()).then(|| overlay::Group::with_children(children).overlay())
+    }
+}
+
+impl<'a, Message, Theme, Renderer> From<SplitView<'a, Message, Theme, Renderer>>
+    for Element<'a, Message, Theme, Renderer>
+where
+    Message: 'a,
+    Theme: 'a + Catalog,
+    Renderer: 'a + advanced::Renderer,
+{
+    fn from(split_view: SplitView<'a, Message, Theme, Renderer>) -> Self {
+        Element::new(split_view)
+    }
+}
@@ -0,0 +1,1122 @@
+use crate::{
+    density,
+    fluent_icon::FluentIcon,
+    font,
+    icon::Icon,
+    style::tab_view::{Status, Style, StyleFn},
+    theme::{
+        class::{self, Class},
+        Theme,
+    },
+    widget::{text, Button, Container, Element, Row},
+};
+
+use iced::{
+    advanced::{
+        layout::{Limits, Node},
+        overlay,
+        renderer::{self, Renderer as _},
+        text::{LineHeight, Renderer as _, Shaping, Text, Wrapping},
+        widget::{tree, Operation, Tree},
+        Clipboard, Layout, Shell, Widget,
+    },
+    alignment::{Horizontal, Vertical},
+    border::Radius,
+    event,
+    keyboard::{self, key::Named, Key},
+    mouse::{self, Cursor},
+    touch, Alignment, Border, Event, Length, Padding, Pixels, Point, Rectangle, Size, Vector,
+};
+
+type Renderer = iced::Renderer;
+
+// TabView sizes from
+// https://github.com/microsoft/microsoft-ui-xaml/blob/main/src/controls/dev/TabView/TabView_themeresources.xaml
+const STRIP_HEIGHT: f32 = 40.0;
+const TAB_HEIGHT: f32 = 32.0;
+const MIN_TAB_WIDTH: f32 = 100.0;
+const MAX_TAB_WIDTH: f32 = 240.0;
+const INSET: f32 = 8.0;
+const ADD_WIDTH: f32 = 40.0;
+const ARROW_WIDTH: f32 = 32.0;
+// How far a tab has to be pulled before it comes loose and can be reordered
+const DRAG_THRESHOLD: f32 = 4.0;
+const SCROLL_LINE: f32 = 40.0;
+
+pub struct Tab<'a, Message> {
+    icon: Option<Icon>,
+    title: String,
+    content: Element<'a, Message>,
+    is_closable: bool,
+}
+
+impl<'a, Message> Tab<'a, Message> {
+    pub fn new(title: impl Into<String>, content: impl Into<Element<'a, Message>>) -> Self {
+        Self {
+            icon: None,
+            title: title.into(),
+            content: content.into(),
+            is_closable: true,
+        }
+    }
+
+    pub fn icon(mut self, icon: impl Into<Icon>) -> Self {
+        self.icon = Some(icon.into());
+        self
+    }
+
+    pub fn closable(mut self, is_closable: bool) -> Self {
+        self.is_closable = is_closable;
+        self
+    }
+}
+
+// Based on https://learn.microsoft.com/en-us/windows/apps/design/controls/tab-view
+//
+// The host owns the tabs, the widget only reports what the user asked for: which
+// tab to select, close or move, and when a new one should be added. Tabs share
+// the width between them and scroll with arrows once they no longer fit
+pub struct TabView<'a, Message> {
+    tabs: Vec<Tab<'a, Message>>,
+    selected: usize,
+    on_select: Box<dyn Fn(usize) -> Message + 'a>,
+    on_close: Option<Box<dyn Fn(usize) -> Message + 'a>>,
+    on_reorder: Option<Box<dyn Fn(usize, usize) -> Message + 'a>>,
+    on_add: Option<Message>,
+    width: Length,
+    height: Length,
+    class: Class<StyleFn>,
+}
+
+impl<'a, Message> TabView<'a, Message>
+where
+    Message: 'a + Clone,
+{
+    pub fn new(selected: usize, on_select: impl Fn(usize) -> Message + 'a) -> Self {
+        Self {
+            tabs: Vec::new(),
+            selected,
+            on_select: Box::new(on_select),
+            on_close: None,
+            on_reorder: None,
+            on_add: None,
+            width: Length::Fill,
+            height: Length::Fill,
+            class: class::DEFAULT.into(),
+        }
+    }
+
+    pub fn push(mut self, tab: Tab<'a, Message>) -> Self {
+        self.tabs.push(tab);
+        self
+    }
+
+    pub fn extend(mut self, tabs: impl IntoIterator<Item = Tab<'a, Message>>) -> Self {
+        self.tabs.extend(tabs);
+        self
+    }
+
+    // Called from a tab's close button, a middle click on the tab or Ctrl+W
+    pub fn on_close(mut self, on_close: impl Fn(usize) -> Message + 'a) -> Self {
+        self.on_close = Some(Box::new(on_close));
+        self
+    }
+
+    // Called with the tab's old and new index once it's dropped, tabs can only be
+    // dragged when this is set
+    pub fn on_reorder(mut self, on_reorder: impl Fn(usize, usize) -> Message + 'a) -> Self {
+        self.on_reorder = Some(Box::new(on_reorder));
+        self
+    }
+
+    // Shows the add tab button after the tabs
+    pub fn on_add(mut self, message: Message) -> Self {
+        self.on_add = Some(message);
+        self
+    }
+
+    pub fn width(mut self, width: impl Into<Length>) -> Self {
+        self.width = width.into();
+        self
+    }
+
+    pub fn height(mut self, height: impl Into<Length>) -> Self {
+        self.height = height.into();
+        self
+    }
+
+    pub fn style(mut self, style: impl Fn(&Theme, Status) -> Style + 'static) -> Self {
+        self.class = Class::Custom(Box::new(style));
+        self
+    }
+
+    pub fn class(mut self, class: impl Into<Class<StyleFn>>) -> Self {
+        self.class = class.into();
+        self
+    }
+}
+
+impl<'a, Message> From<TabView<'a, Message>> for Element<'a, Message>
+where
+    Message: 'a + Clone,
+{
+    fn from(tab_view: TabView<'a, Message>) -> Self {
+        let TabView {
+            tabs,
+            selected,
+            on_select,
+            on_close,
+            on_reorder,
+            on_add,
+            width,
+            height,
+            class,
+        } = tab_view;
+
+        let metrics = density::metrics();
+        let selected = selected.min(tabs.len().saturating_sub(1));
+        let mut content = None;
+        let mut closable = Vec::with_capacity(tabs.len());
+
+        let headers = tabs
+            .into_iter()
+            .enumerate()
+            .map(|(index, tab)| {
+                if index == selected {
+                    content = Some(tab.content);
+                }
+
+                let is_closable = tab.is_closable && on_close.is_some();
+                closable.push(is_closable);
+
+                let close = on_close.as_ref().filter(|_| is_closable).map(|on_close| {
+                    Button::new(text::icon(FluentIcon::Cancel.codepoint(), 10.0))
+                        .width(metrics.small_icon_button_size)
+                        .height(metrics.small_icon_button_size)
+                        .padding((metrics.small_icon_button_size - 10.0) / 2.0)
+                        .class("transparent")
+                        .on_press(on_close(index))
+                });
+
+                Row::new()
+                    .push_maybe(tab.icon.map(|icon| icon.view(16.0)))
+                    .push(
+                        Container::new(text::body1(tab.title).wrapping(Wrapping::None))
+                            .width(Length::Fill)
+                            .clip(true),
+                    )
+                    .push_maybe(close)
+                    .spacing(8)
+                    .padding(Padding::ZERO.left(12).right(4))
+                    .width(Length::Fill)
+                    .height(Length::Fill)
+                    .align_y(Alignment::Center)
+                    .into()
+            })
+            .collect();
+
+        let add = on_add.map(|message| {
+            Button::new(text::icon(FluentIcon::Add.codepoint(), 12.0))
+                .width(metrics.icon_button_size)
+                .height(metrics.icon_button_size)
+                .padding((metrics.icon_button_size - 12.0) / 2.0)
+                .class("transparent")
+                .on_press(message)
+                .into()
+        });
+
+        Element::new(Strip {
+            headers,
+            closable,
+            add,
+            content: content.unwrap_or_else(|| Row::new().into()),
+            selected,
+            on_select,
+            on_close,
+            on_reorder,
+            width,
+            height,
+            class,
+        })
+    }
+}
+
+// The tab strip and the selected tab's content, laid out as the headers, the
+// add button if there is one and then the content
+struct Strip<'a, Message> {
+    headers: Vec<Element<'a, Message>>,
+    closable: Vec<bool>,
+    add: Option<Element<'a, Message>>,
+    content: Element<'a, Message>,
+    selected: usize,
+    on_select: Box<dyn Fn(usize) -> Message + 'a>,
+    on_close: Option<Box<dyn Fn(usize) -> Message + 'a>>,
+    on_reorder: Option<Box<dyn Fn(usize, usize) -> Message + 'a>>,
+    width: Length,
+    height: Length,
+    class: Class<StyleFn>,
+}
+
+#[derive(Default)]
+struct State {
+    // How far the tabs are scrolled to the left when they overflow
+    offset: f32,
+    max_offset: f32,
+    // Where the visible tabs start and how much room they have, relative to the
+    // widget, set on every layout
+    start: f32,
+    visible: f32,
+    tab_width: f32,
+    // The selection the offset was last adjusted for, a new selection is
+    // scrolled into view
+    selected: Option<usize>,
+    pressed: Option<Press>,
+    // Set by a press inside the strip or its content, cleared by one outside
+    is_focused: bool,
+}
+
+struct Press {
+    index: usize,
+    origin: f32,
+    position: f32,
+    is_dragging: bool,
+}
+
+impl State {
+    fn is_overflowing(&self) -> bool {
+        self.max_offset > 0.0
+    }
+
+    fn tabs(&self, bounds: Rectangle) -> Rectangle {
+        Rectangle {
+            x: bounds.x + self.start,
+            y: bounds.y,
+            width: self.visible,
+            height: STRIP_HEIGHT,
+        }
+    }
+
+    fn arrows(&self, bounds: Rectangle) -> Option<(Rectangle, Rectangle)> {
+        self.is_overflowing().then(|| {
+            let arrow = |x| Rectangle {
+                x,
+                y: bounds.y + STRIP_HEIGHT - TAB_HEIGHT,
+                width: ARROW_WIDTH,
+                height: TAB_HEIGHT,
+            };
+
+            (arrow(bounds.x), arrow(bounds.x + self.start + self.visible))
+        })
+    }
+
+    fn scroll_by(&mut self, delta: f32) {
+        self.offset = (self.offset + delta).clamp(0.0, self.max_offset);
+    }
+
+    // The slot a tab dragged to `x` would drop into
+    fn slot_at(&self, bounds: Rectangle, x: f32, count: usize) -> usize {
+        let slot = (x - bounds.x - self.start + self.offset) / self.tab_width;
+
+        (slot.max(0.0) as usize).min(count.saturating_sub(1))
+    }
+
+    // While a tab is dragged it follows the cursor and the tabs it passes move
+    // over to make room for it
+    fn shift(&self, bounds: Rectangle, index: usize, count: usize) -> f32 {
+        let Some(press) = self.pressed.as_ref().filter(|press| press.is_dragging) else {
+            return 0.0;
+        };
+
+        let from = press.index;
+
+        if index == from {
+            return (press.position - press.origin).clamp(
+                -(from as f32) * self.tab_width,
+                (count - 1 - from) as f32 * self.tab_width,
+            );
+        }
+
+        let to = self.slot_at(bounds, press.position, count);
+
+        if from < to && (from + 1..=to).contains(&index) {
+            -self.tab_width
+        } else if to < from && (to..from).contains(&index) {
+            self.tab_width
+        } else {
+            0.0
+        }
+    }
+}
+
+impl<'a, Message> Strip<'a, Message> {
+    fn elements(&self) -> impl Iterator<Item = &Element<'a, Message>> {
+        self.headers
+            .iter()
+            .chain(&self.add)
+            .chain(std::iter::once(&self.content))
+    }
+
+    fn hovered_tab(&self, state: &State, layout: Layout<'_>, cursor: Cursor) -> Option<usize> {
+        if !cursor.is_over(state.tabs(layout.bounds())) {
+            return None;
+        }
+
+        layout
+            .children()
+            .take(self.headers.len())
+            .position(|layout| cursor.is_over(layout.bounds()))
+    }
+}
+
+impl<'a, Message> Widget<Message, Theme, Renderer> for Strip<'a, Message>
+where
+    Message: 'a + Clone,
+{
+    fn tag(&self) -> tree::Tag {
+        tree::Tag::of::<State>()
+    }
+
+    fn state(&self) -> tree::State {
+        tree::State::new(State::default())
+    }
+
+    fn children(&self) -> Vec<Tree> {
+        self.elements().map(Tree::new).collect()
+    }
+
+    fn diff(&self, tree: &mut Tree) {
+        tree.diff_children(&self.elements().collect::<Vec<_>>());
+    }
+
+    fn size(&self) -> Size<Length> {
+        Size::new(self.width, self.height)
+    }
+
+    fn layout(&self, tree: &mut Tree, renderer: &Renderer, limits: &Limits) -> Node {
+        let state = tree.state.downcast_mut::<State>();
+        let limits = limits.width(self.width).height(self.height);
+
+        let content = self.content.as_widget().layout(
+            tree.children.last_mut().unwrap(),
+            renderer,
+            &limits.shrink(Size::new(0.0, STRIP_HEIGHT)),
+        );
+
+        let size = limits.resolve(
+            self.width,
+            self.height,
+            Size::new(content.size().width, content.size().height + STRIP_HEIGHT),
+        );
+
+        // Tabs share the room equally until they reach their minimum width, after
+        // that the add button moves to the end and the arrows show up
+        let count = self.headers.len();
+        let room = size.width - INSET - ADD_WIDTH;
+        let is_overflowing = count as f32 * MIN_TAB_WIDTH > room;
+
+        (state.start, state.visible) = if is_overflowing {
+            (
+                ARROW_WIDTH,
+                (size.width - 2.0 * ARROW_WIDTH - ADD_WIDTH).max(0.0),
+            )
+        } else {
+            (INSET, room.max(0.0))
+        };
+
+        state.tab_width = (state.visible / count.max(1) as f32).clamp(MIN_TAB_WIDTH, MAX_TAB_WIDTH);
+
+        let total = state.tab_width * count as f32;
+        state.max_offset = (total - state.visible).max(0.0);
+
+        if state.selected != Some(self.selected) {
+            state.selected = Some(self.selected);
+
+            let left = self.selected as f32 * state.tab_width;
+
+            state.offset = state
+                .offset
+                .max(left + state.tab_width - state.visible)
+                .min(left);
+        }
+
+        state.scroll_by(0.0);
+
+        let header_limits = Limits::new(Size::ZERO, Size::new(state.tab_width, TAB_HEIGHT));
+        let top = STRIP_HEIGHT - TAB_HEIGHT;
+
+        let mut children: Vec<Node> = self
+            .headers
+            .iter()
+            .zip(&mut tree.children)
+            .enumerate()
+            .map(|(index, (header, tree))| {
+                header
+                    .as_widget()
+                    .layout(tree, renderer, &header_limits)
+                    .move_to(Point::new(
+                        state.start + index as f32 * state.tab_width - state.offset,
+                        top,
+                    ))
+            })
+            .collect();
+
+        if let Some(add) = &self.add {
+            let x = if is_overflowing {
+                size.width - ADD_WIDTH
+            } else {
+                state.start + total
+            };
+
+            children.push(
+                add.as_widget()
+                    .layout(
+                        &mut tree.children[count],
+                        renderer,
+                        &Limits::new(Size::ZERO, Size::new(ADD_WIDTH, TAB_HEIGHT)),
+                    )
+                    .move_to(Point::new(x + (ADD_WIDTH - TAB_HEIGHT) / 2.0, top)),
+            );
+        }
+
+        children.push(content.move_to(Point::new(0.0, STRIP_HEIGHT)));
+
+        Node::with_children(size, children)
+    }
+
+    fn on_event(
+        &mut self,
+        tree: &mut Tree,
+        event: Event,
+        layout: Layout<'_>,
+        cursor: Cursor,
+        renderer: &Renderer,
+        clipboard: &mut dyn Clipboard,
+        shell: &mut Shell<'_, Message>,
+        viewport: &Rectangle,
+    ) -> event::Status {
+        let bounds = layout.bounds();
+        let count = self.headers.len();
+
+        // Noted before the content sees the press, as it may capture it
+        if let Event::Mouse(mouse::Event::ButtonPressed(_))
+        | Event::Touch(touch::Event::FingerPressed { .. }) = event
+        {
+            tree.state.downcast_mut::<State>().is_focused = cursor.is_over(bounds);
+        }
+
+        let status = self.content.as_widget_mut().on_event(
+            tree.children.last_mut().unwrap(),
+            event.clone(),
+            layout.children().last().unwrap(),
+            cursor,
+            renderer,
+            clipboard,
+            shell,
+            viewport,
+        );
+
+        if status == event::Status::Captured {
+            return status;
+        }
+
+        // Ctrl+Tab and Ctrl+Shift+Tab cycle through the tabs, Ctrl+W closes one. Only
+        // while the tab view is focused or hovered, so two of them on a page don't
+        // both react and other widgets keep the shortcuts otherwise
+        if let Event::Keyboard(keyboard::Event::KeyPressed { key, modifiers, .. }) = &event {
+            let is_active = tree.state.downcast_ref::<State>().is_focused || cursor.is_over(bounds);
+
+            if !modifiers.command() || count == 0 || !is_active {
+                return event::Status::Ignored;
+            }
+
+            let message = match key.as_ref() {
+                Key::Named(Named::Tab) => Some((self.on_select)(if modifiers.shift() {
+                    (self.selected + count - 1) % count
+                } else {
+                    (self.selected + 1) % count
+                })),
+                Key::Character("w") if self.closable[self.selected] => self
+                    .on_close
+                    .as_ref()
+                    .map(|on_close| on_close(self.selected)),
+                _ => None,
+            };
+
+            return match message {
+                Some(message) => {
+                    shell.publish(message);
+                    event::Status::Captured
+                }
+                None => event::Status::Ignored,
+            };
+        }
+
+        let state = tree.state.downcast_mut::<State>();
+        let strip = Rectangle {
+            height: STRIP_HEIGHT,
+            ..bounds
+        };
+
+        match event {
+            Event::Mouse(mouse::Event::WheelScrolled { delta })
+                if state.is_overflowing() && cursor.is_over(strip) =>
+            {
+                let (x, y) = match delta {
+                    mouse::ScrollDelta::Lines { x, y } => (x * SCROLL_LINE, y * SCROLL_LINE),
+                    mouse::ScrollDelta::Pixels { x, y } => (x, y),
+                };
+
+                state.scroll_by(-if x != 0.0 { x } else { y });
+                shell.invalidate_layout();
+
+                return event::Status::Captured;
+            }
+            Event::Mouse(mouse::Event::ButtonPressed(mouse::Button::Left))
+            | Event::Touch(touch::Event::FingerPressed { .. }) => {
+                if let Some((left, right)) = state.arrows(bounds) {
+                    let step = if cursor.is_over(left) {
+                        -state.tab_width
+                    } else if cursor.is_over(right) {
+                        state.tab_width
+                    } else {
+                        0.0
+                    };
+
+                    if step != 0.0 {
+                        state.scroll_by(step);
+                        shell.invalidate_layout();
+
+                        return event::Status::Captured;
+                    }
+                }
+            }
+            _ => {}
+        }
+
+        // Only the part of a tab inside the strip can be clicked
+        let header_cursor = if cursor.is_over(state.tabs(bounds)) {
+            cursor
+        } else {
+            Cursor::Unavailable
+        };
+
+        let mut layouts = layout.children();
+
+        for ((header, tree), layout) in self
+            .headers
+            .iter_mut()
+            .zip(&mut tree.children)
+            .zip(&mut layouts)
+        {
+            if header.as_widget_mut().on_event(
+                tree,
+                event.clone(),
+                layout,
+                header_cursor,
+                renderer,
+                clipboard,
+                shell,
+                viewport,
+            ) == event::Status::Captured
+            {
+                return event::Status::Captured;
+            }
+        }
+
+        if let Some(add) = &mut self.add {
+            if add.as_widget_mut().on_event(
+                &mut tree.children[count],
+                event.clone(),
+                layouts.next().unwrap(),
+                cursor,
+                renderer,
+                clipboard,
+                shell,
+                viewport,
+            ) == event::Status::Captured
+            {
+                return event::Status::Captured;
+            }
+        }
+
+        let hovered = self.hovered_tab(tree.state.downcast_ref::<State>(), layout, cursor);
+        let state = tree.state.downcast_mut::<State>();
+
+        match event {
+            Event::Mouse(mouse::Event::ButtonPressed(mouse::Button::Left))
+            | Event::Touch(touch::Event::FingerPressed { .. }) => {
+                let (Some(index), Some(position)) = (hovered, cursor.position()) else {
+                    return event::Status::Ignored;
+                };
+
+                if index != self.selected {
+                    shell.publish((self.on_select)(index));
+                }
+
+                state.pressed = Some(Press {
+                    index,
+                    origin: position.x,
+                    position: position.x,
+                    is_dragging: false,
+                });
+
+                event::Status::Captured
+            }
+            Event::Mouse(mouse::Event::ButtonPressed(mouse::Button::Middle)) => {
+                let Some(index) = hovered.filter(|index| self.closable[*index]) else {
+                    return event::Status::Ignored;
+                };
+
+                if let Some(on_close) = &self.on_close {
+                    shell.publish(on_close(index));
+                }
+
+                event::Status::Captured
+            }
+            Event::Mouse(mouse::Event::CursorMoved { position })
+            | Event::Touch(touch::Event::FingerMoved { position, .. }) => {
+                let Some(press) = &mut state.pressed else {
+                    return event::Status::Ignored;
+                };
+
+                press.position = position.x;
+
+                if self.on_reorder.is_some()
+                    && (press.position - press.origin).abs() > DRAG_THRESHOLD
+                {
+                    press.is_dragging = true;
+                }
+
+                if press.is_dragging {
+                    event::Status::Captured
+                } else {
+                    event::Status::Ignored
+                }
+            }
+            Event::Mouse(mouse::Event::ButtonReleased(mouse::Button::Left))
+            | Event::Touch(touch::Event::FingerLifted { .. })
+            | Event::Touch(touch::Event::FingerLost { .. }) => {
+                let Some(press) = state.pressed.take() else {
+                    return event::Status::Ignored;
+                };
+
+                if !press.is_dragging {
+                    return event::Status::Ignored;
+                }
+
+                let to = state.slot_at(bounds, press.position, count);
+
+                if let Some(on_reorder) = self.on_reorder.as_ref().filter(|_| to != press.index) {
+                    shell.publish(on_reorder(press.index, to));
+                }
+
+                event::Status::Captured
+            }
+            _ => event::Status::Ignored,
+        }
+    }
+
+    fn draw(
+        &self,
+        tree: &Tree,
+        renderer: &mut Renderer,
+        theme: &Theme,
+        _style: &renderer::Style,
+        layout: Layout<'_>,
+        cursor: Cursor,
+        viewport: &Rectangle,
+    ) {
+        let state = tree.state.downcast_ref::<State>();
+        let bounds = layout.bounds();
+        let count = self.headers.len();
+        let style = |status| self.class.resolve(theme, status);
+        let selected = style(Status::Selected);
+
+        // The content surface, with a line along its top that the selected tab
+        // breaks through
+        let content = Rectangle {
+            y: bounds.y + STRIP_HEIGHT,
+            height: (bounds.height - STRIP_HEIGHT).max(0.0),
+            ..bounds
+        };
+
+        renderer.fill_quad(
+            renderer::Quad {
+                bounds: content,
+                ..renderer::Quad::default()
+            },
+            selected.background,
+        );
+
+        renderer.fill_quad(
+            renderer::Quad {
+                bounds: Rectangle {
+                    height: 1.0,
+                    ..content
+                },
+                ..renderer::Quad::default()
+            },
+            selected.border_color,
+        );
+
+        let hovered = self.hovered_tab(state, layout, cursor);
+        let dragged = state
+            .pressed
+            .as_ref()
+            .filter(|press| press.is_dragging)
+            .map(|press| press.index);
+
+        let status = |index| {
+            if index == self.selected {
+                Status::Selected
+            } else if state
+                .pressed
+                .as_ref()
+                .is_some_and(|press| press.index == index)
+            {
+                Status::Pressed
+            } else if hovered == Some(index) && dragged.is_none() {
+                Status::Hovered
+            } else {
+                Status::Active
+            }
+        };
+
+        let tabs = state.tabs(bounds);
+        let clip = Rectangle {
+            height: STRIP_HEIGHT + 1.0,
+            ..tabs
+        };
+
+        let headers: Vec<_> = self
+            .headers
+            .iter()
+            .zip(&tree.children)
+            .zip(layout.children())
+            .collect();
+
+        renderer.with_layer(clip, |renderer| {
+            // The selected and the dragged tab go on top of the others
+            let order = (0..count)
+                .filter(|index| *index != self.selected && Some(*index) != dragged)
+                .chain((self.selected < count).then_some(self.selected))
+                .chain(dragged.filter(|index| *index != self.selected));
+
+            for index in order {
+                let ((header, tree), layout) = headers[index];
+                let status = status(index);
+                let style = style(status);
+                let shift = state.shift(bounds, index, count);
+                let tab = layout.bounds() + Vector::new(shift, 0.0);
+
+                renderer.fill_quad(
+                    renderer::Quad {
+                        bounds: tab,
+                        border: Border {
+                            radius: Radius::new(8).bottom(0),
+                            width: if status == Status::Selected { 1.0 } else { 0.0 },
+                            color: style.border_color,
+                        },
+                        ..renderer::Quad::default()
+                    },
+                    style.background,
+                );
+
+                if status == Status::Selected {
+                    renderer.fill_quad(
+                        renderer::Quad {
+                            bounds: Rectangle {
+                                x: tab.x + 1.0,
+                                y: tab.y + tab.height - 1.0,
+                                width: tab.width - 2.0,
+                                height: 2.0,
+                            },
+                            ..renderer::Quad::default()
+                        },
+                        style.background,
+                    );
+                }
+
+                // A divider on the right of a resting tab, unless the tab after it
+                // stands out
+                let next = index + 1;
+
+                if status == Status::Active
+                    && dragged.is_none()
+                    && next < count
+                    && next != self.selected
+                    && hovered != Some(next)
+                {
+                    renderer.fill_quad(
+                        renderer::Quad {
+                            bounds: Rectangle {
+                                x: tab.x + tab.width - 0.5,
+                                y: tab.y + 8.0,
+                                width: 1.0,
+                                height: tab.height - 16.0,
+                            },
+                            ..renderer::Quad::default()
+                        },
+                        style.divider,
+                    );
+                }
+
+                renderer.with_translation(Vector::new(shift, 0.0), |renderer| {
+                    header.as_widget().draw(
+                        tree,
+                        renderer,
+                        theme,
+                        &renderer::Style {
+                            text_color: style.text_color,
+                        },
+                        layout,
+                        if dragged.is_some() {
+                            Cursor::Unavailable
+                        } else {
+                            cursor
+                        },
+                        &clip,
+                    );
+                });
+            }
+        });
+
+        if let Some((left, right)) = state.arrows(bounds) {
+            let active = style(Status::Active);
+
+            for (arrow, glyph, is_enabled) in [
+                (left, FluentIcon::CaretLeftSolid8, state.offset > 0.0),
+                (
+                    right,
+                    FluentIcon::CaretRightSolid8,
+                    state.offset < state.max_offset,
+                ),
+            ] {
+                if is_enabled && cursor.is_over(arrow) {
+                    renderer.fill_quad(
+                        renderer::Quad {
+                            bounds: arrow,
+                            border: Border {
+                                radius: 4.0.into(),
+                                ..Border::default()
+                            },
+                            ..renderer::Quad::default()
+                        },
+                        style(Status::Hovered).background,
+                    );
+                }
+
+                renderer.fill_text(
+                    Text {
+                        content: glyph.codepoint().to_string(),
+                        bounds: arrow.size(),
+                        size: Pixels(8.0),
+                        line_height: LineHeight::default(),
+                        font: font::icons(),
+                        horizontal_alignment: Horizontal::Center,
+                        vertical_alignment: Vertical::Center,
+                        shaping: Shaping::Advanced,
+                        wrapping: Wrapping::default(),
+                    },
+                    arrow.center(),
+                    if is_enabled {
+                        active.text_color
+                    } else {
+                        active.text_color.scale_alpha(0.4)
+                    },
+                    arrow,
+                );
+            }
+        }
+
+        let mut layouts = layout.children().skip(count);
+
+        if let Some(add) = &self.add {
+            add.as_widget().draw(
+                &tree.children[count],
+                renderer,
+                theme,
+                &renderer::Style {
+                    text_color: style(Status::Active).text_color,
+                },
+                layouts.next().unwrap(),
+                cursor,
+                viewport,
+            );
+        }
+
+        self.content.as_widget().draw(
+            tree.children.last().unwrap(),
+            renderer,
+            theme,
+            &renderer::Style {
+                text_color: selected.text_color,
+            },
+            layouts.next().unwrap(),
+            cursor,
+            &content.intersection(viewport).unwrap_or(content),
+        );
+    }
+
+    fn operate(
+        &self,
+        tree: &mut Tree,
+        layout: Layout<'_>,
+        renderer: &Renderer,
+        operation: &mut dyn Operation,
+    ) {
+        operation.container(None, layout.bounds(), &mut |operation| {
+            for ((element, tree), layout) in self
+                .elements()
+                .zip(&mut tree.children)
+                .zip(layout.children())
+            {
+                element
+                    .as_widget()
+                    .operate(tree, layout, renderer, operation);
+            }
+        });
+    }
+
+    fn mouse_interaction(
+        &self,
+        tree: &Tree,
+        layout: Layout<'_>,
+        cursor: Cursor,
+        viewport: &Rectangle,
+        renderer: &Renderer,
+    ) -> mouse::Interaction {
+        let state = tree.state.downcast_ref::<State>();
+
+        if state
+            .pressed
+            .as_ref()
+            .is_some_and(|press| press.is_dragging)
+        {
+            return mouse::Interaction::Grabbing;
+        }
+
+        if state
+            .arrows(layout.bounds())
+            .is_some_and(|(left, right)| cursor.is_over(left) || cursor.is_over(right))
+        {
+            return mouse::Interaction::Pointer;
+        }
+
+        let header_cursor = if cursor.is_over(state.tabs(layout.bounds())) {
+            cursor
+        } else {
+            Cursor::Unavailable
+        };
+
+        self.elements()
+            .zip(&tree.children)
+            .zip(layout.children())
+            .enumerate()
+            .map(|(index, ((element, tree), layout))| {
+                element.as_widget().mouse_interaction(
+                    tree,
+                    layout,
+                    if index < self.headers.len() {
+                        header_cursor
+                    } else {
+                        cursor
+                    },
+                    viewport,
+                    renderer,
+                )
+            })
+            .max()
+            .unwrap_or_default()
+    }
+
+    fn overlay<'b>(
+        &'b mut self,
+        tree: &'b mut Tree,
+        layout: Layout<'_>,
+        renderer: &Renderer,
+        translation: Vector,
+    ) -> Option<overlay::Element<'b, Message, Theme, Renderer>> {
+        self.content.as_widget_mut().overlay(
+            tree.children.last_mut().unwrap(),
+            layout.children().last().unwrap(),
+            renderer,
+            translation,
+        )
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const BOUNDS: Rectangle = Rectangle {
+        x: 0.0,
+        y: 0.0,
+        width: 600.0,
+        height: 400.0,
+    };
+
+    // Five 100 wide tabs starting after the inset, with `index` pressed at its
+    // middle and dragged to `x`
+    fn dragging(index: usize, x: f32) -> State {
+        let origin = INSET + index as f32 * 100.0 + 50.0;
+
+        State {
+            start: INSET,
+            visible: 500.0,
+            tab_width: 100.0,
+            pressed: Some(Press {
+                index,
+                origin,
+                position: x,
+                is_dragging: true,
+            }),
+            ..State::default()
+        }
+    }
+
+    #[test]
+    fn tabs_drop_into_the_slot_under_the_cursor() {
+        let state = dragging(0, 0.0);
+
+        assert_eq!(state.slot_at(BOUNDS, INSET + 50.0, 5), 0);
+        assert_eq!(state.slot_at(BOUNDS, INSET + 250.0, 5), 2);
+        // Past either end of the strip
+        assert_eq!(state.slot_at(BOUNDS, -40.0, 5), 0);
+        assert_eq!(state.slot_at(BOUNDS, 900.0, 5), 4);
+    }
+
+    #[test]
+    fn slots_account_for_the_scrolled_tabs() {
+        let state = State {
+            offset: 150.0,
+            ..dragging(0, 0.0)
+        };
+
+        assert_eq!(state.slot_at(BOUNDS, INSET + 50.0, 5), 2);
+    }
+
+    #[test]
+    fn passed_tabs_move_over_to_make_room() {
+        let state = dragging(1, INSET + 350.0);
+
+        assert_eq!(state.shift(BOUNDS, 0, 5), 0.0);
+        assert_eq!(state.shift(BOUNDS, 1, 5), 200.0);
+        assert_eq!(state.shift(BOUNDS, 2, 5), -100.0);
+        assert_eq!(state.shift(BOUNDS, 3, 5), -100.0);
+        assert_eq!(state.shift(BOUNDS, 4, 5), 0.0);
+
+        let state = dragging(3, INSET + 50.0);
+
+        assert_eq!(state.shift(BOUNDS, 0, 5), 100.0);
+        assert_eq!(state.shift(BOUNDS, 2, 5), 100.0);
+        assert_eq!(state.shift(BOUNDS, 4, 5), 0.0);
+    }
+
+    #[test]
+    fn the_dragged_tab_stays_within_the_strip() {
+        assert_eq!(dragging(1, -500.0).shift(BOUNDS, 1, 5), -100.0);
+        assert_eq!(dragging(1, 2000.0).shift(BOUNDS, 1, 5), 300.0);
+    }
+}
@@ -0,0 +1,169 @@
+use crate::{
+    density,
+    fluent_icon::FluentIcon,
+    widget::{
+        button,
+        flyout::{Flyout, Placement},
+        text, Button, Column, Element, Row,
+    },
+};
+
+use iced::{alignment::Horizontal, Length};
+
+// Based on https://learn.microsoft.com/en-us/windows/apps/design/controls/dialogs-and-flyouts/teaching-tip
+pub struct TeachingTip<'a, Message>
+where
+    Message: 'a + Clone,
+{
+    target: Element<'a, Message>,
+    title: String,
+    subtitle: Option<String>,
+    hero: Option<Element<'a, Message>>,
+    content: Option<Element<'a, Message>>,
+    action: Option<(String, Message)>,
+    close_button: Option<String>,
+    on_close: Option<Message>,
+    is_open: bool,
+    is_light_dismiss: bool,
+    placement: Placement,
+}
+
+impl<'a, Message> TeachingTip<'a, Message>
+where
+    Message: 'a + Clone,
+{
+    pub fn new(target: impl Into<Element<'a, Message>>, title: impl Into<String>) -> Self {
+        Self {
+            target: target.into(),
+            title: title.into(),
+            subtitle: None,
+            hero: None,
+            content: None,
+            action: None,
+            close_button: None,
+            on_close: None,
+            is_open: false,
+            is_light_dismiss: false,
+            placement: Placement::Auto,
+        }
+    }
+
+    pub fn subtitle(mut self, subtitle: impl Into<String>) -> Self {
+        self.subtitle = Some(subtitle.into());
+        self
+    }
+
+    // An image across the top of the tip, above the title
+    pub fn hero(mut self, hero: impl Into<Element<'a, Message>>) -> Self {
+        self.hero = Some(hero.into());
+        self
+    }
+
+    pub fn content(mut self, content: impl Into<Element<'a, Message>>) -> Self {
+        self.content = Some(content.into());
+        self
+    }
+
+    pub fn action(mut self, label: impl Into<String>, on_press: Message) -> Self {
+        self.action = Some((label.into(), on_press));
+        self
+    }
+
+    // A labelled close button next to the action instead of the X in the corner
+    pub fn close_button(mut self, label: impl Into<String>) -> Self {
+        self.close_button = Some(label.into());
+        self
+    }
+
+    pub fn on_close(mut self, message: Message) -> Self {
+        self.on_close = Some(message);
+        self
+    }
+
+    pub fn open(mut self, is_open: bool) -> Self {
+        self.is_open = is_open;
+        self
+    }
+
+    // Clicking outside the tip or pressing Esc closes it, and the X is left out
+    pub fn light_dismiss(mut self, is_light_dismiss: bool) -> Self {
+        self.is_light_dismiss = is_light_dismiss;
+        self
+    }
+
+    pub fn placement(mut self, placement: Placement) -> Self {
+        self.placement = placement;
+        self
+    }
+}
+
+impl<'a, Message> From<TeachingTip<'a, Message>> for Element<'a, Message>
+where
+    Message: 'a + Clone,
+{
+    fn from(tip: TeachingTip<'a, Message>) -> Self {
+        let close = (!tip.is_light_dismiss && tip.close_button.is_none())
+            .then(|| tip.on_close.clone())
+            .flatten()
+            .map(|message| {
+                let size = density::metrics().icon_button_size;
+
+                Button::new(text::icon(FluentIcon::Cancel.codepoint(), 12.0))
+                    .width(size)
+                    .height(size)
+                    .padding((size - 12.0) / 2.0)
+                    .class("transparent")
+                    .on_press(message)
+            });
+
+        let header = Row::new()
+            .push(
+                Column::new()
+                    .push(text::body1_strong(tip.title))
+                    .push_maybe(tip.subtitle.map(text::body1))
+                    .spacing(2)
+                    .width(Length::Fill),
+            )
+            .push_maybe(close)
+            .spacing(8);
+
+        let action = tip.action.map(|(label, on_press)| {
+            button::standard(text::body1(label).align_x(Horizontal::Center))
+                .width(Length::Fill)
+                .on_press(on_press)
+                .class("primary")
+        });
+
+        let close_button = tip.close_button.map(|label| {
+            button::standard(text::body1(label).align_x(Horizontal::Center))
+                .width(Length::Fill)
+                .on_press_maybe(tip.on_close.clone())
+        });
+
+        let buttons = (action.is_some() || close_button.is_some()).then(|| {
+            Row::new()
+                .push_maybe(action)
+                .push_maybe(close_button)
+                .spacing(8)
+        });
+
+        let body = Column::new()
+            .push(header)
+            .push_maybe(tip.content)
+            .push_maybe(buttons)
+            .spacing(12)
+            .padding(12);
+
+        Flyout::new(
+            tip.target,
+            Column::new().push_maybe(tip.hero).push(body),
+            tip.is_open,
+        )
+        .on_dismiss_maybe(tip.on_close.filter(|_| tip.is_light_dismiss))
+        .placement(tip.placement)
+        .tail(true)
+        .width(320)
+        .padding(0)
+        .into()
+    }
+}
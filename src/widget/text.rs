@@ -1,6 +1,8 @@
 use crate::{font, theme::Theme, widget::Text};
 
-use iced::{advanced::text::LineHeight, widget::text, Pixels};
+use iced::{advanced::text::LineHeight, widget::text, Font, Pixels};
+
+use std::sync::atomic::{AtomicU16, Ordering};
 
 impl iced::widget::text::Catalog for Theme {
     type Class<'a> = Box<dyn Fn(&Theme) -> text::Style + 'a>;
@@ -20,40 +22,95 @@ impl iced::widget::text::Catalog for Theme {
 // Size and line heights from
 // https://learn.microsoft.com/en-gb/windows/apps/design/signature-experiences/typography#type-ramp
 
-pub fn caption1<'a>(fragment: impl text::IntoFragment<'a>) -> Text<'a> {
+// Windows' "Text size" accessibility setting, applied to every style in the ramp
+// and to the text of the other widget constructors
+pub const MIN_SCALE: u16 = 100;
+pub const MAX_SCALE: u16 = 225;
+
+static SCALE: AtomicU16 = AtomicU16::new(MIN_SCALE);
+
+// As a percentage
+pub fn scale() -> u16 {
+    SCALE.load(Ordering::Relaxed)
+}
+
+pub fn set_scale(percent: u16) {
+    SCALE.store(percent.clamp(MIN_SCALE, MAX_SCALE), Ordering::Relaxed);
+}
+
+pub fn scaled(pixels: f32) -> f32 {
+    (pixels * f32::from(scale()) / 100.0).round()
+}
+
+fn ramp<'a>(
+    fragment: impl text::IntoFragment<'a>,
+    font: Font,
+    size: f32,
+    line_height: f32,
+) -> Text<'a> {
     Text::new(fragment)
-        .font(font::SEGOE)
-        .size(12)
-        .line_height(LineHeight::Absolute(Pixels(16.0)))
+        .font(font)
+        .size(scaled(size))
+        .line_height(LineHeight::Absolute(Pixels(scaled(line_height))))
+}
+
+pub fn caption2<'a>(fragment: impl text::IntoFragment<'a>) -> Text<'a> {
+    ramp(fragment, font::text(), 10.0, 14.0)
+}
+
+pub fn caption1<'a>(fragment: impl text::IntoFragment<'a>) -> Text<'a> {
+    ramp(fragment, font::text(), 12.0, 16.0)
 }
 
 pub fn body1<'a>(fragment: impl text::IntoFragment<'a>) -> Text<'a> {
-    Text::new(fragment)
-        .font(font::SEGOE)
-        .size(14)
-        .line_height(LineHeight::Absolute(Pixels(20.0)))
+    ramp(fragment, font::text(), 14.0, 20.0)
+}
+
+pub fn body1_strong<'a>(fragment: impl text::IntoFragment<'a>) -> Text<'a> {
+    ramp(fragment, font::semibold(), 14.0, 20.0)
 }
 
 pub fn bold<'a>(fragment: impl text::IntoFragment<'a>) -> Text<'a> {
-    body1(fragment).font(font::SEGOE_BOLD)
+    body1(fragment).font(font::bold())
+}
+
+pub fn body2<'a>(fragment: impl text::IntoFragment<'a>) -> Text<'a> {
+    ramp(fragment, font::text(), 16.0, 22.0)
+}
+
+pub fn subtitle2<'a>(fragment: impl text::IntoFragment<'a>) -> Text<'a> {
+    ramp(fragment, font::semibold(), 16.0, 22.0)
 }
 
 pub fn subtitle1<'a>(fragment: impl text::IntoFragment<'a>) -> Text<'a> {
-    Text::new(fragment)
-        .font(font::SEGOE_SEMIBOLD)
-        .size(20)
-        .line_height(LineHeight::Absolute(Pixels(26.0)))
+    ramp(fragment, font::semibold(), 20.0, 26.0)
+}
+
+pub fn title3<'a>(fragment: impl text::IntoFragment<'a>) -> Text<'a> {
+    ramp(fragment, font::semibold(), 24.0, 32.0)
 }
 
 pub fn title2<'a>(fragment: impl text::IntoFragment<'a>) -> Text<'a> {
-    Text::new(fragment)
-        .font(font::SEGOE_SEMIBOLD)
-        .size(28)
-        .line_height(LineHeight::Absolute(Pixels(36.0)))
+    ramp(fragment, font::semibold(), 28.0, 36.0)
+}
+
+pub fn title1<'a>(fragment: impl text::IntoFragment<'a>) -> Text<'a> {
+    ramp(fragment, font::semibold(), 32.0, 40.0)
+}
+
+pub fn large_title<'a>(fragment: impl text::IntoFragment<'a>) -> Text<'a> {
+    ramp(fragment, font::semibold(), 40.0, 52.0)
+}
+
+pub fn display<'a>(fragment: impl text::IntoFragment<'a>) -> Text<'a> {
+    ramp(fragment, font::semibold(), 68.0, 92.0)
 }
 
-pub fn icon<'a>(codepoint: char) -> Text<'a> {
+// Glyphs follow the text scale too so they stay in proportion with the labels
+// beside them
+pub fn icon<'a>(codepoint: char, size: f32) -> Text<'a> {
     text(codepoint)
-        .font(font::SEGOE_FLUENT_ICONS)
+        .font(font::icons())
+        .size(scaled(size))
         .shaping(iced::widget::text::Shaping::Advanced)
 }
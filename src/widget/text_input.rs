@@ -1,27 +1,23 @@
 use crate::{
-    font,
+    density, font,
     widget::{
+        text,
         underline::{ElementType, Underline},
         Element, TextInput,
     },
 };
 
-use iced::{widget::text::LineHeight, Padding, Pixels};
+use iced::{widget::text::LineHeight, Pixels};
 
 pub fn standard<'a, Message>(placeholder: &str, value: &str) -> TextInput<'a, Message>
 where
     Message: 'a + Clone,
 {
     TextInput::new(placeholder, value)
-        .font(font::SEGOE)
-        .line_height(LineHeight::Absolute(Pixels(20.0)))
-        .size(14)
-        .padding(Padding {
-            left: 10.0,
-            top: 6.0,
-            right: 10.0,
-            bottom: 6.0,
-        })
+        .font(font::text())
+        .line_height(LineHeight::Absolute(Pixels(text::scaled(20.0))))
+        .size(text::scaled(14.0))
+        .padding(density::metrics().control_padding)
 }
 
 pub fn underline<'a, Message>(text_input: TextInput<'a, Message>) -> Element<'a, Message>
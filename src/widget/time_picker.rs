@@ -0,0 +1,1047 @@
+use crate::{
+    date_time::{English, Formatter, Time},
+    density,
+    fluent_icon::FluentIcon,
+    font,
+    icon::Icon,
+    motion::{self, Animated, Easing},
+    style::time_picker::{Status, Style, StyleFn},
+    theme::{
+        class::{self, Class},
+        Theme,
+    },
+    widget::{text, Element},
+};
+
+use iced::{
+    advanced::{
+        layout::{Limits, Node},
+        overlay,
+        renderer::{self, Renderer as _},
+        text::{LineHeight, Renderer as _, Shaping, Text, Wrapping},
+        widget::{
+            operation::{focusable::Focusable, Operation},
+            tree, Tree,
+        },
+        Clipboard, Layout, Shell, Widget,
+    },
+    alignment::{Horizontal, Vertical},
+    border::Radius,
+    event,
+    keyboard::{self, key::Named, Key},
+    mouse::{self, Cursor},
+    touch,
+    widget::container,
+    window, Background, Border, Color, Event, Length, Pixels, Point, Rectangle, Size, Vector,
+};
+
+use std::{fmt, time::Instant};
+
+type Renderer = iced::Renderer;
+
+// TimePicker sizes from
+// https://github.com/microsoft/microsoft-ui-xaml/blob/main/src/controls/dev/CommonStyles/TimePicker_themeresources.xaml
+const WIDTH: f32 = 242.0;
+const ITEM_HEIGHT: f32 = 40.0;
+// Rows shown in each column of the flyout, the middle one holds the picked value
+const VISIBLE_ITEMS: usize = 7;
+const HALF: i32 = VISIBLE_ITEMS as i32 / 2;
+const PADDING: f32 = 4.0;
+// The accept and dismiss buttons under the columns
+const BUTTON_HEIGHT: f32 = 40.0;
+const CARET_SIZE: f32 = 8.0;
+const ICON_SIZE: f32 = 16.0;
+// Closest the flyout gets to the edges of the window
+const MARGIN: f32 = 8.0;
+
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub enum Clock {
+    // Hours 1 to 12 and an AM/PM column
+    #[default]
+    TwelveHour,
+    TwentyFourHour,
+}
+
+impl Clock {
+    pub const ALL: [Clock; 2] = [Clock::TwelveHour, Clock::TwentyFourHour];
+}
+
+impl fmt::Display for Clock {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(match self {
+            Clock::TwelveHour => "12-hour",
+            Clock::TwentyFourHour => "24-hour",
+        })
+    }
+}
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+enum Part {
+    Hour,
+    Minute,
+    Period,
+}
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+enum Target {
+    // A column and how many rows the item is below the middle, negative above it
+    Item(usize, i32),
+    // The caret buttons over the top and bottom rows of a column
+    Up(usize),
+    Down(usize),
+    Accept,
+    Dismiss,
+}
+
+// Based on https://learn.microsoft.com/en-us/windows/apps/design/controls/time-picker
+//
+// A button showing the time that opens a flyout with a column for each part of it.
+// The hours and minutes loop round. Nothing changes until the flyout is accepted,
+// dismissing it or clicking away leaves the time as it was.
+pub struct TimePicker<'a, Message> {
+    time: Option<Time>,
+    on_change: Box<dyn Fn(Time) -> Message + 'a>,
+    clock: Clock,
+    minute_increment: u8,
+    formatter: &'a dyn Formatter,
+    width: Length,
+    // Read when built, the density isn't known during layout
+    height: f32,
+    class: Class<StyleFn>,
+}
+
+impl<'a, Message> TimePicker<'a, Message> {
+    pub fn new(time: Option<Time>, on_change: impl Fn(Time) -> Message + 'a) -> Self {
+        Self {
+            time,
+            on_change: Box::new(on_change),
+            clock: Clock::default(),
+            minute_increment: 1,
+            formatter: &English,
+            width: Length::Fixed(WIDTH),
+            height: density::metrics().control_height,
+            class: class::DEFAULT.into(),
+        }
+    }
+
+    pub fn clock(mut self, clock: Clock) -> Self {
+        self.clock = clock;
+        self
+    }
+
+    // Only minutes that are a multiple of this can be picked, 15 gives 00, 15, 30
+    // and 45
+    pub fn minute_increment(mut self, minute_increment: u8) -> Self {
+        self.minute_increment = minute_increment.clamp(1, 59);
+        self
+    }
+
+    pub fn formatter(mut self, formatter: &'a dyn Formatter) -> Self {
+        self.formatter = formatter;
+        self
+    }
+
+    pub fn width(mut self, width: impl Into<Length>) -> Self {
+        self.width = width.into();
+        self
+    }
+
+    pub fn style(mut self, style: impl Fn(&Theme, Status) -> Style + 'static) -> Self {
+        self.class = Class::Custom(Box::new(style));
+        self
+    }
+
+    pub fn class(mut self, class: impl Into<Class<StyleFn>>) -> Self {
+        self.class = class.into();
+        self
+    }
+
+    fn parts(&self) -> &'static [Part] {
+        match self.clock {
+            Clock::TwelveHour => &[Part::Hour, Part::Minute, Part::Period],
+            Clock::TwentyFourHour => &[Part::Hour, Part::Minute],
+        }
+    }
+
+    fn count(&self, part: Part) -> i32 {
+        match (part, self.clock) {
+            (Part::Hour, Clock::TwelveHour) => 12,
+            (Part::Hour, Clock::TwentyFourHour) => 24,
+            (Part::Minute, _) => 59 / i32::from(self.minute_increment) + 1,
+            (Part::Period, _) => 2,
+        }
+    }
+
+    // Where `time` is in the part's column
+    fn index(&self, part: Part, time: Time) -> i32 {
+        i32::from(match (part, self.clock) {
+            (Part::Hour, Clock::TwelveHour) => time.hour12() - 1,
+            (Part::Hour, Clock::TwentyFourHour) => time.hour(),
+            (Part::Minute, _) => time.minute() / self.minute_increment,
+            (Part::Period, _) => u8::from(time.is_pm()),
+        })
+    }
+
+    // `time` with the part set to the item at `index` in its column
+    fn with(&self, part: Part, time: Time, index: i32) -> Time {
+        let index = index as u8;
+        let (hour, minute) = match (part, self.clock) {
+            (Part::Hour, Clock::TwelveHour) => (
+                (index + 1) % 12 + 12 * u8::from(time.is_pm()),
+                time.minute(),
+            ),
+            (Part::Hour, Clock::TwentyFourHour) => (index, time.minute()),
+            (Part::Minute, _) => (time.hour(), index * self.minute_increment),
+            (Part::Period, _) => (time.hour() % 12 + 12 * index, time.minute()),
+        };
+
+        Time::new(hour, minute).expect("Time in range")
+    }
+
+    fn label(&self, part: Part, index: i32) -> String {
+        match (part, self.clock) {
+            (Part::Hour, Clock::TwelveHour) => (index + 1).to_string(),
+            (Part::Hour, Clock::TwentyFourHour) => format!("{index:02}"),
+            (Part::Minute, _) => format!("{:02}", index * i32::from(self.minute_increment)),
+            (Part::Period, _) => self.formatter.day_period(index == 1),
+        }
+    }
+
+    fn open(&self, state: &mut State) {
+        let time = self.time.unwrap_or_else(Time::now);
+        let part = Part::Minute;
+
+        // Down to a minute that can be picked
+        state.draft = self.with(part, time, self.index(part, time));
+        state.column = 0;
+        state.pressed = None;
+        state.is_open = true;
+
+        for shift in &mut state.shifts {
+            shift.set(0.0);
+        }
+    }
+
+    fn accept(&self, state: &mut State, shell: &mut Shell<'_, Message>) {
+        state.is_open = false;
+
+        if self.time != Some(state.draft) {
+            shell.publish((self.on_change)(state.draft));
+        }
+    }
+
+    // Moves a column `delta` items on, sliding its items into place
+    fn step(&self, state: &mut State, column: usize, delta: i32, shell: &mut Shell<'_, Message>) {
+        let part = self.parts()[column];
+        let count = self.count(part);
+        let index = self.index(part, state.draft);
+
+        // The AM/PM column doesn't loop
+        let (next, moved) = if part == Part::Period {
+            let next = (index + delta).clamp(0, count - 1);
+
+            (next, next - index)
+        } else {
+            ((index + delta).rem_euclid(count), delta)
+        };
+
+        state.column = column;
+
+        if moved == 0 {
+            return;
+        }
+
+        state.draft = self.with(part, state.draft, next);
+
+        let now = Instant::now();
+        let shift = &mut state.shifts[column];
+
+        shift.set(shift.value(now) + moved as f32 * ITEM_HEIGHT);
+        shift.go_to(0.0, now);
+        state.now = now;
+
+        shell.request_redraw(window::RedrawRequest::NextFrame);
+    }
+
+    fn activate(&self, state: &mut State, target: Target, shell: &mut Shell<'_, Message>) {
+        match target {
+            Target::Item(column, rows) => self.step(state, column, rows, shell),
+            Target::Up(column) => self.step(state, column, -1, shell),
+            Target::Down(column) => self.step(state, column, 1, shell),
+            Target::Accept => self.accept(state, shell),
+            Target::Dismiss => state.is_open = false,
+        }
+    }
+}
+
+struct State {
+    is_open: bool,
+    is_focused: bool,
+    // The focus ring only shows once the keyboard is used, as in WinUI
+    show_focus: bool,
+    is_pressed: bool,
+    // The time being picked in the flyout
+    draft: Time,
+    // How far each column's items are from where they settle, eased to nothing
+    // after a step so the values slide into place
+    shifts: [Animated<f32>; 3],
+    now: Instant,
+    // The column the arrow keys step
+    column: usize,
+    pressed: Option<Target>,
+}
+
+impl Default for State {
+    fn default() -> Self {
+        let shift = Animated::new(0.0)
+            .duration(motion::FAST)
+            .easing(Easing::FAST_OUT_SLOW_IN);
+
+        Self {
+            is_open: false,
+            is_focused: false,
+            show_focus: false,
+            is_pressed: false,
+            draft: Time::new(0, 0).expect("Midnight"),
+            shifts: [shift; 3],
+            now: Instant::now(),
+            column: 0,
+            pressed: None,
+        }
+    }
+}
+
+impl Focusable for State {
+    fn is_focused(&self) -> bool {
+        self.is_focused
+    }
+
+    fn focus(&mut self) {
+        self.is_focused = true;
+        self.show_focus = true;
+    }
+
+    fn unfocus(&mut self) {
+        self.is_focused = false;
+        self.show_focus = false;
+    }
+}
+
+impl<'a, Message> Widget<Message, Theme, Renderer> for TimePicker<'a, Message> {
+    fn tag(&self) -> tree::Tag {
+        tree::Tag::of::<State>()
+    }
+
+    fn state(&self) -> tree::State {
+        tree::State::new(State::default())
+    }
+
+    fn size(&self) -> Size<Length> {
+        Size::new(self.width, Length::Shrink)
+    }
+
+    fn layout(&self, _tree: &mut Tree, _renderer: &Renderer, limits: &Limits) -> Node {
+        Node::new(limits.resolve(self.width, Length::Shrink, Size::new(WIDTH, self.height)))
+    }
+
+    fn operate(
+        &self,
+        tree: &mut Tree,
+        _layout: Layout<'_>,
+        _renderer: &Renderer,
+        operation: &mut dyn Operation,
+    ) {
+        operation.focusable(tree.state.downcast_mut::<State>(), None);
+    }
+
+    fn on_event(
+        &mut self,
+        tree: &mut Tree,
+        event: Event,
+        layout: Layout<'_>,
+        cursor: Cursor,
+        _renderer: &Renderer,
+        _clipboard: &mut dyn Clipboard,
+        _shell: &mut Shell<'_, Message>,
+        _viewport: &Rectangle,
+    ) -> event::Status {
+        let state = tree.state.downcast_mut::<State>();
+        let bounds = layout.bounds();
+
+        match event {
+            Event::Mouse(mouse::Event::ButtonPressed(mouse::Button::Left))
+            | Event::Touch(touch::Event::FingerPressed { .. }) => {
+                if !cursor.is_over(bounds) {
+                    state.is_focused = false;
+
+                    return event::Status::Ignored;
+                }
+
+                state.is_focused = true;
+                state.show_focus = false;
+                state.is_pressed = true;
+
+                event::Status::Captured
+            }
+            Event::Mouse(mouse::Event::ButtonReleased(mouse::Button::Left))
+            | Event::Touch(touch::Event::FingerLifted { .. }) => {
+                if !std::mem::take(&mut state.is_pressed) {
+                    return event::Status::Ignored;
+                }
+
+                if cursor.is_over(bounds) {
+                    if state.is_open {
+                        state.is_open = false;
+                    } else {
+                        self.open(state);
+                    }
+                }
+
+                event::Status::Captured
+            }
+            Event::Touch(touch::Event::FingerLost { .. }) => {
+                state.is_pressed = false;
+
+                event::Status::Ignored
+            }
+            Event::Keyboard(keyboard::Event::KeyPressed { key, modifiers, .. })
+                if state.is_focused && !state.is_open =>
+            {
+                let opens = match key.as_ref() {
+                    Key::Named(Named::Enter | Named::Space) => true,
+                    Key::Named(Named::ArrowDown) => modifiers.alt(),
+                    _ => false,
+                };
+
+                if !opens {
+                    return event::Status::Ignored;
+                }
+
+                self.open(state);
+                state.show_focus = true;
+
+                event::Status::Captured
+            }
+            _ => event::Status::Ignored,
+        }
+    }
+
+    fn draw(
+        &self,
+        tree: &Tree,
+        renderer: &mut Renderer,
+        theme: &Theme,
+        _style: &renderer::Style,
+        layout: Layout<'_>,
+        cursor: Cursor,
+        _viewport: &Rectangle,
+    ) {
+        let state = tree.state.downcast_ref::<State>();
+        let bounds = layout.bounds();
+        let is_over = cursor.is_over(bounds);
+
+        let status = if is_over && state.is_pressed {
+            Status::Pressed
+        } else if is_over {
+            Status::Hovered
+        } else {
+            Status::Active
+        };
+        let style = self.class.resolve(theme, status);
+
+        renderer.fill_quad(
+            renderer::Quad {
+                bounds,
+                border: Border {
+                    color: style.border_color,
+                    width: 1.0,
+                    radius: Radius::new(4.0),
+                },
+                ..renderer::Quad::default()
+            },
+            style.background,
+        );
+
+        let parts = self.parts();
+        let width = bounds.width / parts.len() as f32;
+
+        for (column, part) in parts.iter().enumerate() {
+            let segment = Rectangle {
+                x: bounds.x + column as f32 * width,
+                width,
+                ..bounds
+            };
+
+            let (label, colour) = match (self.time, part) {
+                (Some(time), _) => (self.label(*part, self.index(*part, time)), style.text_color),
+                (None, Part::Hour) => (self.formatter.hour_placeholder(), style.placeholder_color),
+                (None, Part::Minute) => {
+                    (self.formatter.minute_placeholder(), style.placeholder_color)
+                }
+                (None, Part::Period) => (self.formatter.day_period(false), style.placeholder_color),
+            };
+
+            draw_text(renderer, label, segment, colour);
+
+            if column > 0 {
+                renderer.fill_quad(
+                    renderer::Quad {
+                        bounds: Rectangle {
+                            width: 1.0,
+                            ..segment
+                        },
+                        ..renderer::Quad::default()
+                    },
+                    style.divider_color,
+                );
+            }
+        }
+
+        if state.is_focused && state.show_focus && !state.is_open {
+            renderer.fill_quad(
+                renderer::Quad {
+                    bounds: bounds.expand(3.0),
+                    border: Border {
+                        color: style.focus,
+                        width: 2.0,
+                        radius: 7.0.into(),
+                    },
+                    ..renderer::Quad::default()
+                },
+                Color::TRANSPARENT,
+            );
+        }
+    }
+
+    fn mouse_interaction(
+        &self,
+        _tree: &Tree,
+        layout: Layout<'_>,
+        cursor: Cursor,
+        _viewport: &Rectangle,
+        _renderer: &Renderer,
+    ) -> mouse::Interaction {
+        if cursor.is_over(layout.bounds()) {
+            mouse::Interaction::Pointer
+        } else {
+            mouse::Interaction::default()
+        }
+    }
+
+    fn overlay<'b>(
+        &'b mut self,
+        tree: &'b mut Tree,
+        layout: Layout<'_>,
+        _renderer: &Renderer,
+        translation: Vector,
+    ) -> Option<overlay::Element<'b, Message, Theme, Renderer>> {
+        let state = tree.state.downcast_mut::<State>();
+
+        if !state.is_open {
+            return None;
+        }
+
+        Some(overlay::Element::new(Box::new(Flyout {
+            picker: self,
+            state,
+            anchor: layout.bounds() + translation,
+        })))
+    }
+}
+
+impl<'a, Message> From<TimePicker<'a, Message>> for Element<'a, Message>
+where
+    Message: 'a,
+{
+    fn from(time_picker: TimePicker<'a, Message>) -> Self {
+        Self::new(time_picker)
+    }
+}
+
+// The columns, with the middle row over the field so the picked values stay put
+struct Flyout<'a, 'b, Message> {
+    picker: &'b TimePicker<'a, Message>,
+    state: &'b mut State,
+    anchor: Rectangle,
+}
+
+impl<Message> Flyout<'_, '_, Message> {
+    fn columns(&self) -> usize {
+        self.picker.parts().len()
+    }
+
+    fn target_at(&self, bounds: Rectangle, cursor: Cursor) -> Option<Target> {
+        let position = cursor.position_over(bounds)?;
+
+        for target in [Target::Accept, Target::Dismiss] {
+            if button_bounds(bounds, target).contains(position) {
+                return Some(target);
+            }
+        }
+
+        let columns = columns_bounds(bounds);
+
+        if !columns.contains(position) {
+            return None;
+        }
+
+        let column = (((position.x - columns.x) / columns.width * self.columns() as f32) as usize)
+            .min(self.columns() - 1);
+        let rows = ((position.y - columns.y) / ITEM_HEIGHT) as i32 - HALF;
+
+        Some(match rows {
+            rows if rows <= -HALF => Target::Up(column),
+            rows if rows >= HALF => Target::Down(column),
+            rows => Target::Item(column, rows),
+        })
+    }
+
+    fn status(&self, target: Target, hovered: Option<Target>) -> Status {
+        if hovered == Some(target) && self.state.pressed == Some(target) {
+            Status::Pressed
+        } else if hovered == Some(target) {
+            Status::Hovered
+        } else {
+            Status::Active
+        }
+    }
+
+    fn on_key(
+        &mut self,
+        key: &Key,
+        modifiers: keyboard::Modifiers,
+        shell: &mut Shell<'_, Message>,
+    ) -> event::Status {
+        let last = self.columns() - 1;
+        let column = self.state.column.min(last);
+
+        match key.as_ref() {
+            Key::Named(Named::Escape) => self.state.is_open = false,
+            Key::Named(Named::Enter) => self.picker.accept(self.state, shell),
+            Key::Named(Named::ArrowUp) => self.picker.step(self.state, column, -1, shell),
+            Key::Named(Named::ArrowDown) => self.picker.step(self.state, column, 1, shell),
+            Key::Named(Named::ArrowLeft) => self.state.column = column.saturating_sub(1),
+            Key::Named(Named::ArrowRight) => self.state.column = (column + 1).min(last),
+            Key::Named(Named::Tab) if modifiers.shift() => {
+                self.state.column = (column + last) % (last + 1);
+            }
+            Key::Named(Named::Tab) => self.state.column = (column + 1) % (last + 1),
+            _ => return event::Status::Ignored,
+        }
+
+        self.state.show_focus = true;
+
+        event::Status::Captured
+    }
+}
+
+impl<Message> overlay::Overlay<Message, Theme, Renderer> for Flyout<'_, '_, Message> {
+    fn layout(&mut self, _renderer: &Renderer, bounds: Size) -> Node {
+        let size = Size::new(
+            self.anchor.width,
+            VISIBLE_ITEMS as f32 * ITEM_HEIGHT + 1.0 + BUTTON_HEIGHT + 2.0 * PADDING,
+        );
+
+        let middle = PADDING + HALF as f32 * ITEM_HEIGHT + ITEM_HEIGHT / 2.0;
+        let y = (self.anchor.center_y() - middle)
+            .min(bounds.height - size.height - MARGIN)
+            .max(MARGIN);
+        let x = self
+            .anchor
+            .x
+            .min(bounds.width - size.width - MARGIN)
+            .max(MARGIN);
+
+        Node::new(size).move_to(Point::new(x, y))
+    }
+
+    fn draw(
+        &self,
+        renderer: &mut Renderer,
+        theme: &Theme,
+        _style: &renderer::Style,
+        layout: Layout<'_>,
+        cursor: Cursor,
+    ) {
+        let bounds = layout.bounds();
+        let appearance = <Theme as container::Catalog>::style(theme, &"overlay".into());
+        let background = appearance
+            .background
+            .unwrap_or(Background::Color(Color::TRANSPARENT));
+        let style = self.picker.class.resolve(theme, Status::Active);
+        let hovered = self.target_at(bounds, cursor);
+        let columns = columns_bounds(bounds);
+        let band = Rectangle {
+            y: columns.y + HALF as f32 * ITEM_HEIGHT,
+            height: ITEM_HEIGHT,
+            ..columns
+        }
+        .shrink([0.0, PADDING]);
+
+        renderer.fill_quad(
+            renderer::Quad {
+                bounds,
+                border: appearance.border,
+                shadow: appearance.shadow,
+            },
+            background,
+        );
+
+        renderer.fill_quad(
+            renderer::Quad {
+                bounds: band,
+                border: Border {
+                    radius: Radius::new(4.0),
+                    ..Border::default()
+                },
+                ..renderer::Quad::default()
+            },
+            style.highlight_background,
+        );
+
+        for (column, part) in self.picker.parts().iter().enumerate() {
+            let column_bounds = column_bounds(bounds, self.columns(), column);
+            let count = self.picker.count(*part);
+            let selected = self.picker.index(*part, self.state.draft);
+            let shift = self.state.shifts[column].value(self.state.now);
+
+            renderer.with_layer(column_bounds, |renderer| {
+                // One more row either side for the ones sliding into view
+                for rows in -HALF - 1..=HALF + 1 {
+                    let index = if *part == Part::Period {
+                        selected + rows
+                    } else {
+                        (selected + rows).rem_euclid(count)
+                    };
+
+                    if !(0..count).contains(&index) {
+                        continue;
+                    }
+
+                    let row = row_bounds(column_bounds, rows);
+
+                    if rows != 0 {
+                        let status = self.status(Target::Item(column, rows), hovered);
+
+                        renderer.fill_quad(
+                            renderer::Quad {
+                                bounds: row.shrink([2.0, PADDING]),
+                                border: Border {
+                                    radius: Radius::new(4.0),
+                                    ..Border::default()
+                                },
+                                ..renderer::Quad::default()
+                            },
+                            self.picker.class.resolve(theme, status).item_background,
+                        );
+                    }
+
+                    let item = Rectangle {
+                        y: row.y + shift,
+                        ..row
+                    };
+                    let colour = if (item.center_y() - band.center_y()).abs() < ITEM_HEIGHT / 2.0 {
+                        style.highlight_text_color
+                    } else {
+                        style.text_color
+                    };
+
+                    draw_text(renderer, self.picker.label(*part, index), item, colour);
+                }
+
+                // The caret buttons cover the top and bottom rows of the column
+                // under the pointer
+                let is_hovered = matches!(
+                    hovered,
+                    Some(Target::Item(hovered, _) | Target::Up(hovered) | Target::Down(hovered))
+                        if hovered == column
+                );
+
+                if is_hovered {
+                    for (target, rows, glyph) in [
+                        (Target::Up(column), -HALF, FluentIcon::CaretUpSolid8),
+                        (Target::Down(column), HALF, FluentIcon::CaretDownSolid8),
+                    ] {
+                        let row = row_bounds(column_bounds, rows);
+
+                        renderer.fill_quad(
+                            renderer::Quad {
+                                bounds: row,
+                                ..renderer::Quad::default()
+                            },
+                            background,
+                        );
+
+                        let button_style = self
+                            .picker
+                            .class
+                            .resolve(theme, self.status(target, hovered));
+
+                        renderer.fill_quad(
+                            renderer::Quad {
+                                bounds: row.shrink([2.0, PADDING]),
+                                border: Border {
+                                    radius: Radius::new(4.0),
+                                    ..Border::default()
+                                },
+                                ..renderer::Quad::default()
+                            },
+                            button_style.item_background,
+                        );
+
+                        Icon::from(glyph).draw(
+                            renderer,
+                            Rectangle {
+                                x: row.center_x() - CARET_SIZE / 2.0,
+                                y: row.center_y() - CARET_SIZE / 2.0,
+                                width: CARET_SIZE,
+                                height: CARET_SIZE,
+                            },
+                            button_style.text_color,
+                        );
+                    }
+                }
+            });
+
+            if column > 0 {
+                renderer.fill_quad(
+                    renderer::Quad {
+                        bounds: Rectangle {
+                            width: 1.0,
+                            ..column_bounds
+                        },
+                        ..renderer::Quad::default()
+                    },
+                    style.divider_color,
+                );
+            }
+
+            if self.state.show_focus && column == self.state.column {
+                renderer.fill_quad(
+                    renderer::Quad {
+                        bounds: column_bounds.shrink(1.0),
+                        border: Border {
+                            color: style.focus,
+                            width: 2.0,
+                            radius: 4.0.into(),
+                        },
+                        ..renderer::Quad::default()
+                    },
+                    Color::TRANSPARENT,
+                );
+            }
+        }
+
+        renderer.fill_quad(
+            renderer::Quad {
+                bounds: Rectangle {
+                    x: bounds.x,
+                    y: columns.y + columns.height,
+                    width: bounds.width,
+                    height: 1.0,
+                },
+                ..renderer::Quad::default()
+            },
+            style.divider_color,
+        );
+
+        for (target, glyph) in [
+            (Target::Accept, FluentIcon::CheckMark),
+            (Target::Dismiss, FluentIcon::Cancel),
+        ] {
+            let button = button_bounds(bounds, target);
+            let button_style = self
+                .picker
+                .class
+                .resolve(theme, self.status(target, hovered));
+
+            renderer.fill_quad(
+                renderer::Quad {
+                    bounds: button,
+                    border: Border {
+                        radius: Radius::new(4.0),
+                        ..Border::default()
+                    },
+                    ..renderer::Quad::default()
+                },
+                button_style.item_background,
+            );
+
+            Icon::from(glyph).draw(
+                renderer,
+                Rectangle {
+                    x: button.center_x() - ICON_SIZE / 2.0,
+                    y: button.center_y() - ICON_SIZE / 2.0,
+                    width: ICON_SIZE,
+                    height: ICON_SIZE,
+                },
+                button_style.text_color,
+            );
+        }
+    }
+
+    fn on_event(
+        &mut self,
+        event: Event,
+        layout: Layout<'_>,
+        cursor: Cursor,
+        _renderer: &Renderer,
+        _clipboard: &mut dyn Clipboard,
+        shell: &mut Shell<'_, Message>,
+    ) -> event::Status {
+        let bounds = layout.bounds();
+
+        match event {
+            Event::Window(window::Event::RedrawRequested(now)) => {
+                self.state.now = now;
+
+                if self
+                    .state
+                    .shifts
+                    .iter()
+                    .any(|shift| shift.is_animating(now))
+                {
+                    shell.request_redraw(window::RedrawRequest::NextFrame);
+                }
+
+                event::Status::Ignored
+            }
+            // Presses on the field are left to it, it closes the flyout
+            Event::Mouse(mouse::Event::ButtonPressed(_))
+            | Event::Touch(touch::Event::FingerPressed { .. })
+                if !cursor.is_over(bounds) =>
+            {
+                if !cursor.is_over(self.anchor) {
+                    self.state.is_open = false;
+                }
+
+                event::Status::Ignored
+            }
+            Event::Mouse(mouse::Event::ButtonPressed(mouse::Button::Left))
+            | Event::Touch(touch::Event::FingerPressed { .. }) => {
+                self.state.pressed = self.target_at(bounds, cursor);
+                self.state.show_focus = false;
+
+                event::Status::Captured
+            }
+            Event::Mouse(mouse::Event::ButtonReleased(mouse::Button::Left))
+            | Event::Touch(touch::Event::FingerLifted { .. }) => {
+                let Some(pressed) = self.state.pressed.take() else {
+                    return event::Status::Ignored;
+                };
+
+                if self.target_at(bounds, cursor) == Some(pressed) {
+                    self.picker.activate(self.state, pressed, shell);
+                }
+
+                event::Status::Captured
+            }
+            Event::Touch(touch::Event::FingerLost { .. }) => {
+                self.state.pressed = None;
+
+                event::Status::Ignored
+            }
+            // The wheel steps the column under the pointer
+            Event::Mouse(mouse::Event::WheelScrolled { delta }) if cursor.is_over(bounds) => {
+                let y = match delta {
+                    mouse::ScrollDelta::Lines { y, .. } | mouse::ScrollDelta::Pixels { y, .. } => y,
+                };
+
+                if let Some(Target::Item(column, _) | Target::Up(column) | Target::Down(column)) =
+                    self.target_at(bounds, cursor)
+                {
+                    if y != 0.0 {
+                        self.picker
+                            .step(self.state, column, if y > 0.0 { -1 } else { 1 }, shell);
+                    }
+                }
+
+                event::Status::Captured
+            }
+            Event::Keyboard(keyboard::Event::KeyPressed { key, modifiers, .. }) => {
+                self.on_key(&key, modifiers, shell)
+            }
+            _ => event::Status::Ignored,
+        }
+    }
+
+    fn mouse_interaction(
+        &self,
+        layout: Layout<'_>,
+        cursor: Cursor,
+        _viewport: &Rectangle,
+        _renderer: &Renderer,
+    ) -> mouse::Interaction {
+        match self.target_at(layout.bounds(), cursor) {
+            Some(_) => mouse::Interaction::Pointer,
+            None => mouse::Interaction::default(),
+        }
+    }
+}
+
+fn columns_bounds(bounds: Rectangle) -> Rectangle {
+    Rectangle {
+        x: bounds.x + PADDING,
+        y: bounds.y + PADDING,
+        width: bounds.width - 2.0 * PADDING,
+        height: VISIBLE_ITEMS as f32 * ITEM_HEIGHT,
+    }
+}
+
+fn column_bounds(bounds: Rectangle, columns: usize, column: usize) -> Rectangle {
+    let all = columns_bounds(bounds);
+    let width = all.width / columns as f32;
+
+    Rectangle {
+        x: all.x + column as f32 * width,
+        width,
+        ..all
+    }
+}
+
+// The row `rows` from the middle of a column
+fn row_bounds(column: Rectangle, rows: i32) -> Rectangle {
+    Rectangle {
+        y: column.y + (HALF + rows) as f32 * ITEM_HEIGHT,
+        height: ITEM_HEIGHT,
+        ..column
+    }
+}
+
+fn button_bounds(bounds: Rectangle, target: Target) -> Rectangle {
+    let width = (bounds.width - 2.0 * PADDING) / 2.0;
+    let button = Rectangle {
+        x: bounds.x + PADDING,
+        y: bounds.y + bounds.height - PADDING - BUTTON_HEIGHT,
+        width,
+        height: BUTTON_HEIGHT,
+    };
+
+    match target {
+        Target::Dismiss => Rectangle {
+            x: button.x + width,
+            ..button
+        },
+        _ => button,
+    }
+    .shrink([2.0, 0.0])
+}
+
+fn draw_text(renderer: &mut Renderer, content: String, bounds: Rectangle, colour: Color) {
+    renderer.fill_text(
+        Text {
+            content,
+            bounds: bounds.size(),
+            size: Pixels(text::scaled(14.0)),
+            line_height: LineHeight::Absolute(Pixels(text::scaled(20.0))),
+            font: font::text(),
+            horizontal_alignment: Horizontal::Center,
+            vertical_alignment: Vertical::Center,
+            shaping: Shaping::Advanced,
+            wrapping: Wrapping::None,
+        },
+        Point::new(bounds.center_x(), bounds.center_y()),
+        colour,
+        bounds,
+    );
+}
@@ -1,12 +1,403 @@
-use crate::{font, widget::Toggler};
+use crate::{
+    density, font,
+    motion::{self, Animated, Easing, Interpolate},
+    theme,
+    widget::text::scaled,
+};
 
-use iced::{widget::text::LineHeight, Pixels};
+use iced::{
+    advanced::{
+        layout::{self, Limits, Node},
+        renderer,
+        text::{self, Paragraph},
+        widget::{self, tree, Tree},
+        Clipboard, Layout, Shell, Widget,
+    },
+    alignment, event,
+    mouse::{self, Cursor},
+    touch,
+    widget::{
+        text::LineHeight,
+        toggler::{Catalog, Status, Style, StyleFn},
+    },
+    window, Border, Element, Event, Length, Pixels, Rectangle, Size,
+};
+
+use std::time::Instant;
 
 pub fn standard<'a, Message>(is_toggled: bool) -> Toggler<'a, Message> {
     Toggler::new(is_toggled)
         .size(20)
-        .font(font::SEGOE)
-        .text_size(14)
-        .text_line_height(LineHeight::Absolute(Pixels(20.0)))
-        .spacing(12)
+        .font(font::text())
+        .text_size(scaled(14.0))
+        .text_line_height(LineHeight::Absolute(Pixels(scaled(20.0))))
+        .spacing(density::metrics().spacing)
+}
+
+// iced's toggler with a thumb that slides between its two positions
+pub struct Toggler<'a, Message, Theme = theme::Theme, Renderer = iced::Renderer>
+where
+    Theme: Catalog,
+    Renderer: text::Renderer,
+{
+    is_toggled: bool,
+    on_toggle: Option<Box<dyn Fn(bool) -> Message + 'a>>,
+    label: Option<text::Fragment<'a>>,
+    width: Length,
+    size: f32,
+    text_size: Option<Pixels>,
+    text_line_height: LineHeight,
+    text_alignment: alignment::Horizontal,
+    spacing: f32,
+    font: Option<Renderer::Font>,
+    class: Theme::Class<'a>,
+}
+
+impl<'a, Message, Theme, Renderer> Toggler<'a, Message, Theme, Renderer>
+where
+    Theme: Catalog,
+    Renderer: text::Renderer,
+{
+    pub fn new(is_toggled: bool) -> Self {
+        Self {
+            is_toggled,
+            on_toggle: None,
+            label: None,
+            width: Length::Shrink,
+            size: 16.0,
+            text_size: None,
+            text_line_height: LineHeight::default(),
+            text_alignment: alignment::Horizontal::Left,
+            spacing: 8.0,
+            font: None,
+            class: Theme::default(),
+        }
+    }
+
+    pub fn label(mut self, label: impl text::IntoFragment<'a>) -> Self {
+        self.label = Some(label.into_fragment());
+        self
+    }
+
+    pub fn on_toggle(mut self, on_toggle: impl Fn(bool) -> Message + 'a) -> Self {
+        self.on_toggle = Some(Box::new(on_toggle));
+        self
+    }
+
+    pub fn on_toggle_maybe(mut self, on_toggle: Option<impl Fn(bool) -> Message + 'a>) -> Self {
+        self.on_toggle = on_toggle.map(|on_toggle| Box::new(on_toggle) as _);
+        self
+    }
+
+    pub fn size(mut self, size: impl Into<Pixels>) -> Self {
+        self.size = size.into().0;
+        self
+    }
+
+    pub fn width(mut self, width: impl Into<Length>) -> Self {
+        self.width = width.into();
+        self
+    }
+
+    pub fn text_size(mut self, text_size: impl Into<Pixels>) -> Self {
+        self.text_size = Some(text_size.into());
+        self
+    }
+
+    pub fn text_line_height(mut self, line_height: impl Into<LineHeight>) -> Self {
+        self.text_line_height = line_height.into();
+        self
+    }
+
+    pub fn text_alignment(mut self, alignment: alignment::Horizontal) -> Self {
+        self.text_alignment = alignment;
+        self
+    }
+
+    pub fn spacing(mut self, spacing: impl Into<Pixels>) -> Self {
+        self.spacing = spacing.into().0;
+        self
+    }
+
+    pub fn font(mut self, font: impl Into<Renderer::Font>) -> Self {
+        self.font = Some(font.into());
+        self
+    }
+
+    pub fn style(mut self, style: impl Fn(&Theme, Status) -> Style + 'a) -> Self
+    where
+        Theme::Class<'a>: From<StyleFn<'a, Theme>>,
+    {
+        self.class = (Box::new(style) as StyleFn<'a, Theme>).into();
+        self
+    }
+
+    pub fn class(mut self, class: impl Into<Theme::Class<'a>>) -> Self {
+        self.class = class.into();
+        self
+    }
+
+    fn target(&self) -> f32 {
+        if self.is_toggled {
+            1.0
+        } else {
+            0.0
+        }
+    }
+}
+
+struct State<P: Paragraph> {
+    label: widget::text::State<P>,
+    // 0 is off and 1 is on
+    position: Animated<f32>,
+    now: Instant,
+}
+
+impl<'a, Message, Theme, Renderer> Widget<Message, Theme, Renderer>
+    for Toggler<'a, Message, Theme, Renderer>
+where
+    Theme: Catalog,
+    Renderer: text::Renderer,
+{
+    fn tag(&self) -> tree::Tag {
+        tree::Tag::of::<State<Renderer::Paragraph>>()
+    }
+
+    fn state(&self) -> tree::State {
+        tree::State::new(State::<Renderer::Paragraph> {
+            label: widget::text::State::default(),
+            position: Animated::new(self.target())
+                .duration(motion::FAST)
+                .easing(Easing::POINT_TO_POINT),
+            now: Instant::now(),
+        })
+    }
+
+    fn diff(&self, tree: &mut Tree) {
+        let state = tree.state.downcast_mut::<State<Renderer::Paragraph>>();
+        let now = Instant::now();
+
+        if state.position.target() != self.target() {
+            state.position.go_to(self.target(), now);
+            state.now = now;
+        }
+    }
+
+    fn size(&self) -> Size<Length> {
+        Size {
+            width: self.width,
+            height: Length::Shrink,
+        }
+    }
+
+    fn layout(&self, tree: &mut Tree, renderer: &Renderer, limits: &Limits) -> Node {
+        let limits = limits.width(self.width);
+
+        layout::next_to_each_other(
+            &limits,
+            self.spacing,
+            |_| Node::new(Size::new(2.0 * self.size, self.size)),
+            |limits| match self.label.as_deref() {
+                Some(label) => widget::text::layout(
+                    &mut tree
+                        .state
+                        .downcast_mut::<State<Renderer::Paragraph>>()
+                        .label,
+                    renderer,
+                    limits,
+                    self.width,
+                    Length::Shrink,
+                    label,
+                    self.text_line_height,
+                    self.text_size,
+                    self.font,
+                    self.text_alignment,
+                    alignment::Vertical::Top,
+                    text::Shaping::default(),
+                    text::Wrapping::default(),
+                ),
+                None => Node::new(Size::ZERO),
+            },
+        )
+    }
+
+    fn on_event(
+        &mut self,
+        tree: &mut Tree,
+        event: Event,
+        layout: Layout<'_>,
+        cursor: Cursor,
+        _renderer: &Renderer,
+        _clipboard: &mut dyn Clipboard,
+        shell: &mut Shell<'_, Message>,
+        _viewport: &Rectangle,
+    ) -> event::Status {
+        match event {
+            Event::Window(window::Event::RedrawRequested(now)) => {
+                let state = tree.state.downcast_mut::<State<Renderer::Paragraph>>();
+                state.now = now;
+
+                if state.position.is_animating(now) {
+                    shell.request_redraw(window::RedrawRequest::NextFrame);
+                }
+
+                event::Status::Ignored
+            }
+            Event::Mouse(mouse::Event::ButtonPressed(mouse::Button::Left))
+            | Event::Touch(touch::Event::FingerPressed { .. }) => match &self.on_toggle {
+                Some(on_toggle) if cursor.is_over(layout.bounds()) => {
+                    shell.publish(on_toggle(!self.is_toggled));
+
+                    event::Status::Captured
+                }
+                _ => event::Status::Ignored,
+            },
+            _ => event::Status::Ignored,
+        }
+    }
+
+    fn mouse_interaction(
+        &self,
+        _tree: &Tree,
+        layout: Layout<'_>,
+        cursor: Cursor,
+        _viewport: &Rectangle,
+        _renderer: &Renderer,
+    ) -> mouse::Interaction {
+        if cursor.is_over(layout.bounds()) {
+            if self.on_toggle.is_some() {
+                mouse::Interaction::Pointer
+            } else {
+                mouse::Interaction::NotAllowed
+            }
+        } else {
+            mouse::Interaction::default()
+        }
+    }
+
+    fn draw(
+        &self,
+        tree: &Tree,
+        renderer: &mut Renderer,
+        theme: &Theme,
+        style: &renderer::Style,
+        layout: Layout<'_>,
+        cursor: Cursor,
+        viewport: &Rectangle,
+    ) {
+        const BORDER_RADIUS_RATIO: f32 = 32.0 / 13.0;
+        const SPACE_RATIO: f32 = 0.05;
+
+        let state = tree.state.downcast_ref::<State<Renderer::Paragraph>>();
+        let mut children = layout.children();
+        let toggler_layout = children.next().unwrap();
+
+        if self.label.is_some() {
+            widget::text::draw(
+                renderer,
+                style,
+                children.next().unwrap(),
+                state.label.0.raw(),
+                widget::text::Style::default(),
+                viewport,
+            );
+        }
+
+        let position = state.position.value(state.now);
+        let is_mouse_over = cursor.is_over(layout.bounds());
+
+        // The colours fade between the off and on styles as the thumb moves
+        let style = if self.on_toggle.is_none() {
+            theme.style(&self.class, Status::Disabled)
+        } else {
+            let status = |is_toggled| {
+                if is_mouse_over {
+                    Status::Hovered { is_toggled }
+                } else {
+                    Status::Active { is_toggled }
+                }
+            };
+
+            blend(
+                theme.style(&self.class, status(false)),
+                theme.style(&self.class, status(true)),
+                position,
+            )
+        };
+
+        let bounds = toggler_layout.bounds();
+        let border_radius = bounds.height / BORDER_RADIUS_RATIO;
+        let space = SPACE_RATIO * bounds.height;
+
+        renderer.fill_quad(
+            renderer::Quad {
+                bounds: Rectangle {
+                    x: bounds.x + space,
+                    y: bounds.y + space,
+                    width: bounds.width - (2.0 * space),
+                    height: bounds.height - (2.0 * space),
+                },
+                border: Border {
+                    radius: border_radius.into(),
+                    width: style.background_border_width,
+                    color: style.background_border_color,
+                },
+                ..renderer::Quad::default()
+            },
+            style.background,
+        );
+
+        let thumb = bounds.height - (4.0 * space);
+        let off = 2.0 * space;
+        let on = bounds.width - 2.0 * space - thumb;
+
+        renderer.fill_quad(
+            renderer::Quad {
+                bounds: Rectangle {
+                    x: bounds.x + off.interpolate(on, position),
+                    y: bounds.y + (2.0 * space),
+                    width: thumb,
+                    height: thumb,
+                },
+                border: Border {
+                    radius: border_radius.into(),
+                    width: style.foreground_border_width,
+                    color: style.foreground_border_color,
+                },
+                ..renderer::Quad::default()
+            },
+            style.foreground,
+        );
+    }
+}
+
+impl<'a, Message, Theme, Renderer> From<Toggler<'a, Message, Theme, Renderer>>
+    for Element<'a, Message, Theme, Renderer>
+where
+    Message: 'a,
+    Theme: 'a + Catalog,
+    Renderer: 'a + text::Renderer,
+{
+    fn from(toggler: Toggler<'a, Message, Theme, Renderer>) -> Self {
+        Self::new(toggler)
+    }
+}
+
+fn blend(off: Style, on: Style, t: f32) -> Style {
+    Style {
+        background: off.background.interpolate(on.background, t),
+        background_border_width: off
+            .background_border_width
+            .interpolate(on.background_border_width, t),
+        background_border_color: off
+            .background_border_color
+            .interpolate(on.background_border_color, t),
+        foreground: off.foreground.interpolate(on.foreground, t),
+        foreground_border_width: off
+            .foreground_border_width
+            .interpolate(on.foreground_border_width, t),
+        foreground_border_color: off
+            .foreground_border_color
+            .interpolate(on.foreground_border_color, t),
+    }
 }
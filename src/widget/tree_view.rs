@@ -0,0 +1,1107 @@
+use crate::{
+    density,
+    fluent_icon::FluentIcon,
+    font,
+    icon::Icon,
+    style::list_item::{Status, Style, StyleFn},
+    theme::{
+        class::{self, Class},
+        Theme,
+    },
+    widget::{
+        checkbox::{self, ThreeState},
+        text,
+        virtual_scroll::Scroll,
+        Element,
+    },
+};
+
+use iced::{
+    advanced::{
+        layout::{Limits, Node as LayoutNode},
+        mouse::{click, Click},
+        renderer::{self, Renderer as _},
+        text::{LineHeight, Renderer as _, Shaping, Text, Wrapping},
+        widget::{tree, Tree},
+        Clipboard, Layout, Shell, Widget,
+    },
+    alignment::{Horizontal, Vertical},
+    event,
+    keyboard::{self, key::Named, Key},
+    mouse::{self, Cursor},
+    touch, Border, Color, Event, Length, Pixels, Point, Rectangle, Size,
+};
+
+use std::{
+    fmt,
+    time::{Duration, Instant},
+};
+
+type Renderer = iced::Renderer;
+
+// TreeViewItem sizes from
+// https://github.com/microsoft/microsoft-ui-xaml/blob/main/src/controls/dev/TreeView/TreeView_themeresources.xaml
+const INDENT: f32 = 16.0;
+const LEADING: f32 = 8.0;
+const CHEVRON_WIDTH: f32 = 24.0;
+const CHEVRON_SIZE: f32 = 12.0;
+const CHECKBOX_SIZE: f32 = 20.0;
+const ICON_SIZE: f32 = 16.0;
+const SPACING: f32 = 8.0;
+// Rows are inset from the sides and from each other
+const INSET: f32 = 4.0;
+const GAP: f32 = 2.0;
+// Typing within this long of the last key adds to the search rather than
+// starting a new one
+const TYPE_AHEAD: Duration = Duration::from_secs(1);
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash, PartialOrd, Ord)]
+pub struct NodeId(usize);
+
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub enum SelectionMode {
+    None,
+    #[default]
+    Single,
+    // Every node gets a checkbox, checking a node checks everything under it
+    Multiple,
+}
+
+impl SelectionMode {
+    pub const ALL: [SelectionMode; 3] = [
+        SelectionMode::None,
+        SelectionMode::Single,
+        SelectionMode::Multiple,
+    ];
+}
+
+impl fmt::Display for SelectionMode {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(match self {
+            SelectionMode::None => "None",
+            SelectionMode::Single => "Single",
+            SelectionMode::Multiple => "Multiple",
+        })
+    }
+}
+
+pub struct Item {
+    label: String,
+    icon: Option<Icon>,
+    is_lazy: bool,
+}
+
+impl Item {
+    pub fn new(label: impl Into<String>) -> Self {
+        Self {
+            label: label.into(),
+            icon: None,
+            is_lazy: false,
+        }
+    }
+
+    pub fn icon(mut self, icon: impl Into<Icon>) -> Self {
+        self.icon = Some(icon.into());
+        self
+    }
+
+    // The node shows a chevron before it has any children and asks for them with
+    // `TreeView::on_load` the first time it's expanded
+    pub fn lazy(mut self) -> Self {
+        self.is_lazy = true;
+        self
+    }
+}
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+enum Load {
+    Loaded,
+    Pending,
+    Loading,
+}
+
+#[derive(Clone, Debug)]
+struct Node {
+    label: String,
+    icon: Option<Icon>,
+    parent: Option<NodeId>,
+    children: Vec<NodeId>,
+    load: Load,
+    is_expanded: bool,
+    check: ThreeState,
+}
+
+// What the user asked for, applied to the content with `Content::perform`
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum Action {
+    Expand(NodeId),
+    Collapse(NodeId),
+    Select(NodeId),
+    // Flips the node's checkbox, which carries down to its descendants and up to
+    // its ancestors
+    Check(NodeId),
+}
+
+// The nodes of a tree and their expanded, selected and checked states. It lives in
+// the application's state like a `text_editor::Content` and the `TreeView`
+// borrows it to draw.
+#[derive(Clone, Debug, Default)]
+pub struct Content {
+    nodes: Vec<Node>,
+    roots: Vec<NodeId>,
+    selected: Option<NodeId>,
+}
+
+impl Content {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    // Children of a checked node start out checked, which leaves the parent's
+    // state as it was
+    pub fn push(&mut self, parent: Option<NodeId>, item: Item) -> NodeId {
+        let id = NodeId(self.nodes.len());
+        let check = match parent.map(|parent| self.nodes[parent.0].check) {
+            Some(ThreeState::Checked) => ThreeState::Checked,
+            _ => ThreeState::Unchecked,
+        };
+
+        self.nodes.push(Node {
+            label: item.label,
+            icon: item.icon,
+            parent,
+            children: Vec::new(),
+            load: if item.is_lazy {
+                Load::Pending
+            } else {
+                Load::Loaded
+            },
+            is_expanded: false,
+            check,
+        });
+
+        match parent {
+            Some(parent) => self.nodes[parent.0].children.push(id),
+            None => self.roots.push(id),
+        }
+
+        id
+    }
+
+    // Adds the children of a lazy node once they've been fetched
+    pub fn load(&mut self, parent: NodeId, items: impl IntoIterator<Item = Item>) -> Vec<NodeId> {
+        let children = items
+            .into_iter()
+            .map(|item| self.push(Some(parent), item))
+            .collect();
+
+        self.nodes[parent.0].load = Load::Loaded;
+
+        children
+    }
+
+    pub fn perform(&mut self, action: Action) {
+        match action {
+            Action::Expand(id) => {
+                let node = &mut self.nodes[id.0];
+                node.is_expanded = true;
+
+                if node.load == Load::Pending {
+                    node.load = Load::Loading;
+                }
+            }
+            Action::Collapse(id) => self.nodes[id.0].is_expanded = false,
+            Action::Select(id) => self.selected = Some(id),
+            Action::Check(id) => {
+                let check = match self.nodes[id.0].check {
+                    ThreeState::Checked => ThreeState::Unchecked,
+                    ThreeState::Unchecked | ThreeState::Indeterminate => ThreeState::Checked,
+                };
+
+                let mut stack = vec![id];
+
+                while let Some(id) = stack.pop() {
+                    let node = &mut self.nodes[id.0];
+                    node.check = check;
+                    stack.extend(&node.children);
+                }
+
+                self.update_ancestors(self.nodes[id.0].parent);
+            }
+        }
+    }
+
+    // A parent is checked when all its children are, unchecked when none are and
+    // indeterminate otherwise
+    fn update_ancestors(&mut self, mut parent: Option<NodeId>) {
+        while let Some(id) = parent {
+            let mut states = self.nodes[id.0]
+                .children
+                .iter()
+                .map(|child| self.nodes[child.0].check);
+
+            let Some(first) = states.next() else {
+                return;
+            };
+
+            let check = if states.all(|state| state == first) {
+                first
+            } else {
+                ThreeState::Indeterminate
+            };
+
+            let node = &mut self.nodes[id.0];
+
+            if node.check == check {
+                return;
+            }
+
+            node.check = check;
+            parent = node.parent;
+        }
+    }
+
+    pub fn len(&self) -> usize {
+        self.nodes.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.nodes.is_empty()
+    }
+
+    pub fn roots(&self) -> &[NodeId] {
+        &self.roots
+    }
+
+    pub fn label(&self, id: NodeId) -> &str {
+        &self.nodes[id.0].label
+    }
+
+    pub fn parent(&self, id: NodeId) -> Option<NodeId> {
+        self.nodes[id.0].parent
+    }
+
+    pub fn children(&self, id: NodeId) -> &[NodeId] {
+        &self.nodes[id.0].children
+    }
+
+    pub fn has_children(&self, id: NodeId) -> bool {
+        let node = &self.nodes[id.0];
+
+        !node.children.is_empty() || node.load != Load::Loaded
+    }
+
+    pub fn is_expanded(&self, id: NodeId) -> bool {
+        self.nodes[id.0].is_expanded
+    }
+
+    pub fn is_loading(&self, id: NodeId) -> bool {
+        self.nodes[id.0].load == Load::Loading
+    }
+
+    pub fn selected(&self) -> Option<NodeId> {
+        self.selected
+    }
+
+    pub fn check_state(&self, id: NodeId) -> ThreeState {
+        self.nodes[id.0].check
+    }
+
+    pub fn checked(&self) -> impl Iterator<Item = NodeId> + '_ {
+        self.nodes
+            .iter()
+            .enumerate()
+            .filter(|(_, node)| node.check == ThreeState::Checked)
+            .map(|(index, _)| NodeId(index))
+    }
+
+    // The nodes that can be seen with the current expansion, in order
+    fn rows(&self) -> Vec<Row> {
+        let mut rows = Vec::new();
+        let mut stack: Vec<(NodeId, usize)> =
+            self.roots.iter().rev().map(|root| (*root, 0)).collect();
+
+        while let Some((id, depth)) = stack.pop() {
+            let node = &self.nodes[id.0];
+            rows.push(Row::Node { id, depth });
+
+            if !node.is_expanded {
+                continue;
+            }
+
+            if node.load == Load::Loading {
+                rows.push(Row::Loading { depth: depth + 1 });
+            }
+
+            stack.extend(node.children.iter().rev().map(|child| (*child, depth + 1)));
+        }
+
+        rows
+    }
+}
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+enum Row {
+    Node { id: NodeId, depth: usize },
+    // Stands in for the children of a node while they load
+    Loading { depth: usize },
+}
+
+impl Row {
+    fn id(self) -> Option<NodeId> {
+        match self {
+            Row::Node { id, .. } => Some(id),
+            Row::Loading { .. } => None,
+        }
+    }
+
+    fn depth(self) -> usize {
+        match self {
+            Row::Node { depth, .. } | Row::Loading { depth } => depth,
+        }
+    }
+}
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+enum Part {
+    Chevron,
+    Checkbox,
+    Label,
+}
+
+// Based on https://learn.microsoft.com/en-us/windows/apps/design/controls/tree-view
+//
+// Only the expanded part of the tree is flattened into rows and only the rows in
+// view are drawn, so the content can hold thousands of nodes. Nodes marked lazy
+// fetch their children through `on_load` when they're first expanded.
+pub struct TreeView<'a, Message> {
+    content: &'a Content,
+    rows: Vec<Row>,
+    on_action: Box<dyn Fn(Action) -> Message + 'a>,
+    on_load: Option<Box<dyn Fn(NodeId) -> Message + 'a>>,
+    selection_mode: SelectionMode,
+    width: Length,
+    height: Length,
+    // Read when built, the density isn't known during layout
+    row_height: f32,
+    class: Class<StyleFn>,
+}
+
+impl<'a, Message> TreeView<'a, Message> {
+    pub fn new(content: &'a Content, on_action: impl Fn(Action) -> Message + 'a) -> Self {
+        Self {
+            content,
+            rows: content.rows(),
+            on_action: Box::new(on_action),
+            on_load: None,
+            selection_mode: SelectionMode::default(),
+            width: Length::Fill,
+            height: Length::Fill,
+            row_height: density::metrics().control_height,
+            class: class::DEFAULT.into(),
+        }
+    }
+
+    // Sent after `Action::Expand` for a lazy node that hasn't loaded yet, the node
+    // shows a loading row until `Content::load` is given its children
+    pub fn on_load(mut self, on_load: impl Fn(NodeId) -> Message + 'a) -> Self {
+        self.on_load = Some(Box::new(on_load));
+        self
+    }
+
+    pub fn selection_mode(mut self, selection_mode: SelectionMode) -> Self {
+        self.selection_mode = selection_mode;
+        self
+    }
+
+    pub fn width(mut self, width: impl Into<Length>) -> Self {
+        self.width = width.into();
+        self
+    }
+
+    pub fn height(mut self, height: impl Into<Length>) -> Self {
+        self.height = height.into();
+        self
+    }
+
+    pub fn style(mut self, style: impl Fn(&Theme, Status) -> Style + 'static) -> Self {
+        self.class = Class::Custom(Box::new(style));
+        self
+    }
+
+    pub fn class(mut self, class: impl Into<Class<StyleFn>>) -> Self {
+        self.class = class.into();
+        self
+    }
+
+    fn index_of(&self, id: NodeId) -> Option<usize> {
+        self.rows.iter().position(|row| row.id() == Some(id))
+    }
+
+    fn row_bounds(&self, state: &State, bounds: Rectangle, index: usize) -> Rectangle {
+        let row_height = self.row_height;
+
+        Rectangle {
+            x: bounds.x + INSET,
+            y: bounds.y + index as f32 * row_height - state.scroll.offset() + GAP / 2.0,
+            width: (bounds.width - 2.0 * INSET - state.scroll.gutter()).max(0.0),
+            height: row_height - GAP,
+        }
+    }
+
+    fn chevron_bounds(row: Rectangle, depth: usize) -> Rectangle {
+        Rectangle {
+            x: row.x + LEADING + depth as f32 * INDENT,
+            width: CHEVRON_WIDTH,
+            ..row
+        }
+    }
+
+    fn checkbox_bounds(&self, row: Rectangle, depth: usize) -> Option<Rectangle> {
+        (self.selection_mode == SelectionMode::Multiple).then(|| {
+            let chevron = Self::chevron_bounds(row, depth);
+
+            Rectangle {
+                x: chevron.x + chevron.width,
+                y: row.center_y() - CHECKBOX_SIZE / 2.0,
+                width: CHECKBOX_SIZE,
+                height: CHECKBOX_SIZE,
+            }
+        })
+    }
+
+    fn hit(&self, state: &State, bounds: Rectangle, cursor: Cursor) -> Option<(usize, Part)> {
+        let position = cursor.position_over(bounds)?;
+
+        if state.scroll.is_over_scrollbar(bounds, cursor) {
+            return None;
+        }
+
+        let index = (state.scroll.content_y(bounds, position) / self.row_height) as usize;
+        let row = *self.rows.get(index)?;
+        let row_bounds = self.row_bounds(state, bounds, index);
+
+        if !row_bounds.contains(position) {
+            return None;
+        }
+
+        let part = if Self::chevron_bounds(row_bounds, row.depth()).contains(position) {
+            Part::Chevron
+        } else if self
+            .checkbox_bounds(row_bounds, row.depth())
+            .is_some_and(|checkbox| checkbox.contains(position))
+        {
+            Part::Checkbox
+        } else {
+            Part::Label
+        };
+
+        Some((index, part))
+    }
+
+    fn expand(&self, id: NodeId, shell: &mut Shell<'_, Message>) {
+        shell.publish((self.on_action)(Action::Expand(id)));
+
+        if self.content.nodes[id.0].load == Load::Pending {
+            if let Some(on_load) = &self.on_load {
+                shell.publish(on_load(id));
+            }
+        }
+    }
+
+    fn toggle(&self, id: NodeId, shell: &mut Shell<'_, Message>) {
+        if !self.content.has_children(id) {
+            return;
+        }
+
+        if self.content.is_expanded(id) {
+            shell.publish((self.on_action)(Action::Collapse(id)));
+        } else {
+            self.expand(id, shell);
+        }
+    }
+
+    // What clicking a row or pressing Space on it does in each selection mode
+    fn invoke(&self, id: NodeId, shell: &mut Shell<'_, Message>) {
+        match self.selection_mode {
+            SelectionMode::None => self.toggle(id, shell),
+            SelectionMode::Single => {
+                if self.content.selected != Some(id) {
+                    shell.publish((self.on_action)(Action::Select(id)));
+                }
+            }
+            SelectionMode::Multiple => shell.publish((self.on_action)(Action::Check(id))),
+        }
+    }
+
+    // Moves keyboard focus, which the selection follows in single selection mode
+    fn focus(&self, state: &mut State, index: usize, shell: &mut Shell<'_, Message>) {
+        let Some(id) = self.rows.get(index).and_then(|row| row.id()) else {
+            return;
+        };
+
+        state.focused = Some(id);
+
+        let top = index as f32 * self.row_height;
+        state.scroll.show(top, top + self.row_height, shell);
+
+        if self.selection_mode == SelectionMode::Single && self.content.selected != Some(id) {
+            shell.publish((self.on_action)(Action::Select(id)));
+        }
+    }
+
+    // The next node row from `index` in `direction`, skipping loading rows
+    fn step(&self, index: usize, forwards: bool) -> Option<usize> {
+        if forwards {
+            (index + 1..self.rows.len()).find(|index| self.rows[*index].id().is_some())
+        } else {
+            (0..index)
+                .rev()
+                .find(|index| self.rows[*index].id().is_some())
+        }
+    }
+
+    // Finds the next node whose label starts with what's been typed, a single
+    // character moves on from the focused node so repeating it cycles through
+    // the matches
+    fn search(&self, state: &State, from: usize) -> Option<usize> {
+        let count = self.rows.len();
+        let query = state.type_ahead.to_lowercase();
+        let start = if query.chars().count() == 1 {
+            from + 1
+        } else {
+            from
+        };
+
+        (0..count)
+            .map(|offset| (start + offset) % count)
+            .find(|index| {
+                self.rows[*index].id().is_some_and(|id| {
+                    self.content.nodes[id.0]
+                        .label
+                        .to_lowercase()
+                        .starts_with(&query)
+                })
+            })
+    }
+
+    fn on_key(
+        &self,
+        state: &mut State,
+        key: &Key,
+        text: Option<&str>,
+        modifiers: keyboard::Modifiers,
+        shell: &mut Shell<'_, Message>,
+    ) -> event::Status {
+        let focused = state.focused.and_then(|id| self.index_of(id));
+        let current = focused.unwrap_or(0);
+        let first = self.step(0, true).or_else(|| self.rows.first().map(|_| 0));
+        let last = self.step(self.rows.len(), false);
+
+        match key.as_ref() {
+            Key::Named(Named::ArrowDown) => {
+                let next = match focused {
+                    Some(index) => self.step(index, true),
+                    None => first,
+                };
+
+                if let Some(index) = next {
+                    self.focus(state, index, shell);
+                }
+            }
+            Key::Named(Named::ArrowUp) => {
+                let previous = match focused {
+                    Some(index) => self.step(index, false),
+                    None => first,
+                };
+
+                if let Some(index) = previous {
+                    self.focus(state, index, shell);
+                }
+            }
+            Key::Named(Named::Home) => {
+                if let Some(index) = first {
+                    self.focus(state, index, shell);
+                }
+            }
+            Key::Named(Named::End) => {
+                if let Some(index) = last {
+                    self.focus(state, index, shell);
+                }
+            }
+            // Right expands a node and then goes into it, Left collapses it and then
+            // goes up to its parent
+            Key::Named(Named::ArrowRight) => {
+                let Some(id) = focused.and_then(|index| self.rows[index].id()) else {
+                    return event::Status::Ignored;
+                };
+
+                if !self.content.is_expanded(id) {
+                    self.toggle(id, shell);
+                } else if let Some(index) = self
+                    .step(current, true)
+                    .filter(|index| self.rows[*index].depth() > self.rows[current].depth())
+                {
+                    self.focus(state, index, shell);
+                }
+            }
+            Key::Named(Named::ArrowLeft) => {
+                let Some(id) = focused.and_then(|index| self.rows[index].id()) else {
+                    return event::Status::Ignored;
+                };
+
+                if self.content.is_expanded(id) {
+                    self.toggle(id, shell);
+                } else if let Some(index) = self
+                    .content
+                    .parent(id)
+                    .and_then(|parent| self.index_of(parent))
+                {
+                    self.focus(state, index, shell);
+                }
+            }
+            Key::Named(Named::Space | Named::Enter) => {
+                let Some(id) = focused.and_then(|index| self.rows[index].id()) else {
+                    return event::Status::Ignored;
+                };
+
+                self.invoke(id, shell);
+            }
+            _ => {
+                let Some(text) = text
+                    .filter(|text| !modifiers.command() && text.chars().all(|c| !c.is_control()))
+                else {
+                    return event::Status::Ignored;
+                };
+
+                let now = Instant::now();
+
+                if state
+                    .last_key
+                    .is_none_or(|last| now.duration_since(last) > TYPE_AHEAD)
+                {
+                    state.type_ahead.clear();
+                }
+
+                state.type_ahead.push_str(text);
+                state.last_key = Some(now);
+
+                if let Some(index) = self.search(state, current) {
+                    self.focus(state, index, shell);
+                }
+            }
+        }
+
+        event::Status::Captured
+    }
+}
+
+#[derive(Default)]
+struct State {
+    scroll: Scroll,
+    // The node with keyboard focus, and whether the tree has it at all. The tree
+    // takes focus when it's clicked and loses it to a click anywhere else
+    focused: Option<NodeId>,
+    is_focused: bool,
+    pressed: Option<NodeId>,
+    last_click: Option<Click>,
+    type_ahead: String,
+    last_key: Option<Instant>,
+}
+
+impl<'a, Message> Widget<Message, Theme, Renderer> for TreeView<'a, Message> {
+    fn tag(&self) -> tree::Tag {
+        tree::Tag::of::<State>()
+    }
+
+    fn state(&self) -> tree::State {
+        tree::State::new(State::default())
+    }
+
+    fn size(&self) -> Size<Length> {
+        Size::new(self.width, self.height)
+    }
+
+    fn layout(&self, tree: &mut Tree, _renderer: &Renderer, limits: &Limits) -> LayoutNode {
+        let extent = self.rows.len() as f32 * self.row_height;
+        let size = limits.resolve(
+            self.width,
+            self.height,
+            Size::new(limits.max().width, extent),
+        );
+
+        tree.state
+            .downcast_mut::<State>()
+            .scroll
+            .resize(extent, size.height);
+
+        LayoutNode::new(size)
+    }
+
+    fn on_event(
+        &mut self,
+        tree: &mut Tree,
+        event: Event,
+        layout: Layout<'_>,
+        cursor: Cursor,
+        _renderer: &Renderer,
+        _clipboard: &mut dyn Clipboard,
+        shell: &mut Shell<'_, Message>,
+        _viewport: &Rectangle,
+    ) -> event::Status {
+        let state = tree.state.downcast_mut::<State>();
+        let bounds = layout.bounds();
+
+        if state.scroll.on_event(&event, bounds, cursor, shell) == event::Status::Captured {
+            state.is_focused = true;
+
+            return event::Status::Captured;
+        }
+
+        match event {
+            Event::Mouse(mouse::Event::ButtonPressed(mouse::Button::Left))
+            | Event::Touch(touch::Event::FingerPressed { .. }) => {
+                let Some(position) = cursor.position_over(bounds) else {
+                    state.is_focused = false;
+
+                    return event::Status::Ignored;
+                };
+
+                state.is_focused = true;
+
+                let Some((index, part)) = self.hit(state, bounds, cursor) else {
+                    return event::Status::Captured;
+                };
+
+                let Some(id) = self.rows[index].id() else {
+                    return event::Status::Captured;
+                };
+
+                let click = Click::new(position, mouse::Button::Left, state.last_click);
+                state.last_click = Some(click);
+                state.focused = Some(id);
+                state.pressed = Some(id);
+
+                match part {
+                    Part::Chevron => self.toggle(id, shell),
+                    Part::Checkbox => shell.publish((self.on_action)(Action::Check(id))),
+                    Part::Label => {
+                        self.invoke(id, shell);
+
+                        // A double click opens or closes the node where a single
+                        // click doesn't already
+                        if matches!(click.kind(), click::Kind::Double)
+                            && self.selection_mode != SelectionMode::None
+                        {
+                            self.toggle(id, shell);
+                        }
+                    }
+                }
+
+                event::Status::Captured
+            }
+            Event::Mouse(mouse::Event::ButtonReleased(mouse::Button::Left))
+            | Event::Touch(touch::Event::FingerLifted { .. })
+            | Event::Touch(touch::Event::FingerLost { .. }) => {
+                state.pressed = None;
+
+                event::Status::Ignored
+            }
+            Event::Keyboard(keyboard::Event::KeyPressed {
+                key,
+                text,
+                modifiers,
+                ..
+            }) if state.is_focused => self.on_key(state, &key, text.as_deref(), modifiers, shell),
+            _ => event::Status::Ignored,
+        }
+    }
+
+    fn draw(
+        &self,
+        tree: &Tree,
+        renderer: &mut Renderer,
+        theme: &Theme,
+        _style: &renderer::Style,
+        layout: Layout<'_>,
+        cursor: Cursor,
+        viewport: &Rectangle,
+    ) {
+        let state = tree.state.downcast_ref::<State>();
+        let bounds = layout.bounds();
+        let hovered = self.hit(state, bounds, cursor);
+        let clip = bounds.intersection(viewport).unwrap_or(bounds);
+        let visible = state.scroll.visible(self.row_height, self.rows.len(), 0);
+
+        renderer.with_layer(clip, |renderer| {
+            for index in visible {
+                let row = self.rows[index];
+                let bounds = self.row_bounds(state, bounds, index);
+                let depth = row.depth();
+                let is_hovered = hovered.is_some_and(|(hovered, _)| hovered == index);
+
+                let Some(id) = row.id() else {
+                    let style = self.class.resolve(theme, Status::Active);
+
+                    draw_label(
+                        renderer,
+                        "Loading…",
+                        Rectangle {
+                            x: bounds.x + LEADING + depth as f32 * INDENT + CHEVRON_WIDTH,
+                            ..bounds
+                        },
+                        style.text_color.scale_alpha(0.6),
+                    );
+
+                    continue;
+                };
+
+                let node = &self.content.nodes[id.0];
+                let is_selected = self.selection_mode == SelectionMode::Single
+                    && self.content.selected == Some(id);
+
+                let status = match (is_selected, is_hovered) {
+                    (true, true) => Status::SelectedHovered,
+                    (true, false) => Status::Selected,
+                    (false, true) if state.pressed == Some(id) => Status::Pressed,
+                    (false, true) => Status::Hovered,
+                    (false, false) => Status::Active,
+                };
+
+                let style = self.class.resolve(theme, status);
+
+                renderer.fill_quad(
+                    renderer::Quad {
+                        bounds,
+                        border: Border {
+                            radius: 4.0.into(),
+                            ..Border::default()
+                        },
+                        ..renderer::Quad::default()
+                    },
+                    style.background,
+                );
+
+                if is_selected {
+                    renderer.fill_quad(
+                        renderer::Quad {
+                            bounds: Rectangle {
+                                x: bounds.x,
+                                y: bounds.center_y() - 8.0,
+                                width: 3.0,
+                                height: 16.0,
+                            },
+                            border: Border {
+                                radius: 1.5.into(),
+                                ..Border::default()
+                            },
+                            ..renderer::Quad::default()
+                        },
+                        style.indicator,
+                    );
+                }
+
+                if state.is_focused && state.focused == Some(id) {
+                    renderer.fill_quad(
+                        renderer::Quad {
+                            bounds,
+                            border: Border {
+                                color: style.focus,
+                                width: 2.0,
+                                radius: 4.0.into(),
+                            },
+                            ..renderer::Quad::default()
+                        },
+                        Color::TRANSPARENT,
+                    );
+                }
+
+                let chevron = Self::chevron_bounds(bounds, depth);
+
+                if self.content.has_children(id) {
+                    let glyph = if node.is_expanded {
+                        FluentIcon::ChevronDown
+                    } else {
+                        FluentIcon::ChevronRight
+                    };
+
+                    Icon::from(glyph).draw(
+                        renderer,
+                        Rectangle {
+                            x: chevron.center_x() - CHEVRON_SIZE / 2.0,
+                            y: chevron.center_y() - CHEVRON_SIZE / 2.0,
+                            width: CHEVRON_SIZE,
+                            height: CHEVRON_SIZE,
+                        },
+                        style.text_color,
+                    );
+                }
+
+                let mut x = chevron.x + chevron.width;
+
+                if let Some(checkbox) = self.checkbox_bounds(bounds, depth) {
+                    let is_over = hovered == Some((index, Part::Checkbox));
+                    checkbox::draw(renderer, theme, checkbox, node.check, is_over);
+                    x = checkbox.x + checkbox.width + SPACING;
+                }
+
+                if let Some(icon) = &node.icon {
+                    icon.draw(
+                        renderer,
+                        Rectangle {
+                            x,
+                            y: bounds.center_y() - ICON_SIZE / 2.0,
+                            width: ICON_SIZE,
+                            height: ICON_SIZE,
+                        },
+                        style.text_color,
+                    );
+                    x += ICON_SIZE + SPACING;
+                }
+
+                draw_label(
+                    renderer,
+                    &node.label,
+                    Rectangle {
+                        x,
+                        width: (bounds.x + bounds.width - x - SPACING).max(0.0),
+                        ..bounds
+                    },
+                    style.text_color,
+                );
+            }
+        });
+
+        state.scroll.draw(renderer, theme, bounds, cursor);
+    }
+
+    fn mouse_interaction(
+        &self,
+        tree: &Tree,
+        layout: Layout<'_>,
+        cursor: Cursor,
+        _viewport: &Rectangle,
+        _renderer: &Renderer,
+    ) -> mouse::Interaction {
+        let state = tree.state.downcast_ref::<State>();
+
+        match self.hit(state, layout.bounds(), cursor) {
+            Some((index, Part::Chevron)) if self.rows[index].id().is_some() => {
+                mouse::Interaction::Pointer
+            }
+            Some((_, Part::Checkbox)) => mouse::Interaction::Pointer,
+            _ => mouse::Interaction::default(),
+        }
+    }
+}
+
+impl<'a, Message> From<TreeView<'a, Message>> for Element<'a, Message>
+where
+    Message: 'a,
+{
+    fn from(tree_view: TreeView<'a, Message>) -> Self {
+        Self::new(tree_view)
+    }
+}
+
+fn draw_label(renderer: &mut Renderer, label: &str, bounds: Rectangle, colour: Color) {
+    renderer.fill_text(
+        Text {
+            content: label.to_owned(),
+            bounds: bounds.size(),
+            size: Pixels(text::scaled(14.0)),
+            line_height: LineHeight::Absolute(Pixels(text::scaled(20.0))),
+            font: font::text(),
+            horizontal_alignment: Horizontal::Left,
+            vertical_alignment: Vertical::Center,
+            shaping: Shaping::Advanced,
+            wrapping: Wrapping::None,
+        },
+        Point::new(bounds.x, bounds.center_y()),
+        colour,
+        bounds,
+    );
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    // root
+    // ├ a
+    // │ ├ a1
+    // │ └ a2
+    // └ b
+    fn content() -> (Content, [NodeId; 5]) {
+        let mut content = Content::new();
+        let root = content.push(None, Item::new("root"));
+        let a = content.push(Some(root), Item::new("a"));
+        let a1 = content.push(Some(a), Item::new("a1"));
+        let a2 = content.push(Some(a), Item::new("a2"));
+        let b = content.push(Some(root), Item::new("b"));
+
+        (content, [root, a, a1, a2, b])
+    }
+
+    #[test]
+    fn checking_a_node_checks_its_descendants() {
+        let (mut content, [root, a, a1, a2, b]) = content();
+
+        content.perform(Action::Check(root));
+
+        assert_eq!(content.checked().collect::<Vec<_>>(), [root, a, a1, a2, b]);
+
+        content.perform(Action::Check(a));
+
+        assert_eq!(content.checked().collect::<Vec<_>>(), [b]);
+        assert_eq!(content.check_state(root), ThreeState::Indeterminate);
+    }
+
+    #[test]
+    fn parents_are_indeterminate_until_every_child_matches() {
+        let (mut content, [root, a, a1, a2, b]) = content();
+
+        content.perform(Action::Check(a1));
+
+        assert_eq!(content.check_state(a), ThreeState::Indeterminate);
+        assert_eq!(content.check_state(root), ThreeState::Indeterminate);
+
+        content.perform(Action::Check(a2));
+
+        assert_eq!(content.check_state(a), ThreeState::Checked);
+        assert_eq!(content.check_state(root), ThreeState::Indeterminate);
+
+        content.perform(Action::Check(b));
+
+        assert_eq!(content.check_state(root), ThreeState::Checked);
+
+        content.perform(Action::Check(a1));
+        content.perform(Action::Check(a2));
+        content.perform(Action::Check(b));
+
+        assert_eq!(content.check_state(a), ThreeState::Unchecked);
+        assert_eq!(content.check_state(root), ThreeState::Unchecked);
+    }
+
+    #[test]
+    fn checking_an_indeterminate_node_checks_it() {
+        let (mut content, [root, a, a1, ..]) = content();
+
+        content.perform(Action::Check(a1));
+        content.perform(Action::Check(a));
+
+        assert_eq!(content.check_state(a), ThreeState::Checked);
+        assert_eq!(content.check_state(root), ThreeState::Indeterminate);
+    }
+
+    #[test]
+    fn children_of_a_checked_node_start_checked() {
+        let (mut content, [_, a, ..]) = content();
+
+        content.perform(Action::Check(a));
+        let a3 = content.push(Some(a), Item::new("a3"));
+
+        assert_eq!(content.check_state(a3), ThreeState::Checked);
+        assert_eq!(content.check_state(a), ThreeState::Checked);
+    }
+}
@@ -0,0 +1,347 @@
+// Vertical scrolling for widgets that only draw the rows in view, so they can hold
+// thousands of rows without laying each of them out. The widget keeps a `Scroll`
+// in its tree state, tells it how tall the rows are on every layout and asks it
+// which of them are visible when drawing. A horizontal one does the same for
+// columns.
+use crate::{
+    motion::{self, Animated, Easing},
+    theme::Theme,
+};
+
+use iced::{
+    advanced::{renderer, Renderer as _, Shell},
+    border, event,
+    mouse::{self, Cursor},
+    touch,
+    widget::scrollable,
+    window, Background, Event, Point, Rectangle,
+};
+
+use std::{ops::Range, time::Instant};
+
+type Renderer = iced::Renderer;
+
+// Matches `scrollable::scrollbar::standard`
+const RAIL_WIDTH: f32 = 12.0;
+const SCROLLER_WIDTH: f32 = 6.0;
+const MIN_SCROLLER_HEIGHT: f32 = 24.0;
+const LINE: f32 = 48.0;
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+enum Axis {
+    Vertical,
+    Horizontal,
+}
+
+pub struct Scroll {
+    axis: Axis,
+    offset: Animated<f32>,
+    now: Instant,
+    // Length of all the rows and of the area they're shown in
+    extent: f32,
+    viewport: f32,
+    // Where on the scroller it was grabbed
+    grab: Option<f32>,
+}
+
+impl Default for Scroll {
+    fn default() -> Self {
+        Self {
+            axis: Axis::Vertical,
+            offset: Animated::new(0.0)
+                .duration(motion::FAST)
+                .easing(Easing::FAST_OUT_SLOW_IN),
+            now: Instant::now(),
+            extent: 0.0,
+            viewport: 0.0,
+            grab: None,
+        }
+    }
+}
+
+impl Scroll {
+    pub fn horizontal() -> Self {
+        Self {
+            axis: Axis::Horizontal,
+            ..Self::default()
+        }
+    }
+
+    pub fn offset(&self) -> f32 {
+        self.offset.value(self.now)
+    }
+
+    fn max_offset(&self) -> f32 {
+        (self.extent - self.viewport).max(0.0)
+    }
+
+    pub fn is_scrollable(&self) -> bool {
+        self.extent > self.viewport
+    }
+
+    // Called on every layout, rows removed from the end pull the offset back
+    pub fn resize(&mut self, extent: f32, viewport: f32) {
+        self.extent = extent;
+        self.viewport = viewport;
+
+        if self.offset.target() > self.max_offset() {
+            self.offset.set(self.max_offset());
+        }
+    }
+
+    // Moves the offset straight away, for keeping the rows in view still when rows
+    // above them turn out taller or shorter than estimated
+    pub fn shift(&mut self, delta: f32) {
+        self.offset
+            .set((self.offset.target() + delta).clamp(0.0, self.max_offset()));
+    }
+
+    fn scroll_to(&mut self, offset: f32, shell: &mut Shell<'_, impl Sized>) {
+        let offset = offset.clamp(0.0, self.max_offset());
+        let now = Instant::now();
+
+        self.now = now;
+        self.offset.go_to(offset, now);
+
+        shell.request_redraw(window::RedrawRequest::NextFrame);
+    }
+
+    pub fn scroll_by(&mut self, delta: f32, shell: &mut Shell<'_, impl Sized>) {
+        self.scroll_to(self.offset.target() + delta, shell);
+    }
+
+    // Scrolls as little as it takes to bring the span from `top` to `bottom` into view
+    pub fn show(&mut self, top: f32, bottom: f32, shell: &mut Shell<'_, impl Sized>) {
+        let target = self.offset.target();
+        let offset = target.max(bottom - self.viewport).min(top);
+
+        if offset != target {
+            self.scroll_to(offset, shell);
+        }
+    }
+
+    // The rows of `row_height` at least partly in view, with `buffer` more on
+    // either side
+    pub fn visible(&self, row_height: f32, count: usize, buffer: usize) -> Range<usize> {
+        if row_height <= 0.0 {
+            return 0..0;
+        }
+
+        let offset = self.offset();
+        let first = (offset / row_height).floor() as usize;
+        let last = ((offset + self.viewport) / row_height).ceil() as usize;
+
+        first.saturating_sub(buffer).min(count)..(last + buffer).min(count)
+    }
+
+    fn rail(&self, bounds: Rectangle) -> Option<Rectangle> {
+        self.is_scrollable().then_some(match self.axis {
+            Axis::Vertical => Rectangle {
+                x: bounds.x + bounds.width - RAIL_WIDTH,
+                width: RAIL_WIDTH,
+                ..bounds
+            },
+            Axis::Horizontal => Rectangle {
+                y: bounds.y + bounds.height - RAIL_WIDTH,
+                height: RAIL_WIDTH,
+                ..bounds
+            },
+        })
+    }
+
+    // Where a position falls along the axis
+    fn along(&self, point: Point) -> f32 {
+        match self.axis {
+            Axis::Vertical => point.y,
+            Axis::Horizontal => point.x,
+        }
+    }
+
+    // The rail's start and length along the axis
+    fn span(&self, rail: Rectangle) -> (f32, f32) {
+        match self.axis {
+            Axis::Vertical => (rail.y, rail.height),
+            Axis::Horizontal => (rail.x, rail.width),
+        }
+    }
+
+    fn scroller(&self, bounds: Rectangle) -> Option<Rectangle> {
+        let rail = self.rail(bounds)?;
+        let (start, length) = self.span(rail);
+        let size = (length * self.viewport / self.extent)
+            .max(MIN_SCROLLER_HEIGHT)
+            .min(length);
+        let travel = length - size;
+        let position = start + travel * self.offset() / self.max_offset().max(f32::EPSILON);
+        let inset = (RAIL_WIDTH - SCROLLER_WIDTH) / 2.0;
+
+        Some(match self.axis {
+            Axis::Vertical => Rectangle {
+                x: rail.x + inset,
+                y: position,
+                width: SCROLLER_WIDTH,
+                height: size,
+            },
+            Axis::Horizontal => Rectangle {
+                x: position,
+                y: rail.y + inset,
+                width: size,
+                height: SCROLLER_WIDTH,
+            },
+        })
+    }
+
+    // Scrollbar presses and drags, the wheel and animation frames. Everything else
+    // is left to the widget.
+    pub fn on_event(
+        &mut self,
+        event: &Event,
+        bounds: Rectangle,
+        cursor: Cursor,
+        shell: &mut Shell<'_, impl Sized>,
+    ) -> event::Status {
+        match event {
+            Event::Window(window::Event::RedrawRequested(now)) => {
+                self.now = *now;
+
+                if self.offset.is_animating(*now) {
+                    shell.request_redraw(window::RedrawRequest::NextFrame);
+                }
+
+                event::Status::Ignored
+            }
+            Event::Mouse(mouse::Event::WheelScrolled { delta }) if cursor.is_over(bounds) => {
+                let (x, y) = match delta {
+                    mouse::ScrollDelta::Lines { x, y } => (x * LINE, y * LINE),
+                    mouse::ScrollDelta::Pixels { x, y } => (*x, *y),
+                };
+
+                let delta = match self.axis {
+                    Axis::Vertical => y,
+                    Axis::Horizontal => x,
+                };
+
+                if !self.is_scrollable() || delta == 0.0 {
+                    return event::Status::Ignored;
+                }
+
+                self.scroll_by(-delta, shell);
+
+                event::Status::Captured
+            }
+            Event::Mouse(mouse::Event::ButtonPressed(mouse::Button::Left))
+            | Event::Touch(touch::Event::FingerPressed { .. }) => {
+                let (Some(rail), Some(scroller), Some(position)) = (
+                    self.rail(bounds),
+                    self.scroller(bounds),
+                    cursor.position_over(bounds),
+                ) else {
+                    return event::Status::Ignored;
+                };
+
+                if !rail.contains(position) {
+                    return event::Status::Ignored;
+                }
+
+                let scroller_start = self.along(scroller.position());
+
+                if scroller.contains(position) {
+                    self.grab = Some(self.along(position) - scroller_start);
+                } else {
+                    // Pages towards the press like a WinUI ScrollBar
+                    let page = self.viewport
+                        * if self.along(position) < scroller_start {
+                            -1.0
+                        } else {
+                            1.0
+                        };
+                    self.scroll_by(page, shell);
+                }
+
+                event::Status::Captured
+            }
+            Event::Mouse(mouse::Event::CursorMoved { position })
+            | Event::Touch(touch::Event::FingerMoved { position, .. }) => {
+                let (Some(grab), Some(rail), Some(scroller)) =
+                    (self.grab, self.rail(bounds), self.scroller(bounds))
+                else {
+                    return event::Status::Ignored;
+                };
+
+                let (start, length) = self.span(rail);
+                let (_, size) = self.span(scroller);
+                let travel = (length - size).max(f32::EPSILON);
+                let offset = (self.along(*position) - grab - start) / travel * self.max_offset();
+
+                self.offset.set(offset.clamp(0.0, self.max_offset()));
+
+                event::Status::Captured
+            }
+            Event::Mouse(mouse::Event::ButtonReleased(mouse::Button::Left))
+            | Event::Touch(touch::Event::FingerLifted { .. })
+            | Event::Touch(touch::Event::FingerLost { .. }) => {
+                if self.grab.take().is_some() {
+                    event::Status::Captured
+                } else {
+                    event::Status::Ignored
+                }
+            }
+            _ => event::Status::Ignored,
+        }
+    }
+
+    pub fn is_over_scrollbar(&self, bounds: Rectangle, cursor: Cursor) -> bool {
+        self.grab.is_some() || self.rail(bounds).is_some_and(|rail| cursor.is_over(rail))
+    }
+
+    // The rail is only filled in while the pointer is over it, as in WinUI where
+    // the scrollbar widens from a thin indicator
+    pub fn draw(&self, renderer: &mut Renderer, theme: &Theme, bounds: Rectangle, cursor: Cursor) {
+        let (Some(rail), Some(scroller)) = (self.rail(bounds), self.scroller(bounds)) else {
+            return;
+        };
+
+        let style = scrollable::Catalog::style(
+            theme,
+            &<Theme as scrollable::Catalog>::default(),
+            scrollable::Status::Active,
+        );
+
+        if self.is_over_scrollbar(bounds, cursor) {
+            if let Some(Background::Color(colour)) = style.vertical_rail.background {
+                renderer.fill_quad(
+                    renderer::Quad {
+                        bounds: rail,
+                        border: border::rounded(RAIL_WIDTH / 2.0),
+                        ..renderer::Quad::default()
+                    },
+                    colour,
+                );
+            }
+        }
+
+        renderer.fill_quad(
+            renderer::Quad {
+                bounds: scroller,
+                border: border::rounded(SCROLLER_WIDTH / 2.0),
+                ..renderer::Quad::default()
+            },
+            style.vertical_rail.scroller.color,
+        );
+    }
+
+    // Space on the right (or below, for a horizontal one) the rows should leave
+    // clear for the scrollbar
+    pub fn gutter(&self) -> f32 {
+        if self.is_scrollable() {
+            RAIL_WIDTH
+        } else {
+            0.0
+        }
+    }
+
+    // Where a point in the widget sits in the scrolled content
+    pub fn content_y(&self, bounds: Rectangle, position: Point) -> f32 {
+        position.y - bounds.y + self.offset()
+    }
+}